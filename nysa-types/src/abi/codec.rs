@@ -0,0 +1,389 @@
+use alloc::{string::String, vec::Vec};
+
+use crate::{Bytes, FixedArray, FixedBytes, Signed, Unsigned};
+
+use super::{keccak256, EncodePackedElement};
+
+/// The size of a word in the standard ABI encoding.
+const WORD_SIZE: usize = 32;
+
+/// An error returned when the data does not match the expected types.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AbiError {
+    /// The data is shorter than the encoding of the expected types.
+    UnexpectedEnd,
+    /// An offset or a length does not fit into `usize`.
+    InvalidOffset,
+    /// A word does not hold a valid value of the expected type, eg. a `bool` other than zero or one.
+    InvalidValue,
+}
+
+/// A value in the standard ABI encoding.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Token {
+    /// A static value occupying a single word, eg. `uint256` or `bytes32`.
+    Word([u8; WORD_SIZE]),
+    /// A dynamic sequence of bytes - `bytes` or `string`.
+    Bytes(Vec<u8>),
+    /// A dynamic array - `T[]`.
+    Array(Vec<Token>),
+    /// A tuple or a fixed-size array - `(T1, T2)` or `T[N]`.
+    Tuple(Vec<Token>),
+}
+
+impl Token {
+    fn is_dynamic(&self) -> bool {
+        match self {
+            Token::Word(_) => false,
+            Token::Bytes(_) | Token::Array(_) => true,
+            Token::Tuple(items) => items.iter().any(Token::is_dynamic),
+        }
+    }
+
+    /// The size of the token in the head of the enclosing tuple, a dynamic token is referenced by an offset.
+    fn head_size(&self) -> usize {
+        match self {
+            Token::Tuple(items) if !self.is_dynamic() => items.iter().map(Token::head_size).sum(),
+            _ => WORD_SIZE,
+        }
+    }
+
+    fn encode(&self, out: &mut Vec<u8>) {
+        match self {
+            Token::Word(word) => out.extend(word),
+            Token::Bytes(bytes) => {
+                out.extend(usize_to_word(bytes.len()));
+                out.extend(bytes);
+                out.resize(out.len() + padding(bytes.len()), 0);
+            }
+            Token::Array(items) => {
+                out.extend(usize_to_word(items.len()));
+                encode_tuple(items, out);
+            }
+            Token::Tuple(items) => encode_tuple(items, out),
+        }
+    }
+}
+
+/// A value that can be passed to `abi.encode`.
+pub trait AbiEncode {
+    fn to_token(&self) -> Token;
+}
+
+/// A value that can be decoded with `abi.decode`.
+pub trait AbiDecode: Sized {
+    /// Whether the value is placed in the tail and referenced by an offset.
+    const IS_DYNAMIC: bool;
+    /// The size of the value in the head of the enclosing tuple.
+    const HEAD_SIZE: usize = WORD_SIZE;
+
+    /// Decodes the value which encoding starts at the given position.
+    fn decode_at(data: &[u8], pos: usize) -> Result<Self, AbiError>;
+}
+
+/// Encodes the values as a tuple (`abi.encode` in Solidity).
+pub fn encode(values: &[&dyn AbiEncode]) -> Bytes {
+    let tokens = values.iter().map(|v| v.to_token()).collect::<Vec<_>>();
+    let mut out = Vec::new();
+    encode_tuple(&tokens, &mut out);
+    Bytes(out)
+}
+
+/// Encodes the values prefixed with the given selector (`abi.encodeWithSelector` in Solidity).
+pub fn encode_with_selector(selector: FixedBytes<4>, values: &[&dyn AbiEncode]) -> Bytes {
+    let mut out = selector.0.to_vec();
+    out.extend(encode(values).0);
+    Bytes(out)
+}
+
+/// Encodes the values prefixed with the selector of the given signature,
+/// eg. `transfer(address,uint256)` (`abi.encodeWithSignature` in Solidity).
+pub fn encode_with_signature<S: AsRef<str>>(signature: S, values: &[&dyn AbiEncode]) -> Bytes {
+    let hash = keccak256(signature.as_ref());
+    encode_with_selector(FixedBytes([hash[0], hash[1], hash[2], hash[3]]), values)
+}
+
+/// Decodes a tuple of values (`abi.decode` in Solidity), a single value is decoded as a one-element tuple.
+pub fn decode<T: AbiDecode>(data: &[u8]) -> Result<T, AbiError> {
+    T::decode_at(data, 0)
+}
+
+fn encode_tuple(tokens: &[Token], out: &mut Vec<u8>) {
+    let head_size = tokens.iter().map(Token::head_size).sum::<usize>();
+    let mut tail = Vec::new();
+    for token in tokens {
+        if token.is_dynamic() {
+            out.extend(usize_to_word(head_size + tail.len()));
+            token.encode(&mut tail);
+        } else {
+            token.encode(out);
+        }
+    }
+    out.extend(tail);
+}
+
+/// Decodes an item of a tuple or an array, a dynamic item is read from the offset stored in the head.
+fn decode_item<T: AbiDecode>(data: &[u8], head_pos: usize) -> Result<T, AbiError> {
+    if T::IS_DYNAMIC {
+        let offset = read_usize(data, head_pos)?;
+        T::decode_at(data, offset)
+    } else {
+        T::decode_at(data, head_pos)
+    }
+}
+
+fn read_word(data: &[u8], pos: usize) -> Result<&[u8], AbiError> {
+    pos.checked_add(WORD_SIZE)
+        .and_then(|end| data.get(pos..end))
+        .ok_or(AbiError::UnexpectedEnd)
+}
+
+fn read_usize(data: &[u8], pos: usize) -> Result<usize, AbiError> {
+    let word = read_word(data, pos)?;
+    let (high, low) = word.split_at(WORD_SIZE - 8);
+    if high.iter().any(|b| *b != 0) {
+        return Err(AbiError::InvalidOffset);
+    }
+    let value = u64::from_be_bytes(low.try_into().map_err(|_| AbiError::InvalidOffset)?);
+    usize::try_from(value).map_err(|_| AbiError::InvalidOffset)
+}
+
+fn read_bytes(data: &[u8], pos: usize) -> Result<&[u8], AbiError> {
+    let len = read_usize(data, pos)?;
+    let start = pos + WORD_SIZE;
+    start
+        .checked_add(len)
+        .and_then(|end| data.get(start..end))
+        .ok_or(AbiError::UnexpectedEnd)
+}
+
+fn tail(data: &[u8], pos: usize) -> Result<&[u8], AbiError> {
+    data.get(pos..).ok_or(AbiError::UnexpectedEnd)
+}
+
+fn usize_to_word(value: usize) -> [u8; WORD_SIZE] {
+    let mut word = [0u8; WORD_SIZE];
+    word[WORD_SIZE - 8..].copy_from_slice(&(value as u64).to_be_bytes());
+    word
+}
+
+fn padding(len: usize) -> usize {
+    (WORD_SIZE - len % WORD_SIZE) % WORD_SIZE
+}
+
+/// A static value is encoded the same way as an element of an array passed to `abi.encodePacked`.
+fn word<T: EncodePackedElement>(value: &T) -> Token {
+    let mut out = Vec::with_capacity(WORD_SIZE);
+    value.encode_packed_element(&mut out);
+    let mut word = [0u8; WORD_SIZE];
+    word.copy_from_slice(&out);
+    Token::Word(word)
+}
+
+impl<T: AbiEncode + ?Sized> AbiEncode for &T {
+    fn to_token(&self) -> Token {
+        (**self).to_token()
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> AbiEncode for Unsigned<BITS, LIMBS> {
+    fn to_token(&self) -> Token {
+        word(self)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> AbiDecode for Unsigned<BITS, LIMBS> {
+    const IS_DYNAMIC: bool = false;
+
+    fn decode_at(data: &[u8], pos: usize) -> Result<Self, AbiError> {
+        let word = read_word(data, pos)?;
+        let (high, low) = word.split_at(WORD_SIZE - BITS / 8);
+        if high.iter().any(|b| *b != 0) {
+            return Err(AbiError::InvalidValue);
+        }
+        ruint::Uint::try_from_be_slice(low)
+            .map(Self)
+            .ok_or(AbiError::InvalidValue)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> AbiEncode for Signed<BITS, LIMBS> {
+    fn to_token(&self) -> Token {
+        word(self)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> AbiDecode for Signed<BITS, LIMBS> {
+    const IS_DYNAMIC: bool = false;
+
+    fn decode_at(data: &[u8], pos: usize) -> Result<Self, AbiError> {
+        let word = read_word(data, pos)?;
+        let (high, low) = word.split_at(WORD_SIZE - BITS / 8);
+        // the value must be sign-extended to the full word
+        let extension = if low[0] & 0x80 == 0 { 0x00 } else { 0xff };
+        if high.iter().any(|b| *b != extension) {
+            return Err(AbiError::InvalidValue);
+        }
+        ruint::Uint::try_from_be_slice(low)
+            .map(Self)
+            .ok_or(AbiError::InvalidValue)
+    }
+}
+
+impl AbiEncode for bool {
+    fn to_token(&self) -> Token {
+        word(self)
+    }
+}
+
+impl AbiDecode for bool {
+    const IS_DYNAMIC: bool = false;
+
+    fn decode_at(data: &[u8], pos: usize) -> Result<Self, AbiError> {
+        match u8::try_from(read_usize(data, pos).map_err(|_| AbiError::InvalidValue)?) {
+            Ok(0) => Ok(false),
+            Ok(1) => Ok(true),
+            _ => Err(AbiError::InvalidValue),
+        }
+    }
+}
+
+impl<const N: usize> AbiEncode for FixedBytes<N> {
+    fn to_token(&self) -> Token {
+        word(self)
+    }
+}
+
+impl<const N: usize> AbiDecode for FixedBytes<N> {
+    const IS_DYNAMIC: bool = false;
+
+    fn decode_at(data: &[u8], pos: usize) -> Result<Self, AbiError> {
+        let word = read_word(data, pos)?;
+        let (value, padding) = word.split_at(N);
+        if padding.iter().any(|b| *b != 0) {
+            return Err(AbiError::InvalidValue);
+        }
+        Self::try_from(value).map_err(|_| AbiError::InvalidValue)
+    }
+}
+
+impl AbiEncode for Bytes {
+    fn to_token(&self) -> Token {
+        Token::Bytes(self.0.clone())
+    }
+}
+
+impl AbiDecode for Bytes {
+    const IS_DYNAMIC: bool = true;
+
+    fn decode_at(data: &[u8], pos: usize) -> Result<Self, AbiError> {
+        read_bytes(data, pos).map(Bytes::from)
+    }
+}
+
+impl AbiEncode for str {
+    fn to_token(&self) -> Token {
+        Token::Bytes(self.as_bytes().to_vec())
+    }
+}
+
+impl AbiEncode for String {
+    fn to_token(&self) -> Token {
+        self.as_str().to_token()
+    }
+}
+
+impl AbiDecode for String {
+    const IS_DYNAMIC: bool = true;
+
+    // Solidity does not validate strings, so invalid sequences do not fail the decoding.
+    fn decode_at(data: &[u8], pos: usize) -> Result<Self, AbiError> {
+        read_bytes(data, pos).map(|bytes| String::from_utf8_lossy(bytes).into_owned())
+    }
+}
+
+impl<T: AbiEncode> AbiEncode for Vec<T> {
+    fn to_token(&self) -> Token {
+        Token::Array(self.iter().map(AbiEncode::to_token).collect())
+    }
+}
+
+impl<T: AbiDecode> AbiDecode for Vec<T> {
+    const IS_DYNAMIC: bool = true;
+
+    fn decode_at(data: &[u8], pos: usize) -> Result<Self, AbiError> {
+        let len = read_usize(data, pos)?;
+        let items = tail(data, pos + WORD_SIZE)?;
+        (0..len)
+            .map(|i| decode_item(items, i * T::HEAD_SIZE))
+            .collect()
+    }
+}
+
+impl<T: AbiEncode, const N: usize> AbiEncode for FixedArray<T, N> {
+    fn to_token(&self) -> Token {
+        Token::Tuple(self.0.iter().map(AbiEncode::to_token).collect())
+    }
+}
+
+impl<T: AbiDecode, const N: usize> AbiDecode for FixedArray<T, N> {
+    const IS_DYNAMIC: bool = T::IS_DYNAMIC;
+    const HEAD_SIZE: usize = if T::IS_DYNAMIC {
+        WORD_SIZE
+    } else {
+        N * T::HEAD_SIZE
+    };
+
+    fn decode_at(data: &[u8], pos: usize) -> Result<Self, AbiError> {
+        let items = tail(data, pos)?;
+        let items = (0..N)
+            .map(|i| decode_item(items, i * T::HEAD_SIZE))
+            .collect::<Result<Vec<T>, _>>()?;
+        items
+            .try_into()
+            .map(FixedArray)
+            .map_err(|_| AbiError::InvalidValue)
+    }
+}
+
+macro_rules! impl_tuple {
+    ( $( $ty:ident => $idx:tt ),+ ) => {
+        impl<$( $ty: AbiEncode ),+> AbiEncode for ($( $ty, )+) {
+            fn to_token(&self) -> Token {
+                Token::Tuple(alloc::vec![$( self.$idx.to_token() ),+])
+            }
+        }
+
+        impl<$( $ty: AbiDecode ),+> AbiDecode for ($( $ty, )+) {
+            const IS_DYNAMIC: bool = $( $ty::IS_DYNAMIC )||+;
+            const HEAD_SIZE: usize = if Self::IS_DYNAMIC {
+                WORD_SIZE
+            } else {
+                0 $( + $ty::HEAD_SIZE )+
+            };
+
+            // the position is advanced after the last item too
+            #[allow(unused_assignments)]
+            fn decode_at(data: &[u8], pos: usize) -> Result<Self, AbiError> {
+                let items = tail(data, pos)?;
+                let mut head_pos = 0;
+                Ok(($(
+                    {
+                        let item = decode_item::<$ty>(items, head_pos)?;
+                        head_pos += $ty::HEAD_SIZE;
+                        item
+                    },
+                )+))
+            }
+        }
+    };
+}
+
+impl_tuple!(A => 0);
+impl_tuple!(A => 0, B => 1);
+impl_tuple!(A => 0, B => 1, C => 2);
+impl_tuple!(A => 0, B => 1, C => 2, D => 3);
+impl_tuple!(A => 0, B => 1, C => 2, D => 3, E => 4);
+impl_tuple!(A => 0, B => 1, C => 2, D => 3, E => 4, F => 5);
+impl_tuple!(A => 0, B => 1, C => 2, D => 3, E => 4, F => 5, G => 6);
+impl_tuple!(A => 0, B => 1, C => 2, D => 3, E => 4, F => 5, G => 6, H => 7);
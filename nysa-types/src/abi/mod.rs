@@ -0,0 +1,164 @@
+use tiny_keccak::{Hasher, Keccak};
+
+use crate::FixedBytes;
+
+mod codec;
+mod packed;
+
+pub use codec::{
+    decode, encode, encode_with_selector, encode_with_signature, AbiDecode, AbiEncode, AbiError,
+    Token,
+};
+pub use packed::{encode_packed, EncodePacked, EncodePackedElement};
+
+/// Computes the Keccak-256 hash of the given bytes (`keccak256` in Solidity).
+pub fn keccak256<T: AsRef<[u8]>>(data: T) -> FixedBytes<32> {
+    let mut hasher = Keccak::v256();
+    let mut hash = [0u8; 32];
+    hasher.update(data.as_ref());
+    hasher.finalize(&mut hash);
+    FixedBytes(hash)
+}
+
+#[cfg(test)]
+mod t {
+    use super::*;
+    use crate::{Bytes, FixedArray, I16, I8, U16, U256, U32, U8};
+    use alloc::{string::String, vec::Vec};
+
+    fn hex(value: &str) -> alloc::vec::Vec<u8> {
+        const_hex::decode(value).unwrap()
+    }
+
+    #[test]
+    fn keccak() {
+        assert_eq!(
+            keccak256([]).0.to_vec(),
+            hex("c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470")
+        );
+        assert_eq!(
+            keccak256("transfer(address,uint256)").0[..4],
+            hex("a9059cbb")[..]
+        );
+        // keccak256("MINTER_ROLE") - the OpenZeppelin AccessControl role id
+        assert_eq!(
+            keccak256(b"MINTER_ROLE").0.to_vec(),
+            hex("9f2df0fed2c77648de5860a4cc508cd0818c85b8b8a1ab4ceeef8d981c8956a6")
+        );
+    }
+
+    #[test]
+    fn packed_encoding() {
+        // abi.encodePacked(int16(-1), bytes1(0x42), uint16(0x03), "Hello, world!")
+        let encoded = encode_packed(&[
+            &I16::MINUS_ONE,
+            &FixedBytes([0x42u8]),
+            &U16::from(3u16),
+            &"Hello, world!",
+        ]);
+        assert_eq!(encoded.0, hex("ffff42000348656c6c6f2c20776f726c6421"));
+
+        // abi.encodePacked(true, uint8(1), bytes("ab"))
+        let encoded = encode_packed(&[&true, &U8::from(1u8), &Bytes::from("ab")]);
+        assert_eq!(encoded.0, hex("01016162"));
+    }
+
+    #[test]
+    fn packed_encoding_of_arrays() {
+        // abi.encodePacked([uint8(1), uint8(2)]) - array elements are padded to 32 bytes
+        let array = FixedArray([U8::from(1u8), U8::from(2u8)]);
+        let mut expected = alloc::vec![0u8; 64];
+        expected[31] = 1;
+        expected[63] = 2;
+        assert_eq!(encode_packed(&[&array]).0, expected);
+
+        // signed integers are sign-extended, fixed bytes are padded on the right
+        let values = alloc::vec![I16::MINUS_ONE];
+        assert_eq!(encode_packed(&[&values]).0, alloc::vec![0xffu8; 32]);
+        let values = alloc::vec![FixedBytes([0xabu8, 0xcd])];
+        let mut expected = alloc::vec![0u8; 32];
+        expected[0] = 0xab;
+        expected[1] = 0xcd;
+        assert_eq!(encode_packed(&[&values]).0, expected);
+
+        let values = alloc::vec![U256::MAX];
+        assert_eq!(encode_packed(&[&values]).0, alloc::vec![0xffu8; 32]);
+    }
+
+    #[test]
+    fn standard_encoding() {
+        // the example from the Solidity docs:
+        // f(0x123, [0x456, 0x789], "1234567890", "Hello, world!")
+        let array = alloc::vec![U32::from(0x456u32), U32::from(0x789u32)];
+        let fixed = FixedBytes(*b"1234567890");
+        let bytes = Bytes::from("Hello, world!");
+        let values: [&dyn AbiEncode; 4] = [&U256::from(0x123u32), &array, &fixed, &bytes];
+        let expected = hex(concat!(
+            "0000000000000000000000000000000000000000000000000000000000000123",
+            "0000000000000000000000000000000000000000000000000000000000000080",
+            "3132333435363738393000000000000000000000000000000000000000000000",
+            "00000000000000000000000000000000000000000000000000000000000000e0",
+            "0000000000000000000000000000000000000000000000000000000000000002",
+            "0000000000000000000000000000000000000000000000000000000000000456",
+            "0000000000000000000000000000000000000000000000000000000000000789",
+            "000000000000000000000000000000000000000000000000000000000000000d",
+            "48656c6c6f2c20776f726c642100000000000000000000000000000000000000",
+        ));
+        assert_eq!(encode(&values).0, expected);
+
+        let encoded = encode_with_signature("f(uint256,uint32[],bytes10,bytes)", &values);
+        assert_eq!(encoded.0[..4], hex("8be65246")[..]);
+        assert_eq!(encoded.0[4..], expected[..]);
+
+        let encoded = encode_with_selector(FixedBytes([0x8b, 0xe6, 0x52, 0x46]), &values);
+        assert_eq!(encoded.0[4..], expected[..]);
+
+        let decoded = decode::<(U256, Vec<U32>, FixedBytes<10>, Bytes)>(&expected).unwrap();
+        assert_eq!(decoded, (U256::from(0x123u32), array, fixed, bytes));
+    }
+
+    #[test]
+    fn standard_encoding_round_trip() {
+        let strings = alloc::vec![String::from("one"), String::from("two")];
+        let fixed = FixedArray([String::from("a"), String::from("")]);
+        let signed = I16::MINUS_ONE;
+        let encoded = encode(&[&strings, &fixed, &signed, &true]);
+        assert_eq!(
+            decode::<(Vec<String>, FixedArray<String, 2>, I16, bool)>(&encoded).unwrap(),
+            (strings, fixed, signed, true)
+        );
+
+        let nested = alloc::vec![FixedArray([U8::from(1u8), U8::from(2u8)])];
+        let encoded = encode(&[&nested, &(U8::from(3u8), Bytes::from("xyz"))]);
+        assert_eq!(
+            decode::<(Vec<FixedArray<U8, 2>>, (U8, Bytes))>(&encoded).unwrap(),
+            (nested, (U8::from(3u8), Bytes::from("xyz")))
+        );
+    }
+
+    #[test]
+    fn invalid_standard_encoding() {
+        let mut word = [0u8; 32];
+        word[31] = 2;
+        assert_eq!(decode::<(bool,)>(&word), Err(AbiError::InvalidValue));
+        assert_eq!(
+            decode::<(U8,)>(&[0u8; 32][..31]),
+            Err(AbiError::UnexpectedEnd)
+        );
+
+        // uint8(256) and a negative int8 which is not sign-extended
+        word[30] = 1;
+        word[31] = 0;
+        assert_eq!(decode::<(U8,)>(&word), Err(AbiError::InvalidValue));
+        let mut word = [0u8; 32];
+        word[31] = 0xff;
+        assert_eq!(decode::<(I8,)>(&word), Err(AbiError::InvalidValue));
+        word[0..31].fill(0xff);
+        assert_eq!(decode::<(I8,)>(&word), Ok((I8::MINUS_ONE,)));
+
+        // the offset of a string points past the data
+        let mut word = [0u8; 32];
+        word[31] = 0x40;
+        assert_eq!(decode::<(String,)>(&word), Err(AbiError::UnexpectedEnd));
+    }
+}
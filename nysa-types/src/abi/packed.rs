@@ -0,0 +1,130 @@
+use alloc::{string::String, vec::Vec};
+
+use crate::{Bytes, FixedArray, FixedBytes, Signed, Unsigned};
+
+/// The size of a word in the standard ABI encoding.
+const WORD_SIZE: usize = 32;
+
+/// A value that can be passed to `abi.encodePacked`.
+///
+/// In the packed mode, static types take as many bytes as they need (eg. `uint16` takes two bytes,
+/// `bytes3` three bytes), dynamic types are encoded in place without the length, and array elements
+/// are padded to 32 bytes.
+pub trait EncodePacked {
+    /// Appends the packed encoding of the value to the buffer.
+    fn encode_packed(&self, out: &mut Vec<u8>);
+}
+
+/// A value that can be an element of an array passed to `abi.encodePacked`.
+pub trait EncodePackedElement: EncodePacked {
+    /// Appends the value padded to 32 bytes to the buffer.
+    fn encode_packed_element(&self, out: &mut Vec<u8>);
+}
+
+/// Concatenates the packed encodings of the values (`abi.encodePacked` in Solidity).
+pub fn encode_packed(values: &[&dyn EncodePacked]) -> Bytes {
+    let mut out = Vec::new();
+    for value in values {
+        value.encode_packed(&mut out);
+    }
+    Bytes(out)
+}
+
+impl<T: EncodePacked + ?Sized> EncodePacked for &T {
+    fn encode_packed(&self, out: &mut Vec<u8>) {
+        (**self).encode_packed(out)
+    }
+}
+
+impl<T: EncodePackedElement + ?Sized> EncodePackedElement for &T {
+    fn encode_packed_element(&self, out: &mut Vec<u8>) {
+        (**self).encode_packed_element(out)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> EncodePacked for Unsigned<BITS, LIMBS> {
+    fn encode_packed(&self, out: &mut Vec<u8>) {
+        out.extend(self.0.to_be_bytes_vec());
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> EncodePackedElement for Unsigned<BITS, LIMBS> {
+    fn encode_packed_element(&self, out: &mut Vec<u8>) {
+        let bytes = self.0.to_be_bytes_vec();
+        out.resize(out.len() + WORD_SIZE - bytes.len(), 0);
+        out.extend(bytes);
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> EncodePacked for Signed<BITS, LIMBS> {
+    fn encode_packed(&self, out: &mut Vec<u8>) {
+        out.extend(self.0.to_be_bytes_vec());
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> EncodePackedElement for Signed<BITS, LIMBS> {
+    fn encode_packed_element(&self, out: &mut Vec<u8>) {
+        let bytes = self.0.to_be_bytes_vec();
+        let padding = if self.is_negative() { 0xff } else { 0x00 };
+        out.resize(out.len() + WORD_SIZE - bytes.len(), padding);
+        out.extend(bytes);
+    }
+}
+
+impl EncodePacked for bool {
+    fn encode_packed(&self, out: &mut Vec<u8>) {
+        out.push(*self as u8);
+    }
+}
+
+impl EncodePackedElement for bool {
+    fn encode_packed_element(&self, out: &mut Vec<u8>) {
+        out.extend([0u8; WORD_SIZE - 1]);
+        out.push(*self as u8);
+    }
+}
+
+impl<const N: usize> EncodePacked for FixedBytes<N> {
+    fn encode_packed(&self, out: &mut Vec<u8>) {
+        out.extend(self.0);
+    }
+}
+
+impl<const N: usize> EncodePackedElement for FixedBytes<N> {
+    fn encode_packed_element(&self, out: &mut Vec<u8>) {
+        out.extend(self.0);
+        out.resize(out.len() + WORD_SIZE - N, 0);
+    }
+}
+
+impl EncodePacked for Bytes {
+    fn encode_packed(&self, out: &mut Vec<u8>) {
+        out.extend(&self.0);
+    }
+}
+
+impl EncodePacked for str {
+    fn encode_packed(&self, out: &mut Vec<u8>) {
+        out.extend(self.as_bytes());
+    }
+}
+
+impl EncodePacked for String {
+    fn encode_packed(&self, out: &mut Vec<u8>) {
+        out.extend(self.as_bytes());
+    }
+}
+
+impl<T: EncodePackedElement> EncodePacked for Vec<T> {
+    fn encode_packed(&self, out: &mut Vec<u8>) {
+        self.iter().for_each(|item| item.encode_packed_element(out));
+    }
+}
+
+impl<T: EncodePackedElement, const N: usize> EncodePacked for FixedArray<T, N> {
+    fn encode_packed(&self, out: &mut Vec<u8>) {
+        self.0
+            .iter()
+            .for_each(|item| item.encode_packed_element(out));
+    }
+}
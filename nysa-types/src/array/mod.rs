@@ -0,0 +1,97 @@
+use core::ops;
+
+use derive_more::{Deref, DerefMut, From};
+
+/// An array of fixed length (`T[N]` in Solidity).
+///
+/// Unlike `[T; N]`, the type has a default value for any length, so a fixed-size array kept
+/// in the storage is always initialized with `N` default elements.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Deref, DerefMut, From)]
+#[repr(transparent)]
+pub struct FixedArray<T, const N: usize>(pub [T; N]);
+
+impl<T: Default, const N: usize> Default for FixedArray<T, N> {
+    #[inline]
+    fn default() -> Self {
+        Self(core::array::from_fn(|_| T::default()))
+    }
+}
+
+impl<T, const N: usize> FixedArray<T, N> {
+    #[inline]
+    pub const fn new(items: [T; N]) -> Self {
+        Self(items)
+    }
+}
+
+impl<T, const N: usize> From<FixedArray<T, N>> for [T; N] {
+    #[inline]
+    fn from(value: FixedArray<T, N>) -> Self {
+        value.0
+    }
+}
+
+impl<T, const N: usize> ops::Index<usize> for FixedArray<T, N> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.0[index]
+    }
+}
+
+impl<T, const N: usize> ops::IndexMut<usize> for FixedArray<T, N> {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.0[index]
+    }
+}
+
+impl<T, const N: usize> IntoIterator for FixedArray<T, N> {
+    type Item = T;
+    type IntoIter = core::array::IntoIter<T, N>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::U256;
+
+    #[test]
+    fn default_value() {
+        let a = FixedArray::<U256, 40>::default();
+
+        assert_eq!(a.len(), 40);
+        assert!(a.iter().all(|v| *v == U256::ZERO));
+    }
+
+    #[test]
+    fn index_access() {
+        let mut a = FixedArray::new([U256::ZERO, U256::ONE]);
+        a[0] = U256::ONE;
+
+        assert_eq!(a[0], U256::ONE);
+        assert_eq!(a[U256::ONE.as_usize()], U256::ONE);
+    }
+
+    #[test]
+    #[should_panic]
+    fn index_access_out_of_bounds() {
+        let a = FixedArray::new([U256::ZERO, U256::ONE]);
+
+        let _ = a[U256::from_limbs([2, 0, 0, 0]).as_usize()];
+    }
+
+    #[test]
+    #[should_panic]
+    fn index_out_of_usize_range() {
+        let a = FixedArray::new([U256::ZERO, U256::ONE]);
+
+        let _ = a[U256::MAX.as_usize()];
+    }
+}
@@ -0,0 +1,153 @@
+use alloc::{string::String, vec::Vec};
+use core::ops;
+
+use derive_more::{Deref, DerefMut, From, IntoIterator};
+
+use super::FixedBytes;
+
+/// A dynamically-sized byte array (`bytes` in Solidity).
+#[derive(
+    Clone, Default, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Deref, DerefMut, From, IntoIterator,
+)]
+pub struct Bytes(#[into_iterator(owned, ref, ref_mut)] pub Vec<u8>);
+
+impl Bytes {
+    #[inline]
+    pub const fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    /// Concatenates byte slices into a new `Bytes` (`bytes.concat(...)` in Solidity).
+    pub fn concat(parts: &[&[u8]]) -> Self {
+        Self(parts.concat())
+    }
+
+    /// Returns the byte at the given index as `bytes1` (`b[i]` in Solidity),
+    /// or `None` if the index is out of bounds.
+    #[inline]
+    pub fn at(&self, index: usize) -> Option<FixedBytes<1>> {
+        self.0.get(index).map(|b| FixedBytes([*b]))
+    }
+
+    /// Replaces the byte at the given index (`b[i] = v` in Solidity),
+    /// returns `None` if the index is out of bounds.
+    #[inline]
+    pub fn set(&mut self, index: usize, value: FixedBytes<1>) -> Option<()> {
+        self.0.get_mut(index).map(|b| *b = value.0[0])
+    }
+
+    /// Interprets the bytes as a UTF-8 string (`string(b)` in Solidity).
+    ///
+    /// Solidity does not validate the bytes, so the conversion never fails - as a Rust `String`
+    /// must be valid UTF-8, each invalid sequence is replaced with `U+FFFD`.
+    pub fn to_utf8_string(&self) -> String {
+        String::from_utf8_lossy(&self.0).into_owned()
+    }
+}
+
+impl From<&[u8]> for Bytes {
+    #[inline]
+    fn from(bytes: &[u8]) -> Self {
+        Self(bytes.to_vec())
+    }
+}
+
+impl From<&str> for Bytes {
+    #[inline]
+    fn from(value: &str) -> Self {
+        Self(value.as_bytes().to_vec())
+    }
+}
+
+impl From<String> for Bytes {
+    #[inline]
+    fn from(value: String) -> Self {
+        Self(value.into_bytes())
+    }
+}
+
+impl<const N: usize> From<FixedBytes<N>> for Bytes {
+    #[inline]
+    fn from(value: FixedBytes<N>) -> Self {
+        Self(value.0.to_vec())
+    }
+}
+
+impl From<Bytes> for Vec<u8> {
+    #[inline]
+    fn from(value: Bytes) -> Self {
+        value.0
+    }
+}
+
+impl AsRef<[u8]> for Bytes {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl AsMut<[u8]> for Bytes {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+impl ops::Index<usize> for Bytes {
+    type Output = u8;
+
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.0[index]
+    }
+}
+
+impl ops::IndexMut<usize> for Bytes {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.0[index]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn concat() {
+        let a = Bytes::from("ab");
+        let b = FixedBytes::new([0x01, 0x02]);
+
+        let actual = Bytes::concat(&[a.as_slice(), b.as_slice(), &[]]);
+        let expected = Bytes(alloc::vec![b'a', b'b', 0x01, 0x02]);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn index_access() {
+        let mut a = Bytes::from("abc");
+
+        assert_eq!(a.len(), 3);
+        assert_eq!(a.at(1), Some(FixedBytes::new([b'b'])));
+
+        assert_eq!(a.set(1, FixedBytes::new([b'x'])), Some(()));
+        assert_eq!(a.to_utf8_string(), "axc");
+    }
+
+    #[test]
+    fn index_access_out_of_bounds() {
+        let mut a = Bytes::from("abc");
+
+        assert_eq!(a.at(3), None);
+        assert_eq!(a.set(3, FixedBytes::new([b'x'])), None);
+        assert_eq!(a, Bytes::from("abc"));
+    }
+
+    #[test]
+    fn invalid_utf8() {
+        let a = Bytes(alloc::vec![b'a', 0xff]);
+
+        assert_eq!(a.to_utf8_string(), "a\u{FFFD}");
+    }
+}
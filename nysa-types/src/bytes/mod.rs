@@ -2,8 +2,13 @@ use core::{iter, ops, str};
 
 use derive_more::{Deref, DerefMut, From, Index, IndexMut, IntoIterator};
 
+mod dynamic;
 mod formatting;
 
+pub use dynamic::Bytes;
+
+use crate::{Unsigned, U8};
+
 /// A byte array of fixed length (`[u8; N]`).
 ///
 /// This type allows to control serialization, deserialization or bitwise arithmetic on fixed-length
@@ -176,6 +181,74 @@ impl<const N: usize> ops::BitXorAssign for FixedBytes<N> {
     }
 }
 
+impl<const N: usize> ops::Not for FixedBytes<N> {
+    type Output = Self;
+
+    #[inline]
+    fn not(self) -> Self::Output {
+        self.bit_not()
+    }
+}
+
+impl<const N: usize> ops::Shl<usize> for FixedBytes<N> {
+    type Output = Self;
+
+    #[inline]
+    fn shl(self, rhs: usize) -> Self::Output {
+        self.shift_left(rhs)
+    }
+}
+
+impl<const N: usize> ops::Shr<usize> for FixedBytes<N> {
+    type Output = Self;
+
+    #[inline]
+    fn shr(self, rhs: usize) -> Self::Output {
+        self.shift_right(rhs)
+    }
+}
+
+// A shift amount of any unsigned integer type, eg. `b << 8` where `8` is `uint256`.
+impl<const N: usize, const BITS: usize, const LIMBS: usize> ops::Shl<Unsigned<BITS, LIMBS>>
+    for FixedBytes<N>
+{
+    type Output = Self;
+
+    #[inline]
+    fn shl(self, rhs: Unsigned<BITS, LIMBS>) -> Self::Output {
+        self.shift_left(rhs.saturating_to())
+    }
+}
+
+impl<const N: usize, const BITS: usize, const LIMBS: usize> ops::ShlAssign<Unsigned<BITS, LIMBS>>
+    for FixedBytes<N>
+{
+    #[inline]
+    fn shl_assign(&mut self, rhs: Unsigned<BITS, LIMBS>) {
+        *self = *self << rhs;
+    }
+}
+
+impl<const N: usize, const BITS: usize, const LIMBS: usize> ops::Shr<Unsigned<BITS, LIMBS>>
+    for FixedBytes<N>
+{
+    type Output = Self;
+
+    #[inline]
+    fn shr(self, rhs: Unsigned<BITS, LIMBS>) -> Self::Output {
+        self.shift_right(rhs.saturating_to())
+    }
+}
+
+impl<const N: usize, const BITS: usize, const LIMBS: usize> ops::ShrAssign<Unsigned<BITS, LIMBS>>
+    for FixedBytes<N>
+{
+    #[inline]
+    fn shr_assign(&mut self, rhs: Unsigned<BITS, LIMBS>) {
+        *self = *self >> rhs;
+    }
+}
+
 impl<const N: usize> str::FromStr for FixedBytes<N> {
     type Err = const_hex::FromHexError;
 
@@ -207,6 +280,19 @@ impl<const N: usize> FixedBytes<N> {
         true
     }
 
+    /// Returns the byte at the given index as `bytes1` (`b[i]` in Solidity),
+    /// or `None` if the index is out of bounds.
+    #[inline]
+    pub fn at(&self, index: usize) -> Option<FixedBytes<1>> {
+        self.0.get(index).map(|b| FixedBytes([*b]))
+    }
+
+    /// Returns the number of bytes (`b.length` in Solidity).
+    #[inline]
+    pub const fn length(&self) -> U8 {
+        U8::from_limbs([N as u64])
+    }
+
     /// Returns `true` if no bits are set.
     #[inline]
     pub fn is_zero(&self) -> bool {
@@ -288,6 +374,53 @@ impl<const N: usize> FixedBytes<N> {
         lsh.bit_xor(rhs)
     }
 
+    /// Computes the bitwise NOT of `FixedBytes`.
+    pub const fn bit_not(self) -> Self {
+        let mut ret = Self::ZERO;
+        let mut i = 0;
+        while i < N {
+            ret.0[i] = !self.0[i];
+            i += 1;
+        }
+        ret
+    }
+
+    /// Shifts the bits towards the first byte, the vacated bits are zeroed.
+    ///
+    /// Shifting by `8 * N` bits or more results in zero.
+    pub const fn shift_left(self, bits: usize) -> Self {
+        let mut ret = Self::ZERO;
+        let (bytes, bits) = (bits / 8, bits % 8);
+        let mut i = 0;
+        while i + bytes < N {
+            let src = i + bytes;
+            ret.0[i] = self.0[src] << bits;
+            if bits > 0 && src + 1 < N {
+                ret.0[i] |= self.0[src + 1] >> (8 - bits);
+            }
+            i += 1;
+        }
+        ret
+    }
+
+    /// Shifts the bits towards the last byte, the vacated bits are zeroed.
+    ///
+    /// Shifting by `8 * N` bits or more results in zero.
+    pub const fn shift_right(self, bits: usize) -> Self {
+        let mut ret = Self::ZERO;
+        let (bytes, bits) = (bits / 8, bits % 8);
+        let mut i = bytes;
+        while i < N {
+            let src = i - bytes;
+            ret.0[i] = self.0[src] >> bits;
+            if bits > 0 && src > 0 {
+                ret.0[i] |= self.0[src - 1] << (8 - bits);
+            }
+            i += 1;
+        }
+        ret
+    }
+
     pub const fn resize<const M: usize>(self) -> FixedBytes<M> {
         let mut result = [0u8; M];
         let mut i = 0;
@@ -385,6 +518,42 @@ mod tests {
         assert_eq!(a[2], 0b1010);
     }
 
+    #[test]
+    fn at() {
+        let a = FixedBytes::new([0x12, 0x34]);
+
+        assert_eq!(a.at(1), Some(FixedBytes::new([0x34])));
+        assert_eq!(a.at(2), None);
+        assert_eq!(a.length(), U8::from_limbs([2]));
+    }
+
+    #[test]
+    fn not() {
+        let a = FixedBytes::new([0b1100_0000, 0xff]);
+
+        assert_eq!(!a, FixedBytes::new([0b0011_1111, 0x00]));
+    }
+
+    #[test]
+    fn shifts() {
+        let a = FixedBytes::new([0x12, 0x34, 0x56]);
+
+        assert_eq!(a << 4, FixedBytes::new([0x23, 0x45, 0x60]));
+        assert_eq!(a << 8, FixedBytes::new([0x34, 0x56, 0x00]));
+        assert_eq!(a << 12, FixedBytes::new([0x45, 0x60, 0x00]));
+        assert_eq!(a >> 4, FixedBytes::new([0x01, 0x23, 0x45]));
+        assert_eq!(a >> 12, FixedBytes::new([0x00, 0x01, 0x23]));
+        assert_eq!(a << 0, a);
+        assert_eq!(a << 24, FixedBytes::ZERO);
+        assert_eq!(a >> 100, FixedBytes::ZERO);
+
+        // a shift amount of an integer type
+        let mut b = a;
+        b <<= crate::U256::from_limbs([8, 0, 0, 0]);
+        assert_eq!(b, FixedBytes::new([0x34, 0x56, 0x00]));
+        assert_eq!(a >> crate::U256::MAX, FixedBytes::ZERO);
+    }
+
     #[test]
     fn comparisons() {
         assert!(
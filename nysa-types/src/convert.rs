@@ -0,0 +1,154 @@
+//! Explicit type conversions (`T(value)` in Solidity).
+//!
+//! Only the conversions allowed by the Solidity compiler are implemented:
+//! * between integers of the same signedness - the higher-order bits are cut off or the value is
+//!   zero/sign-extended,
+//! * between a signed and an unsigned integer of the same size - the bits are reinterpreted,
+//! * between fixed-size byte arrays - the value is cut off or padded on the right,
+//! * between a fixed-size byte array and an unsigned integer of the same size,
+//! * from a dynamic byte array to a fixed-size byte array.
+use crate::{unsigned::*, Bytes, FixedBytes, Signed};
+
+/// Performs an explicit conversion, the counterpart of [From] following the Solidity rules.
+pub trait ConvertFrom<T>: Sized {
+    fn convert_from(value: T) -> Self;
+}
+
+/// The reciprocal of [ConvertFrom], implemented for each type that can be converted.
+pub trait ConvertInto<T> {
+    fn convert_into(self) -> T;
+}
+
+impl<T, U: ConvertFrom<T>> ConvertInto<U> for T {
+    fn convert_into(self) -> U {
+        U::convert_from(self)
+    }
+}
+
+impl<const B: usize, const L: usize, const BITS: usize, const LIMBS: usize>
+    ConvertFrom<Unsigned<B, L>> for Unsigned<BITS, LIMBS>
+{
+    fn convert_from(value: Unsigned<B, L>) -> Self {
+        Unsigned(value.0.wrapping_to())
+    }
+}
+
+impl<const B: usize, const L: usize, const BITS: usize, const LIMBS: usize>
+    ConvertFrom<Signed<B, L>> for Signed<BITS, LIMBS>
+{
+    fn convert_from(value: Signed<B, L>) -> Self {
+        // The complement of a negative value is non-negative, so it is extended with zeros,
+        // the complement of the result has the higher-order bits set.
+        if value.is_negative() {
+            Signed(!(!value.0).wrapping_to::<ruint::Uint<BITS, LIMBS>>())
+        } else {
+            Signed(value.0.wrapping_to())
+        }
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> ConvertFrom<Signed<BITS, LIMBS>>
+    for Unsigned<BITS, LIMBS>
+{
+    fn convert_from(value: Signed<BITS, LIMBS>) -> Self {
+        Unsigned(value.0)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> ConvertFrom<Unsigned<BITS, LIMBS>>
+    for Signed<BITS, LIMBS>
+{
+    fn convert_from(value: Unsigned<BITS, LIMBS>) -> Self {
+        Signed(value.0)
+    }
+}
+
+impl<const M: usize, const N: usize> ConvertFrom<FixedBytes<M>> for FixedBytes<N> {
+    fn convert_from(value: FixedBytes<M>) -> Self {
+        left_aligned(&value.0)
+    }
+}
+
+impl<const N: usize> ConvertFrom<Bytes> for FixedBytes<N> {
+    fn convert_from(value: Bytes) -> Self {
+        left_aligned(&value.0)
+    }
+}
+
+fn left_aligned<const N: usize>(bytes: &[u8]) -> FixedBytes<N> {
+    let mut result = [0u8; N];
+    let len = N.min(bytes.len());
+    result[..len].copy_from_slice(&bytes[..len]);
+    FixedBytes(result)
+}
+
+macro_rules! impl_bytes_conversion {
+    ($($size:literal => $ty:ty),*) => {
+        $(
+            impl ConvertFrom<FixedBytes<$size>> for $ty {
+                fn convert_from(value: FixedBytes<$size>) -> Self {
+                    Unsigned(ruint::Uint::from_be_bytes(value.0))
+                }
+            }
+
+            impl ConvertFrom<$ty> for FixedBytes<$size> {
+                fn convert_from(value: $ty) -> Self {
+                    FixedBytes(value.0.to_be_bytes())
+                }
+            }
+        )*
+    };
+}
+
+impl_bytes_conversion!(
+    1 => U8, 2 => U16, 3 => U24, 4 => U32, 5 => U40, 6 => U48, 7 => U56, 8 => U64,
+    9 => U72, 10 => U80, 11 => U88, 12 => U96, 13 => U104, 14 => U112, 15 => U120, 16 => U128,
+    17 => U136, 18 => U144, 19 => U152, 20 => U160, 21 => U168, 22 => U176, 23 => U184, 24 => U192,
+    25 => U200, 26 => U208, 27 => U216, 28 => U224, 29 => U232, 30 => U240, 31 => U248, 32 => U256
+);
+
+#[cfg(test)]
+mod t {
+    use super::*;
+    use crate::{I16, I256, I8};
+
+    #[test]
+    fn integer_conversions() {
+        // narrowing cuts off the higher-order bits
+        assert_eq!(U8::convert_from(U16::from(0x1234u16)), U8::from(0x34u8));
+        assert_eq!(I8::convert_from(I16::from_limbs([0xff80])), I8::MIN);
+        assert_eq!(I8::convert_from(I16::from_limbs([0x0180])), I8::MIN);
+        // widening zero or sign-extends
+        assert_eq!(U256::convert_from(U8::MAX), U256::from(255u64));
+        assert_eq!(I256::convert_from(I8::MINUS_ONE), I256::MINUS_ONE);
+        assert_eq!(
+            I256::convert_from(I8::MIN),
+            I256::convert_from(I16::from_limbs([0xff80]))
+        );
+        // the bits are reinterpreted
+        assert_eq!(U8::convert_from(I8::MINUS_ONE), U8::MAX);
+        assert_eq!(I8::convert_from(U8::from(0x80u8)), I8::MIN);
+    }
+
+    #[test]
+    fn bytes_conversions() {
+        let value = FixedBytes([0x12u8, 0x34]);
+        assert_eq!(FixedBytes::<1>::convert_from(value), FixedBytes([0x12]));
+        assert_eq!(
+            FixedBytes::<4>::convert_from(value),
+            FixedBytes([0x12, 0x34, 0, 0])
+        );
+        assert_eq!(U16::convert_from(value), U16::from(0x1234u16));
+        assert_eq!(FixedBytes::<2>::convert_from(U16::from(0x1234u16)), value);
+        let value: U256 = FixedBytes([0xffu8; 32]).convert_into();
+        assert_eq!(value, U256::MAX);
+        assert_eq!(
+            FixedBytes::<3>::convert_from(Bytes::from("ab")),
+            FixedBytes([b'a', b'b', 0])
+        );
+        assert_eq!(
+            FixedBytes::<1>::convert_from(Bytes::from("ab")),
+            FixedBytes([b'a'])
+        );
+    }
+}
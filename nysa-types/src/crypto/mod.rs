@@ -0,0 +1,101 @@
+use alloc::vec::Vec;
+
+mod ripemd160;
+mod secp256k1;
+mod sha256;
+
+pub use ripemd160::ripemd160;
+pub use secp256k1::{recover, PublicKey};
+pub use sha256::sha256;
+
+/// Pads the message to a multiple of 64 bytes: a one bit, zeros and the message length in bits.
+///
+/// SHA-256 stores the length as a big-endian, RIPEMD-160 as a little-endian number.
+fn pad<const LE: bool>(data: &[u8]) -> Vec<u8> {
+    let len = (data.len() as u64).wrapping_mul(8);
+    let mut message = data.to_vec();
+    message.push(0x80);
+    message.resize((message.len() + 8).div_ceil(64) * 64 - 8, 0);
+    if LE {
+        message.extend(len.to_le_bytes());
+    } else {
+        message.extend(len.to_be_bytes());
+    }
+    message
+}
+
+#[cfg(test)]
+mod t {
+    use super::*;
+    use crate::FixedBytes;
+
+    fn hex(value: &str) -> alloc::vec::Vec<u8> {
+        const_hex::decode(value).unwrap()
+    }
+
+    fn bytes32(value: &str) -> FixedBytes<32> {
+        FixedBytes::try_from(hex(value).as_slice()).unwrap()
+    }
+
+    #[test]
+    fn sha256_hash() {
+        assert_eq!(
+            sha256([]).0.to_vec(),
+            hex("e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855")
+        );
+        assert_eq!(
+            sha256("abc").0.to_vec(),
+            hex("ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad")
+        );
+        // two blocks
+        assert_eq!(
+            sha256("abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq")
+                .0
+                .to_vec(),
+            hex("248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1")
+        );
+    }
+
+    #[test]
+    fn ripemd160_hash() {
+        assert_eq!(
+            ripemd160([]).0.to_vec(),
+            hex("9c1185a5c5e9fc54612808977ee8f548b2258d31")
+        );
+        assert_eq!(
+            ripemd160("abc").0.to_vec(),
+            hex("8eb208f7e05d987a9b044a8e98c6b087f15a0bfc")
+        );
+        assert_eq!(
+            ripemd160("abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq")
+                .0
+                .to_vec(),
+            hex("12a053384a9c0c88e405a06c27dcf49ada62eb2b")
+        );
+    }
+
+    #[test]
+    fn public_key_recovery() {
+        // signed by the key of 0xa94f5374fce5edbc8e2a8697c15331677e6ebf0b
+        let hash = bytes32("18c547e4f7b0f325ad1e56f57e26c745b09a3e503d86e00e5255ff7f715d3d1c");
+        let r = bytes32("73b1693892219d736caba55bdb67216e485557ea6b6af75f37096c9aa6a5a75f");
+        let s = bytes32("eeb940b1d03b21e36b0e47e79769f095fe2ab855bd91e3a38756b7d75a9c4549");
+
+        let key = recover(hash, 28, r, s).unwrap();
+        assert_eq!(
+            key.to_eth_address().0.to_vec(),
+            hex("a94f5374fce5edbc8e2a8697c15331677e6ebf0b")
+        );
+        assert_eq!(
+            key.to_compressed().to_vec(),
+            hex("033a514176466fa815ed481ffad09110a2d344f6c9b78c1d14afc351c3a51be33d")
+        );
+
+        // the other parity recovers a different key
+        assert_ne!(recover(hash, 27, r, s), Some(key));
+        // invalid v, r and s
+        assert_eq!(recover(hash, 29, r, s), None);
+        assert_eq!(recover(hash, 28, FixedBytes::ZERO, s), None);
+        assert_eq!(recover(hash, 28, r, FixedBytes([0xff; 32])), None);
+    }
+}
@@ -0,0 +1,221 @@
+use ruint::Uint;
+
+use crate::{keccak256, FixedBytes};
+
+type Int = Uint<256, 4>;
+
+/// The order of the field.
+const P: Int = Int::from_limbs([
+    0xfffffffefffffc2f,
+    0xffffffffffffffff,
+    0xffffffffffffffff,
+    0xffffffffffffffff,
+]);
+/// The order of the curve.
+const N: Int = Int::from_limbs([
+    0xbfd25e8cd0364141,
+    0xbaaedce6af48a03b,
+    0xfffffffffffffffe,
+    0xffffffffffffffff,
+]);
+/// The generator point.
+const G: Affine = Affine {
+    x: Int::from_limbs([
+        0x59f2815b16f81798,
+        0x029bfcdb2dce28d9,
+        0x55a06295ce870b07,
+        0x79be667ef9dcbbac,
+    ]),
+    y: Int::from_limbs([
+        0x9c47d08ffb10d4b8,
+        0xfd17b448a6855419,
+        0x5da4fbfc0e1108a8,
+        0x483ada7726a3c465,
+    ]),
+};
+
+/// A secp256k1 public key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PublicKey {
+    x: FixedBytes<32>,
+    y: FixedBytes<32>,
+}
+
+impl PublicKey {
+    /// Returns the compressed SEC1 form - the parity of `y` followed by `x`.
+    pub fn to_compressed(&self) -> [u8; 33] {
+        let mut bytes = [0u8; 33];
+        bytes[0] = 0x02 | (self.y[31] & 1);
+        bytes[1..].copy_from_slice(&self.x.0);
+        bytes
+    }
+
+    /// Returns the Ethereum address - the last 20 bytes of the keccak256 hash of the coordinates.
+    pub fn to_eth_address(&self) -> FixedBytes<20> {
+        let mut coordinates = [0u8; 64];
+        coordinates[..32].copy_from_slice(&self.x.0);
+        coordinates[32..].copy_from_slice(&self.y.0);
+        let hash = keccak256(coordinates);
+        FixedBytes::try_from(&hash[12..]).unwrap_or_default()
+    }
+}
+
+/// Recovers the public key that signed the hash, following the `ecrecover` precompile.
+///
+/// Returns `None` if `v` is not 27 or 28, `r` or `s` is out of range, or the signature is invalid.
+pub fn recover(
+    hash: FixedBytes<32>,
+    v: u8,
+    r: FixedBytes<32>,
+    s: FixedBytes<32>,
+) -> Option<PublicKey> {
+    let r = Int::from_be_bytes(r.0);
+    let s = Int::from_be_bytes(s.0);
+    if !(27..=28).contains(&v) || r == Int::ZERO || r >= N || s == Int::ZERO || s >= N {
+        return None;
+    }
+
+    // the point which x coordinate is r
+    let y_squared = add(mul(mul(r, r), r), Int::from(7));
+    let mut y = y_squared.pow_mod((P + Int::from(1)) >> 2, P);
+    if mul(y, y) != y_squared {
+        return None;
+    }
+    if y.bit(0) != (v == 28) {
+        y = P - y;
+    }
+    let point = Affine { x: r, y };
+
+    // Q = r^-1 (sR - eG)
+    let e = Int::from_be_bytes(hash.0).reduce_mod(N);
+    let r_inv = r.pow_mod(N - Int::from(2), N);
+    let u1 = (N - e.mul_mod(r_inv, N)).reduce_mod(N);
+    let u2 = s.mul_mod(r_inv, N);
+    let key = Jacobian::mul_add(u1, &G, u2, &point).to_affine()?;
+    Some(PublicKey {
+        x: FixedBytes(key.x.to_be_bytes()),
+        y: FixedBytes(key.y.to_be_bytes()),
+    })
+}
+
+fn add(a: Int, b: Int) -> Int {
+    a.add_mod(b, P)
+}
+
+fn sub(a: Int, b: Int) -> Int {
+    a.add_mod(P - b, P)
+}
+
+fn mul(a: Int, b: Int) -> Int {
+    a.mul_mod(b, P)
+}
+
+struct Affine {
+    x: Int,
+    y: Int,
+}
+
+/// A point in the Jacobian coordinates, `(X, Y, Z)` represents `(X / Z^2, Y / Z^3)`.
+#[derive(Clone, Copy)]
+struct Jacobian {
+    x: Int,
+    y: Int,
+    z: Int,
+}
+
+impl Jacobian {
+    const INFINITY: Self = Self {
+        x: Int::ZERO,
+        y: Int::ZERO,
+        z: Int::ZERO,
+    };
+
+    fn from_affine(point: &Affine) -> Self {
+        Self {
+            x: point.x,
+            y: point.y,
+            z: Int::from_limbs([1, 0, 0, 0]),
+        }
+    }
+
+    fn is_infinity(&self) -> bool {
+        self.z == Int::ZERO
+    }
+
+    fn to_affine(self) -> Option<Affine> {
+        if self.is_infinity() {
+            return None;
+        }
+        let z_inv = self.z.pow_mod(P - Int::from(2), P);
+        let z_inv_squared = mul(z_inv, z_inv);
+        Some(Affine {
+            x: mul(self.x, z_inv_squared),
+            y: mul(self.y, mul(z_inv_squared, z_inv)),
+        })
+    }
+
+    fn double(self) -> Self {
+        if self.is_infinity() || self.y == Int::ZERO {
+            return Self::INFINITY;
+        }
+        let a = mul(self.x, self.x);
+        let b = mul(self.y, self.y);
+        let c = mul(b, b);
+        let x_plus_b = add(self.x, b);
+        let d = mul(Int::from(2), sub(sub(mul(x_plus_b, x_plus_b), a), c));
+        let e = mul(Int::from(3), a);
+        let f = mul(e, e);
+        let x = sub(f, mul(Int::from(2), d));
+        let y = sub(mul(e, sub(d, x)), mul(Int::from(8), c));
+        let z = mul(Int::from(2), mul(self.y, self.z));
+        Self { x, y, z }
+    }
+
+    fn add(self, other: Self) -> Self {
+        if self.is_infinity() {
+            return other;
+        }
+        if other.is_infinity() {
+            return self;
+        }
+        let z1_squared = mul(self.z, self.z);
+        let z2_squared = mul(other.z, other.z);
+        let u1 = mul(self.x, z2_squared);
+        let u2 = mul(other.x, z1_squared);
+        let s1 = mul(self.y, mul(z2_squared, other.z));
+        let s2 = mul(other.y, mul(z1_squared, self.z));
+        if u1 == u2 {
+            return if s1 == s2 {
+                self.double()
+            } else {
+                Self::INFINITY
+            };
+        }
+        let h = sub(u2, u1);
+        let r = sub(s2, s1);
+        let h_squared = mul(h, h);
+        let h_cubed = mul(h_squared, h);
+        let u1_h_squared = mul(u1, h_squared);
+        let x = sub(sub(mul(r, r), h_cubed), mul(Int::from(2), u1_h_squared));
+        let y = sub(mul(r, sub(u1_h_squared, x)), mul(s1, h_cubed));
+        let z = mul(h, mul(self.z, other.z));
+        Self { x, y, z }
+    }
+
+    /// Computes `k1 * p1 + k2 * p2` in a single pass over the bits of the scalars.
+    fn mul_add(k1: Int, p1: &Affine, k2: Int, p2: &Affine) -> Self {
+        let p1 = Self::from_affine(p1);
+        let p2 = Self::from_affine(p2);
+        let mut result = Self::INFINITY;
+        for i in (0..256).rev() {
+            result = result.double();
+            if k1.bit(i) {
+                result = result.add(p1);
+            }
+            if k2.bit(i) {
+                result = result.add(p2);
+            }
+        }
+        result
+    }
+}
@@ -0,0 +1,223 @@
+//! [EIP-712](https://eips.ethereum.org/EIPS/eip-712) typed structured data hashing.
+//!
+//! A struct is hashed as `keccak256(abi.encode(TYPEHASH, member, ...))`, where each member
+//! takes exactly one word: value types are padded to 32 bytes, while dynamic values
+//! (`string`, `bytes`, arrays) are replaced by their hash.
+use alloc::{format, string::String, vec::Vec};
+
+use crate::{
+    abi::{keccak256, EncodePacked, EncodePackedElement},
+    Bytes, FixedArray, FixedBytes, U256,
+};
+
+/// A value that can be a member of an EIP-712 struct.
+pub trait EncodeData {
+    /// Encodes the value as a single word (`encodeData` in EIP-712).
+    fn encode_data(&self) -> FixedBytes<32>;
+}
+
+// A value type is encoded like an element of a packed array, padded to 32 bytes. An address
+// element takes two words - the tag and the hash, only the hash is encoded.
+impl<T: EncodePackedElement> EncodeData for T {
+    fn encode_data(&self) -> FixedBytes<32> {
+        let mut out = Vec::with_capacity(32);
+        self.encode_packed_element(&mut out);
+        let word = out.len().saturating_sub(32);
+        FixedBytes::try_from(&out[word..]).unwrap_or_default()
+    }
+}
+
+impl EncodeData for Bytes {
+    fn encode_data(&self) -> FixedBytes<32> {
+        keccak256(&self.0)
+    }
+}
+
+impl EncodeData for &str {
+    fn encode_data(&self) -> FixedBytes<32> {
+        keccak256(self)
+    }
+}
+
+impl EncodeData for String {
+    fn encode_data(&self) -> FixedBytes<32> {
+        keccak256(self)
+    }
+}
+
+impl<T: EncodeData> EncodeData for Vec<T> {
+    fn encode_data(&self) -> FixedBytes<32> {
+        hash_members(self.iter().map(EncodeData::encode_data))
+    }
+}
+
+impl<T: EncodeData, const N: usize> EncodeData for FixedArray<T, N> {
+    fn encode_data(&self) -> FixedBytes<32> {
+        hash_members(self.0.iter().map(EncodeData::encode_data))
+    }
+}
+
+/// Hashes the type of a struct, eg. `Permit(address owner,address spender,uint256 value)`.
+pub fn type_hash(encoded_type: &str) -> FixedBytes<32> {
+    keccak256(encoded_type)
+}
+
+/// Hashes the type hash followed by the encoded members of a struct (`hashStruct` in EIP-712).
+pub fn hash_struct(type_hash: FixedBytes<32>, members: &[&dyn EncodeData]) -> FixedBytes<32> {
+    hash_members(core::iter::once(type_hash).chain(members.iter().map(|m| m.encode_data())))
+}
+
+/// Computes the digest to be signed, `keccak256("\x19\x01" ‖ domainSeparator ‖ hashStruct(message))`.
+pub fn hash_typed_data(
+    domain_separator: FixedBytes<32>,
+    struct_hash: FixedBytes<32>,
+) -> FixedBytes<32> {
+    let mut data = Vec::with_capacity(66);
+    data.extend([0x19, 0x01]);
+    domain_separator.encode_packed(&mut data);
+    struct_hash.encode_packed(&mut data);
+    keccak256(data)
+}
+
+fn hash_members<I: Iterator<Item = FixedBytes<32>>>(members: I) -> FixedBytes<32> {
+    let mut data = Vec::new();
+    members.for_each(|member| member.encode_packed(&mut data));
+    keccak256(data)
+}
+
+/// The `EIP712Domain` struct, only the fields that are set are a part of the domain type.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Domain {
+    name: Option<FixedBytes<32>>,
+    version: Option<FixedBytes<32>>,
+    chain_id: Option<FixedBytes<32>>,
+    verifying_contract: Option<FixedBytes<32>>,
+    salt: Option<FixedBytes<32>>,
+}
+
+impl Domain {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The name of the signing domain, eg. the name of the token.
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = Some(name.encode_data());
+        self
+    }
+
+    /// The current major version of the signing domain.
+    pub fn version(mut self, version: &str) -> Self {
+        self.version = Some(version.encode_data());
+        self
+    }
+
+    pub fn chain_id(mut self, chain_id: U256) -> Self {
+        self.chain_id = Some(chain_id.encode_data());
+        self
+    }
+
+    /// The address of the contract that verifies the signature.
+    pub fn verifying_contract(mut self, contract: &dyn EncodeData) -> Self {
+        self.verifying_contract = Some(contract.encode_data());
+        self
+    }
+
+    pub fn salt(mut self, salt: FixedBytes<32>) -> Self {
+        self.salt = Some(salt);
+        self
+    }
+
+    /// Hashes the domain type, eg. `EIP712Domain(string name,string version,uint256 chainId)`.
+    pub fn type_hash(&self) -> FixedBytes<32> {
+        let fields = self
+            .fields()
+            .map(|(field, _)| field)
+            .collect::<Vec<_>>()
+            .join(",");
+        type_hash(&format!("EIP712Domain({})", fields))
+    }
+
+    /// Computes the domain separator (`DOMAIN_SEPARATOR` in EIP-2612).
+    pub fn separator(&self) -> FixedBytes<32> {
+        hash_members(core::iter::once(self.type_hash()).chain(self.fields().map(|(_, v)| v)))
+    }
+
+    fn fields(&self) -> impl Iterator<Item = (&'static str, FixedBytes<32>)> + '_ {
+        [
+            ("string name", self.name),
+            ("string version", self.version),
+            ("uint256 chainId", self.chain_id),
+            ("address verifyingContract", self.verifying_contract),
+            ("bytes32 salt", self.salt),
+        ]
+        .into_iter()
+        .filter_map(|(field, value)| value.map(|value| (field, value)))
+    }
+}
+
+#[cfg(test)]
+mod t {
+    use super::*;
+
+    fn hex(value: &str) -> alloc::vec::Vec<u8> {
+        const_hex::decode(value).unwrap()
+    }
+
+    /// An Ethereum address padded to a word.
+    fn address(value: &str) -> FixedBytes<32> {
+        let mut word = [0u8; 32];
+        word[12..].copy_from_slice(&hex(value));
+        FixedBytes(word)
+    }
+
+    // The `Mail` example from the EIP-712 specification.
+    #[test]
+    fn typed_data_hashing() {
+        let domain = Domain::new()
+            .name("Ether Mail")
+            .version("1")
+            .chain_id(U256::from(1u64))
+            .verifying_contract(&address("cccccccccccccccccccccccccccccccccccccccc"));
+        assert_eq!(
+            domain.separator().0.to_vec(),
+            hex("f2cee375fa42b42143804025fc449deafd50cc031ca257e0b194a650a912090f")
+        );
+
+        let person = type_hash("Person(string name,address wallet)");
+        let from = hash_struct(
+            person,
+            &[&"Cow", &address("cd2a3d9f938e13cd947ec05abc7fe734df8dd826")],
+        );
+        let to = hash_struct(
+            person,
+            &[&"Bob", &address("bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb")],
+        );
+        let mail = hash_struct(
+            type_hash(
+                "Mail(Person from,Person to,string contents)Person(string name,address wallet)",
+            ),
+            &[&from, &to, &"Hello, Bob!"],
+        );
+        assert_eq!(
+            mail.0.to_vec(),
+            hex("c52c0ee5d84264471806290a3f2c4cecfc5490626bf912d01f240d7a274b371e")
+        );
+        assert_eq!(
+            hash_typed_data(domain.separator(), mail).0.to_vec(),
+            hex("be609aee343fb3c4b28e1df9e632fca64fcfaede20f02e86244efddf30957bd2")
+        );
+    }
+
+    #[test]
+    fn members_encoding() {
+        // dynamic members are hashed, arrays are hashed after encoding the elements
+        assert_eq!(Bytes::from("ab").encode_data(), keccak256("ab"));
+        let values = alloc::vec![U256::from(1u64), U256::from(2u64)];
+        let mut expected = alloc::vec![0u8; 64];
+        expected[31] = 1;
+        expected[63] = 2;
+        assert_eq!(values.encode_data(), keccak256(expected));
+        assert_eq!(true.encode_data(), U256::from(1u64).encode_data());
+    }
+}
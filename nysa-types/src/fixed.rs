@@ -0,0 +1,313 @@
+use core::ops;
+
+use num_bigint::BigInt;
+
+use crate::{
+    abi::{AbiDecode, AbiEncode, AbiError, EncodePacked, EncodePackedElement, Token},
+    Sign, Signed, Unsigned,
+};
+
+/// Signed fixed-point number (`fixedMxN` in Solidity), an `M`-bit integer scaled down by `10^N`.
+///
+/// Results of multiplication and division are rounded toward zero, eg. `-1 / 3` with two decimals
+/// gives `-0.33`. The operators panic on overflow and on division by zero,
+/// the `checked_` methods return `None` instead.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Fixed<const BITS: usize, const LIMBS: usize, const DECIMALS: usize>(
+    pub(crate) Signed<BITS, LIMBS>,
+);
+
+/// Unsigned fixed-point number (`ufixedMxN` in Solidity), an `M`-bit integer scaled down by `10^N`.
+///
+/// Follows the same rounding rules as [`Fixed`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Ufixed<const BITS: usize, const LIMBS: usize, const DECIMALS: usize>(
+    pub(crate) Unsigned<BITS, LIMBS>,
+);
+
+/// `fixed` in Solidity.
+pub type Fixed128x18 = Fixed<128, 2, 18>;
+/// `ufixed` in Solidity.
+pub type Ufixed128x18 = Ufixed<128, 2, 18>;
+
+/// The intermediate results of multiplication and division do not fit the type,
+/// so they are computed on big integers.
+trait BigValue: Sized {
+    fn to_big(&self) -> BigInt;
+
+    /// Returns `None` if the value does not fit the type.
+    fn from_big(value: &BigInt) -> Option<Self>;
+}
+
+impl<const BITS: usize, const LIMBS: usize> BigValue for Unsigned<BITS, LIMBS> {
+    fn to_big(&self) -> BigInt {
+        BigInt::from_bytes_le(num_bigint::Sign::Plus, &self.0.to_le_bytes_vec())
+    }
+
+    fn from_big(value: &BigInt) -> Option<Self> {
+        match value.to_bytes_le() {
+            (num_bigint::Sign::Minus, _) => None,
+            (_, bytes) => ruint::Uint::try_from_le_slice(&bytes).map(Unsigned),
+        }
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> BigValue for Signed<BITS, LIMBS> {
+    fn to_big(&self) -> BigInt {
+        let (sign, abs) = self.into_sign_and_abs();
+        let sign = match sign {
+            Sign::Positive => num_bigint::Sign::Plus,
+            Sign::Negative => num_bigint::Sign::Minus,
+        };
+        BigInt::from_bytes_le(sign, &abs.to_le_bytes_vec())
+    }
+
+    fn from_big(value: &BigInt) -> Option<Self> {
+        let (sign, bytes) = value.to_bytes_le();
+        let sign = match sign {
+            num_bigint::Sign::Minus => Sign::Negative,
+            _ => Sign::Positive,
+        };
+        let abs = ruint::Uint::try_from_le_slice(&bytes)?;
+        Signed::checked_from_sign_and_abs(sign, abs)
+    }
+}
+
+macro_rules! impl_fixed {
+    ( $( ($ty:ident, $inner:ident) ),* ) => {
+        $(impl<const BITS: usize, const LIMBS: usize, const DECIMALS: usize>
+            $ty<BITS, LIMBS, DECIMALS>
+        {
+            pub const ZERO: Self = Self($inner::ZERO);
+            pub const MIN: Self = Self($inner::MIN);
+            pub const MAX: Self = Self($inner::MAX);
+
+            /// Creates a number from its integer representation, eg. `1.5` with two decimals is `150`.
+            pub const fn from_raw(value: $inner<BITS, LIMBS>) -> Self {
+                Self(value)
+            }
+
+            /// Returns the integer representation of the number.
+            pub const fn into_raw(self) -> $inner<BITS, LIMBS> {
+                self.0
+            }
+
+            /// Converts an integer into a fixed-point number, returns `None` on overflow.
+            pub fn checked_from_int(value: $inner<BITS, LIMBS>) -> Option<Self> {
+                $inner::from_big(&(value.to_big() * scale(DECIMALS))).map(Self)
+            }
+
+            /// Returns the integer part of the number, the fractional part is truncated toward zero.
+            pub fn trunc(self) -> $inner<BITS, LIMBS> {
+                $inner::from_big(&(self.0.to_big() / scale(DECIMALS)))
+                    .expect("the integer part always fits")
+            }
+
+            /// Checked addition, returns `None` on overflow.
+            pub fn checked_add(self, rhs: Self) -> Option<Self> {
+                self.0.checked_add(rhs.0).map(Self)
+            }
+
+            /// Checked subtraction, returns `None` on overflow.
+            pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+                self.0.checked_sub(rhs.0).map(Self)
+            }
+
+            /// Checked multiplication rounding toward zero, returns `None` on overflow.
+            pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+                let product = self.0.to_big() * rhs.0.to_big() / scale(DECIMALS);
+                $inner::from_big(&product).map(Self)
+            }
+
+            /// Returns `None` if the value is zero, eg. to check a divisor.
+            pub fn non_zero(self) -> Option<Self> {
+                (self.0 != $inner::ZERO).then_some(self)
+            }
+
+            /// Checked division rounding toward zero, returns `None` on overflow
+            /// or if `rhs` is zero.
+            pub fn checked_div(self, rhs: Self) -> Option<Self> {
+                if rhs.0 == $inner::ZERO {
+                    return None;
+                }
+                let quotient = self.0.to_big() * scale(DECIMALS) / rhs.0.to_big();
+                $inner::from_big(&quotient).map(Self)
+            }
+
+            /// Checked remainder taking the sign of the dividend, returns `None` if `rhs` is zero.
+            pub fn checked_rem(self, rhs: Self) -> Option<Self> {
+                self.0.checked_rem(rhs.0).map(Self)
+            }
+        }
+
+        impl<const BITS: usize, const LIMBS: usize, const DECIMALS: usize> ops::Add
+            for $ty<BITS, LIMBS, DECIMALS>
+        {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self::Output {
+                self.checked_add(rhs).expect("attempt to add with overflow")
+            }
+        }
+
+        impl<const BITS: usize, const LIMBS: usize, const DECIMALS: usize> ops::Sub
+            for $ty<BITS, LIMBS, DECIMALS>
+        {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self::Output {
+                self.checked_sub(rhs).expect("attempt to subtract with overflow")
+            }
+        }
+
+        impl<const BITS: usize, const LIMBS: usize, const DECIMALS: usize> ops::Mul
+            for $ty<BITS, LIMBS, DECIMALS>
+        {
+            type Output = Self;
+
+            fn mul(self, rhs: Self) -> Self::Output {
+                self.checked_mul(rhs).expect("attempt to multiply with overflow")
+            }
+        }
+
+        impl<const BITS: usize, const LIMBS: usize, const DECIMALS: usize> ops::Div
+            for $ty<BITS, LIMBS, DECIMALS>
+        {
+            type Output = Self;
+
+            fn div(self, rhs: Self) -> Self::Output {
+                assert!(rhs.0 != $inner::ZERO, "attempt to divide by zero");
+                self.checked_div(rhs).expect("attempt to divide with overflow")
+            }
+        }
+
+        impl<const BITS: usize, const LIMBS: usize, const DECIMALS: usize> ops::Rem
+            for $ty<BITS, LIMBS, DECIMALS>
+        {
+            type Output = Self;
+
+            fn rem(self, rhs: Self) -> Self::Output {
+                self.checked_rem(rhs)
+                    .expect("attempt to calculate the remainder with a divisor of zero")
+            }
+        }
+
+        // A fixed-point number is encoded as its integer representation.
+        impl<const BITS: usize, const LIMBS: usize, const DECIMALS: usize> AbiEncode
+            for $ty<BITS, LIMBS, DECIMALS>
+        {
+            fn to_token(&self) -> Token {
+                self.0.to_token()
+            }
+        }
+
+        impl<const BITS: usize, const LIMBS: usize, const DECIMALS: usize> AbiDecode
+            for $ty<BITS, LIMBS, DECIMALS>
+        {
+            const IS_DYNAMIC: bool = false;
+
+            fn decode_at(data: &[u8], pos: usize) -> Result<Self, AbiError> {
+                $inner::decode_at(data, pos).map(Self)
+            }
+        }
+
+        impl<const BITS: usize, const LIMBS: usize, const DECIMALS: usize> EncodePacked
+            for $ty<BITS, LIMBS, DECIMALS>
+        {
+            fn encode_packed(&self, out: &mut alloc::vec::Vec<u8>) {
+                self.0.encode_packed(out)
+            }
+        }
+
+        impl<const BITS: usize, const LIMBS: usize, const DECIMALS: usize> EncodePackedElement
+            for $ty<BITS, LIMBS, DECIMALS>
+        {
+            fn encode_packed_element(&self, out: &mut alloc::vec::Vec<u8>) {
+                self.0.encode_packed_element(out)
+            }
+        })*
+    };
+}
+
+impl_fixed!((Fixed, Signed), (Ufixed, Unsigned));
+
+impl<const BITS: usize, const LIMBS: usize, const DECIMALS: usize> ops::Neg
+    for Fixed<BITS, LIMBS, DECIMALS>
+{
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self(
+            self.0
+                .checked_neg()
+                .expect("attempt to negate with overflow"),
+        )
+    }
+}
+
+fn scale(decimals: usize) -> BigInt {
+    BigInt::from(10u8).pow(decimals as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{I128, I8, U128, U8};
+
+    type F = Fixed<128, 2, 2>;
+    type UF = Ufixed<128, 2, 2>;
+
+    fn fixed(value: i64) -> F {
+        let value = if value < 0 {
+            -I128::from_limbs([value.unsigned_abs(), 0])
+        } else {
+            I128::from_limbs([value as u64, 0])
+        };
+        F::from_raw(value)
+    }
+
+    #[test]
+    fn arithmetics() {
+        assert_eq!(fixed(150) + fixed(25), fixed(175)); // 1.5 + 0.25 = 1.75
+        assert_eq!(fixed(150) - fixed(175), fixed(-25)); // 1.5 - 1.75 = -0.25
+        assert_eq!(fixed(150) * fixed(250), fixed(375)); // 1.5 * 2.5 = 3.75
+        assert_eq!(fixed(-150) * fixed(250), fixed(-375));
+        assert_eq!(fixed(375) / fixed(150), fixed(250)); // 3.75 / 1.5 = 2.5
+        assert_eq!(fixed(375) % fixed(150), fixed(75)); // 3.75 % 1.5 = 0.75
+        assert_eq!(-fixed(375), fixed(-375));
+
+        // rounding toward zero
+        assert_eq!(fixed(100) / fixed(300), fixed(33)); // 1 / 3 = 0.33
+        assert_eq!(fixed(-100) / fixed(300), fixed(-33)); // -1 / 3 = -0.33
+        assert_eq!(fixed(-101) * fixed(50), fixed(-50)); // -1.01 * 0.5 = -0.505
+        assert_eq!(fixed(-375).trunc(), -I128::from_limbs([3, 0]));
+
+        // the intermediate results do not overflow
+        let max = UF::MAX;
+        assert_eq!(max * UF::checked_from_int(U128::ONE).unwrap(), max);
+        assert_eq!(max / UF::checked_from_int(U128::ONE).unwrap(), max);
+    }
+
+    #[test]
+    fn checked_arithmetics() {
+        type F8 = Fixed<8, 1, 1>;
+        type UF8 = Ufixed<8, 1, 1>;
+
+        assert_eq!(F8::MAX.checked_add(F8::from_raw(I8::ONE)), None);
+        assert_eq!(
+            F8::MIN.checked_mul(F8::from_raw(I8::from_limbs([20]))),
+            None
+        );
+        assert_eq!(F8::MIN.checked_div(F8::from_raw(I8::ZERO)), None);
+        assert_eq!(
+            F8::MIN.checked_div(F8::from_raw(I8::from_limbs([20]))), // -12.8 / 2 = -6.4
+            Some(F8::from_raw(I8::from_limbs([0xc0])))
+        );
+        assert_eq!(
+            UF8::checked_from_int(U8::from_limbs([25])),
+            Some(UF8::from_raw(U8::MAX - U8::from_limbs([5])))
+        );
+        assert_eq!(UF8::checked_from_int(U8::from_limbs([26])), None);
+        assert_eq!(UF8::ZERO.checked_sub(UF8::from_raw(U8::ONE)), None);
+    }
+}
@@ -4,11 +4,36 @@ extern crate alloc;
 
 #[cfg(feature = "odra")]
 mod odra;
+#[cfg(feature = "odra")]
+pub use odra::{ecrecover, IntConversionError};
+
+#[cfg(feature = "odra")]
+#[doc(hidden)]
+pub mod __private {
+    pub use alloc::vec::Vec;
+    pub use odra_core::casper_types;
+}
 
+pub mod abi;
+mod array;
 mod bytes;
+mod convert;
+pub mod crypto;
+pub mod eip712;
+mod fixed;
+mod mapping;
+mod native;
+mod panic;
 mod signed;
 mod unsigned;
 
+pub use abi::keccak256;
+pub use array::*;
 pub use bytes::*;
+pub use convert::{ConvertFrom, ConvertInto};
+pub use crypto::{ripemd160, sha256};
+pub use fixed::*;
+pub use mapping::Mapping;
+pub use panic::{panic, unwrap_or_panic};
 pub use signed::*;
 pub use unsigned::*;
@@ -0,0 +1,87 @@
+use alloc::collections::BTreeMap;
+
+/// A mapping kept in a struct (`mapping(K => V)` as a struct field).
+///
+/// Unlike a mapping state variable, the mapping is a part of the struct value, so is read and written
+/// along with the struct. The interface follows `odra::Mapping`, an unset key reads as the default value.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct Mapping<K, V>(BTreeMap<K, V>);
+
+impl<K, V> Default for Mapping<K, V> {
+    #[inline]
+    fn default() -> Self {
+        Self(BTreeMap::new())
+    }
+}
+
+impl<K: Ord, V: Clone> Mapping<K, V> {
+    /// Reads the value, returns `None` if the key is not set.
+    pub fn get(&self, key: &K) -> Option<V> {
+        self.0.get(key).cloned()
+    }
+
+    /// Reads the value, returns the default value if the key is not set.
+    pub fn get_or_default(&self, key: &K) -> V
+    where
+        V: Default,
+    {
+        self.get(key).unwrap_or_default()
+    }
+
+    /// Sets the value, a default value (eg. assigned by `delete`) unsets the key, so is not kept.
+    pub fn set(&mut self, key: &K, value: V)
+    where
+        K: Clone,
+        V: Default + PartialEq,
+    {
+        if value == V::default() {
+            self.0.remove(key);
+        } else {
+            self.0.insert(key.clone(), value);
+        }
+    }
+}
+
+impl<K, V> From<BTreeMap<K, V>> for Mapping<K, V> {
+    #[inline]
+    fn from(value: BTreeMap<K, V>) -> Self {
+        Self(value)
+    }
+}
+
+impl<K, V> AsRef<BTreeMap<K, V>> for Mapping<K, V> {
+    #[inline]
+    fn as_ref(&self) -> &BTreeMap<K, V> {
+        &self.0
+    }
+}
+
+impl<K, V> From<Mapping<K, V>> for BTreeMap<K, V> {
+    #[inline]
+    fn from(value: Mapping<K, V>) -> Self {
+        value.0
+    }
+}
+
+#[cfg(test)]
+mod t {
+    use super::Mapping;
+    use crate::{FixedBytes, U256};
+
+    #[test]
+    fn unset_key_reads_as_default() {
+        let mut indexes = Mapping::<FixedBytes<32>, U256>::default();
+        let key = FixedBytes([1u8; 32]);
+        assert_eq!(indexes.get(&key), None);
+        assert_eq!(indexes.get_or_default(&key), U256::ZERO);
+
+        indexes.set(&key, U256::ONE);
+        assert_eq!(indexes.get(&key), Some(U256::ONE));
+        assert_eq!(indexes.get_or_default(&key), U256::ONE);
+
+        // `delete indexes[key]` assigns the default value
+        indexes.set(&key, U256::ZERO);
+        assert_eq!(indexes.get(&key), None);
+        assert_eq!(indexes, Mapping::default());
+    }
+}
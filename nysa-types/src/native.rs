@@ -0,0 +1,201 @@
+//! Integers up to 128 bits wide are computed on native integers instead of multi-limb arithmetic.
+//!
+//! `LIMBS` is known at compile time, so an operation dispatching on it compiles down to
+//! a single native instruction sequence for narrow integers, the multi-limb code is left out.
+//!
+//! Only the computation is native - a value is still stored as a multi-limb [Uint],
+//! the limbs are converted to a native integer and back on each operation.
+use ruint::Uint;
+
+/// The value of a single-limb integer.
+#[inline(always)]
+pub(crate) fn to_u64<const BITS: usize, const LIMBS: usize>(value: &Uint<BITS, LIMBS>) -> u64 {
+    value.as_limbs()[0]
+}
+
+/// The value of a two-limb integer.
+#[inline(always)]
+pub(crate) fn to_u128<const BITS: usize, const LIMBS: usize>(value: &Uint<BITS, LIMBS>) -> u128 {
+    let limbs = value.as_limbs();
+    limbs[0] as u128 | (limbs[1] as u128) << 64
+}
+
+/// The value of a single-limb integer in two's complement, sign-extended to 64 bits.
+#[inline(always)]
+pub(crate) fn to_i64<const BITS: usize, const LIMBS: usize>(value: &Uint<BITS, LIMBS>) -> i64 {
+    let shift = 64 - BITS;
+    ((to_u64(value) << shift) as i64) >> shift
+}
+
+/// The value of a two-limb integer in two's complement, sign-extended to 128 bits.
+#[inline(always)]
+pub(crate) fn to_i128<const BITS: usize, const LIMBS: usize>(value: &Uint<BITS, LIMBS>) -> i128 {
+    let shift = 128 - BITS;
+    ((to_u128(value) << shift) as i128) >> shift
+}
+
+/// Returns `None` if the value does not fit into `BITS` bits.
+#[inline(always)]
+pub(crate) fn from_u64<const BITS: usize, const LIMBS: usize>(
+    value: u64,
+) -> Option<Uint<BITS, LIMBS>> {
+    (value == truncate_u64::<BITS>(value)).then(|| from_u64_wrapping(value))
+}
+
+/// Returns `None` if the value does not fit into `BITS` bits.
+#[inline(always)]
+pub(crate) fn from_u128<const BITS: usize, const LIMBS: usize>(
+    value: u128,
+) -> Option<Uint<BITS, LIMBS>> {
+    (value == truncate_u128::<BITS>(value)).then(|| from_u128_wrapping(value))
+}
+
+/// Returns `None` if the value does not fit into a `BITS`-bit signed integer.
+#[inline(always)]
+pub(crate) fn from_i64<const BITS: usize, const LIMBS: usize>(
+    value: i64,
+) -> Option<Uint<BITS, LIMBS>> {
+    let shift = 64 - BITS;
+    ((value << shift) >> shift == value).then(|| from_u64_wrapping(value as u64))
+}
+
+/// Returns `None` if the value does not fit into a `BITS`-bit signed integer.
+#[inline(always)]
+pub(crate) fn from_i128<const BITS: usize, const LIMBS: usize>(
+    value: i128,
+) -> Option<Uint<BITS, LIMBS>> {
+    let shift = 128 - BITS;
+    ((value << shift) >> shift == value).then(|| from_u128_wrapping(value as u128))
+}
+
+/// Keeps the lowest `BITS` bits of the value.
+#[inline(always)]
+pub(crate) fn from_u64_wrapping<const BITS: usize, const LIMBS: usize>(
+    value: u64,
+) -> Uint<BITS, LIMBS> {
+    let mut limbs = [0u64; LIMBS];
+    limbs[0] = truncate_u64::<BITS>(value);
+    Uint::from_limbs(limbs)
+}
+
+/// Keeps the lowest `BITS` bits of the value.
+#[inline(always)]
+pub(crate) fn from_u128_wrapping<const BITS: usize, const LIMBS: usize>(
+    value: u128,
+) -> Uint<BITS, LIMBS> {
+    let value = truncate_u128::<BITS>(value);
+    let mut limbs = [0u64; LIMBS];
+    limbs[0] = value as u64;
+    limbs[1] = (value >> 64) as u64;
+    Uint::from_limbs(limbs)
+}
+
+#[inline(always)]
+const fn truncate_u64<const BITS: usize>(value: u64) -> u64 {
+    if BITS >= 64 {
+        value
+    } else {
+        value & ((1 << BITS) - 1)
+    }
+}
+
+#[inline(always)]
+const fn truncate_u128<const BITS: usize>(value: u128) -> u128 {
+    if BITS >= 128 {
+        value
+    } else {
+        value & ((1 << BITS) - 1)
+    }
+}
+
+/// Computes `$lhs.$op($rhs)` on native integers if the operands fit into 128 bits,
+/// evaluates `$fallback` otherwise.
+///
+/// A `checked` operation results in `None` if the native operation fails or the result
+/// does not fit into `BITS` bits, a `wrapping` one truncates the result to `BITS` bits.
+/// `checked_pow` computes `$base.checked_pow($exp)` natively if the exponent fits into `u32`,
+/// `cmp` compares the operands.
+macro_rules! native_op {
+    (checked unsigned, $lhs:expr, $op:ident, $rhs:expr, $fallback:expr) => {
+        match LIMBS {
+            1 => $crate::native::to_u64(&$lhs)
+                .$op($crate::native::to_u64(&$rhs))
+                .and_then($crate::native::from_u64),
+            2 => $crate::native::to_u128(&$lhs)
+                .$op($crate::native::to_u128(&$rhs))
+                .and_then($crate::native::from_u128),
+            _ => $fallback,
+        }
+    };
+    (wrapping unsigned, $lhs:expr, $op:ident, $rhs:expr, $fallback:expr) => {
+        match LIMBS {
+            1 => $crate::native::from_u64_wrapping(
+                $crate::native::to_u64(&$lhs).$op($crate::native::to_u64(&$rhs)),
+            ),
+            2 => $crate::native::from_u128_wrapping(
+                $crate::native::to_u128(&$lhs).$op($crate::native::to_u128(&$rhs)),
+            ),
+            _ => $fallback,
+        }
+    };
+    (checked signed, $lhs:expr, $op:ident, $rhs:expr, $fallback:expr) => {
+        match LIMBS {
+            1 => $crate::native::to_i64(&$lhs)
+                .$op($crate::native::to_i64(&$rhs))
+                .and_then($crate::native::from_i64),
+            2 => $crate::native::to_i128(&$lhs)
+                .$op($crate::native::to_i128(&$rhs))
+                .and_then($crate::native::from_i128),
+            _ => $fallback,
+        }
+    };
+    (wrapping signed, $lhs:expr, $op:ident, $rhs:expr, $fallback:expr) => {
+        match LIMBS {
+            1 => $crate::native::from_u64_wrapping(
+                $crate::native::to_i64(&$lhs).$op($crate::native::to_i64(&$rhs)) as u64,
+            ),
+            2 => $crate::native::from_u128_wrapping(
+                $crate::native::to_i128(&$lhs).$op($crate::native::to_i128(&$rhs)) as u128,
+            ),
+            _ => $fallback,
+        }
+    };
+    (checked_pow unsigned, $base:expr, $exp:expr, $fallback:expr) => {
+        match LIMBS {
+            1 if $exp.bit_len() <= 32 => $crate::native::to_u64(&$base)
+                .checked_pow($crate::native::to_u64(&$exp) as u32)
+                .and_then($crate::native::from_u64),
+            2 if $exp.bit_len() <= 32 => $crate::native::to_u128(&$base)
+                .checked_pow($crate::native::to_u64(&$exp) as u32)
+                .and_then($crate::native::from_u128),
+            _ => $fallback,
+        }
+    };
+    (checked_pow signed, $base:expr, $exp:expr, $fallback:expr) => {
+        match LIMBS {
+            1 if $exp.bit_len() <= 32 => $crate::native::to_i64(&$base)
+                .checked_pow($crate::native::to_u64(&$exp) as u32)
+                .and_then($crate::native::from_i64),
+            2 if $exp.bit_len() <= 32 => $crate::native::to_i128(&$base)
+                .checked_pow($crate::native::to_u64(&$exp) as u32)
+                .and_then($crate::native::from_i128),
+            _ => $fallback,
+        }
+    };
+    (cmp unsigned, $lhs:expr, $rhs:expr, $fallback:expr) => {
+        match LIMBS {
+            1 => $crate::native::to_u64(&$lhs).cmp(&$crate::native::to_u64(&$rhs)),
+            2 => $crate::native::to_u128(&$lhs).cmp(&$crate::native::to_u128(&$rhs)),
+            _ => $fallback,
+        }
+    };
+    (cmp signed, $lhs:expr, $rhs:expr, $fallback:expr) => {
+        match LIMBS {
+            1 => $crate::native::to_i64(&$lhs).cmp(&$crate::native::to_i64(&$rhs)),
+            2 => $crate::native::to_i128(&$lhs).cmp(&$crate::native::to_i128(&$rhs)),
+            _ => $fallback,
+        }
+    };
+}
+
+pub(crate) use native_op;
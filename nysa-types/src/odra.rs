@@ -1,73 +1,61 @@
+use alloc::collections::BTreeMap;
+use core::fmt;
+
 use odra_core::casper_types::{
-    bytesrepr::{Error, FromBytes, ToBytes},
-    CLType, CLTyped,
+    account::AccountHash,
+    bytesrepr::{Error, FromBytes, ToBytes, U32_SERIALIZED_LENGTH},
+    CLType, CLTyped, ContractHash, ContractPackageHash, PublicKey, U128 as CasperU128,
+    U256 as CasperU256, U512 as CasperU512,
 };
 
-use crate::{FixedBytes, Signed, Unsigned};
+use crate::{
+    abi::{AbiDecode, AbiEncode, AbiError, EncodePacked, EncodePackedElement, Token},
+    crypto, Bytes, ConvertFrom, Fixed, FixedArray, FixedBytes, Mapping, Sign, Signed, Ufixed,
+    Unsigned, I104, I112, I120, I128, I136, I144, I152, I16, I160, I168, I176, I184, I192, I200,
+    I208, I216, I224, I232, I24, I240, I248, I256, I32, I40, I48, I56, I64, I72, I8, I80, I88, I96,
+    U104, U112, U120, U128, U136, U144, U152, U16, U160, U168, U176, U184, U192, U200, U208, U216,
+    U224, U232, U24, U240, U248, U256, U32, U40, U48, U56, U64, U72, U8, U80, U88, U96,
+};
 
-pub const U64_SERIALIZED_LENGTH: usize = core::mem::size_of::<u64>();
-pub const U128_SERIALIZED_LENGTH: usize = U64_SERIALIZED_LENGTH * 2;
-pub const U192_SERIALIZED_LENGTH: usize = U64_SERIALIZED_LENGTH * 3;
-pub const U256_SERIALIZED_LENGTH: usize = U64_SERIALIZED_LENGTH * 4;
+/// A tag followed by a 32-byte account or contract hash.
+const ADDRESS_SERIALIZED_LENGTH: usize = 33;
+/// The length of an account or contract hash, also the size of an ABI word.
+const ADDRESS_HASH_LENGTH: usize = 32;
 
+// An integer is serialized as its `BITS / 8` little-endian bytes, eg. `U8` takes a single byte.
 macro_rules! impl_int_deser {
     ( $( $ty:ident ),* ) => {
         $(impl<const BITS: usize, const LIMBS: usize> ToBytes for $ty<BITS, LIMBS> {
             fn to_bytes(&self) -> Result<alloc::vec::Vec<u8>, Error> {
                 Ok(self
+                    .0
                     .as_limbs()
                     .iter()
-                    .map(|word| word.to_le_bytes().to_vec())
-                    .flatten()
+                    .flat_map(|word| word.to_le_bytes())
+                    .take(int_serialized_length(BITS))
                     .collect::<alloc::vec::Vec<_>>())
             }
 
             fn serialized_length(&self) -> usize {
-                U64_SERIALIZED_LENGTH * LIMBS
+                int_serialized_length(BITS)
             }
         }
 
         impl<const BITS: usize, const LIMBS: usize> FromBytes for $ty<BITS, LIMBS> {
             fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
-                if U64_SERIALIZED_LENGTH * LIMBS > bytes.len() {
-                    return Err(Error::Formatting);
-                } else {
-                    let (bytes, remainder) = bytes.split_at(U64_SERIALIZED_LENGTH * LIMBS);
-
-                    match LIMBS {
-                        1 => {
-                            let bytes = <[u8; U64_SERIALIZED_LENGTH]>::try_from(bytes)
-                                .map_err(|_| Error::EarlyEndOfStream)?;
-                            try_from_bytes(bytes, remainder)
-                                .map(|(uint, remainder)| ($ty(uint), remainder))
-                        }
-                        2 => {
-                            let bytes = <[u8; U128_SERIALIZED_LENGTH]>::try_from(bytes)
-                                .map_err(|_| Error::EarlyEndOfStream)?;
-                            try_from_bytes(bytes, remainder)
-                                .map(|(uint, remainder)| ($ty(uint), remainder))
-                        }
-                        3 => {
-                            let bytes = <[u8; U192_SERIALIZED_LENGTH]>::try_from(bytes)
-                                .map_err(|_| Error::EarlyEndOfStream)?;
-                            try_from_bytes(bytes, remainder)
-                                .map(|(uint, remainder)| ($ty(uint), remainder))
-                        }
-                        4 => {
-                            let bytes = <[u8; U256_SERIALIZED_LENGTH]>::try_from(bytes)
-                                .map_err(|_| Error::EarlyEndOfStream)?;
-                            try_from_bytes(bytes, remainder)
-                                .map(|(uint, remainder)| ($ty(uint), remainder))
-                        }
-                        _ => Err(Error::Formatting),
-                    }
+                let length = int_serialized_length(BITS);
+                if length > bytes.len() {
+                    return Err(Error::EarlyEndOfStream);
                 }
+                let (bytes, remainder) = bytes.split_at(length);
+                let value = ruint::Uint::try_from_le_slice(bytes).ok_or(Error::Formatting)?;
+                Ok(($ty(value), remainder))
             }
         }
 
         impl<const BITS: usize, const LIMBS: usize> CLTyped for $ty<BITS, LIMBS> {
             fn cl_type() -> CLType {
-                <alloc::vec::Vec<u64>>::cl_type()
+                CLType::ByteArray(int_serialized_length(BITS) as u32)
             }
         })*
     };
@@ -75,6 +63,41 @@ macro_rules! impl_int_deser {
 
 impl_int_deser!(Unsigned, Signed);
 
+// A fixed-point number is serialized as its integer representation.
+macro_rules! impl_fixed_deser {
+    ( $( $ty:ident ),* ) => {
+        $(impl<const BITS: usize, const LIMBS: usize, const DECIMALS: usize> ToBytes
+            for $ty<BITS, LIMBS, DECIMALS>
+        {
+            fn to_bytes(&self) -> Result<alloc::vec::Vec<u8>, Error> {
+                self.0.to_bytes()
+            }
+
+            fn serialized_length(&self) -> usize {
+                self.0.serialized_length()
+            }
+        }
+
+        impl<const BITS: usize, const LIMBS: usize, const DECIMALS: usize> FromBytes
+            for $ty<BITS, LIMBS, DECIMALS>
+        {
+            fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
+                FromBytes::from_bytes(bytes).map(|(value, remainder)| ($ty(value), remainder))
+            }
+        }
+
+        impl<const BITS: usize, const LIMBS: usize, const DECIMALS: usize> CLTyped
+            for $ty<BITS, LIMBS, DECIMALS>
+        {
+            fn cl_type() -> CLType {
+                CLType::ByteArray(int_serialized_length(BITS) as u32)
+            }
+        })*
+    };
+}
+
+impl_fixed_deser!(Fixed, Ufixed);
+
 impl<const N: usize> ToBytes for FixedBytes<N> {
     fn to_bytes(&self) -> Result<alloc::vec::Vec<u8>, Error> {
         self.0.to_bytes()
@@ -97,19 +120,409 @@ impl<const N: usize> CLTyped for FixedBytes<N> {
     }
 }
 
-#[inline]
-fn try_from_bytes<const BITS: usize, const LIMBS: usize, const LEN: usize>(
-    bytes: [u8; LEN],
-    remainder: &[u8],
-) -> Result<(ruint::Uint<BITS, LIMBS>, &[u8]), Error> {
-    let value = ruint::Uint::try_from_le_slice(&bytes).ok_or(Error::Formatting)?;
-    Ok((value, remainder))
+impl ToBytes for Bytes {
+    fn to_bytes(&self) -> Result<alloc::vec::Vec<u8>, Error> {
+        self.0.to_bytes()
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.0.serialized_length()
+    }
+}
+
+impl FromBytes for Bytes {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
+        FromBytes::from_bytes(bytes).map(|(b, remainder)| (Bytes(b), remainder))
+    }
+}
+
+impl CLTyped for Bytes {
+    fn cl_type() -> CLType {
+        <alloc::vec::Vec<u8>>::cl_type()
+    }
+}
+
+// A fixed-size array is serialized like a list, so it is compatible with `Vec<T>`.
+impl<T: ToBytes, const N: usize> ToBytes for FixedArray<T, N> {
+    fn to_bytes(&self) -> Result<alloc::vec::Vec<u8>, Error> {
+        let mut result = (N as u32).to_bytes()?;
+        for item in self.iter() {
+            result.append(&mut item.to_bytes()?);
+        }
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        U32_SERIALIZED_LENGTH + self.iter().map(ToBytes::serialized_length).sum::<usize>()
+    }
+}
+
+impl<T: FromBytes, const N: usize> FromBytes for FixedArray<T, N> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
+        let (items, remainder) = <alloc::vec::Vec<T>>::from_bytes(bytes)?;
+        let items = <[T; N]>::try_from(items).map_err(|_| Error::Formatting)?;
+        Ok((FixedArray(items), remainder))
+    }
+}
+
+impl<T: CLTyped, const N: usize> CLTyped for FixedArray<T, N> {
+    fn cl_type() -> CLType {
+        <alloc::vec::Vec<T>>::cl_type()
+    }
+}
+
+// A mapping kept in a struct is serialized like a map, along with the struct.
+impl<K: ToBytes, V: ToBytes> ToBytes for Mapping<K, V> {
+    fn to_bytes(&self) -> Result<alloc::vec::Vec<u8>, Error> {
+        let map: &BTreeMap<K, V> = self.as_ref();
+        map.to_bytes()
+    }
+
+    fn serialized_length(&self) -> usize {
+        let map: &BTreeMap<K, V> = self.as_ref();
+        map.serialized_length()
+    }
+}
+
+impl<K: FromBytes + Ord, V: FromBytes> FromBytes for Mapping<K, V> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
+        let (map, remainder) = <BTreeMap<K, V>>::from_bytes(bytes)?;
+        Ok((Mapping::from(map), remainder))
+    }
+}
+
+impl<K: CLTyped, V: CLTyped> CLTyped for Mapping<K, V> {
+    fn cl_type() -> CLType {
+        <BTreeMap<K, V>>::cl_type()
+    }
+}
+
+// There is no 20-byte address on Casper, an address is packed as its serialized form - a tag
+// (account or contract) followed by the hash. Padded, the tag and the hash take a word each.
+impl EncodePacked for odra_core::Address {
+    fn encode_packed(&self, out: &mut alloc::vec::Vec<u8>) {
+        out.extend(address_bytes(Some(self)));
+    }
+}
+
+impl EncodePackedElement for odra_core::Address {
+    fn encode_packed_element(&self, out: &mut alloc::vec::Vec<u8>) {
+        Some(*self).encode_packed_element(out)
+    }
+}
+
+// The zero address is represented by `None` and is packed as zeros.
+impl EncodePacked for Option<odra_core::Address> {
+    fn encode_packed(&self, out: &mut alloc::vec::Vec<u8>) {
+        out.extend(address_bytes(self.as_ref()));
+    }
+}
+
+impl EncodePackedElement for Option<odra_core::Address> {
+    fn encode_packed_element(&self, out: &mut alloc::vec::Vec<u8>) {
+        let (tag, hash) = split_address(self.as_ref());
+        out.extend(tag);
+        out.extend(hash);
+    }
+}
+
+// In the standard encoding, an address takes two words, the same words as an address element
+// of an array passed to `abi.encodePacked`, so a decoded address keeps its variant.
+// The zero words are the zero address (`None`).
+impl AbiEncode for odra_core::Address {
+    fn to_token(&self) -> Token {
+        Some(*self).to_token()
+    }
+}
+
+impl AbiEncode for Option<odra_core::Address> {
+    fn to_token(&self) -> Token {
+        let (tag, hash) = split_address(self.as_ref());
+        Token::Tuple(alloc::vec![Token::Word(tag), Token::Word(hash)])
+    }
+}
+
+impl AbiDecode for odra_core::Address {
+    const IS_DYNAMIC: bool = false;
+    const HEAD_SIZE: usize = 2 * ADDRESS_HASH_LENGTH;
+
+    fn decode_at(data: &[u8], pos: usize) -> Result<Self, AbiError> {
+        <Option<odra_core::Address>>::decode_at(data, pos)?.ok_or(AbiError::InvalidValue)
+    }
+}
+
+impl AbiDecode for Option<odra_core::Address> {
+    const IS_DYNAMIC: bool = false;
+    const HEAD_SIZE: usize = 2 * ADDRESS_HASH_LENGTH;
+
+    fn decode_at(data: &[u8], pos: usize) -> Result<Self, AbiError> {
+        let tag = U8::decode_at(data, pos)?;
+        let hash_pos = pos
+            .checked_add(ADDRESS_HASH_LENGTH)
+            .ok_or(AbiError::UnexpectedEnd)?;
+        let hash = FixedBytes::<32>::decode_at(data, hash_pos)?;
+        if tag == U8::ZERO && hash == FixedBytes::ZERO {
+            return Ok(None);
+        }
+        let mut bytes = alloc::vec::Vec::with_capacity(ADDRESS_SERIALIZED_LENGTH);
+        bytes.push(tag.0.as_limbs()[0] as u8);
+        bytes.extend(hash.0);
+        odra_core::Address::from_bytes(&bytes)
+            .map(|(address, _)| Some(address))
+            .map_err(|_| AbiError::InvalidValue)
+    }
+}
+
+/// The serialized address, zeros for the zero address.
+fn address_bytes(address: Option<&odra_core::Address>) -> [u8; ADDRESS_SERIALIZED_LENGTH] {
+    address
+        .and_then(|address| address.to_bytes().ok())
+        .and_then(|bytes| bytes.try_into().ok())
+        .unwrap_or([0u8; ADDRESS_SERIALIZED_LENGTH])
+}
+
+/// The tag of the serialized address padded to a word, and the hash.
+fn split_address(
+    address: Option<&odra_core::Address>,
+) -> ([u8; ADDRESS_HASH_LENGTH], [u8; ADDRESS_HASH_LENGTH]) {
+    let bytes = address_bytes(address);
+    let mut tag = [0u8; ADDRESS_HASH_LENGTH];
+    tag[ADDRESS_HASH_LENGTH - 1] = bytes[0];
+    let mut hash = [0u8; ADDRESS_HASH_LENGTH];
+    hash.copy_from_slice(&bytes[1..]);
+    (tag, hash)
+}
+
+impl ConvertFrom<Option<odra_core::Address>> for Option<odra_core::Address> {
+    fn convert_from(value: Option<odra_core::Address>) -> Self {
+        value
+    }
+}
+
+/// The value does not fit the target integer type, eg. a negative number converted into
+/// a casper `U256`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IntConversionError;
+
+impl fmt::Display for IntConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("integer out of range for the target type")
+    }
+}
+
+/// Copies the limbs of an integer into a casper integer of `N` limbs, returns `None`
+/// if the value does not fit.
+fn to_casper_limbs<const BITS: usize, const LIMBS: usize, const N: usize>(
+    value: &ruint::Uint<BITS, LIMBS>,
+) -> Option<[u64; N]> {
+    let limbs = value.as_limbs();
+    if limbs.iter().skip(N).any(|limb| *limb != 0) {
+        return None;
+    }
+    let mut result = [0u64; N];
+    let len = LIMBS.min(N);
+    result[..len].copy_from_slice(&limbs[..len]);
+    Some(result)
+}
+
+fn unsigned_to_casper<const BITS: usize, const LIMBS: usize, const N: usize>(
+    value: &Unsigned<BITS, LIMBS>,
+) -> Option<[u64; N]> {
+    to_casper_limbs(&value.0)
+}
+
+fn signed_to_casper<const BITS: usize, const LIMBS: usize, const N: usize>(
+    value: &Signed<BITS, LIMBS>,
+) -> Option<[u64; N]> {
+    match value.into_sign_and_abs() {
+        (Sign::Negative, _) => None,
+        (Sign::Positive, abs) => to_casper_limbs(&abs),
+    }
+}
+
+fn unsigned_from_casper<const BITS: usize, const LIMBS: usize>(
+    limbs: &[u64],
+) -> Option<Unsigned<BITS, LIMBS>> {
+    ruint::Uint::checked_from_limbs_slice(limbs).map(Unsigned)
+}
+
+fn signed_from_casper<const BITS: usize, const LIMBS: usize>(
+    limbs: &[u64],
+) -> Option<Signed<BITS, LIMBS>> {
+    let abs = ruint::Uint::checked_from_limbs_slice(limbs)?;
+    Signed::checked_from_sign_and_abs(Sign::Positive, abs)
+}
+
+// Casper integers are unsigned, so a conversion is lossless (`From`) only if every value
+// of the source type fits the target type, otherwise it is checked (`TryFrom`).
+macro_rules! impl_casper_int_conversions {
+    (
+        into $casper:ident with $convert:ident;
+        lossless: $($lossless:ident),*;
+        checked: $($checked:ident),*
+    ) => {
+        $(impl From<$lossless> for $casper {
+            fn from(value: $lossless) -> Self {
+                $casper($convert(&value).expect("the value always fits"))
+            }
+        })*
+
+        $(impl TryFrom<$checked> for $casper {
+            type Error = IntConversionError;
+
+            fn try_from(value: $checked) -> Result<Self, Self::Error> {
+                $convert(&value).map($casper).ok_or(IntConversionError)
+            }
+        })*
+    };
+    (
+        from $casper:ident with $convert:ident;
+        lossless: $($lossless:ident),*;
+        checked: $($checked:ident),*
+    ) => {
+        $(impl From<$casper> for $lossless {
+            fn from(value: $casper) -> Self {
+                $convert(&value.0).expect("the value always fits")
+            }
+        })*
+
+        $(impl TryFrom<$casper> for $checked {
+            type Error = IntConversionError;
+
+            fn try_from(value: $casper) -> Result<Self, Self::Error> {
+                $convert(&value.0).ok_or(IntConversionError)
+            }
+        })*
+    };
+}
+
+impl_casper_int_conversions!(into CasperU128 with unsigned_to_casper;
+    lossless: U8, U16, U24, U32, U40, U48, U56, U64, U72, U80, U88, U96, U104, U112, U120, U128;
+    checked: U136, U144, U152, U160, U168, U176, U184, U192, U200, U208, U216, U224, U232, U240,
+        U248, U256
+);
+impl_casper_int_conversions!(from CasperU128 with unsigned_from_casper;
+    lossless: U128, U136, U144, U152, U160, U168, U176, U184, U192, U200, U208, U216, U224, U232,
+        U240, U248, U256;
+    checked: U8, U16, U24, U32, U40, U48, U56, U64, U72, U80, U88, U96, U104, U112, U120
+);
+impl_casper_int_conversions!(from CasperU256 with unsigned_from_casper;
+    lossless: U256;
+    checked: U8, U16, U24, U32, U40, U48, U56, U64, U72, U80, U88, U96, U104, U112, U120, U128,
+        U136, U144, U152, U160, U168, U176, U184, U192, U200, U208, U216, U224, U232, U240, U248
+);
+impl_casper_int_conversions!(from CasperU128 with signed_from_casper;
+    lossless: I136, I144, I152, I160, I168, I176, I184, I192, I200, I208, I216, I224, I232, I240,
+        I248, I256;
+    checked: I8, I16, I24, I32, I40, I48, I56, I64, I72, I80, I88, I96, I104, I112, I120, I128
+);
+
+// Every unsigned integer fits a casper `U256` and `U512`, a negative one does not fit any casper
+// integer, and a casper `U512` may not fit any integer.
+macro_rules! impl_generic_casper_int_conversions {
+    ( $( $casper:ident ),* ) => {
+        $(impl<const BITS: usize, const LIMBS: usize> TryFrom<Signed<BITS, LIMBS>> for $casper {
+            type Error = IntConversionError;
+
+            fn try_from(value: Signed<BITS, LIMBS>) -> Result<Self, Self::Error> {
+                signed_to_casper(&value).map($casper).ok_or(IntConversionError)
+            }
+        })*
+    };
+}
+
+impl_generic_casper_int_conversions!(CasperU128, CasperU256, CasperU512);
+
+impl<const BITS: usize, const LIMBS: usize> From<Unsigned<BITS, LIMBS>> for CasperU256 {
+    fn from(value: Unsigned<BITS, LIMBS>) -> Self {
+        CasperU256(unsigned_to_casper(&value).expect("an integer has at most 256 bits"))
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> From<Unsigned<BITS, LIMBS>> for CasperU512 {
+    fn from(value: Unsigned<BITS, LIMBS>) -> Self {
+        CasperU512(unsigned_to_casper(&value).expect("an integer has at most 256 bits"))
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> TryFrom<CasperU256> for Signed<BITS, LIMBS> {
+    type Error = IntConversionError;
+
+    fn try_from(value: CasperU256) -> Result<Self, Self::Error> {
+        signed_from_casper(&value.0).ok_or(IntConversionError)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> TryFrom<CasperU512> for Unsigned<BITS, LIMBS> {
+    type Error = IntConversionError;
+
+    fn try_from(value: CasperU512) -> Result<Self, Self::Error> {
+        unsigned_from_casper(&value.0).ok_or(IntConversionError)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> TryFrom<CasperU512> for Signed<BITS, LIMBS> {
+    type Error = IntConversionError;
+
+    fn try_from(value: CasperU512) -> Result<Self, Self::Error> {
+        signed_from_casper(&value.0).ok_or(IntConversionError)
+    }
+}
+
+// A 32-byte hash is interchangeable with `bytes32`.
+macro_rules! impl_hash_conversions {
+    ( $( $hash:ident ),* ) => {
+        $(impl From<$hash> for FixedBytes<32> {
+            fn from(value: $hash) -> Self {
+                FixedBytes(value.value())
+            }
+        }
+
+        impl From<FixedBytes<32>> for $hash {
+            fn from(value: FixedBytes<32>) -> Self {
+                $hash::new(value.0)
+            }
+        })*
+    };
+}
+
+impl_hash_conversions!(AccountHash, ContractHash, ContractPackageHash);
+
+/// Recovers the address that signed the hash (`ecrecover` in Solidity).
+///
+/// There are no Ethereum addresses on Casper, so the address of the account owning
+/// the recovered secp256k1 key is returned. An invalid signature gives the zero address (`None`).
+pub fn ecrecover(
+    hash: FixedBytes<32>,
+    v: U8,
+    r: FixedBytes<32>,
+    s: FixedBytes<32>,
+) -> Option<odra_core::Address> {
+    let key = crypto::recover(hash, v.as_usize() as u8, r, s)?;
+    let key = PublicKey::secp256k1_from_bytes(key.to_compressed()).ok()?;
+    Some(odra_core::Address::Account(AccountHash::from(&key)))
+}
+
+#[inline(always)]
+const fn int_serialized_length(bits: usize) -> usize {
+    (bits + 7) / 8
 }
 
 #[cfg(test)]
 mod t {
-    use crate::{I32, U8};
-    use odra_core::casper_types::bytesrepr::{FromBytes, ToBytes};
+    use super::IntConversionError;
+    use crate::abi::EncodePackedElement;
+    use crate::{
+        Bytes, Fixed128x18, FixedArray, FixedBytes, I128, I136, I32, I72, I8, U128, U160, U256,
+        U64, U8,
+    };
+    use alloc::vec::Vec;
+    use odra_core::casper_types::{
+        account::AccountHash,
+        bytesrepr::{FromBytes, ToBytes},
+        ContractHash, ContractPackageHash, U128 as CasperU128, U256 as CasperU256,
+        U512 as CasperU512,
+    };
 
     #[test]
     fn ser_de() {
@@ -124,5 +537,206 @@ mod t {
         let deserialized = I32::from_bytes(&bytes).unwrap().0;
 
         assert_eq!(value, deserialized);
+
+        let value = I72::MIN;
+        let bytes = value.to_bytes().unwrap();
+        let deserialized = I72::from_bytes(&bytes).unwrap().0;
+
+        assert_eq!(value, deserialized);
+
+        let value = U256::MAX;
+        let bytes = value.to_bytes().unwrap();
+        let deserialized = U256::from_bytes(&bytes).unwrap().0;
+
+        assert_eq!(value, deserialized);
+
+        let value = FixedArray::new([U8::from_limbs([7u64]), U8::from_limbs([8u64])]);
+        let bytes = value.to_bytes().unwrap();
+        let deserialized = FixedArray::<U8, 2>::from_bytes(&bytes).unwrap().0;
+
+        assert_eq!(value, deserialized);
+
+        let value = Fixed128x18::from_raw(I128::MIN);
+        let bytes = value.to_bytes().unwrap();
+        let deserialized = Fixed128x18::from_bytes(&bytes).unwrap().0;
+
+        assert_eq!(value, deserialized);
+        assert_eq!(bytes.len(), 16);
+
+        let value = Bytes::from("nysa");
+        let bytes = value.to_bytes().unwrap();
+        let deserialized = Bytes::from_bytes(&bytes).unwrap().0;
+
+        assert_eq!(value, deserialized);
+    }
+
+    #[test]
+    fn int_serialized_length() {
+        assert_eq!(U8::MAX.to_bytes().unwrap(), [0xff]);
+        assert_eq!(I32::MINUS_ONE.to_bytes().unwrap(), [0xff; 4]);
+        assert_eq!(I72::MIN.serialized_length(), 9);
+        assert_eq!(U160::ONE.to_bytes().unwrap().len(), 20);
+        assert_eq!(U256::ZERO.serialized_length(), 32);
+
+        let (value, remainder) = U8::from_bytes(&[7, 8]).unwrap();
+        assert_eq!(value, U8::from_limbs([7]));
+        assert_eq!(remainder, [8]);
+        assert!(I32::from_bytes(&[0xff; 3]).is_err());
+    }
+
+    #[test]
+    fn address_abi_encoding() {
+        let hash = [0xab; 32];
+        let address = Some(odra_core::Address::Account(AccountHash::new(hash)));
+
+        let encoded = crate::abi::encode(&[&address, &None::<odra_core::Address>]);
+        let mut expected = [0u8; 32].to_vec();
+        expected.extend(hash);
+        expected.extend([0u8; 64]);
+        assert_eq!(encoded.0, expected);
+        assert_eq!(
+            crate::abi::decode::<(Option<odra_core::Address>, Option<odra_core::Address>)>(
+                &encoded.0
+            ),
+            Ok((address, None))
+        );
+
+        // the tag is kept, a contract address is not decoded as an account
+        let contract = odra_core::Address::Contract(ContractPackageHash::new(hash));
+        let encoded = crate::abi::encode(&[&contract, &U8::ONE]);
+        assert_eq!(encoded.0[31], 1);
+        assert_eq!(
+            crate::abi::decode::<(odra_core::Address, U8)>(&encoded.0),
+            Ok((contract, U8::ONE))
+        );
+        let mut packed = Vec::new();
+        contract.encode_packed_element(&mut packed);
+        assert_eq!(packed, &encoded.0[..64]);
+        assert_eq!(crate::abi::encode_packed(&[&contract]).0.len(), 33);
+
+        let mut invalid_tag = encoded.0.clone();
+        invalid_tag[31] = 2;
+        assert_eq!(
+            crate::abi::decode::<(odra_core::Address, U8)>(&invalid_tag),
+            Err(crate::abi::AbiError::InvalidValue)
+        );
+
+        let type_hash = crate::eip712::type_hash("Owner(address owner)");
+        assert_eq!(
+            crate::eip712::hash_struct(type_hash, &[&address]),
+            crate::keccak256(crate::abi::encode(&[&type_hash, &FixedBytes(hash)]))
+        );
+    }
+
+    #[test]
+    fn casper_int_conversions() {
+        // lossless
+        assert_eq!(CasperU256::from(U256::MAX), CasperU256::MAX);
+        assert_eq!(CasperU512::from(U8::MAX), CasperU512::from(255u64));
+        assert_eq!(CasperU128::from(U128::MAX), CasperU128::MAX);
+        // `Unsigned::from` is an inherent method converting primitives
+        let value: U256 = CasperU256::MAX.into();
+        assert_eq!(value, U256::MAX);
+        let value: U160 = CasperU128::MAX.into();
+        assert_eq!(value, U160::from_limbs([u64::MAX, u64::MAX, 0]));
+        assert_eq!(
+            I136::from(CasperU128::MAX),
+            I136::from_limbs([u64::MAX, u64::MAX, 0])
+        );
+
+        // checked
+        assert_eq!(CasperU128::try_from(U160::ONE), Ok(CasperU128::one()));
+        assert_eq!(CasperU128::try_from(U256::MAX), Err(IntConversionError));
+        assert_eq!(
+            CasperU256::try_from(I32::MINUS_ONE),
+            Err(IntConversionError)
+        );
+        assert_eq!(
+            CasperU256::try_from(I128::MAX),
+            Ok(CasperU256::from(u128::MAX >> 1))
+        );
+        assert_eq!(U64::try_from(CasperU256::from(u64::MAX)), Ok(U64::MAX));
+        assert_eq!(U64::try_from(CasperU256::MAX), Err(IntConversionError));
+        assert_eq!(
+            I8::try_from(CasperU128::from(128u64)),
+            Err(IntConversionError)
+        );
+        assert_eq!(U256::try_from(CasperU512::from(U256::MAX)), Ok(U256::MAX));
+        assert_eq!(U256::try_from(CasperU512::MAX), Err(IntConversionError));
+    }
+
+    #[test]
+    fn casper_hash_conversions() {
+        let hash = [0xab; 32];
+
+        assert_eq!(FixedBytes::from(AccountHash::new(hash)), FixedBytes(hash));
+        assert_eq!(
+            ContractHash::from(FixedBytes(hash)),
+            ContractHash::new(hash)
+        );
     }
 }
+
+/// Implements the casper serialization and ABI encoding traits for a user-defined value type,
+/// a newtype wrapping one of the serializable types, eg. `struct Price(nysa_types::U128)`.
+#[macro_export]
+macro_rules! impl_value_type {
+    ($name:ty, $inner:ty) => {
+        impl $crate::__private::casper_types::bytesrepr::ToBytes for $name {
+            fn to_bytes(
+                &self,
+            ) -> Result<
+                $crate::__private::Vec<u8>,
+                $crate::__private::casper_types::bytesrepr::Error,
+            > {
+                $crate::__private::casper_types::bytesrepr::ToBytes::to_bytes(&self.0)
+            }
+
+            fn serialized_length(&self) -> usize {
+                $crate::__private::casper_types::bytesrepr::ToBytes::serialized_length(&self.0)
+            }
+        }
+
+        impl $crate::__private::casper_types::bytesrepr::FromBytes for $name {
+            fn from_bytes(
+                bytes: &[u8],
+            ) -> Result<(Self, &[u8]), $crate::__private::casper_types::bytesrepr::Error> {
+                <$inner as $crate::__private::casper_types::bytesrepr::FromBytes>::from_bytes(bytes)
+                    .map(|(value, remainder)| (Self(value), remainder))
+            }
+        }
+
+        impl $crate::__private::casper_types::CLTyped for $name {
+            fn cl_type() -> $crate::__private::casper_types::CLType {
+                <$inner as $crate::__private::casper_types::CLTyped>::cl_type()
+            }
+        }
+
+        impl $crate::abi::EncodePacked for $name {
+            fn encode_packed(&self, out: &mut $crate::__private::Vec<u8>) {
+                $crate::abi::EncodePacked::encode_packed(&self.0, out)
+            }
+        }
+
+        impl $crate::abi::EncodePackedElement for $name {
+            fn encode_packed_element(&self, out: &mut $crate::__private::Vec<u8>) {
+                $crate::abi::EncodePackedElement::encode_packed_element(&self.0, out)
+            }
+        }
+
+        impl $crate::abi::AbiEncode for $name {
+            fn to_token(&self) -> $crate::abi::Token {
+                $crate::abi::AbiEncode::to_token(&self.0)
+            }
+        }
+
+        impl $crate::abi::AbiDecode for $name {
+            const IS_DYNAMIC: bool = <$inner as $crate::abi::AbiDecode>::IS_DYNAMIC;
+            const HEAD_SIZE: usize = <$inner as $crate::abi::AbiDecode>::HEAD_SIZE;
+
+            fn decode_at(data: &[u8], pos: usize) -> Result<Self, $crate::abi::AbiError> {
+                <$inner as $crate::abi::AbiDecode>::decode_at(data, pos).map(Self)
+            }
+        }
+    };
+}
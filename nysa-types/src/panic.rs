@@ -0,0 +1,91 @@
+//! Panics raised outside of a contract method.
+//!
+//! Library functions are associated functions, there is no contract env to revert with,
+//! so a failure reverts through the Casper host API with the error code - the same
+//! `ApiError::User` code `ContractEnv::revert` would produce. Off-chain (or without the `odra`
+//! feature) the execution aborts with a Rust panic carrying the code.
+#[cfg(not(feature = "odra"))]
+use core::fmt::Debug;
+
+#[cfg(all(feature = "odra", target_arch = "wasm32"))]
+extern "C" {
+    fn casper_revert(status: u32) -> !;
+}
+
+/// Aborts the execution with the given error code.
+#[cfg(feature = "odra")]
+#[track_caller]
+pub fn panic<E: Into<odra_core::OdraError>>(error: E) -> ! {
+    let code = error.into().code();
+    #[cfg(target_arch = "wasm32")]
+    unsafe {
+        casper_revert(u32::from(odra_core::casper_types::ApiError::User(code)))
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    panic!("User error: {}", code)
+}
+
+/// Aborts the execution with the given error.
+#[cfg(not(feature = "odra"))]
+#[track_caller]
+pub fn panic<E: Debug>(error: E) -> ! {
+    panic!("{:?}", error)
+}
+
+/// Unwraps the value or aborts the execution with the given error code.
+#[cfg(feature = "odra")]
+#[track_caller]
+pub fn unwrap_or_panic<T, E: Into<odra_core::OdraError>>(value: Option<T>, error: E) -> T {
+    match value {
+        Some(value) => value,
+        None => panic(error),
+    }
+}
+
+/// Unwraps the value or aborts the execution with the given error.
+#[cfg(not(feature = "odra"))]
+#[track_caller]
+pub fn unwrap_or_panic<T, E: Debug>(value: Option<T>, error: E) -> T {
+    match value {
+        Some(value) => value,
+        None => panic(error),
+    }
+}
+
+#[cfg(all(test, not(feature = "odra")))]
+mod tests {
+    use super::unwrap_or_panic;
+
+    #[derive(Debug)]
+    enum Panic {
+        IndexOutOfBounds,
+    }
+
+    #[test]
+    fn unwrap_some() {
+        assert_eq!(unwrap_or_panic(Some(1), Panic::IndexOutOfBounds), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "IndexOutOfBounds")]
+    fn unwrap_none() {
+        unwrap_or_panic(None::<u8>, Panic::IndexOutOfBounds);
+    }
+}
+
+#[cfg(all(test, feature = "odra"))]
+mod odra_tests {
+    use super::unwrap_or_panic;
+    use odra_core::ExecutionError;
+
+    #[test]
+    fn unwrap_some() {
+        assert_eq!(unwrap_or_panic(Some(1), ExecutionError::User(0x4e32)), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "User error: 20018")]
+    fn unwrap_none_keeps_the_code() {
+        unwrap_or_panic(None::<u8>, ExecutionError::User(0x4e32));
+    }
+}
@@ -41,7 +41,7 @@ pub type I256 = Signed<256, 4>;
 
 #[cfg(test)]
 mod t {
-    use crate::U64;
+    use crate::{U256, U64, U72, U8};
 
     use super::*;
 
@@ -124,6 +124,8 @@ mod t {
         assert_eq!(TWO.pow(U64::from_limbs([3])), EIGHT); //  2**3= 8
         assert_eq!(MINUS_TWO.pow(U64::from_limbs([3])), MINUS_EIGHT); // -2**3=-8
         assert_eq!(MINUS_TWO.pow(U64::from_limbs([2])), FOUR); // -2**2= 4
+        assert_eq!(MINUS_TWO.checked_pow(U64::from_limbs([3])), Some(MINUS_EIGHT));
+        assert_eq!(MINUS_TWO.checked_pow(U64::from_limbs([64])), None);
 
         assert_eq!(TEN + TEN, TWENTY); //10 +  10 = 20
         assert_eq!(ONE + MINUS_TEN, MINUS_NINE); //1  + -10 = -9
@@ -152,4 +154,101 @@ mod t {
         assert_eq!(-MINUS_EIGHT, EIGHT); // --8==8
         assert_eq!(-SIX, MINUS_SIX); // -6==6
     }
+
+    #[test]
+    fn division_and_remainder() {
+        // division truncates toward zero
+        assert_eq!(MINUS_SEVEN / TWO, MINUS_THREE); // -7 / 2 = -3
+        assert_eq!(SEVEN / MINUS_TWO, MINUS_THREE); // 7 / -2 = -3
+        assert_eq!(MINUS_SEVEN / MINUS_TWO, THREE); // -7 / -2 = 3
+
+        // the remainder takes the sign of the dividend
+        assert_eq!(MINUS_SEVEN % TWO, MINUS_ONE); // -7 % 2 = -1
+        assert_eq!(SEVEN % MINUS_TWO, ONE); // 7 % -2 = 1
+
+        assert_eq!(I64::MIN.checked_div(MINUS_ONE), None);
+        assert_eq!(SEVEN.checked_div(I64::ZERO), None);
+        assert_eq!(I64::MIN.checked_rem(MINUS_ONE), Some(I64::ZERO));
+        assert_eq!(SEVEN.checked_rem(I64::ZERO), None);
+
+        // a zero divisor is told apart from the `MIN / -1` overflow, which wraps unchecked
+        assert_eq!(I64::ZERO.non_zero(), None);
+        assert_eq!(MINUS_ONE.non_zero(), Some(MINUS_ONE));
+        assert_eq!(I64::MIN / MINUS_ONE, I64::MIN);
+    }
+
+    #[test]
+    fn arithmetic_shifts() {
+        // `>>` rounds toward negative infinity
+        assert_eq!(MINUS_SEVEN >> 1, MINUS_FOUR); // -7 >> 1 = -4
+        assert_eq!(MINUS_ONE >> 10, MINUS_ONE);
+        assert_eq!(SEVEN >> 1, THREE);
+        assert_eq!(I8::MIN >> 7, I8::MINUS_ONE);
+        assert_eq!(I8::MIN >> 6, I8::from_limbs([0xfe])); // -128 >> 6 = -2
+
+        // shift amounts given as unsigned integers of any size
+        assert_eq!(MINUS_SEVEN >> U8::from_limbs([1]), MINUS_FOUR);
+        assert_eq!(MINUS_SEVEN >> U256::from_limbs([64, 0, 0, 0]), MINUS_ONE);
+        assert_eq!(SEVEN >> U256::MAX, I64::ZERO);
+        assert_eq!(
+            MINUS_THREE << U8::from_limbs([2]),
+            I64::from_limbs([u64::MAX - 11])
+        );
+        assert_eq!(SEVEN << U256::MAX, I64::ZERO);
+        assert_eq!(I8::ONE << U8::from_limbs([8]), I8::ZERO);
+
+        let mut v = MINUS_EIGHT;
+        v >>= U8::from_limbs([2]);
+        assert_eq!(v, MINUS_TWO);
+        v <<= U8::from_limbs([2]);
+        assert_eq!(v, MINUS_EIGHT);
+    }
+
+    #[test]
+    fn narrow_arithmetics() {
+        // native fast paths keep the overflow semantics of the type width
+        assert_eq!(I8::MAX.checked_add(I8::ONE), None);
+        assert_eq!(I8::MAX + I8::ONE, I8::MIN);
+        assert_eq!(I8::MIN.checked_sub(I8::ONE), None);
+        assert_eq!(I8::MIN - I8::ONE, I8::MAX);
+        assert_eq!(I8::MIN.checked_mul(I8::MINUS_ONE), None);
+        assert_eq!(I8::MIN.checked_div(I8::MINUS_ONE), None);
+        assert_eq!(I8::MIN.checked_rem(I8::MINUS_ONE), Some(I8::ZERO));
+        assert_eq!(I8::MIN % I8::MINUS_ONE, I8::ZERO);
+
+        assert_eq!(I64::MIN.checked_div(MINUS_ONE), None);
+        assert_eq!(I64::MIN.checked_rem(MINUS_ONE), Some(I64::ZERO));
+        assert_eq!(I64::MAX * TWO, MINUS_TWO);
+
+        assert_eq!(I72::MAX.checked_add(I72::ONE), None);
+        assert_eq!(I72::MAX + I72::ONE, I72::MIN);
+        assert_eq!(I72::MIN.checked_mul(I72::MINUS_ONE), None);
+        assert_eq!(I72::MINUS_ONE * I72::MINUS_ONE, I72::ONE);
+
+        assert_eq!(I128::MIN.checked_add(I128::MINUS_ONE), None);
+        assert_eq!(I128::MIN.checked_div(I128::MINUS_ONE), None);
+        assert_eq!(
+            I128::MIN.checked_div(I128::from_limbs([2, 0])),
+            Some(I128::from_limbs([0, 0xc000000000000000]))
+        );
+
+        assert_eq!(
+            I8::MINUS_ONE.checked_pow(U8::from(255u8)),
+            Some(I8::MINUS_ONE)
+        );
+        assert_eq!(MINUS_TWO.checked_pow(U64::from_limbs([63])), Some(I64::MIN));
+        assert_eq!(
+            I72::MINUS_ONE.checked_pow(U72::from_limbs([0, 1])),
+            Some(I72::ONE)
+        );
+        assert_eq!(
+            I72::from_limbs([2, 0]).checked_pow(U72::from_limbs([71, 0])),
+            None
+        );
+
+        assert!(I8::MIN < I8::MINUS_ONE);
+        assert!(I8::MINUS_ONE < I8::ZERO);
+        assert!(I72::MIN < I72::MAX);
+        assert!(I128::MINUS_ONE > I128::MIN);
+    }
 }
@@ -1,4 +1,4 @@
-use crate::Unsigned;
+use crate::{native::native_op, Unsigned};
 
 use super::{utils::twos_complement, Sign, Signed};
 use core::{cmp, ops};
@@ -105,10 +105,13 @@ impl<const BITS: usize, const LIMBS: usize> Signed<BITS, LIMBS> {
     #[inline(always)]
     #[must_use]
     pub fn checked_add(self, rhs: Self) -> Option<Self> {
-        match self.overflowing_add(rhs) {
-            (value, false) => Some(value),
-            _ => None,
-        }
+        native_op!(checked signed, self.0, checked_add, rhs.0, {
+            match self.overflowing_add(rhs) {
+                (value, false) => Some(value.0),
+                _ => None,
+            }
+        })
+        .map(Self)
     }
 
     #[inline(always)]
@@ -128,7 +131,10 @@ impl<const BITS: usize, const LIMBS: usize> Signed<BITS, LIMBS> {
     #[inline(always)]
     #[must_use]
     pub fn wrapping_add(self, rhs: Self) -> Self {
-        self.overflowing_add(rhs).0
+        Self(native_op!(wrapping signed, self.0, wrapping_add, rhs.0, {
+            let (value, _) = self.overflowing_add(rhs);
+            value.0
+        }))
     }
 
     #[inline(always)]
@@ -151,10 +157,13 @@ impl<const BITS: usize, const LIMBS: usize> Signed<BITS, LIMBS> {
     #[inline(always)]
     #[must_use]
     pub fn checked_sub(self, rhs: Self) -> Option<Self> {
-        match self.overflowing_sub(rhs) {
-            (value, false) => Some(value),
-            _ => None,
-        }
+        native_op!(checked signed, self.0, checked_sub, rhs.0, {
+            match self.overflowing_sub(rhs) {
+                (value, false) => Some(value.0),
+                _ => None,
+            }
+        })
+        .map(Self)
     }
 
     #[inline(always)]
@@ -174,7 +183,10 @@ impl<const BITS: usize, const LIMBS: usize> Signed<BITS, LIMBS> {
     #[inline(always)]
     #[must_use]
     pub fn wrapping_sub(self, rhs: Self) -> Self {
-        self.overflowing_sub(rhs).0
+        Self(native_op!(wrapping signed, self.0, wrapping_sub, rhs.0, {
+            let (value, _) = self.overflowing_sub(rhs);
+            value.0
+        }))
     }
 
     #[inline(always)]
@@ -193,10 +205,13 @@ impl<const BITS: usize, const LIMBS: usize> Signed<BITS, LIMBS> {
     #[inline(always)]
     #[must_use]
     pub fn checked_mul(self, rhs: Self) -> Option<Self> {
-        match self.overflowing_mul(rhs) {
-            (value, false) => Some(value),
-            _ => None,
-        }
+        native_op!(checked signed, self.0, checked_mul, rhs.0, {
+            match self.overflowing_mul(rhs) {
+                (value, false) => Some(value.0),
+                _ => None,
+            }
+        })
+        .map(Self)
     }
 
     #[inline(always)]
@@ -216,9 +231,20 @@ impl<const BITS: usize, const LIMBS: usize> Signed<BITS, LIMBS> {
     #[inline(always)]
     #[must_use]
     pub fn wrapping_mul(self, rhs: Self) -> Self {
-        self.overflowing_mul(rhs).0
+        Self(native_op!(wrapping signed, self.0, wrapping_mul, rhs.0, {
+            let (value, _) = self.overflowing_mul(rhs);
+            value.0
+        }))
     }
 
+    /// Returns `None` if the value is zero, eg. to check a divisor.
+    #[inline(always)]
+    #[must_use]
+    pub fn non_zero(self) -> Option<Self> {
+        (!self.is_zero()).then_some(self)
+    }
+
+    /// Divides truncating toward zero, `MIN / -1` overflows.
     #[inline(always)]
     #[must_use]
     pub fn overflowing_div(self, rhs: Self) -> (Self, bool) {
@@ -234,11 +260,15 @@ impl<const BITS: usize, const LIMBS: usize> Signed<BITS, LIMBS> {
     #[inline(always)]
     #[must_use]
     pub fn checked_div(self, rhs: Self) -> Option<Self> {
-        if rhs.is_zero() || (self == Self::MIN && rhs == Self::MINUS_ONE) {
-            None
-        } else {
-            Some(self.overflowing_div(rhs).0)
-        }
+        native_op!(checked signed, self.0, checked_div, rhs.0, {
+            if rhs.is_zero() || (self == Self::MIN && rhs == Self::MINUS_ONE) {
+                None
+            } else {
+                let (value, _) = self.overflowing_div(rhs);
+                Some(value.0)
+            }
+        })
+        .map(Self)
     }
 
     #[inline(always)]
@@ -257,6 +287,7 @@ impl<const BITS: usize, const LIMBS: usize> Signed<BITS, LIMBS> {
         self.overflowing_div(rhs).0
     }
 
+    /// The remainder takes the sign of the dividend, eg. `-7 % 2 == -1`.
     #[inline(always)]
     #[must_use]
     pub fn overflowing_rem(self, rhs: Self) -> (Self, bool) {
@@ -271,17 +302,21 @@ impl<const BITS: usize, const LIMBS: usize> Signed<BITS, LIMBS> {
     #[inline(always)]
     #[must_use]
     pub fn checked_rem(self, rhs: Self) -> Option<Self> {
-        if rhs.is_zero() || (self == Self::MIN && rhs == Self::MINUS_ONE) {
+        // as in Solidity, `MIN % -1` does not revert and yields zero
+        if rhs.is_zero() {
             None
         } else {
-            Some(self.overflowing_rem(rhs).0)
+            Some(self.wrapping_rem(rhs))
         }
     }
 
     #[inline(always)]
     #[must_use]
     pub fn wrapping_rem(self, rhs: Self) -> Self {
-        self.overflowing_rem(rhs).0
+        Self(native_op!(wrapping signed, self.0, wrapping_rem, rhs.0, {
+            let (value, _) = self.overflowing_rem(rhs);
+            value.0
+        }))
     }
 
     #[inline(always)]
@@ -315,15 +350,17 @@ impl<const BITS: usize, const LIMBS: usize> Signed<BITS, LIMBS> {
         (result, overflow_pow || overflow_conv)
     }
 
+    /// Checked exponentiation, returns `None` on overflow.
     #[inline(always)]
     #[must_use]
-    pub fn checked_pow(self, exp: Uint<BITS, LIMBS>) -> Option<Self> {
-        let (result, overflow) = self.overflowing_pow(exp);
-        if overflow {
-            None
-        } else {
-            Some(result)
-        }
+    pub fn checked_pow(self, exp: Unsigned<BITS, LIMBS>) -> Option<Self> {
+        native_op!(checked_pow signed, self.0, exp.0, {
+            match self.overflowing_pow(exp.0) {
+                (value, false) => Some(value.0),
+                _ => None,
+            }
+        })
+        .map(Self)
     }
 
     #[inline(always)]
@@ -349,7 +386,7 @@ impl<const BITS: usize, const LIMBS: usize> Signed<BITS, LIMBS> {
     #[inline(always)]
     #[must_use]
     pub fn overflowing_shl(self, rhs: usize) -> (Self, bool) {
-        if rhs >= 256 {
+        if rhs >= BITS {
             (Self::ZERO, true)
         } else {
             (Self(self.0 << rhs), false)
@@ -374,7 +411,7 @@ impl<const BITS: usize, const LIMBS: usize> Signed<BITS, LIMBS> {
     #[inline(always)]
     #[must_use]
     pub fn overflowing_shr(self, rhs: usize) -> (Self, bool) {
-        if rhs >= 256 {
+        if rhs >= BITS {
             (Self::ZERO, true)
         } else {
             (Self(self.0 >> rhs), false)
@@ -396,6 +433,7 @@ impl<const BITS: usize, const LIMBS: usize> Signed<BITS, LIMBS> {
         self.overflowing_shr(rhs).0
     }
 
+    /// Arithmetic shift right, rounds toward negative infinity, eg. `-7 >> 1 == -4`.
     #[inline(always)]
     #[must_use]
     pub fn asr(self, rhs: usize) -> Self {
@@ -450,7 +488,7 @@ impl<const BITS: usize, const LIMBS: usize> Signed<BITS, LIMBS> {
 macro_rules! impl_shift {
     ($($t:ty),+) => {
         // We are OK with wrapping behavior here because it's how Rust behaves with the primitive
-        // integer types.
+        // integer types. As in Solidity, `>>` is an arithmetic shift.
 
         // $t <= usize: cast to usize
         $(
@@ -475,7 +513,7 @@ macro_rules! impl_shift {
 
                 #[inline(always)]
                 fn shr(self, rhs: $t) -> Self::Output {
-                    self.wrapping_shr(rhs as usize)
+                    self.asr(rhs as usize)
                 }
             }
 
@@ -491,6 +529,48 @@ macro_rules! impl_shift {
 
 impl_shift!(i8, u8, i16, u16, i32, u32, isize, usize);
 
+// A shift amount is an unsigned integer of any size, an amount not fitting in `usize`
+// shifts all the bits out.
+impl<const BITS: usize, const LIMBS: usize, const B: usize, const L: usize> ops::Shl<Unsigned<B, L>>
+    for Signed<BITS, LIMBS>
+{
+    type Output = Self;
+
+    #[inline(always)]
+    fn shl(self, rhs: Unsigned<B, L>) -> Self::Output {
+        self.wrapping_shl(rhs.saturating_to())
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize, const B: usize, const L: usize>
+    ops::ShlAssign<Unsigned<B, L>> for Signed<BITS, LIMBS>
+{
+    #[inline(always)]
+    fn shl_assign(&mut self, rhs: Unsigned<B, L>) {
+        *self = *self << rhs;
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize, const B: usize, const L: usize> ops::Shr<Unsigned<B, L>>
+    for Signed<BITS, LIMBS>
+{
+    type Output = Self;
+
+    #[inline(always)]
+    fn shr(self, rhs: Unsigned<B, L>) -> Self::Output {
+        self.asr(rhs.saturating_to())
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize, const B: usize, const L: usize>
+    ops::ShrAssign<Unsigned<B, L>> for Signed<BITS, LIMBS>
+{
+    #[inline(always)]
+    fn shr_assign(&mut self, rhs: Unsigned<B, L>) {
+        *self = *self >> rhs;
+    }
+}
+
 // cmp
 impl<const BITS: usize, const LIMBS: usize> cmp::PartialOrd for Signed<BITS, LIMBS> {
     #[inline(always)]
@@ -505,12 +585,14 @@ impl<const BITS: usize, const LIMBS: usize> cmp::Ord for Signed<BITS, LIMBS> {
         use cmp::Ordering::*;
         use Sign::*;
 
-        match (self.into_sign_and_abs(), other.into_sign_and_abs()) {
-            ((Positive, _), (Negative, _)) => Greater,
-            ((Negative, _), (Positive, _)) => Less,
-            ((Positive, this), (Positive, other)) => this.cmp(&other),
-            ((Negative, this), (Negative, other)) => other.cmp(&this),
-        }
+        native_op!(cmp signed, self.0, other.0, {
+            match (self.into_sign_and_abs(), other.into_sign_and_abs()) {
+                ((Positive, _), (Negative, _)) => Greater,
+                ((Negative, _), (Positive, _)) => Less,
+                ((Positive, this), (Positive, other)) => this.cmp(&other),
+                ((Negative, this), (Negative, other)) => other.cmp(&this),
+            }
+        })
     }
 }
 
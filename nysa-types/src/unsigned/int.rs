@@ -1,6 +1,7 @@
 use core::cmp::{Ord, Ordering, PartialOrd};
 
 use super::utils;
+use crate::native::native_op;
 
 #[derive(
     Debug, Default, Clone, Copy, Eq, PartialEq, Hash, derive_more::Deref, derive_more::DerefMut,
@@ -26,6 +27,58 @@ impl<const BITS: usize, const LIMBS: usize> Unsigned<BITS, LIMBS> {
         Self(self.0.pow(exp.0))
     }
 
+    /// Checked exponentiation, returns `None` on overflow.
+    pub fn checked_pow(self, exp: Self) -> Option<Self> {
+        native_op!(checked_pow unsigned, self.0, exp.0, self.0.checked_pow(exp.0)).map(Self)
+    }
+
+    /// Checked addition, returns `None` on overflow.
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        native_op!(checked unsigned, self.0, checked_add, rhs.0, self.0.checked_add(rhs.0))
+            .map(Self)
+    }
+
+    /// Checked subtraction, returns `None` on underflow.
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        native_op!(checked unsigned, self.0, checked_sub, rhs.0, self.0.checked_sub(rhs.0))
+            .map(Self)
+    }
+
+    /// Checked multiplication, returns `None` on overflow.
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        native_op!(checked unsigned, self.0, checked_mul, rhs.0, self.0.checked_mul(rhs.0))
+            .map(Self)
+    }
+
+    /// Returns `None` if the value is zero, eg. to check a divisor.
+    pub fn non_zero(self) -> Option<Self> {
+        (self != Self::ZERO).then_some(self)
+    }
+
+    /// Checked division, returns `None` if `rhs` is zero.
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        native_op!(checked unsigned, self.0, checked_div, rhs.0, self.0.checked_div(rhs.0))
+            .map(Self)
+    }
+
+    /// Checked remainder, returns `None` if `rhs` is zero.
+    pub fn checked_rem(self, rhs: Self) -> Option<Self> {
+        native_op!(checked unsigned, self.0, checked_rem, rhs.0, self.0.checked_rem(rhs.0))
+            .map(Self)
+    }
+
+    /// Computes `(self + rhs) % modulus` without overflowing the intermediate sum (`addmod` in Solidity),
+    /// returns `None` if `modulus` is zero.
+    pub fn checked_add_mod(self, rhs: Self, modulus: Self) -> Option<Self> {
+        (modulus != Self::ZERO).then(|| Self(self.0.add_mod(rhs.0, modulus.0)))
+    }
+
+    /// Computes `(self * rhs) % modulus` with a 512-bit intermediate product (`mulmod` in Solidity),
+    /// returns `None` if `modulus` is zero.
+    pub fn checked_mul_mod(self, rhs: Self, modulus: Self) -> Option<Self> {
+        (modulus != Self::ZERO).then(|| Self(self.0.mul_mod(rhs.0, modulus.0)))
+    }
+
     pub fn from<T>(value: T) -> Self
     where
         ruint::Uint<BITS, LIMBS>: ruint::UintTryFrom<T>,
@@ -36,11 +89,23 @@ impl<const BITS: usize, const LIMBS: usize> Unsigned<BITS, LIMBS> {
     pub fn cast<const B: usize, const L: usize>(self) -> Unsigned<B, L> {
         Unsigned(ruint::Uint::from(self.0))
     }
+
+    /// Converts the value into `usize`, eg. to index an array.
+    ///
+    /// Panics if the value does not fit into `usize`.
+    pub fn as_usize(&self) -> usize {
+        self.0.to::<usize>()
+    }
+
+    /// Converts the value into `usize`, returns `None` if the value does not fit into `usize`.
+    pub fn checked_as_usize(&self) -> Option<usize> {
+        self.0.try_into().ok()
+    }
 }
 
 impl<const BITS: usize, const LIMBS: usize> Ord for Unsigned<BITS, LIMBS> {
     fn cmp(&self, rhs: &Self) -> Ordering {
-        self.0.cmp(&rhs.0)
+        native_op!(cmp unsigned, self.0, rhs.0, self.0.cmp(&rhs.0))
     }
 }
 
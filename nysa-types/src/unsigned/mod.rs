@@ -132,6 +132,12 @@ mod t {
             U32::from_limbs([0b00000000000000000000000000000000]),
             v >> 8
         );
+
+        // the amount may be of any size
+        assert_eq!(v << U8::from_limbs([1]), v << 1);
+        assert_eq!(v >> U256::from_limbs([7, 0, 0, 0]), v >> 7);
+        assert_eq!(v << U256::MAX, ZERO);
+        assert_eq!(v >> U256::MAX, ZERO);
     }
 
     #[test]
@@ -161,4 +167,91 @@ mod t {
         assert_eq!(TEN % TWENTY, TEN);
         assert_eq!(TEN % THREE, ONE);
     }
+
+    #[test]
+    fn checked_arithmetics() {
+        let max = U32::MAX;
+
+        assert_eq!(TEN.checked_add(TEN), Some(TWENTY));
+        assert_eq!(max.checked_add(ONE), None);
+
+        assert_eq!(TWENTY.checked_sub(TEN), Some(TEN));
+        assert_eq!(ZERO.checked_sub(ONE), None);
+
+        assert_eq!(TWENTY.checked_mul(TEN), Some(U32::from_limbs([200])));
+        assert_eq!(max.checked_mul(TWO), None);
+
+        assert_eq!(TWENTY.checked_div(TEN), Some(TWO));
+        assert_eq!(TEN.checked_div(ZERO), None);
+
+        assert_eq!(TEN.checked_rem(THREE), Some(ONE));
+        assert_eq!(TEN.checked_rem(ZERO), None);
+
+        assert_eq!(TWO.checked_pow(THREE), Some(EIGHT));
+        assert_eq!(TWO.checked_pow(THIRTY_TWO), None);
+    }
+
+    #[test]
+    fn usize_conversion() {
+        assert_eq!(TEN.checked_as_usize(), Some(10));
+        assert_eq!(U256::MAX.checked_as_usize(), None);
+    }
+
+    #[test]
+    fn modular_arithmetics() {
+        let max = U32::MAX;
+
+        // the intermediate results do not overflow
+        assert_eq!(max.checked_add_mod(max, TEN), Some(ZERO));
+        assert_eq!(max.checked_add_mod(TWO, max), Some(TWO));
+        assert_eq!(max.checked_mul_mod(max, THREE), Some(ZERO));
+        assert_eq!(max.checked_mul_mod(TWO, NINE), Some(U32::from(6u32)));
+
+        assert_eq!(TEN.checked_add_mod(ONE, ZERO), None);
+        assert_eq!(TEN.checked_mul_mod(ONE, ZERO), None);
+    }
+
+    #[test]
+    fn narrow_arithmetics() {
+        // native fast paths keep the overflow semantics of the type width
+        assert_eq!(U8::MAX.checked_add(U8::ONE), None);
+        assert_eq!(U8::MAX + U8::ONE, U8::ZERO);
+        assert_eq!(U8::ZERO - U8::ONE, U8::MAX);
+        assert_eq!(U8::from(16u8).checked_mul(U8::from(16u8)), None);
+        assert_eq!(U8::from(15u8) * U8::from(17u8), U8::MAX);
+        assert_eq!(U8::MAX.checked_div(U8::ZERO), None);
+
+        assert_eq!(U64::MAX.checked_add(U64::ONE), None);
+        assert_eq!(U64::MAX * U64::MAX, U64::ONE);
+
+        assert_eq!(U72::MAX.checked_add(U72::ONE), None);
+        assert_eq!(U72::MAX + U72::ONE, U72::ZERO);
+        assert_eq!(
+            U72::from_limbs([0, 1]).checked_mul(U72::from(128u8)),
+            Some(U72::from_limbs([0, 128]))
+        );
+        assert_eq!(U72::from_limbs([0, 1]).checked_mul(U72::from(256u16)), None);
+
+        assert_eq!(U128::MAX.checked_sub(U128::MAX), Some(U128::ZERO));
+        assert_eq!(U128::ZERO.checked_sub(U128::ONE), None);
+        assert_eq!(U128::MAX % U128::from(10u8), U128::from(5u8));
+
+        assert_eq!(
+            U8::from(2u8).checked_pow(U8::from(7u8)),
+            Some(U8::from(128u8))
+        );
+        assert_eq!(U8::from(2u8).checked_pow(U8::from(8u8)), None);
+        assert_eq!(
+            U72::from(2u8).checked_pow(U72::from(71u8)),
+            Some(U72::from_limbs([0, 128]))
+        );
+        assert_eq!(U72::from(2u8).checked_pow(U72::from(72u8)), None);
+        assert_eq!(
+            U72::ONE.checked_pow(U72::from_limbs([0, 1])),
+            Some(U72::ONE)
+        );
+
+        assert!(U8::MAX > U8::ONE);
+        assert!(U72::from_limbs([0, 1]) > U72::from_limbs([u64::MAX, 0]));
+    }
 }
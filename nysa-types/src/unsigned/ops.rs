@@ -2,7 +2,7 @@ use core::ops::{
     self, Add, AddAssign, Div, DivAssign, Mul, MulAssign, Rem, RemAssign, Sub, SubAssign,
 };
 
-use crate::Unsigned;
+use crate::{native::native_op, Unsigned};
 
 macro_rules! impl_bin_op {
     ( $trait:ident, $fn:ident, $trait_assign:ident, $fn_assign:ident, $fdel:ident) => {
@@ -10,7 +10,7 @@ macro_rules! impl_bin_op {
             for Unsigned<BITS, LIMBS>
         {
             fn $fn_assign(&mut self, rhs: Unsigned<BITS, LIMBS>) {
-                *self = Unsigned((**self).$fdel(*rhs));
+                *self = $trait::$fn(*self, rhs);
             }
         }
 
@@ -18,7 +18,7 @@ macro_rules! impl_bin_op {
             for Unsigned<BITS, LIMBS>
         {
             fn $fn_assign(&mut self, rhs: &Unsigned<BITS, LIMBS>) {
-                *self = Unsigned((**self).$fdel(**rhs));
+                *self = $trait::$fn(*self, *rhs);
             }
         }
 
@@ -28,7 +28,13 @@ macro_rules! impl_bin_op {
             type Output = Unsigned<BITS, LIMBS>;
 
             fn $fn(self, rhs: Unsigned<BITS, LIMBS>) -> Self::Output {
-                Unsigned((*self).$fdel(*rhs))
+                Unsigned(native_op!(
+                    wrapping unsigned,
+                    self.0,
+                    $fdel,
+                    rhs.0,
+                    self.0.$fdel(rhs.0)
+                ))
             }
         }
 
@@ -38,7 +44,7 @@ macro_rules! impl_bin_op {
             type Output = Unsigned<BITS, LIMBS>;
 
             fn $fn(self, rhs: &Unsigned<BITS, LIMBS>) -> Self::Output {
-                Unsigned((*self).$fdel(**rhs))
+                $trait::$fn(self, *rhs)
             }
         }
 
@@ -48,7 +54,7 @@ macro_rules! impl_bin_op {
             type Output = Unsigned<BITS, LIMBS>;
 
             fn $fn(self, rhs: Unsigned<BITS, LIMBS>) -> Self::Output {
-                Unsigned((**self).$fdel(*rhs))
+                $trait::$fn(*self, rhs)
             }
         }
 
@@ -58,7 +64,7 @@ macro_rules! impl_bin_op {
             type Output = Unsigned<BITS, LIMBS>;
 
             fn $fn(self, rhs: &Unsigned<BITS, LIMBS>) -> Self::Output {
-                Unsigned((**self).$fdel(**rhs))
+                $trait::$fn(*self, *rhs)
             }
         }
     };
@@ -228,139 +234,141 @@ impl<const BITS: usize, const LIMBS: usize> ops::Shr<usize> for &Unsigned<BITS,
     }
 }
 
-impl<const BITS: usize, const LIMBS: usize> ops::Shr<&Unsigned<BITS, LIMBS>>
-    for Unsigned<BITS, LIMBS>
+// A shift amount is an unsigned integer of any size, an amount not fitting in `usize`
+// shifts all the bits out.
+impl<const BITS: usize, const LIMBS: usize, const B: usize, const L: usize>
+    ops::Shr<&Unsigned<B, L>> for Unsigned<BITS, LIMBS>
 {
     type Output = Self;
 
     #[allow(clippy::inline_always)]
     #[inline(always)]
-    fn shr(self, rhs: &Unsigned<BITS, LIMBS>) -> Self {
-        Self(self.wrapping_shr(rhs.to()))
+    fn shr(self, rhs: &Unsigned<B, L>) -> Self {
+        Self(self.wrapping_shr(rhs.saturating_to()))
     }
 }
 
-impl<const BITS: usize, const LIMBS: usize> ops::Shr<&Unsigned<BITS, LIMBS>>
-    for &Unsigned<BITS, LIMBS>
+impl<const BITS: usize, const LIMBS: usize, const B: usize, const L: usize>
+    ops::Shr<&Unsigned<B, L>> for &Unsigned<BITS, LIMBS>
 {
     type Output = Unsigned<BITS, LIMBS>;
 
     #[allow(clippy::inline_always)]
     #[inline(always)]
-    fn shr(self, rhs: &Unsigned<BITS, LIMBS>) -> Self::Output {
-        Unsigned(self.wrapping_shr(rhs.to()))
+    fn shr(self, rhs: &Unsigned<B, L>) -> Self::Output {
+        Unsigned(self.wrapping_shr(rhs.saturating_to()))
     }
 }
 
-impl<const BITS: usize, const LIMBS: usize> ops::ShlAssign<Unsigned<BITS, LIMBS>>
-    for Unsigned<BITS, LIMBS>
+impl<const BITS: usize, const LIMBS: usize, const B: usize, const L: usize>
+    ops::ShlAssign<Unsigned<B, L>> for Unsigned<BITS, LIMBS>
 {
     #[allow(clippy::inline_always)]
     #[inline(always)]
-    fn shl_assign(&mut self, rhs: Unsigned<BITS, LIMBS>) {
-        **self = self.wrapping_shl(rhs.to());
+    fn shl_assign(&mut self, rhs: Unsigned<B, L>) {
+        **self = self.wrapping_shl(rhs.saturating_to());
     }
 }
 
-impl<const BITS: usize, const LIMBS: usize> ops::ShlAssign<&Unsigned<BITS, LIMBS>>
-    for Unsigned<BITS, LIMBS>
+impl<const BITS: usize, const LIMBS: usize, const B: usize, const L: usize>
+    ops::ShlAssign<&Unsigned<B, L>> for Unsigned<BITS, LIMBS>
 {
     #[allow(clippy::inline_always)]
     #[inline(always)]
-    fn shl_assign(&mut self, rhs: &Unsigned<BITS, LIMBS>) {
-        **self = self.wrapping_shl(rhs.to());
+    fn shl_assign(&mut self, rhs: &Unsigned<B, L>) {
+        **self = self.wrapping_shl(rhs.saturating_to());
     }
 }
 
-impl<const BITS: usize, const LIMBS: usize> ops::Shl<Unsigned<BITS, LIMBS>>
+impl<const BITS: usize, const LIMBS: usize, const B: usize, const L: usize> ops::Shl<Unsigned<B, L>>
     for Unsigned<BITS, LIMBS>
 {
     type Output = Self;
 
     #[allow(clippy::inline_always)]
     #[inline(always)]
-    fn shl(self, rhs: Unsigned<BITS, LIMBS>) -> Self {
-        Self(self.wrapping_shl(rhs.to()))
+    fn shl(self, rhs: Unsigned<B, L>) -> Self {
+        Self(self.wrapping_shl(rhs.saturating_to()))
     }
 }
 
-impl<const BITS: usize, const LIMBS: usize> ops::Shl<Unsigned<BITS, LIMBS>>
+impl<const BITS: usize, const LIMBS: usize, const B: usize, const L: usize> ops::Shl<Unsigned<B, L>>
     for &Unsigned<BITS, LIMBS>
 {
     type Output = Unsigned<BITS, LIMBS>;
 
     #[allow(clippy::inline_always)]
     #[inline(always)]
-    fn shl(self, rhs: Unsigned<BITS, LIMBS>) -> Self::Output {
-        Unsigned(self.wrapping_shl(rhs.to()))
+    fn shl(self, rhs: Unsigned<B, L>) -> Self::Output {
+        Unsigned(self.wrapping_shl(rhs.saturating_to()))
     }
 }
 
-impl<const BITS: usize, const LIMBS: usize> ops::Shl<&Unsigned<BITS, LIMBS>>
-    for Unsigned<BITS, LIMBS>
+impl<const BITS: usize, const LIMBS: usize, const B: usize, const L: usize>
+    ops::Shl<&Unsigned<B, L>> for Unsigned<BITS, LIMBS>
 {
     type Output = Self;
 
     #[allow(clippy::inline_always)]
     #[inline(always)]
-    fn shl(self, rhs: &Unsigned<BITS, LIMBS>) -> Self {
-        Self(self.wrapping_shl(rhs.to()))
+    fn shl(self, rhs: &Unsigned<B, L>) -> Self {
+        Self(self.wrapping_shl(rhs.saturating_to()))
     }
 }
 
-impl<const BITS: usize, const LIMBS: usize> ops::Shl<&Unsigned<BITS, LIMBS>>
-    for &Unsigned<BITS, LIMBS>
+impl<const BITS: usize, const LIMBS: usize, const B: usize, const L: usize>
+    ops::Shl<&Unsigned<B, L>> for &Unsigned<BITS, LIMBS>
 {
     type Output = Unsigned<BITS, LIMBS>;
 
     #[allow(clippy::inline_always)]
     #[inline(always)]
-    fn shl(self, rhs: &Unsigned<BITS, LIMBS>) -> Self::Output {
-        Unsigned(self.wrapping_shl(rhs.to()))
+    fn shl(self, rhs: &Unsigned<B, L>) -> Self::Output {
+        Unsigned(self.wrapping_shl(rhs.saturating_to()))
     }
 }
 
-impl<const BITS: usize, const LIMBS: usize> ops::ShrAssign<Unsigned<BITS, LIMBS>>
-    for Unsigned<BITS, LIMBS>
+impl<const BITS: usize, const LIMBS: usize, const B: usize, const L: usize>
+    ops::ShrAssign<Unsigned<B, L>> for Unsigned<BITS, LIMBS>
 {
     #[allow(clippy::inline_always)]
     #[inline(always)]
-    fn shr_assign(&mut self, rhs: Unsigned<BITS, LIMBS>) {
-        **self = self.wrapping_shr(rhs.to());
+    fn shr_assign(&mut self, rhs: Unsigned<B, L>) {
+        **self = self.wrapping_shr(rhs.saturating_to());
     }
 }
 
-impl<const BITS: usize, const LIMBS: usize> ops::ShrAssign<&Unsigned<BITS, LIMBS>>
-    for Unsigned<BITS, LIMBS>
+impl<const BITS: usize, const LIMBS: usize, const B: usize, const L: usize>
+    ops::ShrAssign<&Unsigned<B, L>> for Unsigned<BITS, LIMBS>
 {
     #[allow(clippy::inline_always)]
     #[inline(always)]
-    fn shr_assign(&mut self, rhs: &Unsigned<BITS, LIMBS>) {
-        **self = self.wrapping_shr(rhs.to());
+    fn shr_assign(&mut self, rhs: &Unsigned<B, L>) {
+        **self = self.wrapping_shr(rhs.saturating_to());
     }
 }
 
-impl<const BITS: usize, const LIMBS: usize> ops::Shr<Unsigned<BITS, LIMBS>>
+impl<const BITS: usize, const LIMBS: usize, const B: usize, const L: usize> ops::Shr<Unsigned<B, L>>
     for Unsigned<BITS, LIMBS>
 {
     type Output = Self;
 
     #[allow(clippy::inline_always)]
     #[inline(always)]
-    fn shr(self, rhs: Unsigned<BITS, LIMBS>) -> Self {
-        Self(self.wrapping_shr(rhs.to()))
+    fn shr(self, rhs: Unsigned<B, L>) -> Self {
+        Self(self.wrapping_shr(rhs.saturating_to()))
     }
 }
 
-impl<const BITS: usize, const LIMBS: usize> ops::Shr<Unsigned<BITS, LIMBS>>
+impl<const BITS: usize, const LIMBS: usize, const B: usize, const L: usize> ops::Shr<Unsigned<B, L>>
     for &Unsigned<BITS, LIMBS>
 {
     type Output = Unsigned<BITS, LIMBS>;
 
     #[allow(clippy::inline_always)]
     #[inline(always)]
-    fn shr(self, rhs: Unsigned<BITS, LIMBS>) -> Self::Output {
-        Unsigned(self.wrapping_shr(rhs.to()))
+    fn shr(self, rhs: Unsigned<B, L>) -> Self::Output {
+        Unsigned(self.wrapping_shr(rhs.saturating_to()))
     }
 }
 
@@ -3,6 +3,8 @@ use std::{collections::HashSet, vec};
 use c3_lang_linearization::{Class, C3};
 use solidity_parser::pt::{self, ContractDefinition, Identifier};
 
+use crate::ParserError;
+
 /// Applies C3 superclass linearization algorithm to solidity [ContractDefinition]
 ///
 /// C3 superclass linearization is an algorithm used to obtain the order
@@ -14,10 +16,11 @@ use solidity_parser::pt::{self, ContractDefinition, Identifier};
 ///
 /// * `contracts` - A [ContractDefinition] slice that holds solidity contact abstract syntax tree (AST).
 ///
-/// # Panics
+/// # Errors
 ///
-/// * if c3 linearization fails from any reason
-pub fn linearization(contracts: &[&ContractDefinition]) -> C3 {
+/// * [ParserError::InvalidInheritance] if c3 linearization fails from any reason
+/// or the resulting order diverges from the order used by solc.
+pub fn linearization(contracts: &[&ContractDefinition]) -> Result<C3, ParserError> {
     // collect interfaces to exclude them from the inheritance path
     let interfaces = contracts
         .iter()
@@ -32,7 +35,89 @@ pub fn linearization(contracts: &[&ContractDefinition]) -> C3 {
         .filter(|c| !matches!(c.ty, pt::ContractTy::Interface(_)))
         .for_each(|contract| register_class(contract, &interfaces, &mut c3));
 
-    c3_lang_linearization::c3_linearization(c3).expect("Linearization failed")
+    let c3 = c3_lang_linearization::c3_linearization(c3)
+        .map_err(|_| ParserError::InvalidInheritance("Linearization failed".to_string()))?;
+
+    contracts
+        .iter()
+        .filter(|c| !matches!(c.ty, pt::ContractTy::Interface(_)))
+        .try_for_each(|contract| verify_path(contract, contracts, &interfaces, &c3))?;
+    Ok(c3)
+}
+
+/// Checks if the contract path matches the linearization computed by solc.
+///
+/// Interfaces are excluded from the c3 graph, so the resulting path may diverge from the solc order,
+/// and then `super` calls would be dispatched to a different contract.
+fn verify_path(
+    contract: &ContractDefinition,
+    contracts: &[&ContractDefinition],
+    interfaces: &[Class],
+    c3: &C3,
+) -> Result<(), ParserError> {
+    let class = to_class(&contract.name);
+    let expected = solc_linearization(contract, contracts)?
+        .into_iter()
+        .filter(|c| !interfaces.contains(c))
+        .collect::<Vec<_>>();
+    let path = c3
+        .path(&class)
+        .map_err(|_| ParserError::InvalidInheritance(format!("No path for {}", class)))?;
+
+    match path == expected {
+        true => Ok(()),
+        false => Err(ParserError::InvalidInheritance(format!(
+            "{} linearized as {:?}, but solc expects {:?}",
+            class, path, expected
+        ))),
+    }
+}
+
+/// Linearizes the inheritance graph the way solc does, including interfaces.
+///
+/// The base contracts are declared from the "most base-like" to the "most derived",
+/// so the bases are merged in the reversed order.
+fn solc_linearization(
+    contract: &ContractDefinition,
+    contracts: &[&ContractDefinition],
+) -> Result<Vec<Class>, ParserError> {
+    let bases = contract
+        .base
+        .iter()
+        .rev()
+        .map(|b| {
+            contracts.iter().find(|c| c.name.name == b.name.name).ok_or(
+                ParserError::InvalidInheritance(format!("Unknown base contract {}", b.name.name)),
+            )
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut sequences = bases
+        .iter()
+        .map(|b| solc_linearization(b, contracts))
+        .collect::<Result<Vec<_>, _>>()?;
+    sequences.push(bases.iter().map(|b| to_class(&b.name)).collect());
+
+    let mut result = vec![to_class(&contract.name)];
+    loop {
+        sequences.retain(|s| !s.is_empty());
+        if sequences.is_empty() {
+            return Ok(result);
+        }
+        // the first head that does not appear in the tail of any sequence
+        let head = sequences
+            .iter()
+            .map(|s| s[0].clone())
+            .find(|head| sequences.iter().all(|s| !s[1..].contains(head)))
+            .ok_or(ParserError::InvalidInheritance(format!(
+                "Linearization of inheritance graph impossible for {}",
+                contract.name.name
+            )))?;
+        sequences.iter_mut().filter(|s| s[0] == head).for_each(|s| {
+            s.remove(0);
+        });
+        result.push(head);
+    }
 }
 
 /// Filters ids of top-level contracts from a slice. A top-level contract is a contract that is the root of the inheritance graph.
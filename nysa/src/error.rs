@@ -10,6 +10,9 @@ pub enum ParserError {
     /// Type is not supported by the parser.
     #[error("Unsupported type `{0:?}`.")]
     UnsupportedType(Type),
+    /// External function types cannot be expressed in Odra, only internal function pointers are supported.
+    #[error("External function type `{0:?}` is not supported.")]
+    ExternalFunctionType(Type),
     /// Expression is not supported by the parser.
     #[error("Unexpected expression, expected `{0}`, but found `{1:?}`.")]
     UnexpectedExpression(&'static str, Expression),
@@ -40,6 +43,27 @@ pub enum ParserError {
     /// Attempt to use an expression in invalid context.
     #[error("Could not parse expression: {0}")]
     InvalidExpression(String),
+    /// The inheritance graph cannot be linearized the way solc does.
+    #[error("Invalid inheritance: {0}")]
+    InvalidInheritance(String),
+    /// An explicit conversion between the types is not allowed in Solidity.
+    #[error("Explicit conversion from `{0:?}` to `{1:?}` is not allowed.")]
+    InvalidConversion(Type, Type),
+    /// A Casper address (a tag and a 32-byte hash) does not fit in 20 bytes, the conversion would truncate it.
+    #[error("Conversion from `{0:?}` to `{1:?}` is not supported, a Casper address does not fit in 20 bytes.")]
+    AddressConversion(Type, Type),
+    /// A value is used where a value of another type is expected and cannot be implicitly converted.
+    #[error("Type `{1:?}` is not implicitly convertible to expected type `{0:?}`.")]
+    TypeMismatch(Type, Type),
+    /// A number literal does not fit in the integer type it is used as.
+    #[error("Literal `{0}` is out of range of `{1:?}`.")]
+    LiteralOutOfRange(String, Type),
+    /// A custom error is not defined or is reverted with an invalid number of arguments.
+    #[error("Invalid custom error {0}")]
+    InvalidError(String),
+    /// No unique error code can be derived from the selector of a custom error.
+    #[error("Custom error {0} has no unique error code")]
+    ErrorCodeCollision(String),
 }
 
 #[macro_export]
@@ -11,7 +11,7 @@ use crate::{
 
 use super::{
     func::{Constructor, FnImplementations, Function},
-    misc::{ContractMetadata, LibUsing, Var},
+    misc::{ContractMetadata, FunctionType, LibUsing, Type, Var},
     Named,
 };
 
@@ -29,11 +29,13 @@ pub struct ContractData {
     c3_path: Vec<Class>,
 }
 
-impl TryFrom<(&Class, &Vec<&ContractDefinition>)> for ContractData {
+impl TryFrom<(&Class, &Vec<&ContractDefinition>, &[LibUsing])> for ContractData {
     type Error = &'static str;
 
-    fn try_from(value: (&Class, &Vec<&ContractDefinition>)) -> Result<Self, Self::Error> {
-        let (class, contracts) = value;
+    fn try_from(
+        value: (&Class, &Vec<&ContractDefinition>, &[LibUsing]),
+    ) -> Result<Self, Self::Error> {
+        let (class, contracts, file_libs) = value;
 
         // extract the main contract definition
         let contract: ContractMetadata = extract_contract(class, contracts)
@@ -41,11 +43,11 @@ impl TryFrom<(&Class, &Vec<&ContractDefinition>)> for ContractData {
             .to_owned()
             .into();
 
-        let c3 = c3::linearization(&contracts);
+        let c3 = c3::linearization(&contracts).map_err(|_| "Linearization failed")?;
 
         let mut fn_map: HashMap<String, Vec<(Class, Function)>> = HashMap::new();
         // let mut var_map = HashMap::new();
-        let mut libs = Vec::<LibUsing>::new();
+        let mut libs = file_libs.to_vec();
         let mut vars = vec![];
         // Iterate over all the classes from the inheritance graph and pull out variables, functions and libs
         c3.path(&contract.name().into())
@@ -55,7 +57,11 @@ impl TryFrom<(&Class, &Vec<&ContractDefinition>)> for ContractData {
             .for_each(|class| {
                 let def = extract_contract(&class, contracts).unwrap();
 
-                libs.extend(map_collection(ast::extract_using(def)));
+                libs.extend(
+                    ast::extract_using(def)
+                        .into_iter()
+                        .flat_map(LibUsing::from_directive),
+                );
                 let mut fns: Vec<Function> = map_collection(ast::extract_functions(def));
 
                 let constructor = fns
@@ -147,16 +153,55 @@ impl ContractData {
         self.functions.clone()
     }
 
+    /// Returns contract functions to be rewritten, eg. by a pass over the IR.
+    pub fn fn_implementations_mut(&mut self) -> &mut [FnImplementations] {
+        &mut self.functions
+    }
+
     /// Checks if a function with a given name exists in the contract.
     pub fn has_function(&self, name: &str) -> bool {
         self.functions.iter().find(|f| &f.name == name).is_some()
     }
 
+    /// Returns the names of internal functions that can be assigned to a variable of the given function type.
+    pub fn internal_fns_of_type(&self, ty: &FunctionType) -> Vec<String> {
+        self.functions
+            .iter()
+            .filter(|f| match f.implementations.first() {
+                Some((_, Function::Function(f))) => &FunctionType::from(f) == ty,
+                _ => false,
+            })
+            .map(|f| f.name.clone())
+            .collect()
+    }
+
+    /// Returns the names of internal functions that can be kept in the contract storage, the union of
+    /// functions assignable to function-typed state variables.
+    pub fn stored_internal_fns(&self) -> Vec<String> {
+        let mut fns = self
+            .vars
+            .iter()
+            .filter_map(|v| match &v.ty {
+                Type::Function(f) if !f.is_external => Some(self.internal_fns_of_type(f)),
+                _ => None,
+            })
+            .flatten()
+            .collect::<Vec<_>>();
+        fns.sort();
+        fns.dedup();
+        fns
+    }
+
     /// Returns state variables (from the main contract and inherited).
     pub fn vars(&self) -> Vec<Var> {
         self.vars.clone()
     }
 
+    /// Returns state variables to be rewritten, eg. by a pass over the IR.
+    pub fn vars_mut(&mut self) -> &mut [Var] {
+        &mut self.vars
+    }
+
     /// Returns if the contract of a given `class` is abstract.
     pub fn is_abstract(&self, class: &Class) -> bool {
         self.all_contracts
@@ -1,9 +1,10 @@
 use solidity_parser::pt::{self, Parameter};
 use syn::parse_quote;
 
-use crate::{formatted_invalid_expr, parser::context::*, ParserError};
+use crate::{formatted_invalid_expr, parser::context::*, utils, ParserError};
 
 use super::{
+    func::Function,
     misc::{Type, Var},
     op::{BitwiseOp, LogicalOp, MathOp, Op, UnaryOp},
     stmt::Stmt,
@@ -18,20 +19,47 @@ use super::{
 /// An expression is intended to be parsed into [syn::Expr](syn::Expr).
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord)]
 pub enum Expression {
-    /// Error handling expression eg. `require(c >= a, "SafeMath: addition overflow");`
-    Require(Box<Expression>, Box<Expression>),
+    /// Error handling expression eg. `require(c >= a, "SafeMath: addition overflow");`,
+    /// `require(c >= a);` or `require(c >= a, Overflow(c, a));`
+    Require(Box<Expression>, Option<Box<Expression>>),
+    /// Internal error check eg. `assert(totalSupply >= balance);`
+    Assert(Box<Expression>),
     /// A special `_` occurring in modifiers.
     Placeholder,
     /// A special `address(0)` expr.
     ZeroAddress,
     /// keccak256 function call.
     Keccak256(Vec<Expression>),
+    /// sha256 function call.
+    Sha256(Vec<Expression>),
+    /// ripemd160 function call.
+    Ripemd160(Vec<Expression>),
+    /// ecrecover function call - a hash, v, r and s.
+    EcRecover(Vec<Expression>),
+    /// addmod function call.
+    AddMod(Vec<Expression>),
+    /// mulmod function call.
+    MulMod(Vec<Expression>),
     /// abi.encodePacked function call.
     AbiEncodePacked(Vec<Expression>),
+    /// abi.encode function call.
+    AbiEncode(Vec<Expression>),
+    /// abi.encodeWithSelector function call - a selector and values.
+    AbiEncodeWithSelector(Box<Expression>, Vec<Expression>),
+    /// abi.encodeWithSignature function call - a signature and values.
+    AbiEncodeWithSignature(Box<Expression>, Vec<Expression>),
+    /// abi.decode function call - data and the types of the decoded values.
+    AbiDecode(Box<Expression>, Vec<Expression>),
+    /// string.concat function call.
+    StringConcat(Vec<Expression>),
+    /// bytes.concat function call.
+    BytesConcat(Vec<Expression>),
     /// msg.* expr (eg. msg.sender).
     Message(Message),
     /// A collection access (local array, state array/mapping)
     Collection(String, Vec<Expression>),
+    /// A collection access of a struct field, eg. `set._values[i]` or `set._indexes[value]`.
+    Index(Box<Expression>, Vec<Expression>),
     /// A variable access (local, state).
     Variable(String),
     /// Boolean literal (false, true).
@@ -54,6 +82,9 @@ pub enum Expression {
     MemberAccess(String, Box<Expression>),
     /// Number literal, eg. `123`.
     NumberLiteral(Vec<u64>),
+    /// Non-integer rational number literal, eg. `1.25` is `RationalLiteral(vec![5], vec![4])`,
+    /// the numerator and the denominator.
+    RationalLiteral(Vec<u64>, Vec<u64>),
     /// A regular function call.
     Func(Box<Expression>, Vec<Expression>),
     /// Super function call.
@@ -137,9 +168,10 @@ impl TryInto<syn::Expr> for &Message {
     }
 }
 
-fn try_to_zero_address(name: &pt::Expression) -> Option<Expression> {
-    if let pt::Expression::Type(_, ty) = name {
-        if *ty == pt::Type::Address || *ty == pt::Type::AddressPayable {
+fn try_to_zero_address(name: &pt::Expression, args: &[pt::Expression]) -> Option<Expression> {
+    if let (pt::Expression::Type(_, ty), [pt::Expression::NumberLiteral(_, num)]) = (name, args) {
+        let is_zero = num.to_u64_digits().1.is_empty();
+        if is_zero && (*ty == pt::Type::Address || *ty == pt::Type::AddressPayable) {
             return Some(Expression::ZeroAddress);
         }
     }
@@ -150,8 +182,18 @@ fn try_to_require(name: &pt::Expression, args: &[pt::Expression]) -> Option<Expr
     if let pt::Expression::Variable(ref id) = name {
         if id.name.as_str() == "require" {
             let condition = args.get(0).expect("Should be revert condition").into();
-            let error = args.get(1).expect("Should be the error message").into();
-            return Some(Expression::Require(Box::new(condition), Box::new(error)));
+            let error = args.get(1).map(|e| Box::new(e.into()));
+            return Some(Expression::Require(Box::new(condition), error));
+        }
+    }
+    None
+}
+
+fn try_to_assert(name: &pt::Expression, args: &[pt::Expression]) -> Option<Expression> {
+    if let pt::Expression::Variable(ref id) = name {
+        if id.name.as_str() == "assert" {
+            let condition = args.get(0).expect("Should be assert condition").into();
+            return Some(Expression::Assert(Box::new(condition)));
         }
     }
     None
@@ -196,17 +238,72 @@ fn try_to_keccak(name: &pt::Expression, args: &[pt::Expression]) -> Option<Expre
     None
 }
 
+fn try_to_builtin_fn(name: &pt::Expression, args: &[pt::Expression]) -> Option<Expression> {
+    if let pt::Expression::Variable(var) = name {
+        let args = || args.iter().map(From::from).collect();
+        return match var.name.as_str() {
+            "sha256" => Some(Expression::Sha256(args())),
+            "ripemd160" => Some(Expression::Ripemd160(args())),
+            "ecrecover" => Some(Expression::EcRecover(args())),
+            "addmod" => Some(Expression::AddMod(args())),
+            "mulmod" => Some(Expression::MulMod(args())),
+            _ => None,
+        };
+    }
+    None
+}
+
+fn try_to_concat(name: &pt::Expression, args: &[pt::Expression]) -> Option<Expression> {
+    if let pt::Expression::MemberAccess(_, box pt::Expression::Type(_, ty), fn_id) = name {
+        let args = || args.iter().map(From::from).collect();
+        return match (ty, fn_id.name.as_str()) {
+            (pt::Type::String, "concat") => Some(Expression::StringConcat(args())),
+            (pt::Type::DynamicBytes, "concat") => Some(Expression::BytesConcat(args())),
+            _ => None,
+        };
+    }
+    None
+}
+
 fn try_to_abi_encode(name: &pt::Expression, args: &[pt::Expression]) -> Option<Expression> {
     if let pt::Expression::MemberAccess(_, box pt::Expression::Variable(var), fn_id) = name {
-        if &var.name == "abi" && &fn_id.name == "encodePacked" {
-            return Some(Expression::AbiEncodePacked(
-                args.iter().map(From::from).collect(),
-            ));
+        if &var.name != "abi" {
+            return None;
         }
+        let values = |args: &[pt::Expression]| args.iter().map(From::from).collect();
+        return match (fn_id.name.as_str(), args) {
+            ("encodePacked", _) => Some(Expression::AbiEncodePacked(values(args))),
+            ("encode", _) => Some(Expression::AbiEncode(values(args))),
+            ("encodeWithSelector", [selector, args @ ..]) => Some(
+                Expression::AbiEncodeWithSelector(to_boxed_expr(selector), values(args)),
+            ),
+            ("encodeWithSignature", [signature, args @ ..]) => Some(
+                Expression::AbiEncodeWithSignature(to_boxed_expr(signature), values(args)),
+            ),
+            ("decode", [data, types]) => Some(Expression::AbiDecode(
+                to_boxed_expr(data),
+                to_decoded_types(types),
+            )),
+            _ => None,
+        };
     }
     None
 }
 
+/// Reads the types of `abi.decode(data, (uint256, address))`, a single type is not wrapped in a list.
+///
+/// The types are kept as expressions, an unknown type is reported when the call is parsed.
+fn to_decoded_types(types: &pt::Expression) -> Vec<Expression> {
+    match types {
+        pt::Expression::List(_, params) => params
+            .iter()
+            .filter_map(|(_, p)| p.as_ref())
+            .map(|p| Expression::from(&p.ty))
+            .collect(),
+        ty => vec![Expression::from(ty)],
+    }
+}
+
 fn parse_expr(e: &pt::Expression) -> Expression {
     match e {
         pt::Expression::ArraySubscript(_, arr, key) => {
@@ -222,37 +319,60 @@ fn parse_expr(e: &pt::Expression) -> Expression {
                 .map(|key_expr| key_expr.as_ref().into())
                 .expect("Unspecfied key");
 
-            if let pt::Expression::ArraySubscript(_, arr2, key2) = &**arr {
-                let key_expr2 = key2
-                    .clone()
-                    .map(|key_expr| key_expr.as_ref().into())
-                    .expect("Unspecified key");
-                let name = try_to_variable_name(arr2).expect("Collection name expected");
-                Expression::Collection(name, vec![key_expr2, key_expr])
-            } else {
-                let name = try_to_variable_name(arr).expect("Collection name expected");
-                Expression::Collection(name, vec![key_expr])
+            // Eg. allowances[owner][spender] - collect all the keys, starting from the outermost one.
+            let mut keys = vec![key_expr];
+            let mut arr = arr;
+            while let pt::Expression::ArraySubscript(_, arr2, Some(key2)) = &**arr {
+                keys.insert(0, key2.as_ref().into());
+                arr = arr2;
+            }
+            let base = Expression::from(&**arr);
+            match try_to_variable_name(arr) {
+                Some(name) => Expression::Collection(name, keys),
+                // Eg. set._values[i], a field of a struct
+                None if Type::try_from(&base).is_err() => Expression::Index(Box::new(base), keys),
+                // Eg. uint[3][], a fixed-size array type
+                None => {
+                    let ty = Type::try_from(&base).expect("Array type expected");
+                    let ty = keys.iter().fold(ty, |ty, size| match size {
+                        Expression::NumberLiteral(limbs) => Type::FixedArray(
+                            Box::new(ty),
+                            limbs.first().copied().unwrap_or_default() as usize,
+                        ),
+                        // a constant length is replaced with its value while parsing the source code
+                        _ => unreachable!("Array length should be resolved, found {:?}", size),
+                    });
+                    Expression::Type(ty)
+                }
             }
         }
         pt::Expression::Assign(_, l, r) => {
             Expression::Assign(to_boxed_expr(l), Some(to_boxed_expr(r)))
         }
         pt::Expression::StringLiteral(strings) => {
-            let strings = strings
+            // adjacent literals are concatenated, eg. `"abc" "def"` is `"abcdef"`
+            let bytes = strings
                 .iter()
-                .map(|lit| lit.string.clone())
+                .flat_map(|lit| unescape(&lit.string))
                 .collect::<Vec<_>>();
-            Expression::StringLiteral(strings.join(","))
+            // a literal which is not a valid UTF-8 sequence may be used as bytes only
+            match String::from_utf8(bytes) {
+                Ok(string) => Expression::StringLiteral(string),
+                Err(err) => Expression::BytesLiteral(err.into_bytes()),
+            }
         }
         pt::Expression::FunctionCall(_, name, args) => {
             let to_func =
                 || Expression::Func(to_boxed_expr(name), args.iter().map(From::from).collect());
 
-            try_to_zero_address(name)
+            try_to_zero_address(name, args)
                 .or(try_to_keccak(name, args))
+                .or(try_to_builtin_fn(name, args))
                 .or(try_to_abi_encode(name, args))
+                .or(try_to_concat(name, args))
                 .or(try_to_super_call(name, args))
                 .or(try_to_require(name, args))
+                .or(try_to_assert(name, args))
                 .or(try_to_ext_contract_call(name, args))
                 .unwrap_or_else(to_func)
         }
@@ -352,8 +472,10 @@ fn parse_expr(e: &pt::Expression) -> Expression {
             Expression::BytesLiteral(bytes)
         }
         pt::Expression::HexLiteral(hex) => {
-            let hex = hex.first().unwrap();
-            let bytes = hex_string_to_u8_array(&hex.hex).unwrap_or_default();
+            let bytes = hex
+                .iter()
+                .flat_map(|hex| hex_string_to_u8_array(&hex.hex).unwrap_or_default())
+                .collect();
             Expression::BytesLiteral(bytes)
         }
         pt::Expression::New(_, initializer) => {
@@ -402,7 +524,13 @@ fn parse_expr(e: &pt::Expression) -> Expression {
         pt::Expression::NamedFunctionCall(_, _, _) => todo!(),
         pt::Expression::Unit(_, _, _) => todo!(),
         pt::Expression::This(_) => todo!(),
-        pt::Expression::RationalNumberLiteral(_, _) => todo!(),
+        // eg. `1.5e18` is an integer
+        pt::Expression::RationalNumberLiteral(_, n) if n.is_integer() => {
+            Expression::NumberLiteral(n.numer().to_u64_digits().1)
+        }
+        pt::Expression::RationalNumberLiteral(_, n) => {
+            Expression::RationalLiteral(n.numer().to_u64_digits().1, n.denom().to_u64_digits().1)
+        }
         pt::Expression::AddressLiteral(_, _) => todo!(),
         pt::Expression::List(_, params) => {
             let params = params.iter().map(|(_, p)| p).collect::<Vec<_>>();
@@ -453,6 +581,56 @@ fn hex_string_to_u8_array(hex_string: &str) -> Option<Vec<u8>> {
     Some(result)
 }
 
+/// Decodes the escape sequences of a string literal, eg. `\x19` or `\n`, into bytes.
+///
+/// An unknown escape sequence is kept as is.
+fn unescape(literal: &str) -> Vec<u8> {
+    let mut result = Vec::with_capacity(literal.len());
+    let mut chars = literal.chars();
+    while let Some(ch) = chars.next() {
+        if ch != '\\' {
+            let mut buf = [0u8; 4];
+            result.extend_from_slice(ch.encode_utf8(&mut buf).as_bytes());
+            continue;
+        }
+        let rest = chars.as_str();
+        let escaped = match chars.next() {
+            Some('n') => Some(vec![b'\n']),
+            Some('r') => Some(vec![b'\r']),
+            Some('t') => Some(vec![b'\t']),
+            Some(ch @ ('\\' | '\'' | '"')) => Some(vec![ch as u8]),
+            // a line continuation
+            Some('\n') => Some(vec![]),
+            Some('x') => rest
+                .get(1..3)
+                .filter(|hex| hex.bytes().all(|b| b.is_ascii_hexdigit()))
+                .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+                .map(|byte| {
+                    chars.nth(1);
+                    vec![byte]
+                }),
+            Some('u') => rest
+                .get(1..5)
+                .filter(|hex| hex.bytes().all(|b| b.is_ascii_hexdigit()))
+                .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                .and_then(char::from_u32)
+                .map(|ch| {
+                    chars.nth(3);
+                    ch.to_string().into_bytes()
+                }),
+            _ => None,
+        };
+        match escaped {
+            Some(bytes) => result.extend(bytes),
+            None => {
+                result.push(b'\\');
+                chars = rest.chars();
+            }
+        }
+    }
+    result
+}
+
 fn to_assign_op_expr(l: &pt::Expression, r: &pt::Expression, op: Op) -> Expression {
     Expression::AssignAnd(to_boxed_expr(l), to_boxed_expr(r), op)
 }
@@ -469,20 +647,107 @@ fn to_boxed_expr(e: &pt::Expression) -> Box<Expression> {
     Box::new(e.into())
 }
 
+fn eval_field_type<T>(struct_name: &str, field_name: &str, ctx: &T) -> Option<Type>
+where
+    T: TypeInfo + ContractInfo,
+{
+    match ctx.type_from_string(struct_name) {
+        Some(ItemType::Struct(s)) => s
+            .fields
+            .iter()
+            .find(|(f, _)| f == field_name)
+            .and_then(|(_, ty)| eval_expression_type(ty, ctx)),
+        _ => None,
+    }
+}
+
+/// Evaluates the type of a collection item accessed with the given number of keys.
+///
+/// Eg. for `mapping(address => uint256[]) balances`, `balances[owner]` is of type `uint256[]`
+/// and `balances[owner][0]` is of type `uint256`.
+fn eval_collection_item_type<T>(ty: &Type, keys_len: usize, ctx: &T) -> Option<Type>
+where
+    T: TypeInfo + ContractInfo,
+{
+    if keys_len == 0 {
+        return Some(ty.clone());
+    }
+    match ty {
+        Type::Mapping(_, value) => eval_expression_type(value, ctx)
+            .and_then(|value| eval_collection_item_type(&value, keys_len - 1, ctx)),
+        Type::Array(item) | Type::FixedArray(item, _) => {
+            eval_collection_item_type(item, keys_len - 1, ctx)
+        }
+        // Indexing a byte array yields `bytes1`.
+        Type::DynamicBytes | Type::Bytes(_) if keys_len == 1 => Some(Type::Bytes(1)),
+        _ => None,
+    }
+}
+
+/// Finds a library function attached to the receiver with a `using L for T;` directive.
+///
+/// A function matches if its first parameter accepts the receiver, eg. `a.add(b)` is `SafeMath.add(a, b)`.
+/// Returns the library name and the function.
+pub fn find_attached_fn<T>(
+    receiver: &Expression,
+    fn_name: &str,
+    ctx: &T,
+) -> Option<(String, Function)>
+where
+    T: TypeInfo + ContractInfo,
+{
+    let receiver_ty = match ctx.type_from_expression(receiver) {
+        Some(ItemType::Storage(v) | ItemType::Local(v))
+            if matches!(receiver, Expression::Variable(_)) =>
+        {
+            Some(v.ty)
+        }
+        _ => eval_expression_type(receiver, ctx),
+    };
+    let fn_name = utils::to_snake_case(fn_name);
+
+    ctx.current_contract()
+        .libs()
+        .iter()
+        .filter(|lib| match (&lib.ty, &receiver_ty) {
+            (Some(ty), Some(receiver_ty)) => ty == receiver_ty,
+            _ => true,
+        })
+        .filter(|lib| match &lib.fn_name {
+            Some(name) => utils::to_snake_case(name) == fn_name,
+            None => true,
+        })
+        .filter_map(|lib| {
+            ctx.find_fn(&lib.name, &fn_name)
+                .map(|f| (lib.name.clone(), f))
+        })
+        .find(|(_, f)| match (f.params().first(), &receiver_ty) {
+            (Some(param), Some(receiver_ty)) => is_assignable(receiver_ty, &param.ty),
+            (Some(_), None) => true,
+            (None, _) => false,
+        })
+}
+
+/// Checks if a value of type `from` can be passed where `to` is expected.
+fn is_assignable(from: &Type, to: &Type) -> bool {
+    from == to || from.widens_to(to)
+}
+
 pub fn eval_expression_type<T>(expr: &Expression, ctx: &T) -> Option<Type>
 where
     T: TypeInfo + ContractInfo,
 {
     match expr {
         Expression::Require(_, _) => None,
+        Expression::Assert(_) => None,
         Expression::Placeholder => None,
         Expression::ZeroAddress => Some(Type::Address),
         Expression::Message(msg) => match msg {
             Message::Sender => Some(Type::Address),
-            Message::Value => None,
-            Message::Data => None,
+            Message::Value => Some(Type::Uint(256)),
+            Message::Data => Some(Type::DynamicBytes),
         },
-        Expression::Collection(name, key) => ctx
+        Expression::Collection(name, keys) => ctx
             .type_from_string(name)
             .map(|t| match t {
                 ItemType::Contract(_) => None,
@@ -490,18 +755,15 @@ where
                 ItemType::Interface(_) => None,
                 ItemType::Enum(e) => Some(Type::Custom(e)),
                 ItemType::Struct(s) => Some(Type::Custom(s.name)),
+                ItemType::ValueType(v) => Some(Type::Custom(v.name)),
                 ItemType::Event => None,
-                ItemType::Storage(Var {
-                    ty: Type::Mapping(t, ta),
-                    ..
-                }) => eval_expression_type(&t, ctx),
-                ItemType::Storage(v) => Some(v.ty.clone()),
-                ItemType::Local(Var {
-                    ty: Type::Array(t), ..
-                }) => Some(*t),
-                ItemType::Local(v) => Some(v.ty.clone()),
+                ItemType::Storage(v) | ItemType::Local(v) => {
+                    eval_collection_item_type(&v.ty, keys.len(), ctx)
+                }
             })
             .flatten(),
+        Expression::Index(base, keys) => eval_expression_type(base, ctx)
+            .and_then(|ty| eval_collection_item_type(&ty, keys.len(), ctx)),
         Expression::Variable(name) => ctx
             .type_from_string(name)
             .map(|t| match t {
@@ -510,6 +772,7 @@ where
                 ItemType::Interface(_) => None,
                 ItemType::Enum(e) => Some(Type::Custom(e)),
                 ItemType::Struct(s) => Some(Type::Custom(s.name)),
+                ItemType::ValueType(v) => Some(Type::Custom(v.name)),
                 ItemType::Event => None,
                 ItemType::Storage(Var {
                     ty: Type::Array(t), ..
@@ -520,114 +783,290 @@ where
                 }) => Some(*t),
                 ItemType::Local(v) => Some(v.ty.clone()),
             })
-            .flatten(),
+            .flatten()
+            .or_else(|| {
+                // A constant is not a part of the storage.
+                ctx.current_contract()
+                    .vars()
+                    .iter()
+                    .find(|v| v.is_immutable && &v.name == name)
+                    .map(|v| v.ty.clone())
+            }),
         Expression::BoolLiteral(_) => Some(Type::Bool),
         Expression::StringLiteral(_) => Some(Type::String),
         Expression::Assign(l, _) => eval_expression_type(l, ctx),
         Expression::LogicalOp(_, _, _) => Some(Type::Bool),
-        Expression::MathOp(l, r, op) => {
-            let lty = eval_expression_type(l, ctx);
-            let rty = eval_expression_type(r, ctx);
-            match (lty, rty) {
-                (Some(Type::Uint(s1)), Some(Type::Uint(s2))) => Some(Type::Uint(u16::max(s1, s2))),
-                (None, Some(Type::Uint(s))) => Some(Type::Uint(s)),
-                (Some(Type::Uint(s)), None) => Some(Type::Uint(s)),
-                (Some(Type::Int(s1)), Some(Type::Int(s2))) => Some(Type::Int(u16::max(s1, s2))),
-                (None, Some(Type::Int(s))) => Some(Type::Int(s)),
-                (Some(Type::Int(s)), None) => Some(Type::Int(s)),
-                (Some(Type::Bytes(s1)), Some(Type::Bytes(s2))) => {
-                    Some(Type::Bytes(u8::max(s1, s2)))
-                }
-                _ => None,
+        // the exponent does not affect the type of the result
+        Expression::MathOp(l, r, MathOp::Pow) => {
+            eval_expression_type(l, ctx).or_else(|| eval_expression_type(r, ctx))
+        }
+        Expression::BitwiseOp(l, _, BitwiseOp::ShiftLeft | BitwiseOp::ShiftRight) => {
+            eval_expression_type(l, ctx)
+        }
+        // eg. `uint8 + uint256` evaluates to `uint256`, a user-defined operator on a value type
+        // evaluates to the value type
+        Expression::MathOp(l, r, _) | Expression::BitwiseOp(l, r, _) => {
+            match (eval_expression_type(l, ctx), eval_expression_type(r, ctx)) {
+                (Some(l), Some(r)) => l.common(&r),
+                (Some(ty), None) | (None, Some(ty)) => Some(ty),
+                (None, None) => None,
             }
         }
         Expression::AssignAnd(l, _, _) => eval_expression_type(l, ctx),
         Expression::Increment(e) => eval_expression_type(e, ctx),
         Expression::Decrement(e) => eval_expression_type(e, ctx),
+        Expression::MemberAccess(name, e) if name == "length" => match eval_expression_type(e, ctx)
+        {
+            Some(Type::Array(_) | Type::FixedArray(_, _) | Type::DynamicBytes) => {
+                Some(Type::Uint(256))
+            }
+            Some(Type::Bytes(_)) => Some(Type::Uint(8)),
+            // a storage array variable evaluates to the item type
+            _ => match ctx.type_from_expression(e) {
+                Some(ItemType::Storage(Var {
+                    ty: Type::Array(_), ..
+                })) => Some(Type::Uint(256)),
+                _ => None,
+            },
+        },
+        Expression::MemberAccess(name, e) if name == "balance" => {
+            match eval_expression_type(e, ctx) {
+                Some(Type::Address) => Some(Type::Uint(256)),
+                Some(Type::Custom(struct_name)) => eval_field_type(&struct_name, name, ctx),
+                _ => None,
+            }
+        }
         Expression::MemberAccess(name, e) => {
-            if let Expression::MemberAccess(nested_name, nested_e) = &**e {
-                let ty = eval_expression_type(nested_e, ctx).unwrap();
-                match ty {
-                    Type::Custom(class_name) => {
-                        if let Some(ItemType::Struct(s)) = ctx.type_from_string(&class_name) {
-                            let f = s.fields.iter().find(|f| &f.0 == nested_name).unwrap();
-                            return eval_expression_type(&f.1, ctx);
-                        } else {
-                            todo!()
-                        }
-                    }
-                    _ => todo!(),
-                }
+            // eg. `position.size.amount`, the nested access evaluates to a struct
+            if let Expression::MemberAccess(_, _) = &**e {
+                return match eval_expression_type(e, ctx) {
+                    Some(Type::Custom(struct_name)) => eval_field_type(&struct_name, name, ctx),
+                    _ => None,
+                };
             }
             match ctx.type_from_expression(e) {
                 Some(ItemType::Enum(ty)) => Some(Type::Custom(ty)),
-                Some(ItemType::Struct(ty)) => {
-                    if let Some((name, fty)) = ty.fields.iter().find(|(f, t)| f == &ty.name) {
-                        eval_expression_type(fty, ctx)
-                    } else {
-                        None
-                    }
-                }
+                Some(ItemType::Struct(ty)) => ty
+                    .fields
+                    .iter()
+                    .find(|(f, _)| f == name)
+                    .and_then(|(_, fty)| Type::try_from(fty).ok()),
                 Some(ItemType::Library(ty)) => ty
                     .vars()
                     .iter()
                     .find(|v| &v.name == name)
-                    .map(|v| v.ty.clone()),
+                    .map(|v| v.ty.clone())
+                    // eg. a struct defined in a library `Counters.Counter`
+                    .or_else(|| match ctx.type_from_string(name) {
+                        Some(ItemType::Struct(s)) => Some(Type::Custom(s.name)),
+                        _ => None,
+                    }),
+                Some(ItemType::Storage(Var {
+                    ty: Type::Custom(struct_name),
+                    ..
+                })) => eval_field_type(&struct_name, name, ctx)
+                    .or_else(|| Some(Type::Custom(struct_name))),
                 Some(ItemType::Storage(Var { ty, .. })) => Some(ty),
                 Some(ItemType::Local(Var {
                     ty: Type::Custom(struct_name),
                     ..
-                })) => {
-                    if let Some(ItemType::Struct(ty)) = ctx.type_from_string(&struct_name) {
-                        if let Some((name, fty)) = ty.fields.iter().find(|(f, t)| f == name) {
-                            eval_expression_type(fty, ctx)
-                        } else {
-                            None
-                        }
-                    } else {
-                        None
-                    }
-                }
+                })) => eval_field_type(&struct_name, name, ctx),
                 Some(ItemType::Local(Var { ty, .. })) => Some(ty),
-                e => panic!("{:?}", e),
+                // eg. a struct stored in a collection `positions[id].size`
+                _ => match eval_expression_type(e, ctx) {
+                    Some(Type::Custom(struct_name)) => eval_field_type(&struct_name, name, ctx),
+                    _ => None,
+                },
             }
         }
-        Expression::NumberLiteral(_) => None,
+        Expression::NumberLiteral(_) | Expression::RationalLiteral(_, _) => None,
         Expression::Func(f, args) => {
             if let Expression::MemberAccess(function_name, ty_expr) = &**f {
-                let ty = eval_expression_type(ty_expr, ctx);
-
-                // find matching lib
-                let matching_lib = ctx
-                    .current_contract()
-                    .libs()
-                    .iter()
-                    .find(|lib| eval_expression_type(&lib.ty, ctx) == ty)
-                    .unwrap();
-
-                let matching_fn = ctx.find_fn(&matching_lib.name, function_name).unwrap();
-                return matching_fn.ret_ty(ctx);
+                // eg. balances[owner].push(value) returns None
+                return find_attached_fn(ty_expr, function_name, ctx)
+                    .and_then(|(_, f)| f.ret_ty(ctx))
+                    // eg. an external call `IERC20(token).balanceOf(owner)`
+                    .or_else(|| match eval_expression_type(ty_expr, ctx) {
+                        Some(Type::Custom(class)) => ctx
+                            .find_fn(&class, &utils::to_snake_case(function_name))
+                            .and_then(|f| f.ret_ty(ctx)),
+                        _ => None,
+                    });
             }
             if let Expression::Type(t) = &**f {
                 return Some(t.clone());
             }
-            todo!()
+            // eg. a struct constructor `Position(a, b)` or a contract cast `IERC20(token)`
+            if let Some(ItemType::Struct(_) | ItemType::Contract(_) | ItemType::Interface(_)) =
+                ctx.type_from_expression(f)
+            {
+                return Type::try_from(&**f).ok();
+            }
+            // eg. a call through an internal function pointer `op(a, b)`
+            if let Some(
+                ItemType::Local(Var {
+                    ty: Type::Function(f),
+                    ..
+                })
+                | ItemType::Storage(Var {
+                    ty: Type::Function(f),
+                    ..
+                }),
+            ) = ctx.type_from_expression(f)
+            {
+                return f.returns.first().cloned();
+            }
+            // eg. a call of an internal function `double(a)`
+            if let Expression::Variable(name) = &**f {
+                let name = utils::to_snake_case(name);
+                return ctx
+                    .current_contract()
+                    .fn_implementations()
+                    .iter()
+                    .find(|f| f.name == name)
+                    .and_then(|f| f.implementations.first().cloned())
+                    .and_then(|(_, f)| match f {
+                        Function::Function(f) if f.ret.len() == 1 => {
+                            Type::try_from(&f.ret[0].1).ok()
+                        }
+                        _ => None,
+                    });
+            }
+            None
         }
-        Expression::SuperCall(_, _) => todo!(),
-        Expression::ExternalCall(_, _, _) => todo!(),
-        Expression::TypeInfo(_, _) => todo!(),
+        // a super call returns the same type as an internal call of the function
+        Expression::SuperCall(name, args) => eval_expression_type(
+            &Expression::Func(Box::new(Expression::Variable(name.clone())), args.clone()),
+            ctx,
+        ),
+        Expression::ExternalCall(var, fn_name, _) => match ctx.type_from_string(var) {
+            // eg. `Price.wrap(value)` or `Price.unwrap(price)`
+            Some(ItemType::ValueType(v)) => match fn_name.as_str() {
+                "wrap" => Some(Type::Custom(v.name)),
+                "unwrap" => Some(v.ty),
+                _ => None,
+            },
+            // eg. `value.max(other)`, where a library is attached to `uint256`,
+            // or an external call `token.balanceOf(owner)`
+            Some(ItemType::Storage(v) | ItemType::Local(v)) => {
+                find_attached_fn(&Expression::Variable(var.to_owned()), fn_name, ctx)
+                    .and_then(|(_, f)| f.ret_ty(ctx))
+                    .or_else(|| match v.ty {
+                        Type::Custom(class) => ctx
+                            .find_fn(&class, &utils::to_snake_case(fn_name))
+                            .and_then(|f| f.ret_ty(ctx)),
+                        _ => None,
+                    })
+            }
+            // eg. `FullMath.mulDiv(a, b, c)`
+            Some(ItemType::Library(_)) => ctx
+                .find_fn(var, &utils::to_snake_case(fn_name))
+                .and_then(|f| f.ret_ty(ctx)),
+            _ => None,
+        },
+        Expression::TypeInfo(ty, property) => match property.as_str() {
+            "min" | "max" => eval_expression_type(ty, ctx),
+            "interfaceId" => Some(Type::Bytes(4)),
+            "name" => Some(Type::String),
+            _ => None,
+        },
         Expression::Type(t) => Some(t.clone()),
         Expression::Not(e) => eval_expression_type(e, ctx),
         Expression::BytesLiteral(b) => Some(Type::Bytes(b.len() as u8)),
-        Expression::ArrayLiteral(_) => todo!(),
-        Expression::Initializer(_) => todo!(),
-        Expression::Statement(s) => todo!(),
-        Expression::BitwiseOp(_, _, _) => None,
-        Expression::UnaryOp(_, _) => todo!(),
+        Expression::ArrayLiteral(_) => None,
+        // eg. `new Token()`
+        Expression::Initializer(e) => match &**e {
+            Expression::Func(ty, _) => Type::try_from(&**ty).ok(),
+            _ => None,
+        },
+        // a conditional expression evaluates to the common type of both branches
+        Expression::Statement(_) => as_conditional(expr).and_then(|(_, l, r)| {
+            match (eval_expression_type(l, ctx), eval_expression_type(r, ctx)) {
+                (Some(l), Some(r)) => l.common(&r),
+                (Some(ty), None) | (None, Some(ty)) => Some(ty),
+                (None, None) => None,
+            }
+        }),
+        Expression::UnaryOp(e, _) => eval_expression_type(e, ctx),
         Expression::Tuple(_) => None,
         #[cfg(test)]
         Expression::Fail => None,
-        Expression::Keccak256(_) => Some(Type::Bytes(32)),
-        Expression::AbiEncodePacked(_) => Some(Type::Array(Box::new(Type::Uint(8)))),
+        Expression::Keccak256(_) | Expression::Sha256(_) => Some(Type::Bytes(32)),
+        Expression::Ripemd160(_) => Some(Type::Bytes(20)),
+        Expression::EcRecover(_) => Some(Type::Address),
+        Expression::AddMod(_) | Expression::MulMod(_) => Some(Type::Uint(256)),
+        Expression::AbiEncodePacked(_)
+        | Expression::AbiEncode(_)
+        | Expression::AbiEncodeWithSelector(_, _)
+        | Expression::AbiEncodeWithSignature(_, _) => Some(Type::DynamicBytes),
+        Expression::AbiDecode(_, types) => match types.as_slice() {
+            [ty] => Type::try_from(ty).ok(),
+            _ => None,
+        },
+        Expression::StringConcat(_) => Some(Type::String),
+        Expression::BytesConcat(_) => Some(Type::DynamicBytes),
+    }
+}
+
+/// Inserts an implicit conversion of the expression to the given type wherever Solidity widens
+/// the value, eg. a `uint8` value assigned to a `uint256` variable.
+///
+/// A literal is left intact as it takes the type from the context, the branches of
+/// a conditional expression are converted separately.
+pub fn widen<T>(expr: &Expression, ty: &Type, ctx: &T) -> Expression
+where
+    T: TypeInfo + ContractInfo,
+{
+    if let Some((condition, l, r)) = as_conditional(expr) {
+        let branch = |e: &Expression| {
+            Box::new(Stmt::ReturningBlock(vec![Stmt::Expression(widen(
+                e, ty, ctx,
+            ))]))
+        };
+        return Expression::Statement(Box::new(Stmt::IfElse(
+            condition.clone(),
+            branch(l),
+            branch(r),
+        )));
+    }
+    let cast = |e: Expression, ty: Type| Expression::Func(Box::new(Expression::Type(ty)), vec![e]);
+    match (eval_expression_type(expr, ctx), ty) {
+        (Some(Type::Uint(from)), Type::Int(to)) if from < *to => {
+            // an unsigned integer becomes signed only if the size stays the same
+            cast(cast(expr.clone(), Type::Uint(*to)), ty.clone())
+        }
+        (Some(from), _) if from.widens_to(ty) && !is_literal(expr) => {
+            cast(expr.clone(), ty.clone())
+        }
+        _ => expr.clone(),
+    }
+}
+
+/// Returns the condition and both branches of a conditional expression `c ? a : b`.
+fn as_conditional(expr: &Expression) -> Option<(&Expression, &Expression, &Expression)> {
+    match expr {
+        Expression::Statement(stmt) => match &**stmt {
+            Stmt::IfElse(condition, l, r) => match (&**l, &**r) {
+                (Stmt::ReturningBlock(l), Stmt::ReturningBlock(r)) => {
+                    match (l.as_slice(), r.as_slice()) {
+                        ([Stmt::Expression(l)], [Stmt::Expression(r)]) => Some((condition, l, r)),
+                        _ => None,
+                    }
+                }
+                _ => None,
+            },
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn is_literal(expr: &Expression) -> bool {
+    match expr {
+        Expression::NumberLiteral(_)
+        | Expression::RationalLiteral(_, _)
+        | Expression::BytesLiteral(_) => true,
+        Expression::UnaryOp(expr, UnaryOp::Minus) => is_literal(expr),
+        _ => false,
     }
 }
@@ -45,9 +45,7 @@ impl Function {
             Function::Function(f) => {
                 let ret = &f.ret;
                 if ret.len() == 1 {
-                    let ty = ctx.type_from_expression(&ret[0].1);
-                    let var = ty.map(|i| i.as_var().cloned()).flatten();
-                    var.map(|v| v.ty.clone())
+                    Type::try_from(&ret[0].1).ok()
                 } else {
                     // TODO: should return a tuple
                     None
@@ -71,6 +69,8 @@ impl Function {
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Func {
     pub name: String,
+    /// The name as declared in solidity, required to compute the function selector.
+    pub sol_name: String,
     pub vis: Visibility,
     pub params: Vec<Param>,
     pub is_payable: bool,
@@ -168,6 +168,11 @@ impl From<&&pt::FunctionDefinition> for Function {
         } else {
             Function::Function(Func {
                 name: parse_name(value),
+                sol_name: value
+                    .name
+                    .as_ref()
+                    .map(|id| id.name.to_owned())
+                    .unwrap_or_default(),
                 vis: parse_visibility(value),
                 params,
                 is_payable,
@@ -317,10 +322,21 @@ fn parse_params(func: &pt::FunctionDefinition) -> Vec<Param> {
             let ty = match &param.ty {
                 pt::Expression::Type(_, ty) => Type::from(ty),
                 pt::Expression::Variable(name) => Type::from(name),
+                pt::Expression::ArraySubscript(_, _, _) => {
+                    Type::try_from(&Expression::from(&param.ty))
+                        .expect("Should be a valid array type")
+                }
+                // a type defined in a library or a contract, eg. `EnumerableSet.Set`
+                pt::Expression::MemberAccess(_, _, name) => Type::from(name),
                 _ => panic!("Function param must be of type Type"),
             };
+            let is_storage = matches!(param.storage, Some(pt::StorageLocation::Storage(_)));
 
-            Param { name, ty }
+            Param {
+                name,
+                ty,
+                is_storage,
+            }
         })
         .collect()
 }
@@ -460,6 +476,17 @@ impl FnImplementations {
             .flatten()
             .expect("Function should have at least one implementation.")
     }
+
+    /// Returns the types of all the values the function returns.
+    pub fn ret_types(&self) -> Vec<Expression> {
+        self.implementations
+            .first()
+            .map(|(_, func)| match &func {
+                Function::Function(Func { ret, .. }) => ret.iter().map(|r| r.1.clone()).collect(),
+                _ => vec![],
+            })
+            .unwrap_or_default()
+    }
 }
 
 /// A function parameter representation.
@@ -467,4 +494,6 @@ impl FnImplementations {
 pub struct Param {
     pub name: String,
     pub ty: Type,
+    /// A reference to a storage item, eg. `Set storage set` in a library function.
+    pub is_storage: bool,
 }
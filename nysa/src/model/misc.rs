@@ -1,9 +1,13 @@
 use c3_lang_linearization::Class;
 use solidity_parser::pt::{self, VariableAttribute};
 
-use crate::model::expr::to_expr;
+use crate::{model::expr::to_expr, utils::ast::FREE_FUNCTIONS_LIB};
 
-use super::expr::Expression;
+use super::{
+    expr::Expression,
+    func::{Func, Visibility},
+    op::{BitwiseOp, LogicalOp, MathOp, Op},
+};
 
 /// Stores a basic contract metadata.
 #[derive(Debug, Clone, PartialEq)]
@@ -70,13 +74,82 @@ pub enum Type {
     String,
     Int(u16),
     Uint(u16),
+    /// Signed fixed-point number, eg. `fixed128x18` is `Fixed(128, 18)`.
+    Fixed(u16, u8),
+    /// Unsigned fixed-point number, eg. `ufixed128x18` is `Ufixed(128, 18)`.
+    Ufixed(u16, u8),
     Bytes(u8),
+    DynamicBytes,
     Mapping(Box<Expression>, Box<Expression>),
     Custom(String),
     Array(Box<Type>),
+    FixedArray(Box<Type>, usize),
+    Function(FunctionType),
     Unknown,
 }
 
+/// Function type representation, eg. `function(uint256) internal view returns (uint256)`.
+#[derive(Debug, Clone, PartialEq, Eq, Ord, PartialOrd)]
+pub struct FunctionType {
+    pub params: Vec<Type>,
+    pub returns: Vec<Type>,
+    pub is_mutable: bool,
+    pub is_external: bool,
+}
+
+impl FunctionType {
+    fn new(
+        params: &[(pt::Loc, Option<pt::Parameter>)],
+        returns: &[(pt::Loc, Option<pt::Parameter>)],
+        attributes: &[pt::FunctionAttribute],
+    ) -> Self {
+        let to_types = |params: &[(pt::Loc, Option<pt::Parameter>)]| {
+            params
+                .iter()
+                .filter_map(|(_, p)| p.as_ref())
+                .map(|p| Type::try_from(&Expression::from(&p.ty)).unwrap_or(Type::Unknown))
+                .collect()
+        };
+        let is_mutable = !attributes.iter().any(|attr| {
+            matches!(
+                attr,
+                pt::FunctionAttribute::Mutability(
+                    pt::Mutability::Pure(_) | pt::Mutability::View(_)
+                )
+            )
+        });
+        let is_external = attributes.iter().any(|attr| {
+            matches!(
+                attr,
+                pt::FunctionAttribute::Visibility(
+                    pt::Visibility::External(_) | pt::Visibility::Public(_)
+                )
+            )
+        });
+        Self {
+            params: to_types(params),
+            returns: to_types(returns),
+            is_mutable,
+            is_external,
+        }
+    }
+}
+
+impl From<&Func> for FunctionType {
+    fn from(value: &Func) -> Self {
+        Self {
+            params: value.params.iter().map(|p| p.ty.clone()).collect(),
+            returns: value
+                .ret
+                .iter()
+                .map(|(_, e)| Type::try_from(e).unwrap_or(Type::Unknown))
+                .collect(),
+            is_mutable: value.is_mutable,
+            is_external: value.vis == Visibility::Public,
+        }
+    }
+}
+
 impl Type {
     pub fn as_unit(&self) -> Option<u16> {
         if let Type::Uint(size) = self {
@@ -84,6 +157,29 @@ impl Type {
         }
         None
     }
+
+    /// Checks if a value of the type is implicitly converted to the other type - an integer
+    /// of a bigger size (an unsigned one may become signed) or a longer fixed-size byte array.
+    pub fn widens_to(&self, other: &Type) -> bool {
+        match (self, other) {
+            (Type::Uint(from), Type::Uint(to))
+            | (Type::Int(from), Type::Int(to))
+            | (Type::Uint(from), Type::Int(to)) => from < to,
+            (Type::Bytes(from), Type::Bytes(to)) => from < to,
+            _ => false,
+        }
+    }
+
+    /// Returns the type both values are converted to, if there is one.
+    pub fn common(&self, other: &Type) -> Option<Type> {
+        if self == other || other.widens_to(self) {
+            Some(self.clone())
+        } else if self.widens_to(other) {
+            Some(other.clone())
+        } else {
+            None
+        }
+    }
 }
 
 impl From<&pt::Type> for Type {
@@ -96,11 +192,23 @@ impl From<&pt::Type> for Type {
             pt::Type::String => Self::String,
             pt::Type::Int(i) => Self::Int(*i),
             pt::Type::Uint(i) => Self::Uint(*i),
+            pt::Type::Fixed(m, n) => Self::Fixed(*m, *n),
+            pt::Type::Ufixed(m, n) => Self::Ufixed(*m, *n),
             pt::Type::Bytes(i) => Self::Bytes(*i),
+            pt::Type::DynamicBytes => Self::DynamicBytes,
             pt::Type::Mapping(_, k, v) => Self::Mapping(
                 Box::new(Expression::from(&**k)),
                 Box::new(Expression::from(&**v)),
             ),
+            pt::Type::Function {
+                params,
+                attributes,
+                returns,
+                trailing_attributes,
+            } => {
+                let attributes = [attributes.as_slice(), trailing_attributes.as_slice()].concat();
+                Self::Function(FunctionType::new(params, returns, &attributes))
+            }
             _ => panic!("Unsupported type {:?}", value),
         }
     }
@@ -120,6 +228,18 @@ impl TryFrom<&Expression> for Type {
         match value {
             Expression::Type(ty) => Ok(ty.clone()),
             Expression::Variable(ty) => Ok(Type::Custom(ty.to_owned())),
+            // In a type context `Position[2][3]` is a fixed-size array of structs.
+            Expression::Collection(ty, sizes) => {
+                sizes
+                    .iter()
+                    .try_fold(Type::Custom(ty.to_owned()), |ty, size| match size {
+                        Expression::NumberLiteral(limbs) => Ok(Type::FixedArray(
+                            Box::new(ty),
+                            limbs.first().copied().unwrap_or_default() as usize,
+                        )),
+                        _ => Err(()),
+                    })
+            }
             _ => Err(()),
         }
     }
@@ -144,11 +264,12 @@ impl From<&&pt::VariableDefinition> for Var {
             ty: match &value.ty {
                 pt::Expression::Type(_, ty) => Type::from(ty),
                 pt::Expression::Variable(id) => Type::from(id),
-                pt::Expression::ArraySubscript(_, ty, _) => {
-                    let ty = Expression::from(&**ty);
-                    let ty = Type::try_from(&ty).expect("Should be a valid array type");
-                    Type::Array(Box::new(ty))
+                pt::Expression::ArraySubscript(_, _, _) => {
+                    let ty = Expression::from(&value.ty);
+                    Type::try_from(&ty).expect("Should be a valid array type")
                 }
+                // a type defined in a library or a contract, eg. `Counters.Counter`
+                pt::Expression::MemberAccess(_, _, id) => Type::from(id),
                 t => panic!("Not a type. {:?}", t),
             },
             initializer: value.initializer.as_ref().map(Expression::from),
@@ -167,6 +288,7 @@ impl Into<Class> for &Var {
 }
 
 /// Stores data required to create an event.
+#[derive(Debug, Clone, PartialEq)]
 pub struct Event {
     pub name: String,
     pub fields: Vec<(String, Expression)>,
@@ -195,12 +317,25 @@ impl From<&&pt::EventDefinition> for Event {
 #[derive(Debug, Clone, PartialEq)]
 pub struct Error {
     pub name: String,
+    pub fields: Vec<(String, Expression)>,
 }
 
 impl From<&&pt::ErrorDefinition> for Error {
     fn from(value: &&pt::ErrorDefinition) -> Self {
         let name = value.name.name.to_owned();
-        Self { name }
+
+        let to_field_name = |(idx, f): (usize, &pt::ErrorParameter)| {
+            (
+                f.name
+                    .as_ref()
+                    .map(|id| id.name.to_owned())
+                    .unwrap_or(format!("field_{}", idx)),
+                Expression::from(&f.ty),
+            )
+        };
+
+        let fields = value.fields.iter().enumerate().map(to_field_name).collect();
+        Self { name, fields }
     }
 }
 
@@ -251,17 +386,105 @@ impl From<(Option<String>, &pt::StructDefinition)> for Struct {
     }
 }
 
+/// Stores data required to create a user-defined value type, eg. `type Price is uint128;`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValueType {
+    pub namespace: Option<String>,
+    pub name: String,
+    pub ty: Type,
+    pub operators: Vec<UserOperator>,
+}
+
+impl From<(Option<String>, &pt::TypeDefinition)> for ValueType {
+    fn from(value: (Option<String>, &pt::TypeDefinition)) -> Self {
+        let (namespace, def) = value;
+        let ty = Type::try_from(&Expression::from(&def.ty)).expect("Should be a valid value type");
+        Self {
+            namespace,
+            name: def.name.name.to_owned(),
+            ty,
+            operators: vec![],
+        }
+    }
+}
+
+/// An operator bound to a function with `using {add as +} for Price global;`.
+///
+/// If the function is a free function, `lib` is the name of the library free functions are gathered in.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UserOperator {
+    pub op: Op,
+    pub lib: String,
+    pub fn_name: String,
+}
+
+impl From<&pt::UserDefinedOperator> for Op {
+    fn from(value: &pt::UserDefinedOperator) -> Self {
+        match value {
+            pt::UserDefinedOperator::BitwiseAnd => Op::Bitwise(BitwiseOp::And),
+            pt::UserDefinedOperator::BitwiseOr => Op::Bitwise(BitwiseOp::Or),
+            pt::UserDefinedOperator::BitwiseXor => Op::Bitwise(BitwiseOp::Xor),
+            pt::UserDefinedOperator::BitwiseNot => Op::Bitwise(BitwiseOp::Not),
+            pt::UserDefinedOperator::Add => Op::Math(MathOp::Add),
+            pt::UserDefinedOperator::Subtract => Op::Math(MathOp::Sub),
+            pt::UserDefinedOperator::Multiply => Op::Math(MathOp::Mul),
+            pt::UserDefinedOperator::Divide => Op::Math(MathOp::Div),
+            pt::UserDefinedOperator::Modulo => Op::Math(MathOp::Modulo),
+            pt::UserDefinedOperator::Equal => Op::Logical(LogicalOp::Eq),
+            pt::UserDefinedOperator::NotEqual => Op::Logical(LogicalOp::NotEq),
+            pt::UserDefinedOperator::Less => Op::Logical(LogicalOp::Less),
+            pt::UserDefinedOperator::LessEqual => Op::Logical(LogicalOp::LessEq),
+            pt::UserDefinedOperator::More => Op::Logical(LogicalOp::More),
+            pt::UserDefinedOperator::MoreEqual => Op::Logical(LogicalOp::MoreEq),
+        }
+    }
+}
+
 /// Stores data representing solidity using `lib_name` for `type` expression.
+///
+/// If `ty` is None, the library functions are attached to any type (`using lib_name for *;`).
+/// If `fn_name` is None, all the library functions are attached, otherwise a single function
+/// from a function list is attached, eg. `using {add} for uint256;`.
 #[derive(Debug, Clone, PartialEq)]
 pub struct LibUsing {
     pub name: String,
-    pub ty: Expression,
+    pub ty: Option<Type>,
+    pub fn_name: Option<String>,
 }
 
-impl From<&&pt::Using> for LibUsing {
-    fn from(value: &&pt::Using) -> Self {
-        let name = value.library.name.to_owned();
-        let ty = value.ty.as_ref().map(Expression::from).unwrap();
-        Self { name, ty }
+impl LibUsing {
+    /// Converts a `using` directive into the attached libraries or library functions.
+    ///
+    /// A free function is attached as a function of the [FREE_FUNCTIONS_LIB] library.
+    /// A function bound as an operator (`using {add as +} for Price global;`) is not attached.
+    pub fn from_directive(value: &pt::Using) -> Vec<Self> {
+        let ty = value.ty.as_ref().map(|ty| {
+            match Expression::from(ty) {
+                // a type defined in a library or a contract, eg. `EnumerableSet.Set`
+                Expression::MemberAccess(name, _) => Type::Custom(name),
+                ty => Type::try_from(&ty).expect("Should be a valid type"),
+            }
+        });
+        match &value.list {
+            pt::UsingList::Library(library) => vec![Self {
+                name: library.name.to_owned(),
+                ty,
+                fn_name: None,
+            }],
+            pt::UsingList::Functions(functions) => functions
+                .iter()
+                .filter(|f| f.oper.is_none())
+                .filter_map(|f| match f.path.as_slice() {
+                    [name] => Some((FREE_FUNCTIONS_LIB.to_string(), name.name.to_owned())),
+                    [lib, name] => Some((lib.name.to_owned(), name.name.to_owned())),
+                    _ => None,
+                })
+                .map(|(name, fn_name)| Self {
+                    name,
+                    ty: ty.clone(),
+                    fn_name: Some(fn_name),
+                })
+                .collect(),
+        }
     }
 }
@@ -14,16 +14,13 @@ mod package;
 mod stmt;
 
 pub use contract::ContractData;
-use itertools::Itertools;
 
-use crate::utils::AsStringVec;
-
-use self::misc::{ContractMetadata, Enum, Error, Event, Struct};
+use self::misc::{ContractMetadata, Enum, Error, Event, Struct, ValueType};
 
 pub(super) const RESERVED_NAMES: [&str; 1] = ["self"];
 
 pub mod ir {
-    pub use super::expr::{eval_expression_type, Expression, TupleItem};
+    pub use super::expr::{eval_expression_type, find_attached_fn, widen, Expression, TupleItem};
     pub use super::func::*;
     pub use super::interface::InterfaceData;
     pub use super::misc::*;
@@ -38,18 +35,6 @@ pub trait Named {
     fn name(&self) -> String;
 }
 
-impl<T: Named> AsStringVec for &[T] {
-    fn as_string_vec(&self) -> Vec<String> {
-        self.iter().map(|i| i.name()).collect_vec()
-    }
-}
-
-impl<T: Named> AsStringVec for Vec<&T> {
-    fn as_string_vec(&self) -> Vec<String> {
-        self.iter().map(|i| i.name()).collect_vec()
-    }
-}
-
 macro_rules! impl_named {
     ($($t:ty),+) => {
         $(
@@ -62,4 +47,4 @@ macro_rules! impl_named {
     };
 }
 
-impl_named!(Enum, Error, Event, ContractMetadata, Struct);
+impl_named!(Enum, Error, Event, ContractMetadata, Struct, ValueType);
@@ -1,6 +1,6 @@
 use super::{
     interface::InterfaceData,
-    misc::{Enum, Error, Event, Struct},
+    misc::{Enum, Error, Event, Struct, ValueType},
     ContractData,
 };
 
@@ -15,6 +15,7 @@ pub struct Package {
     enums: Vec<Enum>,
     interfaces: Vec<InterfaceData>,
     structs: Vec<Struct>,
+    value_types: Vec<ValueType>,
 }
 
 impl Package {
@@ -25,6 +26,7 @@ impl Package {
         enums: Vec<Enum>,
         interfaces: Vec<InterfaceData>,
         structs: Vec<Struct>,
+        value_types: Vec<ValueType>,
     ) -> Self {
         Self {
             contracts,
@@ -33,6 +35,7 @@ impl Package {
             enums,
             interfaces,
             structs,
+            value_types,
         }
     }
 
@@ -48,6 +51,10 @@ impl Package {
         self.contracts.as_ref()
     }
 
+    pub fn contracts_mut(&mut self) -> &mut [ContractData] {
+        self.contracts.as_mut()
+    }
+
     pub fn libraries(&self) -> Vec<ContractData> {
         self.contracts
             .clone()
@@ -67,4 +74,8 @@ impl Package {
     pub fn structs(&self) -> &[Struct] {
         self.structs.as_ref()
     }
+
+    pub fn value_types(&self) -> &[ValueType] {
+        self.value_types.as_ref()
+    }
 }
@@ -20,18 +20,23 @@ pub enum Stmt {
     VarDefinition(String, Type, Expression),
     /// Variable declaration with the name, and the type.
     VarDeclaration(String, Type),
+    /// Storage pointer definition with the name, type and the referenced storage expression
+    /// eg. `Position storage p = positions[id];`.
+    StorageRef(String, Type, Expression),
     /// If expression with the condition, and the conditional statement.
     If(Expression, Box<Stmt>),
     /// If expression with the condition expression, the conditional statement, the fallback statement.
     IfElse(Expression, Box<Stmt>, Box<Stmt>),
     /// A regular block of statements.
     Block(Vec<Stmt>),
+    /// A block of statements with unchecked arithmetic.
+    UncheckedBlock(Vec<Stmt>),
     /// A block that the last statement returns a value.
     ReturningBlock(Vec<Stmt>),
     /// Emit event statement.
     Emit(Expression),
-    /// Revert statement with a string message.
-    RevertWithError(String),
+    /// Revert statement with a custom error and its arguments.
+    RevertWithError(String, Vec<Expression>),
     /// Revert statement with a complex error expression.
     Revert(Option<Expression>),
     /// _ statement.
@@ -50,6 +55,11 @@ pub enum Stmt {
 impl From<&pt::Statement> for Stmt {
     fn from(value: &pt::Statement) -> Self {
         match value {
+            pt::Statement::Block {
+                loc,
+                unchecked: true,
+                statements,
+            } => Self::UncheckedBlock(statements.iter().map(From::from).collect()),
             pt::Statement::Block {
                 loc,
                 unchecked,
@@ -97,7 +107,10 @@ impl From<&pt::Statement> for Stmt {
                 let name = declaration.name.name.clone();
                 let ty = Expression::from(&declaration.ty);
                 let ty = Type::try_from(&ty).unwrap_or(Type::Unknown);
+                let is_storage_ref =
+                    matches!(declaration.storage, Some(pt::StorageLocation::Storage(_)));
                 match init {
+                    Some(expr) if is_storage_ref => Self::StorageRef(name, ty, expr.into()),
                     Some(expr) => Self::VarDefinition(name, ty, expr.into()),
                     None => Self::VarDeclaration(name, ty),
                 }
@@ -112,7 +125,7 @@ impl From<&pt::Statement> for Stmt {
             },
             pt::Statement::Revert(_, error_id, err) => {
                 if let Some(id) = error_id {
-                    Self::RevertWithError(id.name.to_owned())
+                    Self::RevertWithError(id.name.to_owned(), err.iter().map(From::from).collect())
                 } else {
                     if err.is_empty() {
                         Self::Revert(None)
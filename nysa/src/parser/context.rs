@@ -1,8 +1,13 @@
 use std::collections::{HashMap, HashSet};
 
+use c3_lang_linearization::Class;
+
 use crate::{
     model::{
-        ir::{Expression, FnImplementations, Function, InterfaceData, Stmt, Struct, Type, Var},
+        ir::{
+            Enum, Error, Event, Expression, FnImplementations, Function, InterfaceData, Stmt,
+            Struct, Type, ValueType, Var,
+        },
         ContractData, Named,
     },
     utils,
@@ -15,6 +20,7 @@ pub enum ItemType {
     Interface(String),
     Enum(String),
     Struct(Struct),
+    ValueType(ValueType),
     Event,
     Storage(Var),
     Local(Var),
@@ -54,6 +60,12 @@ pub trait TypeInfo {
     }
     fn has_enums(&self) -> bool;
     fn find_fn(&self, class: &str, name: &str) -> Option<Function>;
+    /// Finds all the functions of the class with a given name - the overloads and the overridden
+    /// implementations.
+    fn find_fns(&self, class: &str, name: &str) -> Vec<Function>;
+    fn find_enum(&self, name: &str) -> Option<Enum>;
+    fn find_interface(&self, name: &str) -> Option<InterfaceData>;
+    fn find_event(&self, name: &str) -> Option<Event>;
 }
 
 /// Provides information about the currently processing contract.
@@ -67,6 +79,8 @@ pub trait ErrorInfo {
     fn error_count(&self) -> u16;
     fn increment_error_counter(&mut self);
     fn insert_error<T: ToString>(&mut self, msg: T);
+    /// Finds a custom error definition by name.
+    fn find_error(&self, name: &str) -> Option<Error>;
 }
 
 /// Provides info about the contract storage.
@@ -94,10 +108,31 @@ pub trait FnContext {
     fn clear_current_fn(&mut self);
     /// Returns the current function.
     fn current_fn(&self) -> &FnImplementations;
+    /// Sets the class the currently processed function implementation is defined in.
+    fn set_current_class(&mut self, class: &Class);
+    /// Returns the class the currently processed function implementation is defined in.
+    fn current_class(&self) -> Option<&Class>;
+    /// Marks if the currently processed statements are in an `unchecked` block.
+    fn set_unchecked(&mut self, unchecked: bool);
+    /// Checks if the currently processed statements are in an `unchecked` block.
+    fn is_unchecked(&self) -> bool;
     /// Adds a new local variable in the function context.
     fn register_local_var<T: ToString>(&mut self, name: T, ty: &Type);
     /// Finds a local variable by name.
     fn get_local_var_by_name(&self, name: &str) -> Option<&Var>;
+    /// Registers a local storage pointer - a local variable that refers to a storage item.
+    ///
+    /// If the pointer is already declared in the current block, it is re-pointed.
+    fn register_storage_ref<T: ToString>(&mut self, name: T, target: &Expression);
+    /// Finds the storage expression a local storage pointer refers to.
+    fn get_storage_ref(&self, name: &str) -> Option<&Expression>;
+    /// Checks if a local storage pointer is declared in the current block.
+    fn is_storage_ref_in_scope(&self, name: &str) -> bool;
+    /// Opens a block - local variables and storage pointers declared from now on
+    /// are dropped when the block is closed.
+    fn push_scope(&mut self);
+    /// Closes the innermost block.
+    fn drop_scope(&mut self);
     /// Push an expression to the context stack.
     /// It adds more context to the currently processed expression.
     /// Some expressions are made of a few expressions (left and right expression
@@ -113,28 +148,29 @@ pub trait FnContext {
 #[allow(dead_code)]
 #[derive(Debug, Default)]
 pub struct GlobalContext {
-    events: Vec<String>,
+    events: Vec<Event>,
     interfaces: Vec<InterfaceData>,
     libraries: Vec<ContractData>,
-    enums: Vec<String>,
-    errors: Vec<String>,
+    enums: Vec<Enum>,
+    errors: Vec<Error>,
     classes: Vec<ContractData>,
     structs: Vec<Struct>,
+    value_types: Vec<ValueType>,
     error_map: HashMap<String, u16>,
     error_count: u16,
 }
 
 impl GlobalContext {
     pub fn new(
-        events: Vec<String>,
+        events: Vec<Event>,
         interfaces: Vec<InterfaceData>,
         libraries: Vec<ContractData>,
-        enums: Vec<String>,
-        errors: Vec<String>,
+        enums: Vec<Enum>,
+        errors: Vec<Error>,
         classes: Vec<ContractData>,
         structs: Vec<Struct>,
+        value_types: Vec<ValueType>,
     ) -> Self {
-        let error_count = errors.len() as u16;
         Self {
             events,
             interfaces,
@@ -143,7 +179,7 @@ impl GlobalContext {
             errors,
             classes,
             structs,
-            error_count,
+            value_types,
             ..Default::default()
         }
     }
@@ -158,18 +194,21 @@ impl TypeInfo for GlobalContext {
         if self.classes.iter().any(|c| c.name() == name.to_string()) {
             return Some(ItemType::Contract(name.clone()));
         }
-        if self.events.contains(name) {
+        if self.events.iter().any(|e| e.name == *name) {
             return Some(ItemType::Event);
         }
         if self.interfaces.iter().any(|c| c.name() == name.to_string()) {
             return Some(ItemType::Interface(name.clone()));
         }
-        if self.enums.contains(name) {
+        if self.enums.iter().any(|e| e.name == *name) {
             return Some(ItemType::Enum(name.clone()));
         }
         if let Some(s) = self.structs.iter().find(|c| c.name() == name.to_string()) {
             return Some(ItemType::Struct(s.clone()));
         }
+        if let Some(v) = self.value_types.iter().find(|v| v.name == *name) {
+            return Some(ItemType::ValueType(v.clone()));
+        }
         None
     }
 
@@ -177,6 +216,18 @@ impl TypeInfo for GlobalContext {
         !self.enums.is_empty()
     }
 
+    fn find_enum(&self, name: &str) -> Option<Enum> {
+        self.enums.iter().find(|e| e.name == name).cloned()
+    }
+
+    fn find_interface(&self, name: &str) -> Option<InterfaceData> {
+        self.interfaces.iter().find(|i| i.name() == name).cloned()
+    }
+
+    fn find_event(&self, name: &str) -> Option<Event> {
+        self.events.iter().find(|e| e.name == name).cloned()
+    }
+
     fn find_fn(&self, class: &str, name: &str) -> Option<Function> {
         if let Some(lib) = self
             .libraries
@@ -206,6 +257,25 @@ impl TypeInfo for GlobalContext {
 
         None
     }
+
+    fn find_fns(&self, class: &str, name: &str) -> Vec<Function> {
+        if let Some(i) = self.interfaces.iter().find(|c| c.name() == class) {
+            return i
+                .fns()
+                .iter()
+                .filter(|f| f.name() == name)
+                .cloned()
+                .collect();
+        }
+        self.libraries
+            .iter()
+            .chain(self.classes.iter())
+            .filter(|c| c.name() == class)
+            .flat_map(|c| c.fn_implementations())
+            .filter(|f| f.name == name)
+            .flat_map(|f| f.implementations.into_iter().map(|(_, f)| f))
+            .collect()
+    }
 }
 
 impl ErrorInfo for GlobalContext {
@@ -225,6 +295,10 @@ impl ErrorInfo for GlobalContext {
     fn get_error<T: ToString>(&self, msg: T) -> Option<u16> {
         self.error_map.get(&msg.to_string()).copied()
     }
+
+    fn find_error(&self, name: &str) -> Option<Error> {
+        self.errors.iter().find(|e| e.name == name).cloned()
+    }
 }
 
 #[derive(Debug)]
@@ -275,7 +349,10 @@ impl EventsRegister for ContractContext<'_> {
     }
 
     fn emitted_events(&self) -> Vec<&String> {
-        self.emitted_events.iter().collect::<Vec<_>>()
+        // sorted, so the generated code does not depend on the order of emitting
+        let mut events = self.emitted_events.iter().collect::<Vec<_>>();
+        events.sort();
+        events
     }
 }
 
@@ -301,6 +378,10 @@ impl TypeInfo for ContractContext<'_> {
         to self.global {
             fn has_enums(&self) -> bool;
             fn find_fn(&self, class: &str, name: &str) -> Option<Function>;
+            fn find_fns(&self, class: &str, name: &str) -> Vec<Function>;
+            fn find_enum(&self, name: &str) -> Option<Enum>;
+            fn find_interface(&self, name: &str) -> Option<InterfaceData>;
+            fn find_event(&self, name: &str) -> Option<Event>;
         }
     }
 }
@@ -312,6 +393,7 @@ impl ErrorInfo for ContractContext<'_> {
             fn error_count(&self) -> u16;
             fn increment_error_counter(&mut self);
             fn insert_error<T: ToString>(&mut self, msg: T);
+            fn find_error(&self, name: &str) -> Option<Error>;
         }
     }
 }
@@ -320,7 +402,12 @@ impl ErrorInfo for ContractContext<'_> {
 pub struct LocalContext<'a> {
     contract: ContractContext<'a>,
     current_fn: Option<FnImplementations>,
+    current_class: Option<Class>,
+    unchecked: bool,
     local_vars: Vec<Var>,
+    storage_refs: Vec<(String, Expression)>,
+    /// The number of local variables and storage pointers declared before each open block.
+    scopes: Vec<(usize, usize)>,
     contextual_expressions: Vec<Expression>,
 }
 
@@ -329,7 +416,11 @@ impl<'a> LocalContext<'a> {
         Self {
             contract: ctx,
             current_fn: None,
+            current_class: None,
+            unchecked: false,
             local_vars: Default::default(),
+            storage_refs: Default::default(),
+            scopes: Default::default(),
             contextual_expressions: Default::default(),
         }
     }
@@ -351,6 +442,10 @@ impl TypeInfo for LocalContext<'_> {
         to self.contract {
             fn has_enums(&self) -> bool;
             fn find_fn(&self, class: &str, name: &str) -> Option<Function>;
+            fn find_fns(&self, class: &str, name: &str) -> Vec<Function>;
+            fn find_enum(&self, name: &str) -> Option<Enum>;
+            fn find_interface(&self, name: &str) -> Option<InterfaceData>;
+            fn find_event(&self, name: &str) -> Option<Event>;
         }
     }
 }
@@ -362,7 +457,11 @@ impl FnContext for LocalContext<'_> {
 
     fn clear_current_fn(&mut self) {
         self.current_fn = None;
+        self.current_class = None;
+        self.unchecked = false;
         self.local_vars.clear();
+        self.storage_refs.clear();
+        self.scopes.clear();
     }
 
     fn current_fn(&self) -> &FnImplementations {
@@ -372,6 +471,22 @@ impl FnContext for LocalContext<'_> {
             .expect("The current function should be set")
     }
 
+    fn set_current_class(&mut self, class: &Class) {
+        self.current_class = Some(class.clone());
+    }
+
+    fn current_class(&self) -> Option<&Class> {
+        self.current_class.as_ref()
+    }
+
+    fn set_unchecked(&mut self, unchecked: bool) {
+        self.unchecked = unchecked;
+    }
+
+    fn is_unchecked(&self) -> bool {
+        self.unchecked
+    }
+
     fn register_local_var<T: ToString>(&mut self, name: T, ty: &Type) {
         let var = Var {
             name: name.to_string(),
@@ -383,11 +498,60 @@ impl FnContext for LocalContext<'_> {
     }
 
     fn get_local_var_by_name(&self, name: &str) -> Option<&Var> {
+        // the innermost declaration shadows the outer ones
         self.local_vars
             .iter()
+            .rev()
             .find(|v| v.name == name || v.name == utils::to_snake_case(name))
     }
 
+    fn register_storage_ref<T: ToString>(&mut self, name: T, target: &Expression) {
+        let name = name.to_string();
+        let scope_start = self
+            .scopes
+            .last()
+            .map(|(_, refs)| *refs)
+            .unwrap_or_default();
+        match self.storage_refs[scope_start..]
+            .iter_mut()
+            .find(|(n, _)| n == &name)
+        {
+            Some((_, current)) => *current = target.clone(),
+            None => self.storage_refs.push((name, target.clone())),
+        }
+    }
+
+    fn get_storage_ref(&self, name: &str) -> Option<&Expression> {
+        self.storage_refs
+            .iter()
+            .rev()
+            .find(|(n, _)| n == name || n == &utils::to_snake_case(name))
+            .map(|(_, target)| target)
+    }
+
+    fn is_storage_ref_in_scope(&self, name: &str) -> bool {
+        let scope_start = self
+            .scopes
+            .last()
+            .map(|(_, refs)| *refs)
+            .unwrap_or_default();
+        self.storage_refs[scope_start..]
+            .iter()
+            .any(|(n, _)| n == name || n == &utils::to_snake_case(name))
+    }
+
+    fn push_scope(&mut self) {
+        self.scopes
+            .push((self.local_vars.len(), self.storage_refs.len()));
+    }
+
+    fn drop_scope(&mut self) {
+        if let Some((vars, refs)) = self.scopes.pop() {
+            self.local_vars.truncate(vars);
+            self.storage_refs.truncate(refs);
+        }
+    }
+
     fn push_contextual_expr(&mut self, expr: Expression) -> bool {
         self.contextual_expressions.push(expr);
         true
@@ -443,6 +607,7 @@ impl ErrorInfo for LocalContext<'_> {
             fn error_count(&self) -> u16;
             fn increment_error_counter(&mut self);
             fn insert_error<T: ToString>(&mut self, msg: T);
+            fn find_error(&self, name: &str) -> Option<Error>;
         }
     }
 }
@@ -488,11 +653,28 @@ pub mod test {
         fn find_fn(&self, class: &str, name: &str) -> Option<crate::model::ir::Function> {
             None
         }
+
+        fn find_fns(&self, class: &str, name: &str) -> Vec<crate::model::ir::Function> {
+            vec![]
+        }
+
+        fn find_enum(&self, name: &str) -> Option<crate::model::ir::Enum> {
+            None
+        }
+
+        fn find_interface(&self, name: &str) -> Option<crate::model::ir::InterfaceData> {
+            None
+        }
+
+        fn find_event(&self, name: &str) -> Option<crate::model::ir::Event> {
+            None
+        }
     }
 
     impl ContractInfo for EmptyContext {
         fn current_contract(&self) -> &ContractData {
-            unimplemented!()
+            // a regular contract, leaked to outlive the stateless context
+            Box::leak(Box::new(ContractData::empty("test")))
         }
     }
 
@@ -521,12 +703,38 @@ pub mod test {
             todo!()
         }
 
+        fn set_current_class(&mut self, class: &c3_lang_linearization::Class) {}
+
+        fn current_class(&self) -> Option<&c3_lang_linearization::Class> {
+            None
+        }
+
+        fn set_unchecked(&mut self, unchecked: bool) {}
+
+        fn is_unchecked(&self) -> bool {
+            false
+        }
+
         fn register_local_var<T: ToString>(&mut self, name: T, ty: &crate::model::ir::Type) {}
 
         fn get_local_var_by_name(&self, name: &str) -> Option<&crate::model::ir::Var> {
             todo!()
         }
 
+        fn register_storage_ref<T: ToString>(&mut self, name: T, target: &Expression) {}
+
+        fn get_storage_ref(&self, name: &str) -> Option<&Expression> {
+            None
+        }
+
+        fn is_storage_ref_in_scope(&self, name: &str) -> bool {
+            false
+        }
+
+        fn push_scope(&mut self) {}
+
+        fn drop_scope(&mut self) {}
+
         fn push_contextual_expr(&mut self, expr: Expression) -> bool {
             false
         }
@@ -550,5 +758,9 @@ pub mod test {
         fn increment_error_counter(&mut self) {}
 
         fn insert_error<T: ToString>(&mut self, msg: T) {}
+
+        fn find_error(&self, name: &str) -> Option<crate::model::ir::Error> {
+            None
+        }
     }
 }
@@ -0,0 +1,429 @@
+//! Implicit conversions of the IR.
+//!
+//! Solidity implicitly widens a value wherever a wider type is expected - an assignment, a returned
+//! value, a function, event or struct argument, an array item, a mapping key or an operand
+//! of a binary operation. Rust does not, so before any code is generated, the pass walks each
+//! function body, tracks the types of local variables and wraps every such value
+//! in an explicit conversion, eg. `uint256(a)`.
+use crate::model::{
+    ir::{
+        eval_expression_type, widen, BitwiseOp, Expression, Function, MathOp, Op, Package, Stmt,
+        TupleItem, Type, Var,
+    },
+    ContractData,
+};
+
+use super::{
+    context::{
+        ContractContext, ContractInfo, FnContext, GlobalContext, ItemType, LocalContext, TypeInfo,
+    },
+    typecheck,
+};
+
+/// Inserts implicit conversions in all the contracts of the package.
+pub fn convert_package(package: &mut Package, ctx: &mut GlobalContext) {
+    for data in package.contracts_mut() {
+        let mut ctx = LocalContext::new(ContractContext::new(ctx, data.clone()));
+        convert_contract(data, &mut ctx);
+    }
+}
+
+/// Inserts implicit conversions in the state variable initializers and all the functions
+/// of the contract.
+fn convert_contract<T: TypeInfo + ContractInfo + FnContext>(data: &mut ContractData, ctx: &mut T) {
+    let functions = data.fn_implementations();
+    for (implementations, converted) in functions.iter().zip(data.fn_implementations_mut()) {
+        // each implementation has its own local variables
+        for (_, f) in converted.implementations.iter_mut() {
+            ctx.set_current_fn(implementations);
+            convert_fn(f, ctx);
+            ctx.clear_current_fn();
+        }
+    }
+    for var in data.vars_mut().iter_mut().filter(|v| !v.is_immutable) {
+        let target = Expression::Variable(var.name.clone());
+        var.initializer = var
+            .initializer
+            .as_ref()
+            .map(|init| convert_assigned(&target, &convert_expr(init, ctx), None, ctx));
+    }
+}
+
+fn convert_fn<T: TypeInfo + ContractInfo + FnContext>(f: &mut Function, ctx: &mut T) {
+    f.params()
+        .iter()
+        .for_each(|p| ctx.register_local_var(&p.name, &p.ty));
+    match f {
+        Function::Function(f) => {
+            f.ret
+                .iter()
+                .filter_map(|(name, ty)| Some((name.as_ref()?, Type::try_from(ty).ok()?)))
+                .for_each(|(name, ty)| ctx.register_local_var(name, &ty));
+            f.stmts = convert_stmts(&f.stmts, ctx);
+        }
+        Function::Constructor(c) => c.stmts = convert_stmts(&c.stmts, ctx),
+        Function::Modifier(m) => {
+            m.before_stmts = convert_stmts(&m.before_stmts, ctx);
+            m.after_stmts = convert_stmts(&m.after_stmts, ctx);
+        }
+    }
+}
+
+fn convert_stmts<T: TypeInfo + ContractInfo + FnContext>(stmts: &[Stmt], ctx: &mut T) -> Vec<Stmt> {
+    stmts.iter().map(|stmt| convert_stmt(stmt, ctx)).collect()
+}
+
+fn convert_stmt<T: TypeInfo + ContractInfo + FnContext>(stmt: &Stmt, ctx: &mut T) -> Stmt {
+    let boxed = |stmt: &Stmt, ctx: &mut T| Box::new(convert_stmt(stmt, ctx));
+    match stmt {
+        Stmt::Return(expr) => {
+            let expr = convert_expr(expr, ctx);
+            Stmt::Return(convert_returned(&expr, ctx))
+        }
+        Stmt::Expression(expr) => Stmt::Expression(convert_expr(expr, ctx)),
+        // the values are converted to the types of the event fields
+        Stmt::Emit(Expression::Func(name, args)) => {
+            let args = convert_exprs(args, ctx);
+            let fields = match &**name {
+                Expression::Variable(event_name) => ctx.find_event(event_name).and_then(|e| {
+                    e.fields
+                        .iter()
+                        .map(|(_, ty)| Type::try_from(ty))
+                        .collect::<Result<Vec<_>, _>>()
+                        .ok()
+                }),
+                _ => None,
+            };
+            let args = match fields {
+                Some(fields) => convert_args(&args, &fields, ctx),
+                None => args,
+            };
+            Stmt::Emit(Expression::Func(name.clone(), args))
+        }
+        Stmt::Emit(expr) => Stmt::Emit(convert_expr(expr, ctx)),
+        Stmt::Revert(Some(expr)) => Stmt::Revert(Some(convert_expr(expr, ctx))),
+        Stmt::RevertWithError(name, args) => {
+            Stmt::RevertWithError(name.clone(), convert_exprs(args, ctx))
+        }
+        Stmt::VarDefinition(name, ty, init) => {
+            let init = convert_expr(init, ctx);
+            if ty == &Type::Unknown {
+                let init_ty = eval_expression_type(&init, ctx).unwrap_or(Type::Unknown);
+                ctx.register_local_var(name, &init_ty);
+                Stmt::VarDefinition(name.clone(), ty.clone(), init)
+            } else {
+                ctx.register_local_var(name, ty);
+                Stmt::VarDefinition(name.clone(), ty.clone(), convert_to(&init, ty, ctx))
+            }
+        }
+        Stmt::VarDeclaration(name, ty) => {
+            ctx.register_local_var(name, ty);
+            stmt.clone()
+        }
+        Stmt::StorageRef(name, ty, target) => {
+            let target = convert_expr(target, ctx);
+            ctx.register_local_var(name, ty);
+            Stmt::StorageRef(name.clone(), ty.clone(), target)
+        }
+        Stmt::If(condition, body) => Stmt::If(convert_expr(condition, ctx), boxed(body, ctx)),
+        Stmt::While(condition, body) => Stmt::While(convert_expr(condition, ctx), boxed(body, ctx)),
+        Stmt::IfElse(condition, if_body, else_body) => Stmt::IfElse(
+            convert_expr(condition, ctx),
+            boxed(if_body, ctx),
+            boxed(else_body, ctx),
+        ),
+        // a local variable is visible until the end of the block it is defined in
+        Stmt::Block(stmts) => Stmt::Block(convert_block(stmts, ctx)),
+        Stmt::UncheckedBlock(stmts) => Stmt::UncheckedBlock(convert_block(stmts, ctx)),
+        Stmt::ReturningBlock(stmts) => Stmt::ReturningBlock(convert_block(stmts, ctx)),
+        _ => stmt.clone(),
+    }
+}
+
+fn convert_block<T: TypeInfo + ContractInfo + FnContext>(stmts: &[Stmt], ctx: &mut T) -> Vec<Stmt> {
+    ctx.push_scope();
+    let stmts = convert_stmts(stmts, ctx);
+    ctx.drop_scope();
+    stmts
+}
+
+fn convert_exprs<T: TypeInfo + ContractInfo + FnContext>(
+    exprs: &[Expression],
+    ctx: &mut T,
+) -> Vec<Expression> {
+    exprs.iter().map(|e| convert_expr(e, ctx)).collect()
+}
+
+/// Converts the expression and all its subexpressions.
+fn convert_expr<T: TypeInfo + ContractInfo + FnContext>(
+    expr: &Expression,
+    ctx: &mut T,
+) -> Expression {
+    let boxed = |e: &Expression, ctx: &mut T| Box::new(convert_expr(e, ctx));
+    match expr {
+        Expression::Require(condition, message) => Expression::Require(
+            boxed(condition, ctx),
+            message.as_ref().map(|m| boxed(m, ctx)),
+        ),
+        Expression::Assert(condition) => Expression::Assert(boxed(condition, ctx)),
+        Expression::Keccak256(args) => Expression::Keccak256(convert_exprs(args, ctx)),
+        Expression::Sha256(args) => Expression::Sha256(convert_exprs(args, ctx)),
+        Expression::Ripemd160(args) => Expression::Ripemd160(convert_exprs(args, ctx)),
+        Expression::EcRecover(args) => Expression::EcRecover(convert_exprs(args, ctx)),
+        Expression::AddMod(args) => Expression::AddMod(convert_exprs(args, ctx)),
+        Expression::MulMod(args) => Expression::MulMod(convert_exprs(args, ctx)),
+        Expression::AbiEncodePacked(args) => Expression::AbiEncodePacked(convert_exprs(args, ctx)),
+        Expression::AbiEncode(args) => Expression::AbiEncode(convert_exprs(args, ctx)),
+        Expression::AbiEncodeWithSelector(selector, args) => {
+            Expression::AbiEncodeWithSelector(boxed(selector, ctx), convert_exprs(args, ctx))
+        }
+        Expression::AbiEncodeWithSignature(signature, args) => {
+            Expression::AbiEncodeWithSignature(boxed(signature, ctx), convert_exprs(args, ctx))
+        }
+        Expression::AbiDecode(data, types) => {
+            Expression::AbiDecode(boxed(data, ctx), types.clone())
+        }
+        Expression::StringConcat(args) => Expression::StringConcat(convert_exprs(args, ctx)),
+        Expression::BytesConcat(args) => Expression::BytesConcat(convert_exprs(args, ctx)),
+        Expression::Collection(name, keys) => {
+            let keys = convert_exprs(keys, ctx);
+            let keys = match ctx.type_from_string(name) {
+                Some(ItemType::Storage(Var { ty, .. })) => convert_keys(&keys, &ty, true, ctx),
+                Some(ItemType::Local(Var { ty, .. })) => convert_keys(&keys, &ty, false, ctx),
+                _ => keys,
+            };
+            Expression::Collection(name.clone(), keys)
+        }
+        Expression::Index(base, keys) => {
+            let base = convert_expr(base, ctx);
+            let keys = convert_exprs(keys, ctx);
+            let keys = match eval_expression_type(&base, ctx) {
+                Some(ty) => convert_keys(&keys, &ty, false, ctx),
+                None => keys,
+            };
+            Expression::Index(Box::new(base), keys)
+        }
+        Expression::Assign(left, Some(right)) => {
+            let left = convert_expr(left, ctx);
+            let right = convert_expr(right, ctx);
+            let right = convert_assigned(&left, &right, None, ctx);
+            Expression::Assign(Box::new(left), Some(Box::new(right)))
+        }
+        Expression::Assign(left, None) => Expression::Assign(boxed(left, ctx), None),
+        Expression::AssignAnd(left, right, op) => {
+            let left = convert_expr(left, ctx);
+            let right = convert_expr(right, ctx);
+            let right = convert_assigned(&left, &right, Some(op), ctx);
+            Expression::AssignAnd(Box::new(left), Box::new(right), op.clone())
+        }
+        // a power is computed in the type of the base, the exponent may be of any unsigned type
+        Expression::MathOp(left, right, MathOp::Pow) => {
+            Expression::MathOp(boxed(left, ctx), boxed(right, ctx), MathOp::Pow)
+        }
+        Expression::MathOp(left, right, op) => {
+            let (left, right) = convert_operands(left, right, ctx);
+            Expression::MathOp(left, right, op.clone())
+        }
+        Expression::LogicalOp(left, right, op) => {
+            let (left, right) = convert_operands(left, right, ctx);
+            Expression::LogicalOp(left, right, op.clone())
+        }
+        // a shift amount does not have to be of the same type as the shifted value
+        Expression::BitwiseOp(left, right, op @ (BitwiseOp::ShiftLeft | BitwiseOp::ShiftRight)) => {
+            Expression::BitwiseOp(boxed(left, ctx), boxed(right, ctx), op.clone())
+        }
+        Expression::BitwiseOp(left, right, op) => {
+            let (left, right) = convert_operands(left, right, ctx);
+            Expression::BitwiseOp(left, right, op.clone())
+        }
+        Expression::Increment(e) => Expression::Increment(boxed(e, ctx)),
+        Expression::Decrement(e) => Expression::Decrement(boxed(e, ctx)),
+        Expression::MemberAccess(field, base) => {
+            Expression::MemberAccess(field.clone(), boxed(base, ctx))
+        }
+        Expression::Func(name, args) => {
+            let name = convert_expr(name, ctx);
+            let args = convert_call_args(expr, args, ctx);
+            Expression::Func(Box::new(name), args)
+        }
+        Expression::SuperCall(name, args) => {
+            Expression::SuperCall(name.clone(), convert_call_args(expr, args, ctx))
+        }
+        Expression::ExternalCall(variable, fn_name, args) => Expression::ExternalCall(
+            variable.clone(),
+            fn_name.clone(),
+            convert_call_args(expr, args, ctx),
+        ),
+        Expression::TypeInfo(e, property) => Expression::TypeInfo(boxed(e, ctx), property.clone()),
+        Expression::Not(e) => Expression::Not(boxed(e, ctx)),
+        Expression::ArrayLiteral(items) => Expression::ArrayLiteral(convert_exprs(items, ctx)),
+        Expression::Initializer(e) => Expression::Initializer(boxed(e, ctx)),
+        Expression::Statement(stmt) => Expression::Statement(Box::new(convert_stmt(stmt, ctx))),
+        Expression::UnaryOp(e, op) => Expression::UnaryOp(boxed(e, ctx), op.clone()),
+        Expression::Tuple(items) => Expression::Tuple(
+            items
+                .iter()
+                .map(|item| match item {
+                    TupleItem::Expr(e) => TupleItem::Expr(convert_expr(e, ctx)),
+                    _ => item.clone(),
+                })
+                .collect(),
+        ),
+        _ => expr.clone(),
+    }
+}
+
+/// Converts a value to the expected type, an array literal is converted item by item.
+fn convert_to<T: TypeInfo + ContractInfo>(expr: &Expression, ty: &Type, ctx: &T) -> Expression {
+    match (expr, ty) {
+        (Expression::ArrayLiteral(items), Type::Array(item_ty) | Type::FixedArray(item_ty, _)) => {
+            Expression::ArrayLiteral(items.iter().map(|i| convert_to(i, item_ty, ctx)).collect())
+        }
+        _ => widen(expr, ty, ctx),
+    }
+}
+
+/// Converts each argument to the type of the matching parameter.
+///
+/// The arguments are left intact if the numbers differ.
+fn convert_args<T: TypeInfo + ContractInfo>(
+    args: &[Expression],
+    params: &[Type],
+    ctx: &T,
+) -> Vec<Expression> {
+    match params.len() == args.len() {
+        true => args
+            .iter()
+            .zip(params.iter())
+            .map(|(arg, ty)| convert_to(arg, ty, ctx))
+            .collect(),
+        false => args.to_vec(),
+    }
+}
+
+/// Converts the arguments of a call to the parameter types of the function the call resolves to.
+fn convert_call_args<T: TypeInfo + ContractInfo + FnContext>(
+    call: &Expression,
+    args: &[Expression],
+    ctx: &mut T,
+) -> Vec<Expression> {
+    let args = convert_exprs(args, ctx);
+    match typecheck::resolve_call(call, &args, ctx) {
+        Some(params) => convert_args(&args, &params, ctx),
+        None => args,
+    }
+}
+
+/// Converts the operands of a binary operation to their common type, eg. in `a + b`
+/// a `uint8` operand is converted to `uint256` if the other one is `uint256`.
+fn convert_operands<T: TypeInfo + ContractInfo + FnContext>(
+    left: &Expression,
+    right: &Expression,
+    ctx: &mut T,
+) -> (Box<Expression>, Box<Expression>) {
+    let left = convert_expr(left, ctx);
+    let right = convert_expr(right, ctx);
+    let common_ty = match (
+        eval_expression_type(&left, ctx),
+        eval_expression_type(&right, ctx),
+    ) {
+        (Some(l), Some(r)) => l.common(&r),
+        _ => None,
+    };
+    match common_ty {
+        Some(ty) => (
+            Box::new(widen(&left, &ty, ctx)),
+            Box::new(widen(&right, &ty, ctx)),
+        ),
+        None => (Box::new(left), Box::new(right)),
+    }
+}
+
+/// Converts the assigned value to the type of the target, a shift amount is left intact
+/// unless it is a literal, which is typed as `uint256`.
+fn convert_assigned<T: TypeInfo + ContractInfo>(
+    left: &Expression,
+    right: &Expression,
+    operator: Option<&Op>,
+    ctx: &T,
+) -> Expression {
+    if let Some(Op::Bitwise(BitwiseOp::ShiftLeft | BitwiseOp::ShiftRight)) = operator {
+        return match right {
+            Expression::NumberLiteral(_) => Expression::Func(
+                Box::new(Expression::Type(Type::Uint(256))),
+                vec![right.clone()],
+            ),
+            _ => right.clone(),
+        };
+    }
+    let left_ty = eval_expression_type(left, ctx);
+    if let (Expression::ArrayLiteral(_), Some(ty)) = (right, &left_ty) {
+        return convert_to(right, ty, ctx);
+    }
+    // only an integer or a fixed-size byte array may be widened
+    let is_widenable = matches!(
+        eval_expression_type(right, ctx),
+        Some(Type::Uint(_) | Type::Int(_) | Type::Bytes(_))
+    );
+    match left_ty {
+        Some(ty) if is_widenable => widen(right, &ty, ctx),
+        _ => right.clone(),
+    }
+}
+
+/// Converts the returned value (or each item of a returned tuple) to the declared type.
+fn convert_returned<T: TypeInfo + ContractInfo + FnContext>(
+    expr: &Expression,
+    ctx: &T,
+) -> Expression {
+    let convert = |e: &Expression, ty: &Expression| match Type::try_from(ty) {
+        Ok(ty) => convert_to(e, &ty, ctx),
+        Err(_) => e.clone(),
+    };
+    let ret_types = ctx.current_fn().ret_types();
+    match expr {
+        Expression::Tuple(items) if items.len() == ret_types.len() => Expression::Tuple(
+            items
+                .iter()
+                .zip(ret_types.iter())
+                .map(|(item, ty)| match item {
+                    TupleItem::Expr(e) => TupleItem::Expr(convert(e, ty)),
+                    _ => item.clone(),
+                })
+                .collect(),
+        ),
+        _ => ret_types
+            .first()
+            .map(|ty| convert(expr, ty))
+            .unwrap_or_else(|| expr.clone()),
+    }
+}
+
+/// Converts the keys of a mapping to the key types, an index of a storage array to `uint256`.
+fn convert_keys<T: TypeInfo + ContractInfo>(
+    keys: &[Expression],
+    ty: &Type,
+    is_storage: bool,
+    ctx: &T,
+) -> Vec<Expression> {
+    let mut ty = ty.clone();
+    keys.iter()
+        .map(|key| match ty.clone() {
+            Type::Mapping(key_ty, value_ty) => {
+                ty = Type::try_from(&*value_ty).unwrap_or(Type::Unknown);
+                match Type::try_from(&*key_ty) {
+                    Ok(key_ty) => widen(key, &key_ty, ctx),
+                    Err(_) => key.clone(),
+                }
+            }
+            Type::Array(item_ty) | Type::FixedArray(item_ty, _) => {
+                ty = *item_ty;
+                match is_storage {
+                    true => widen(key, &Type::Uint(256), ctx),
+                    false => key.clone(),
+                }
+            }
+            _ => key.clone(),
+        })
+        .collect()
+}
@@ -1,16 +1,21 @@
 use proc_macro2::TokenStream;
-use solidity_parser::pt::ContractDefinition;
+use solidity_parser::pt::{self, ContractDefinition};
 
 use crate::{
     c3,
     model::ContractData,
     utils::{ast, map_collection, SolidityAST},
 };
-use crate::{model::ir::Package, ParserError};
+use crate::{
+    model::ir::{LibUsing, Package, UserOperator, ValueType},
+    ParserError,
+};
 
 pub mod context;
+mod conversion;
 pub mod odra;
 pub mod soroban;
+mod typecheck;
 
 /// Type that converts a pre-processed `package` into [TokenStream].
 pub trait Parser {
@@ -20,7 +25,7 @@ pub trait Parser {
 
 pub(crate) fn preprocess(solidity_ast: &SolidityAST) -> Result<Package, ParserError> {
     let contracts: Vec<&ContractDefinition> = ast::extract_contracts(solidity_ast);
-    let c3 = c3::linearization(&contracts);
+    let c3 = c3::linearization(&contracts)?;
 
     let top_lvl_classes =
         c3::find_top_level_contracts(&contracts, &c3).expect("At least one contract expected");
@@ -35,13 +40,61 @@ pub(crate) fn preprocess(solidity_ast: &SolidityAST) -> Result<Package, ParserEr
         .map(From::from)
         .collect();
 
+    let value_types = value_types(solidity_ast);
+
+    let file_libs = ast::extract_file_using(solidity_ast)
+        .into_iter()
+        .flat_map(LibUsing::from_directive)
+        .collect::<Vec<_>>();
     let contracts = top_lvl_classes
         .iter()
-        .map(|class| ContractData::try_from((class, &contracts)))
+        .map(|class| ContractData::try_from((class, &contracts, file_libs.as_slice())))
         .collect::<Result<_, _>>()
         .expect("The ast should allow to create a valid PackageDef");
 
     Ok(Package::new(
-        contracts, events, errors, enums, interfaces, structs,
+        contracts,
+        events,
+        errors,
+        enums,
+        interfaces,
+        structs,
+        value_types,
     ))
 }
+
+/// Collects user-defined value types along with the operators bound to them.
+fn value_types(solidity_ast: &SolidityAST) -> Vec<ValueType> {
+    let mut value_types: Vec<ValueType> = ast::extract_type_definitions(solidity_ast)
+        .into_iter()
+        .map(From::from)
+        .collect();
+
+    for using in ast::extract_using_functions(solidity_ast) {
+        if let (Some(pt::Expression::Variable(ty)), pt::UsingList::Functions(functions)) =
+            (&using.ty, &using.list)
+        {
+            if let Some(value_type) = value_types.iter_mut().find(|v| v.name == ty.name) {
+                value_type
+                    .operators
+                    .extend(functions.iter().filter_map(to_user_operator));
+            }
+        }
+    }
+    value_types
+}
+
+fn to_user_operator(f: &pt::UsingFunction) -> Option<UserOperator> {
+    let op = f.oper.as_ref()?;
+    // a free function `add` or a library function `Math.add`
+    let (lib, fn_name) = match f.path.as_slice() {
+        [name] => (ast::FREE_FUNCTIONS_LIB.to_string(), name.name.to_owned()),
+        [lib, name] => (lib.name.to_owned(), name.name.to_owned()),
+        _ => return None,
+    };
+    Some(UserOperator {
+        op: op.into(),
+        lib,
+        fn_name,
+    })
+}
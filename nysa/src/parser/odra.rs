@@ -1,8 +1,4 @@
-use crate::{
-    model::ir::Package,
-    utils::{self, AsStringVec},
-    ParserError,
-};
+use crate::{model::ir::Package, utils, ParserError};
 use c3_lang_parser::c3_ast::{ClassDef, PackageDef};
 use proc_macro2::TokenStream;
 
@@ -10,7 +6,7 @@ use self::syn_utils::attr;
 
 use super::{
     context::{ContractContext, ContractInfo, EventsRegister, GlobalContext, LocalContext},
-    Parser,
+    conversion, typecheck, Parser,
 };
 
 mod custom;
@@ -29,20 +25,23 @@ mod var;
 pub struct OdraParser;
 
 impl Parser for OdraParser {
-    fn parse(package: Package) -> Result<TokenStream, ParserError> {
+    fn parse(mut package: Package) -> Result<TokenStream, ParserError> {
         // register all metadata in the global context.
         let mut ctx = GlobalContext::new(
-            package.events().as_string_vec(),
+            package.events().to_vec(),
             package.interfaces().to_vec(),
             package.libraries().to_vec(),
-            package.enums().as_string_vec(),
-            package.errors().as_string_vec(),
+            package.enums().to_vec(),
+            package.errors().to_vec(),
             package.contracts().to_vec(),
             package.structs().to_vec(),
+            package.value_types().to_vec(),
         );
+        typecheck::check_package(&package, &mut ctx)?;
+        conversion::convert_package(&mut package, &mut ctx);
 
         let events = event::events_def(&package, &ctx)?;
-        let errors = errors::errors_def(&package);
+        let errors = errors::errors_def(&package, &ctx)?;
         let enums = custom::enums_def(&package);
         let structs = custom::struct_def(&package, &ctx)?;
         let ext = ext::ext_contracts_def(&package, &ctx)?;
@@ -107,6 +106,7 @@ fn parse_packages(
             let classes = vec![contract_def(&mut ctx)?];
 
             let mut other_code = vec![];
+            other_code.extend(other::internal_functions_code(&ctx));
             other_code.extend(other::imports_code(&ctx));
             other_code.extend(other::other_code());
 
@@ -4,7 +4,10 @@ use quote::quote;
 use syn::{parse_quote, punctuated::Punctuated, Token};
 
 use crate::{
-    model::{ir::Package, Named},
+    model::{
+        ir::{BitwiseOp, LogicalOp, MathOp, Op, Package, UserOperator, ValueType},
+        Named,
+    },
     parser::{
         context::TypeInfo,
         odra::{syn_utils::attr, ty},
@@ -39,17 +42,16 @@ pub(crate) fn enums_def(package: &Package) -> Vec<syn::Item> {
         .collect()
 }
 
+/// Builds structs and user-defined value types, grouped by the namespace (the contract they are defined in).
 pub(crate) fn struct_def<T: TypeInfo>(
     package: &Package,
     t: &T,
 ) -> Result<Vec<syn::Item>, ParserError> {
     let structs = package.structs();
 
-    let mut result: Vec<syn::Item> = vec![];
+    let mut modules: Vec<(Option<String>, Vec<syn::Item>)> = vec![];
 
     for (key, group) in &structs.into_iter().group_by(|s| s.namespace.clone()) {
-        let namespace = key.as_ref().map(utils::to_snake_case_ident);
-
         let items = group
             .map(|s| {
                 let derive_attr = attr::derive_odra_ty();
@@ -70,8 +72,23 @@ pub(crate) fn struct_def<T: TypeInfo>(
                 Ok(struct_def)
             })
             .collect::<Result<Vec<syn::Item>, ParserError>>()?;
+        modules.push((key, items));
+    }
+
+    for value_type in package.value_types() {
+        let items = value_type_def(value_type, t)?;
+        match modules
+            .iter_mut()
+            .find(|(ns, _)| ns == &value_type.namespace)
+        {
+            Some((_, module)) => module.extend(items),
+            None => modules.push((value_type.namespace.clone(), items)),
+        }
+    }
 
-        if let Some(ns) = namespace {
+    let mut result: Vec<syn::Item> = vec![];
+    for (key, items) in modules {
+        if let Some(ns) = key.as_ref().map(utils::to_snake_case_ident) {
             result.push(parse_quote!(pub mod #ns { #(#items)* }));
         } else {
             result.extend(items);
@@ -79,3 +96,150 @@ pub(crate) fn struct_def<T: TypeInfo>(
     }
     Ok(result)
 }
+
+/// Builds a newtype representing a user-defined value type along with the operators bound to it.
+///
+/// sol:
+/// type Price is uint128;
+/// using {add as +} for Price global;
+///
+/// rs:
+/// pub struct Price(pub nysa_types::U128);
+/// impl core::ops::Add for Price { .. }
+fn value_type_def<T: TypeInfo>(
+    value_type: &ValueType,
+    t: &T,
+) -> Result<Vec<syn::Item>, ParserError> {
+    let name = utils::to_ident(&value_type.name);
+    let inner = ty::parse_type_from_ty(&value_type.ty, t)?;
+
+    // A value type is defined in the `structs` module, a namespaced type is nested one level deeper.
+    let lib_path = match value_type.namespace {
+        Some(_) => quote!(super::super),
+        None => quote!(super),
+    };
+    let call = |op: &UserOperator, args: TokenStream| {
+        let lib = utils::to_ident(&op.lib);
+        let lib_mod = utils::to_snake_case_ident(&op.lib);
+        let fn_ident = utils::to_snake_case_ident(&op.fn_name);
+        quote!(#lib_path::#lib_mod::#lib::#fn_ident(#args))
+    };
+    let find = |op: Op| value_type.operators.iter().find(|o| o.op == op);
+
+    let mut derives = vec![quote!(Clone), quote!(Copy), quote!(Debug), quote!(Default)];
+    let mut items: Vec<syn::Item> = vec![];
+
+    for operator in &value_type.operators {
+        let item: syn::Item = match &operator.op {
+            Op::Math(MathOp::Sub) if is_unary(operator, t) => {
+                let call = call(operator, quote!(self));
+                parse_quote!(
+                    impl core::ops::Neg for #name {
+                        type Output = Self;
+                        fn neg(self) -> Self::Output { #call }
+                    }
+                )
+            }
+            Op::Bitwise(BitwiseOp::Not) => {
+                let call = call(operator, quote!(self));
+                parse_quote!(
+                    impl core::ops::Not for #name {
+                        type Output = Self;
+                        fn not(self) -> Self::Output { #call }
+                    }
+                )
+            }
+            Op::Math(_) | Op::Bitwise(_) => {
+                let (trait_name, fn_ident) = match &operator.op {
+                    Op::Math(MathOp::Add) => (quote!(Add), quote!(add)),
+                    Op::Math(MathOp::Sub) => (quote!(Sub), quote!(sub)),
+                    Op::Math(MathOp::Mul) => (quote!(Mul), quote!(mul)),
+                    Op::Math(MathOp::Div) => (quote!(Div), quote!(div)),
+                    Op::Math(MathOp::Modulo) => (quote!(Rem), quote!(rem)),
+                    Op::Bitwise(BitwiseOp::And) => (quote!(BitAnd), quote!(bitand)),
+                    Op::Bitwise(BitwiseOp::Or) => (quote!(BitOr), quote!(bitor)),
+                    Op::Bitwise(BitwiseOp::Xor) => (quote!(BitXor), quote!(bitxor)),
+                    _ => continue,
+                };
+                let call = call(operator, quote!(self, rhs));
+                parse_quote!(
+                    impl core::ops::#trait_name for #name {
+                        type Output = Self;
+                        fn #fn_ident(self, rhs: Self) -> Self::Output { #call }
+                    }
+                )
+            }
+            // comparison operators are handled below
+            _ => continue,
+        };
+        items.push(item);
+    }
+
+    // Comparison operators override the default methods of `PartialEq` and `PartialOrd`.
+    let eq_fns = [(LogicalOp::Eq, quote!(eq)), (LogicalOp::NotEq, quote!(ne))]
+        .into_iter()
+        .filter_map(|(op, fn_ident)| find(Op::Logical(op)).map(|o| (o, fn_ident)))
+        .map(|(op, fn_ident)| {
+            let call = call(op, quote!(*self, *other));
+            quote!(fn #fn_ident(&self, other: &Self) -> bool { #call })
+        })
+        .collect::<Vec<_>>();
+    if eq_fns.is_empty() {
+        derives.push(quote!(PartialEq));
+    } else {
+        let default_eq = find(Op::Logical(LogicalOp::Eq)).is_none().then(|| {
+            quote!(
+                fn eq(&self, other: &Self) -> bool {
+                    self.0 == other.0
+                }
+            )
+        });
+        items.push(parse_quote!(
+            impl PartialEq for #name {
+                #default_eq
+                #(#eq_fns)*
+            }
+        ));
+    }
+
+    let ord_fns = [
+        (LogicalOp::Less, quote!(lt)),
+        (LogicalOp::LessEq, quote!(le)),
+        (LogicalOp::More, quote!(gt)),
+        (LogicalOp::MoreEq, quote!(ge)),
+    ]
+    .into_iter()
+    .filter_map(|(op, fn_ident)| find(Op::Logical(op)).map(|o| (o, fn_ident)))
+    .map(|(op, fn_ident)| {
+        let call = call(op, quote!(*self, *other));
+        quote!(fn #fn_ident(&self, other: &Self) -> bool { #call })
+    })
+    .collect::<Vec<_>>();
+    if ord_fns.is_empty() {
+        derives.push(quote!(PartialOrd));
+    } else {
+        items.push(parse_quote!(
+            impl PartialOrd for #name {
+                fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+                    self.0.partial_cmp(&other.0)
+                }
+                #(#ord_fns)*
+            }
+        ));
+    }
+
+    let type_def: syn::Item = parse_quote!(
+        #[derive(#(#derives),*)]
+        pub struct #name(pub #inner);
+    );
+    let ser_de: syn::Item = parse_quote!(nysa_types::impl_value_type!(#name, #inner););
+
+    Ok([vec![type_def, ser_de], items].concat())
+}
+
+/// Checks if the function bound to `-` takes a single argument - then it is a negation, not a subtraction.
+fn is_unary<T: TypeInfo>(operator: &UserOperator, t: &T) -> bool {
+    t.find_fn(&operator.lib, &utils::to_snake_case(&operator.fn_name))
+        .map(|f| f.params().len() == 1)
+        .unwrap_or_default()
+}
@@ -1,33 +1,113 @@
+use std::collections::HashSet;
+
+use itertools::Itertools;
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn::parse_quote;
 
 use crate::{
-    model::{ir::Package, Named},
-    utils,
+    error::ParserResult,
+    model::{
+        ir::{Error, Package, Type},
+        Named,
+    },
+    parser::context::TypeInfo,
+    utils, ParserError,
 };
 
-use super::syn_utils::attr;
+use super::{expr::abi, syn_utils::attr};
 
-pub(crate) fn errors_def(package: &Package) -> Option<syn::Item> {
+/// Builds the `Panic` and `Error` enums.
+///
+/// A Casper revert discards the events emitted before, so the error data cannot be passed to the caller,
+/// only the error code is.
+pub(crate) fn errors_def<T: TypeInfo>(package: &Package, ctx: &T) -> ParserResult<TokenStream> {
+    let panic = panic_def();
+    let codes = error_codes(package.errors(), ctx)?;
     let execution_error_body = package
         .errors()
         .iter()
-        .enumerate()
-        .map(|(idx, e)| {
+        .zip(codes)
+        .map(|(e, code)| {
             let name = utils::to_ident(e.name());
-            let idx = idx as isize;
-            quote!(#name = #idx,)
+            let code = code as isize;
+            quote!(#name = #code,)
         })
         .collect::<TokenStream>();
     if execution_error_body.is_empty() {
-        return None;
+        return Ok(panic);
     }
+
     let derive_attr = attr::derive_odra_err();
-    Some(parse_quote! {
+    Ok(quote! {
+        #panic
+
         #derive_attr
         pub enum Error {
             #execution_error_body
         }
     })
 }
+
+/// Derives the error codes from the error selectors, so a code does not depend on the declaration order.
+///
+/// The most significant bit is always set to separate custom errors from panics
+/// and errors with a message, which are numbered from one.
+/// A code is taken from the first two bytes of the selector. If the codes of errors collide, each of the
+/// colliding errors takes the code from the next two bytes of its selector which is not taken yet,
+/// the errors are processed in the order of their selectors, so the other codes stay intact.
+fn error_codes<T: TypeInfo>(errors: &[Error], ctx: &T) -> ParserResult<Vec<u16>> {
+    let selectors = errors
+        .iter()
+        .map(|e| error_selector(e, ctx))
+        .collect::<ParserResult<Vec<_>>>()?;
+    let code = |selector: &[u8; 4], idx: usize| {
+        0x8000 | u16::from_be_bytes([selector[idx], selector[idx + 1]])
+    };
+
+    let mut codes = selectors.iter().map(|s| code(s, 0)).collect::<Vec<_>>();
+    let colliding = (0..errors.len())
+        .filter(|&i| codes.iter().filter(|&&c| c == codes[i]).count() > 1)
+        .sorted_by_key(|&i| selectors[i])
+        .collect::<Vec<_>>();
+    let mut taken = codes.iter().copied().collect::<HashSet<_>>();
+    for i in colliding {
+        let next = (1..3)
+            .map(|idx| code(&selectors[i], idx))
+            .find(|c| !taken.contains(c))
+            .ok_or_else(|| ParserError::ErrorCodeCollision(errors[i].name.clone()))?;
+        taken.insert(next);
+        codes[i] = next;
+    }
+    Ok(codes)
+}
+
+fn error_selector<T: TypeInfo>(error: &Error, ctx: &T) -> ParserResult<[u8; 4]> {
+    let params = error
+        .fields
+        .iter()
+        .map(|(_, ty)| Type::try_from(ty).map_err(|_| ParserError::InvalidType))
+        .collect::<ParserResult<Vec<_>>>()?;
+    Ok(abi::selector(&abi::signature(&error.name, &params, ctx)?))
+}
+
+/// Builds the `Panic` enum - the equivalent of the Solidity `Panic(uint256)` error.
+///
+/// A code is the Solidity panic code prefixed with `0x4e` - the first byte of the `Panic(uint256)` selector.
+fn panic_def() -> TokenStream {
+    let derive_attr = attr::derive_odra_err();
+    quote! {
+        #derive_attr
+        pub enum Panic {
+            Generic = 0x4e00,
+            Assert = 0x4e01,
+            ArithmeticOverflow = 0x4e11,
+            DivisionByZero = 0x4e12,
+            EnumConversion = 0x4e21,
+            StorageEncoding = 0x4e22,
+            EmptyArrayPop = 0x4e31,
+            IndexOutOfBounds = 0x4e32,
+            OutOfMemory = 0x4e41,
+            ZeroFunction = 0x4e51,
+        }
+    }
+}
@@ -24,7 +24,7 @@ pub(crate) fn events_def<T: TypeInfo>(
         .collect()
 }
 
-fn event_def<T: TypeInfo>(ev: &Event, ctx: &T) -> Result<ClassDef, ParserError> {
+pub(crate) fn event_def<T: TypeInfo>(ev: &Event, ctx: &T) -> Result<ClassDef, ParserError> {
     let class: Class = ev.name.clone().into();
     let path = vec![class.clone()];
     let variables = ev
@@ -0,0 +1,92 @@
+use tiny_keccak::{Hasher, Keccak};
+
+use crate::{
+    error::ParserResult,
+    model::ir::{Function, InterfaceData, Type},
+    parser::context::{ItemType, TypeInfo},
+    ParserError,
+};
+
+/// Returns the canonical name of a type used in a function signature, eg. `uint256[]`.
+///
+/// A contract is represented as `address`, an enum as `uint8` and a struct as a tuple of its fields.
+pub fn canonical_type<T: TypeInfo>(ty: &Type, ctx: &T) -> ParserResult<String> {
+    match ty {
+        Type::Address => Ok("address".to_string()),
+        Type::Bool => Ok("bool".to_string()),
+        Type::String => Ok("string".to_string()),
+        Type::Int(size) => Ok(format!("int{}", size)),
+        Type::Uint(size) => Ok(format!("uint{}", size)),
+        Type::Fixed(m, n) => Ok(format!("fixed{}x{}", m, n)),
+        Type::Ufixed(m, n) => Ok(format!("ufixed{}x{}", m, n)),
+        Type::Bytes(size) => Ok(format!("bytes{}", size)),
+        Type::DynamicBytes => Ok("bytes".to_string()),
+        Type::Array(ty) => Ok(format!("{}[]", canonical_type(ty, ctx)?)),
+        Type::FixedArray(ty, len) => Ok(format!("{}[{}]", canonical_type(ty, ctx)?, len)),
+        Type::Function(_) => Ok("function".to_string()),
+        Type::Custom(name) => match ctx.type_from_string(name) {
+            Some(ItemType::Contract(_) | ItemType::Interface(_)) => Ok("address".to_string()),
+            Some(ItemType::Enum(_)) => Ok("uint8".to_string()),
+            Some(ItemType::ValueType(v)) => canonical_type(&v.ty, ctx),
+            Some(ItemType::Struct(s)) => {
+                let fields = s
+                    .fields
+                    .iter()
+                    .map(|(_, ty)| {
+                        Type::try_from(ty)
+                            .map_err(|_| ParserError::InvalidType)
+                            .and_then(|ty| canonical_type(&ty, ctx))
+                    })
+                    .collect::<ParserResult<Vec<_>>>()?;
+                Ok(format!("({})", fields.join(",")))
+            }
+            _ => Err(ParserError::InvalidType),
+        },
+        Type::Mapping(_, _) | Type::Unknown => Err(ParserError::UnsupportedType(ty.clone())),
+    }
+}
+
+/// Builds a function signature, eg. `transfer(address,uint256)`.
+pub fn signature<T: TypeInfo>(name: &str, params: &[Type], ctx: &T) -> ParserResult<String> {
+    let params = params
+        .iter()
+        .map(|ty| canonical_type(ty, ctx))
+        .collect::<ParserResult<Vec<_>>>()?;
+    Ok(format!("{}({})", name, params.join(",")))
+}
+
+/// Computes the keccak256 hash, used to evaluate hashes of literals at compile time.
+pub fn keccak256(bytes: &[u8]) -> [u8; 32] {
+    let mut hasher = Keccak::v256();
+    let mut hash = [0u8; 32];
+    hasher.update(bytes);
+    hasher.finalize(&mut hash);
+    hash
+}
+
+/// Computes the 4-byte function selector - the first bytes of the keccak256 hash of the signature.
+pub fn selector(signature: &str) -> [u8; 4] {
+    let hash = keccak256(signature.as_bytes());
+    [hash[0], hash[1], hash[2], hash[3]]
+}
+
+/// Computes the ERC-165 interface id - XOR of the selectors of all the interface functions.
+///
+/// The functions inherited from other interfaces are not included.
+pub fn interface_id<T: TypeInfo>(interface: &InterfaceData, ctx: &T) -> ParserResult<[u8; 4]> {
+    interface
+        .fns()
+        .iter()
+        .filter_map(|f| match f {
+            Function::Function(f) => Some(f),
+            _ => None,
+        })
+        .map(|f| {
+            let params = f.params.iter().map(|p| p.ty.clone()).collect::<Vec<_>>();
+            signature(&f.sol_name, &params, ctx).map(|s| selector(&s))
+        })
+        .try_fold([0u8; 4], |id, selector| {
+            let selector = selector?;
+            Ok(core::array::from_fn(|i| id[i] ^ selector[i]))
+        })
+}
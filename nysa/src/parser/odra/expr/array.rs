@@ -1,17 +1,22 @@
-use super::{parse, parse_many, primitives};
+use super::{parse_many, primitives, syn_utils};
 use crate::{
     error::ParserResult,
     model::ir::Expression,
-    parser::context::{
-        ContractInfo, ErrorInfo, EventsRegister, ExternalCallsRegister, FnContext, StorageInfo,
-        TypeInfo,
+    parser::{
+        context::{
+            ContractInfo, ErrorInfo, EventsRegister, ExternalCallsRegister, FnContext, StorageInfo,
+            TypeInfo,
+        },
+        odra::syn_utils::ty,
     },
     utils,
 };
 use proc_macro2::Ident;
+use quote::quote;
 use syn::parse_quote;
 
 const PROPERTY_LENGTH: &str = "length";
+const FN_POP: &str = "pop";
 
 /// Parses an expression reading a property from an array into a `syn::Expr`.
 ///
@@ -34,7 +39,8 @@ pub fn read_property<
 ) -> ParserResult<syn::Expr> {
     let array = primitives::get_var_or_parse(expr, ctx)?;
     if property_name == PROPERTY_LENGTH {
-        Ok(parse_quote!(#array.len().into()))
+        let u256 = ty::u256();
+        Ok(parse_quote!(#u256::from(#array.len())))
     } else {
         let property = utils::to_ident(property_name);
         Ok(parse_quote!(#array.#property))
@@ -46,9 +52,10 @@ pub fn read_property<
 /// # Solidity Example
 /// ```ignore
 /// arr.push(i);
+/// balances[owner].push(i);
 /// ```
 pub fn fn_call<T>(
-    array_name: &str,
+    array: &Expression,
     fn_ident: Ident,
     args: &[Expression],
     ctx: &mut T,
@@ -63,44 +70,25 @@ where
         + ErrorInfo,
 {
     let result_expr: syn::Expr = parse_quote!(result);
-    let array = primitives::get_var_or_parse(&Expression::from(array_name), ctx)?;
     let args = parse_many(args, ctx)?;
-    let update_array = primitives::set_var(array_name, result_expr.clone(), ctx)?;
+    let update_array = primitives::write(array, result_expr.clone(), ctx)?;
+    let array = match array {
+        // a struct field cannot be moved out of a struct passed by a reference
+        Expression::MemberAccess(_, _) => {
+            let array = primitives::get_var_or_parse(array, ctx)?;
+            parse_quote!(#array.clone())
+        }
+        _ => primitives::get_var_or_parse(array, ctx)?,
+    };
+    let call: syn::Expr = parse_quote!(#result_expr.#fn_ident(#(#args),*));
+    // popping from an empty array reverts
+    let call = match fn_ident.to_string().as_str() {
+        FN_POP => syn_utils::unwrap_or_panic(call, quote!(EmptyArrayPop), ctx),
+        _ => call,
+    };
     Ok(parse_quote!({
         let mut #result_expr = #array;
-        #result_expr.#fn_ident(#(#args),*);
-        #update_array;
-    }))
-}
-
-/// Parses an expression replacing a value in an array into a `syn::Expr`.
-///
-/// # Solidity Example
-/// ```ignore
-/// // uint[] memory a = new uint[](5);
-/// a[1] = 123;
-/// ```
-pub fn replace_value<
-    T: StorageInfo
-        + TypeInfo
-        + EventsRegister
-        + ExternalCallsRegister
-        + ContractInfo
-        + FnContext
-        + ErrorInfo,
->(
-    array_name: &str,
-    index: &Expression,
-    value: syn::Expr,
-    ctx: &mut T,
-) -> ParserResult<syn::Expr> {
-    let result_expr: syn::Expr = parse_quote!(result);
-    let index = parse(index, ctx)?;
-    let array = primitives::get_var_or_parse(&Expression::from(array_name), ctx)?;
-    let update_array = primitives::set_var(array_name, result_expr.clone(), ctx)?;
-    Ok(parse_quote!({
-        let mut #result_expr = #array;
-        #result_expr[#index] = #value;
+        #call;
         #update_array;
     }))
 }
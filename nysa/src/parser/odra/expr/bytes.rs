@@ -0,0 +1,122 @@
+use super::primitives;
+use crate::{
+    error::ParserResult,
+    model::ir::{eval_expression_type, Expression, Type},
+    parser::{
+        context::{
+            ContractInfo, ErrorInfo, EventsRegister, ExternalCallsRegister, FnContext, StorageInfo,
+            TypeInfo,
+        },
+        odra::syn_utils::ty,
+    },
+};
+use syn::parse_quote;
+
+/// Parses a `bytes.concat()` call into a `syn::Expr`.
+///
+/// # Solidity Example
+/// ```ignore
+/// bytes.concat(b, bytes2(0x0102));
+/// ```
+pub fn concat<T>(args: &[Expression], ctx: &mut T) -> ParserResult<syn::Expr>
+where
+    T: StorageInfo
+        + TypeInfo
+        + EventsRegister
+        + ExternalCallsRegister
+        + ContractInfo
+        + FnContext
+        + ErrorInfo,
+{
+    let bytes = ty::bytes();
+    let args = args
+        .iter()
+        .map(|arg| primitives::get_var_or_parse(arg, ctx))
+        .collect::<ParserResult<Vec<_>>>()?;
+    Ok(parse_quote!(#bytes::concat(&[#(#args.as_slice()),*])))
+}
+
+/// Parses a `string.concat()` call into a `syn::Expr`.
+///
+/// # Solidity Example
+/// ```ignore
+/// string.concat(name, " ", symbol);
+/// ```
+pub fn concat_strings<T>(args: &[Expression], ctx: &mut T) -> ParserResult<syn::Expr>
+where
+    T: StorageInfo
+        + TypeInfo
+        + EventsRegister
+        + ExternalCallsRegister
+        + ContractInfo
+        + FnContext
+        + ErrorInfo,
+{
+    let args = args
+        .iter()
+        .map(|arg| primitives::get_var_or_parse(arg, ctx))
+        .collect::<ParserResult<Vec<_>>>()?;
+    Ok(parse_quote!([#(#args.as_str()),*].concat()))
+}
+
+/// Checks if the contextual type is a dynamic byte array, eg. a hex literal is `bytes`
+/// only if assigned to `bytes`.
+pub(crate) fn is_contextual<T: TypeInfo + ContractInfo + FnContext>(ctx: &T) -> bool {
+    matches!(
+        ctx.contextual_expr()
+            .and_then(|e| eval_expression_type(e, ctx)),
+        Some(Type::DynamicBytes)
+    )
+}
+
+/// Parses a hex literal into a `bytes` value.
+///
+/// # Solidity Example
+/// ```ignore
+/// bytes memory data = hex"61ff";
+/// ```
+pub fn parse_lit(bytes: &[u8]) -> syn::Expr {
+    let ty = ty::bytes();
+    parse_quote!(#ty::from([#(#bytes),*].as_slice()))
+}
+
+/// Parses a conversion to/from `bytes` into a `syn::Expr`.
+///
+/// Returns `None` if the conversion does not involve a dynamic byte array.
+/// Converting bytes into a `string` does not revert, invalid UTF-8 sequences are replaced.
+///
+/// # Solidity Example
+/// ```ignore
+/// bytes(name).length;
+/// string(data);
+/// ```
+pub fn try_cast<T>(ty: &Type, arg: &Expression, ctx: &mut T) -> ParserResult<Option<syn::Expr>>
+where
+    T: StorageInfo
+        + TypeInfo
+        + EventsRegister
+        + ExternalCallsRegister
+        + ContractInfo
+        + FnContext
+        + ErrorInfo,
+{
+    let arg_ty = eval_expression_type(arg, ctx);
+    let result = match (ty, arg_ty) {
+        (Type::DynamicBytes, Some(Type::String)) => {
+            let bytes = ty::bytes();
+            let arg = primitives::get_var_or_parse(arg, ctx)?;
+            Some(parse_quote!(#bytes::from(#arg.as_bytes())))
+        }
+        (Type::DynamicBytes, _) => {
+            let bytes = ty::bytes();
+            let arg = primitives::get_var_or_parse(arg, ctx)?;
+            Some(parse_quote!(#bytes::from(#arg)))
+        }
+        (Type::String, Some(Type::DynamicBytes)) => {
+            let arg = primitives::get_var_or_parse(arg, ctx)?;
+            Some(parse_quote!(#arg.to_utf8_string()))
+        }
+        _ => None,
+    };
+    Ok(result)
+}
@@ -0,0 +1,106 @@
+use super::{bytes, math, primitives};
+use crate::{
+    error::ParserResult,
+    model::ir::{eval_expression_type, Expression, Type, UnaryOp},
+    parser::{
+        context::{
+            ContractInfo, ErrorInfo, EventsRegister, ExternalCallsRegister, FnContext, ItemType,
+            StorageInfo, TypeInfo,
+        },
+        odra::ty,
+    },
+    ParserError,
+};
+use syn::parse_quote;
+
+/// Parses an explicit conversion `T(value)` into a `syn::Expr`.
+///
+/// A literal is typed as the target type, a value is converted by `nysa_types::ConvertFrom`.
+/// Returns an error if the conversion is not allowed in Solidity, or if it is an `address`
+/// to/from `uint160` or `bytes20` conversion, which would truncate a Casper address.
+///
+/// # Solidity Example
+/// ```ignore
+/// uint8(value);
+/// bytes4(hash);
+/// address(0);
+/// ```
+pub fn parse_cast<T>(ty: &Type, arg: &Expression, ctx: &mut T) -> ParserResult<syn::Expr>
+where
+    T: StorageInfo
+        + TypeInfo
+        + EventsRegister
+        + ExternalCallsRegister
+        + ContractInfo
+        + FnContext
+        + ErrorInfo,
+{
+    if is_literal(arg) {
+        return math::eval_in_context(arg, &Expression::Type(ty.clone()), ctx);
+    }
+    if let Some(expr) = bytes::try_cast(ty, arg, ctx)? {
+        return Ok(expr);
+    }
+
+    let target = ty::parse_type_from_ty(ty, ctx)?;
+    let value = primitives::get_var_or_parse(arg, ctx)?;
+    match eval_expression_type(arg, ctx) {
+        Some(from) if &from == ty => Ok(value),
+        // An enum variant has the index as the discriminant.
+        Some(Type::Custom(name)) if ctx.find_enum(&name).is_some() => match ty {
+            Type::Uint(_) => Ok(parse_quote!(#target::from(#value as u8))),
+            _ => Err(ParserError::InvalidConversion(
+                Type::Custom(name),
+                ty.clone(),
+            )),
+        },
+        // A contract is represented by its address.
+        Some(Type::Custom(name)) if is_contract(&name, ctx) && ty == &Type::Address => Ok(value),
+        Some(from) if is_address_conversion(&from, ty) => {
+            Err(ParserError::AddressConversion(from, ty.clone()))
+        }
+        Some(from) if !is_allowed(&from, ty) => {
+            Err(ParserError::InvalidConversion(from, ty.clone()))
+        }
+        _ => Ok(parse_quote!(<#target as nysa_types::ConvertFrom<_>>::convert_from(#value))),
+    }
+}
+
+/// Checks if Solidity allows an explicit conversion between the types.
+fn is_allowed(from: &Type, to: &Type) -> bool {
+    match (from, to) {
+        (Type::Uint(_), Type::Uint(_))
+        | (Type::Int(_), Type::Int(_))
+        | (Type::Bytes(_), Type::Bytes(_))
+        | (Type::DynamicBytes, Type::Bytes(_)) => true,
+        (Type::Uint(m), Type::Int(n)) | (Type::Int(m), Type::Uint(n)) => m == n,
+        (Type::Bytes(size), Type::Uint(bits)) | (Type::Uint(bits), Type::Bytes(size)) => {
+            *size as u16 * 8 == *bits
+        }
+        _ => false,
+    }
+}
+
+/// Checks if the conversion is allowed in Solidity between an `address` and a 20-byte value.
+fn is_address_conversion(from: &Type, to: &Type) -> bool {
+    matches!(
+        (from, to),
+        (Type::Address, Type::Uint(160) | Type::Bytes(20))
+            | (Type::Uint(160) | Type::Bytes(20), Type::Address)
+    )
+}
+
+fn is_literal(expr: &Expression) -> bool {
+    match expr {
+        Expression::NumberLiteral(_) | Expression::BytesLiteral(_) => true,
+        Expression::UnaryOp(expr, UnaryOp::Minus) => is_literal(expr),
+        _ => false,
+    }
+}
+
+fn is_contract<T: TypeInfo>(name: &str, ctx: &T) -> bool {
+    matches!(
+        ctx.type_from_string(name),
+        Some(ItemType::Contract(_) | ItemType::Interface(_))
+    )
+}
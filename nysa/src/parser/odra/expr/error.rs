@@ -1,3 +1,5 @@
+use quote::quote;
+
 use super::{parse, syn_utils};
 use crate::{
     error::ParserResult,
@@ -35,8 +37,13 @@ pub fn revert<
 ) -> ParserResult<syn::Expr> {
     match error {
         Expression::StringLiteral(message) => revert_with_str(condition, message, ctx),
+        Expression::Func(box Expression::Variable(name), args)
+            if ctx.find_error(name).is_some() =>
+        {
+            revert_with_custom_err(condition, name, args, ctx)
+        }
         _ => Err(ParserError::UnexpectedExpression(
-            "Error should be Expression::StringLiteral",
+            "Error should be Expression::StringLiteral or a custom error",
             error.clone(),
         )),
     }
@@ -72,7 +79,7 @@ pub fn revert_with_str<
         }
     };
 
-    let error = syn_utils::revert_user_error(error_num);
+    let error = syn_utils::revert_user_error(error_num, ctx);
 
     match condition {
         Some(condition) => {
@@ -83,14 +90,87 @@ pub fn revert_with_str<
     }
 }
 
+/// Reverts the execution of the contract with the `Panic::Assert` code if the condition is false.
+///
+/// # Arguments
+/// * `condition` - An expression representing a condition.
+/// * `ctx` - A mutable reference to the context object that provides information about the contract.
+///
+/// # Returns
+/// Returns a `ParserResult` containing a `syn::Expr` representing the assert expression if successful, or a `ParserError` if an error occurs.
+pub fn assert<
+    T: StorageInfo
+        + TypeInfo
+        + EventsRegister
+        + ExternalCallsRegister
+        + ContractInfo
+        + FnContext
+        + ErrorInfo,
+>(
+    condition: &Expression,
+    ctx: &mut T,
+) -> ParserResult<syn::Expr> {
+    let condition = parse(condition, ctx)?;
+    Ok(syn_utils::if_not(
+        condition,
+        syn_utils::panic(quote!(Assert), ctx),
+    ))
+}
+
 /// Reverts the execution of the contract with an error message.
 ///
 /// # Arguments
 /// * `error_name` - The error name to revert with.
+/// * `ctx` - A reference to the context object that provides information about the contract.
 ///
 /// # Returns
 /// Returns a `syn::Expr` representing the revert expression.
-pub fn revert_with_err(error_name: &str) -> syn::Expr {
+pub fn revert_with_err<T: ContractInfo>(error_name: &str, ctx: &T) -> syn::Expr {
     let error = utils::to_ident(error_name);
-    syn_utils::revert(error)
+    syn_utils::revert(error, ctx)
+}
+
+/// Reverts the execution of the contract with a custom error.
+///
+/// A Casper revert discards the events emitted before, so the error arguments cannot be passed
+/// to the caller, only the error code is.
+///
+/// # Arguments
+/// * `condition` - An optional expression representing a condition. If the condition is false, the contract execution will be reverted.
+/// * `error_name` - The custom error name.
+/// * `args` - The custom error arguments.
+/// * `ctx` - A mutable reference to the context object that provides information about the contract.
+///
+/// # Returns
+/// Returns a `ParserResult` containing a `syn::Expr` representing the revert expression if successful, or a `ParserError` if an error occurs.
+pub fn revert_with_custom_err<
+    T: StorageInfo
+        + TypeInfo
+        + EventsRegister
+        + ExternalCallsRegister
+        + ContractInfo
+        + FnContext
+        + ErrorInfo,
+>(
+    condition: Option<&Expression>,
+    error_name: &str,
+    args: &[Expression],
+    ctx: &mut T,
+) -> ParserResult<syn::Expr> {
+    let arity = ctx
+        .find_error(error_name)
+        .map(|e| e.fields.len())
+        .unwrap_or_default();
+    if arity != args.len() {
+        return Err(ParserError::InvalidError(error_name.to_string()));
+    }
+    let revert = revert_with_err(error_name, ctx);
+
+    match condition {
+        Some(condition) => {
+            let condition = parse(condition, ctx)?;
+            Ok(syn_utils::if_not(condition, revert))
+        }
+        None => Ok(revert),
+    }
 }
@@ -1,6 +1,6 @@
-use super::num;
+use super::{num, syn_utils};
 use crate::error::ParserResult;
-use crate::model::ir::MathOp;
+use crate::model::ir::{eval_expression_type, MathOp, Type};
 use crate::parser::context::ErrorInfo;
 use crate::parser::odra::syn_utils::in_context;
 use crate::{
@@ -11,9 +11,10 @@ use crate::{
         },
         odra::expr::primitives,
     },
-    utils,
+    utils, ParserError,
 };
-use ::syn::parse_quote;
+use ::syn::{parse_quote, BinOp};
+use quote::quote;
 
 /// Parses a binary mathematical operation and returns a `syn::Expr` representing the operation.
 ///
@@ -40,7 +41,125 @@ pub(crate) fn parse_op<
     let op: syn::BinOp = op.into();
     let left_expr = eval_in_context(left, right, ctx)?;
     let right_expr = eval_in_context(right, left, ctx)?;
-    Ok(parse_quote!( (#left_expr #op #right_expr) ))
+    let ty = eval_expression_type(left, ctx).or(eval_expression_type(right, ctx));
+    match checked_op(&left_expr, &op, &right_expr, ty, ctx) {
+        Some(expr) => Ok(expr),
+        None => Ok(parse_quote!( (#left_expr #op #right_expr) )),
+    }
+}
+
+/// Builds an arithmetic operation reverting with a `Panic` code on overflow or division by zero.
+///
+/// A zero divisor reverts with `Panic::DivisionByZero` even in an `unchecked` block, a signed
+/// (or fixed-point) division may additionally overflow (`MIN / -1`) - `Panic::ArithmeticOverflow`.
+///
+/// Returns `None` if the operation should not be checked - is placed in an `unchecked` block,
+/// or the operands are not numbers (eg. a user-defined value type with a custom operator).
+pub(crate) fn checked_op<T: FnContext + ContractInfo>(
+    left: &syn::Expr,
+    op: &BinOp,
+    right: &syn::Expr,
+    ty: Option<Type>,
+    ctx: &T,
+) -> Option<syn::Expr> {
+    if !matches!(
+        ty,
+        Some(Type::Uint(_) | Type::Int(_) | Type::Fixed(_, _) | Type::Ufixed(_, _))
+    ) {
+        return None;
+    }
+    let is_division = matches!(
+        op,
+        BinOp::Div(_) | BinOp::DivEq(_) | BinOp::Rem(_) | BinOp::RemEq(_)
+    );
+    if ctx.is_unchecked() {
+        return is_division.then(|| {
+            let divisor = non_zero(right, ctx);
+            parse_quote!((#left #op #divisor))
+        });
+    }
+    let receiver = receiver(left);
+    let (method, panic) = match op {
+        BinOp::Add(_) | BinOp::AddEq(_) => (quote!(checked_add), quote!(ArithmeticOverflow)),
+        BinOp::Sub(_) | BinOp::SubEq(_) => (quote!(checked_sub), quote!(ArithmeticOverflow)),
+        BinOp::Mul(_) | BinOp::MulEq(_) => (quote!(checked_mul), quote!(ArithmeticOverflow)),
+        BinOp::Div(_) | BinOp::DivEq(_) if !matches!(ty, Some(Type::Uint(_))) => {
+            let divisor = non_zero(right, ctx);
+            return Some(syn_utils::unwrap_or_panic(
+                quote!(#receiver.checked_div(#divisor)),
+                quote!(ArithmeticOverflow),
+                ctx,
+            ));
+        }
+        BinOp::Div(_) | BinOp::DivEq(_) => (quote!(checked_div), quote!(DivisionByZero)),
+        BinOp::Rem(_) | BinOp::RemEq(_) => (quote!(checked_rem), quote!(DivisionByZero)),
+        _ => return None,
+    };
+    Some(syn_utils::unwrap_or_panic(
+        quote!(#receiver.#method(#right)),
+        panic,
+        ctx,
+    ))
+}
+
+/// Checks the divisor is not zero, reverts with `Panic::DivisionByZero` otherwise.
+fn non_zero<T: ContractInfo>(divisor: &syn::Expr, ctx: &T) -> syn::Expr {
+    let divisor = receiver(divisor);
+    syn_utils::unwrap_or_panic(quote!(#divisor.non_zero()), quote!(DivisionByZero), ctx)
+}
+
+/// Parses `addmod(x, y, k)` or `mulmod(x, y, k)`.
+///
+/// The intermediate result is not truncated, a zero modulus reverts with `Panic::DivisionByZero`.
+pub(crate) fn parse_mod_op<
+    T: StorageInfo
+        + TypeInfo
+        + EventsRegister
+        + ExternalCallsRegister
+        + ContractInfo
+        + FnContext
+        + ErrorInfo,
+>(
+    args: &[Expression],
+    op: &MathOp,
+    ctx: &mut T,
+) -> ParserResult<syn::Expr> {
+    let method = match op {
+        MathOp::Add => quote!(checked_add_mod),
+        MathOp::Mul => quote!(checked_mul_mod),
+        _ => return Err(ParserError::InvalidExpression(format!("{:?}mod", op))),
+    };
+    let uint256 = Expression::Type(Type::Uint(256));
+    let args = args
+        .iter()
+        .map(|arg| eval_in_context(arg, &uint256, ctx))
+        .collect::<ParserResult<Vec<_>>>()?;
+    match args.as_slice() {
+        [x, y, k] => {
+            let x = receiver(x);
+            Ok(syn_utils::unwrap_or_panic(
+                quote!(#x.#method(#y, #k)),
+                quote!(DivisionByZero),
+                ctx,
+            ))
+        }
+        _ => Err(ParserError::InvalidExpression(
+            "a modular operation expects three arguments".to_string(),
+        )),
+    }
+}
+
+/// Wraps an expression in parens, unless it can be a method call receiver as it is.
+fn receiver(expr: &syn::Expr) -> syn::Expr {
+    match expr {
+        syn::Expr::Path(_)
+        | syn::Expr::MethodCall(_)
+        | syn::Expr::Call(_)
+        | syn::Expr::Paren(_)
+        | syn::Expr::Field(_)
+        | syn::Expr::Index(_) => expr.clone(),
+        _ => parse_quote!((#expr)),
+    }
 }
 
 /// Parses an expression to `syn::Expr` that returns a value.
@@ -85,6 +204,7 @@ pub(crate) fn eval<
         Expression::Increment(expr) => eval_or_parse(expr, ctx),
         Expression::Decrement(expr) => eval_or_parse(expr, ctx),
         Expression::NumberLiteral(values) => num::to_typed_int_expr(values, ctx),
+        Expression::RationalLiteral(numer, denom) => num::to_typed_fixed_expr(numer, denom, ctx),
         _ => primitives::get_var_or_parse(expr, ctx),
     }
 }
@@ -99,7 +219,7 @@ pub(crate) fn eval<
 /// apply to the `123` literal.
 ///
 /// In this example let's assume `y` and `x` are of `nysa_type::256` then, the subtraction result
-/// is the same type and finally the `123` literal should be parsed to`nysa_types::U256::from_limbs_slice(&[123u64])`
+/// is the same type and finally the `123` literal should be parsed to`nysa_types::U256::from_limbs([123u64, 0u64, 0u64, 0u64])`
 pub(crate) fn eval_in_context<
     T: StorageInfo
         + TypeInfo
@@ -129,7 +249,22 @@ fn pow<
     right: &Expression,
     ctx: &mut T,
 ) -> ParserResult<::syn::Expr> {
+    let ty = eval_expression_type(left, ctx).or(eval_expression_type(right, ctx));
     let left_expr = eval_in_context(left, right, ctx)?;
-    let right_expr = eval_in_context(right, left, ctx)?;
-    Ok(parse_quote!(#left_expr.pow(#right_expr)))
+    // the exponent is always unsigned
+    let right_expr = match ty {
+        Some(Type::Int(bits)) => eval_in_context(right, &Expression::Type(Type::Uint(bits)), ctx)?,
+        _ => eval_in_context(right, left, ctx)?,
+    };
+    match ty {
+        Some(Type::Uint(_) | Type::Int(_)) if !ctx.is_unchecked() => {
+            let receiver = receiver(&left_expr);
+            Ok(syn_utils::unwrap_or_panic(
+                quote!(#receiver.checked_pow(#right_expr)),
+                quote!(ArithmeticOverflow),
+                ctx,
+            ))
+        }
+        _ => Ok(parse_quote!(#left_expr.pow(#right_expr))),
+    }
 }
@@ -1,10 +1,14 @@
 use crate::error::ParserResult;
-use crate::model::ir::{eval_expression_type, Expression, Op, Stmt, TupleItem, Type, Var};
+use crate::model::ir::{
+    eval_expression_type, find_attached_fn, Expression, Func, Function, MathOp, Op, Param, Stmt,
+    Struct, TupleItem, Type, ValueType, Var,
+};
 use crate::model::Named;
 use crate::parser::context::{
     ContractInfo, ErrorInfo, EventsRegister, ExternalCallsRegister, FnContext, ItemType,
     StorageInfo, TypeInfo,
 };
+use crate::utils::ast::FREE_FUNCTIONS_LIB;
 use crate::ParserError;
 use crate::{formatted_invalid_expr, utils};
 use proc_macro2::{Ident, TokenStream};
@@ -12,13 +16,16 @@ use quote::quote;
 use syn::{parse_quote, punctuated::Punctuated, Token};
 
 use super::stmt;
-use super::syn_utils::AsExpression;
+use super::syn_utils::{in_context, AsExpression};
 use super::ty;
 
+pub(crate) mod abi;
 mod array;
+mod bytes;
+mod cast;
 pub(crate) mod error;
 mod math;
-mod num;
+pub(crate) mod num;
 mod op;
 pub(crate) mod primitives;
 pub(crate) mod syn_utils;
@@ -36,11 +43,16 @@ where
         + ErrorInfo,
 {
     match expression {
-        Expression::Require(condition, error) => error::revert(Some(condition), error, ctx),
+        Expression::Require(condition, error) => match error {
+            Some(error) => error::revert(Some(condition), error, ctx),
+            None => error::revert_with_str(Some(condition), "", ctx),
+        },
+        Expression::Assert(condition) => error::assert(condition, ctx),
         Expression::Placeholder => formatted_invalid_expr!("Placeholder"),
         Expression::ZeroAddress => Ok(syn_utils::none()),
         Expression::Message(msg) => msg.try_into(),
         Expression::Collection(name, keys) => primitives::parse_collection(name, keys, None, ctx),
+        Expression::Index(base, keys) => primitives::parse_index(base, keys, None, ctx),
         Expression::Variable(name) => parse_variable(name, ctx),
         Expression::Assign(left, right) => {
             primitives::assign(left, right.as_deref(), None::<&Op>, ctx)
@@ -51,18 +63,33 @@ where
         Expression::AssignAnd(left, right, op) => {
             primitives::assign(left, Some(right), Some(op), ctx)
         }
-        Expression::Increment(expr) => {
-            let expr = parse(expr, ctx)?;
-            let one = syn_utils::unsigned_one();
-            Ok(parse_quote!(#expr += #one))
+        Expression::Increment(expr) | Expression::Decrement(expr)
+            if matches!(**expr, Expression::MemberAccess(_, _)) =>
+        {
+            // A struct field may be kept in the storage, so must be updated like any other assignment.
+            let op = match expression {
+                Expression::Increment(_) => Op::Math(MathOp::Add),
+                _ => Op::Math(MathOp::Sub),
+            };
+            let one = Expression::NumberLiteral(vec![1]);
+            primitives::assign(expr, Some(&one), Some(&op), ctx)
         }
-        Expression::Decrement(expr) => {
-            let expr = parse(expr, ctx)?;
+        Expression::Increment(expr) | Expression::Decrement(expr) => {
+            let target = parse(expr, ctx)?;
             let one = syn_utils::unsigned_one();
-            Ok(parse_quote!(#expr -= one))
+            let op: syn::BinOp = match expression {
+                Expression::Increment(_) => parse_quote!(+),
+                _ => parse_quote!(-),
+            };
+            let ty = eval_expression_type(expr, ctx);
+            match math::checked_op(&target, &op, &one, ty, ctx) {
+                Some(value) => Ok(parse_quote!(#target = #value)),
+                None => Ok(parse_quote!(#target = #target #op #one)),
+            }
         }
         Expression::MemberAccess(name, expr) => parse_member_access(name, expr, ctx),
         Expression::NumberLiteral(limbs) => num::to_typed_int_expr(limbs, ctx),
+        Expression::RationalLiteral(numer, denom) => num::to_typed_fixed_expr(numer, denom, ctx),
         Expression::Func(name, args) => parse_func(name, args, ctx),
         Expression::SuperCall(name, args) => parse_super_call(name, args, ctx),
         Expression::ExternalCall(var, fn_name, args) => parse_ext_call(var, fn_name, args, ctx),
@@ -73,6 +100,12 @@ where
             let expr = primitives::get_var_or_parse(expr, ctx)?;
             Ok(parse_quote!(!(#expr)))
         }
+        // a hex literal assigned to an integer, eg. `uint256 x = 0xff`
+        Expression::BytesLiteral(bytes) if num::contextual_int_type(ctx).is_some() => {
+            num::to_typed_int_expr(&num::to_limbs(bytes), ctx)
+        }
+        // a hex literal assigned to a dynamic byte array, eg. `bytes memory b = hex"61ff"`
+        Expression::BytesLiteral(bytes) if bytes::is_contextual(ctx) => Ok(bytes::parse_lit(bytes)),
         Expression::BytesLiteral(bytes) => parse_bytes_lit(bytes),
         Expression::ArrayLiteral(values) => parse_array_lit(values, ctx),
         Expression::Initializer(expr) => parse_init(expr, ctx),
@@ -82,14 +115,39 @@ where
         Expression::Tuple(items) => parse_tuple(items, ctx),
         #[cfg(test)]
         Expression::Fail => formatted_invalid_expr!("Fail"),
-        Expression::Keccak256(args) => {
-            let args = parse_many(&args, ctx)?;
-            Ok(syn_utils::try_fixed_bytes(&args))
+        Expression::Keccak256(args) => parse_keccak(args, ctx),
+        Expression::Sha256(args) => {
+            let args = parse_values(args, ctx)?;
+            Ok(syn_utils::sha256(&args))
+        }
+        Expression::Ripemd160(args) => {
+            let args = parse_values(args, ctx)?;
+            Ok(syn_utils::ripemd160(&args))
         }
+        Expression::EcRecover(args) => parse_ecrecover(args, ctx),
+        Expression::AddMod(args) => math::parse_mod_op(args, &MathOp::Add, ctx),
+        Expression::MulMod(args) => math::parse_mod_op(args, &MathOp::Mul, ctx),
         Expression::AbiEncodePacked(args) => {
-            let args = parse_many(&args, ctx)?;
-            Ok(syn_utils::serialize(&args))
+            let args = parse_values(args, ctx)?;
+            Ok(syn_utils::encode_packed(&args))
         }
+        Expression::AbiEncode(args) => {
+            let args = parse_values(args, ctx)?;
+            Ok(syn_utils::abi_encode(&args))
+        }
+        Expression::AbiEncodeWithSelector(selector, args) => {
+            let selector = parse(selector, ctx)?;
+            let args = parse_values(args, ctx)?;
+            Ok(syn_utils::abi_encode_with_selector(selector, &args))
+        }
+        Expression::AbiEncodeWithSignature(signature, args) => {
+            let signature = parse(signature, ctx)?;
+            let args = parse_values(args, ctx)?;
+            Ok(syn_utils::abi_encode_with_signature(signature, &args))
+        }
+        Expression::AbiDecode(data, types) => parse_abi_decode(data, types, ctx),
+        Expression::StringConcat(args) => bytes::concat_strings(args, ctx),
+        Expression::BytesConcat(args) => bytes::concat(args, ctx),
     }
 }
 
@@ -127,6 +185,23 @@ where
         .collect::<Result<Vec<syn::Expr>, _>>()
 }
 
+/// Parses expressions used as values, a state variable is read from the storage.
+fn parse_values<T>(expressions: &[Expression], ctx: &mut T) -> ParserResult<Vec<syn::Expr>>
+where
+    T: StorageInfo
+        + TypeInfo
+        + EventsRegister
+        + ExternalCallsRegister
+        + ContractInfo
+        + FnContext
+        + ErrorInfo,
+{
+    expressions
+        .iter()
+        .map(|e| primitives::get_var_or_parse(e, ctx))
+        .collect()
+}
+
 fn parse_func<T>(fn_name: &Expression, args: &[Expression], ctx: &mut T) -> ParserResult<syn::Expr>
 where
     T: StorageInfo
@@ -139,12 +214,78 @@ where
 {
     if let Expression::Type(ty) = fn_name {
         // cast expression
-        let ty = ty::parse_type_from_ty(ty, ctx)?;
-        let arg = primitives::get_var_or_parse(args.first().unwrap(), ctx)?;
-        return Ok(parse_quote!(#ty::from(*#arg)));
+        return cast::parse_cast(ty, &args[0], ctx);
     }
 
-    let args = parse_many(&args, ctx)?;
+    // eg. balances[owner].push(value)
+    if let Expression::MemberAccess(function_name, array) = fn_name {
+        if let (Some(Type::Array(_)), None) = (
+            eval_expression_type(array, ctx),
+            find_attached_fn(array, function_name, ctx),
+        ) {
+            let fn_ident = utils::to_snake_case_ident(function_name);
+            return array::fn_call(array, fn_ident, args, ctx);
+        }
+    }
+
+    // eg. op(a, b), where `op` is an internal function pointer
+    if let Some(
+        ItemType::Local(Var {
+            ty: Type::Function(f),
+            ..
+        })
+        | ItemType::Storage(Var {
+            ty: Type::Function(f),
+            ..
+        }),
+    ) = ctx.type_from_expression(fn_name)
+    {
+        let fn_ptr = match primitives::get_var_or_parse(fn_name, ctx)? {
+            fn_ptr @ syn::Expr::Path(_) => fn_ptr,
+            // a stored function is dispatched by a match expression
+            fn_ptr => parse_quote!((#fn_ptr)),
+        };
+        let args = args
+            .iter()
+            .zip(f.params.iter())
+            .map(|(arg, ty)| {
+                in_context(&Expression::Type(ty.clone()), ctx, |ctx| {
+                    primitives::get_var_or_parse(arg, ctx)
+                })
+            })
+            .collect::<ParserResult<Vec<_>>>()?;
+        return Ok(parse_quote!(#fn_ptr(self, #(#args),*)));
+    }
+
+    // eg. Status(value), an integer converted to an enum
+    if let Some(ItemType::Enum(name)) = ctx.type_from_expression(fn_name) {
+        return parse_enum_conversion(&name, &args[0], ctx);
+    }
+
+    // eg. Position(size, owner), a struct is initialized with the values of its fields in order
+    if let Some(ItemType::Struct(s)) = ctx.type_from_expression(fn_name) {
+        return parse_struct_init(&s, args, ctx);
+    }
+
+    // eg. _reduce(allowances[owner], spender), a storage mapping is passed by its leading keys
+    if let Some((name, params)) = storage_mapping_params(fn_name, ctx) {
+        let fn_ident = utils::to_snake_case_ident(&name);
+        let args = args
+            .iter()
+            .zip(params.iter())
+            .map(
+                |(arg, param)| match primitives::is_storage_mapping(param, ctx) {
+                    true => primitives::storage_mapping_arg(arg, &param.ty, ctx),
+                    false => parse(arg, ctx).map(|arg| vec![arg]),
+                },
+            )
+            .collect::<ParserResult<Vec<_>>>()?
+            .into_iter()
+            .flatten();
+        return Ok(parse_quote!(self.#fn_ident(#(#args),*)));
+    }
+
+    let args = parse_values(args, ctx)?;
     // Context allows us to distinct an external contract initialization from a regular function call
     if let Some(ItemType::Interface(name) | ItemType::Contract(name)) =
         ctx.type_from_expression(fn_name)
@@ -164,22 +305,64 @@ where
         Expression::MemberAccess(function_name, ty_expr) => {
             // member_name = get
             // ty = state.positions
-            // check of what type is `state.position`, then check if there is a matching library or a regular call.
-            let ty = eval_expression_type(ty_expr, ctx);
-
-            // find matching lib
-            let matching_lib = ctx
-                .current_contract()
-                .libs()
-                .iter()
-                .find(|lib| eval_expression_type(&lib.ty, ctx) == ty)
-                .unwrap();
-
-            let matching_fn = ctx.find_fn(&matching_lib.name, function_name).unwrap();
-            let lib_ident = utils::to_ident(&matching_lib.name);
+            // check of what type is `state.position`, then find a library function attached to it.
+            let (lib_name, matching_fn) = match find_attached_fn(ty_expr, function_name, ctx) {
+                Some(f) => f,
+                None => {
+                    return formatted_invalid_expr!(
+                        "no library function {} attached to {:?}",
+                        function_name,
+                        ty_expr
+                    )
+                }
+            };
             let fn_ident = utils::to_snake_case_ident(function_name);
-            let first_arg = parse(ty_expr, ctx)?;
-            Ok(parse_quote!(#lib_ident::#fn_ident(#first_arg, #(#args),*)))
+            let receiver = matching_fn.params().first().unwrap();
+            if receiver.is_storage {
+                // a storage reference is modified in place, so must be written back
+                let is_mutable = matches!(
+                    matching_fn,
+                    Function::Function(Func {
+                        is_mutable: true,
+                        ..
+                    })
+                );
+                return primitives::call_with_storage_ref(
+                    ty_expr,
+                    is_mutable,
+                    |receiver| {
+                        lib_call(
+                            &lib_name,
+                            fn_ident,
+                            [receiver].into_iter().chain(args).collect(),
+                        )
+                    },
+                    ctx,
+                );
+            }
+            let receiver = in_context(&Expression::Type(receiver.ty.clone()), ctx, |ctx| {
+                primitives::get_var_or_parse(ty_expr, ctx)
+            })?;
+            lib_call(
+                &lib_name,
+                fn_ident,
+                [receiver].into_iter().chain(args).collect(),
+            )
+        }
+        Expression::Variable(name) => {
+            let fn_ident = utils::to_snake_case_ident(name);
+            // a free function is called like any other library function
+            let snake_name = utils::to_snake_case(name);
+            if !ctx.current_contract().has_function(&snake_name)
+                && ctx.find_fn(FREE_FUNCTIONS_LIB, &snake_name).is_some()
+            {
+                return lib_call(FREE_FUNCTIONS_LIB, fn_ident, args);
+            }
+            // library functions are associated functions
+            if ctx.current_contract().is_library() {
+                return Ok(parse_quote!(Self::#fn_ident(#(#args),*)));
+            }
+            Ok(parse_quote!(self.#fn_ident(#(#args),*)))
         }
         _ => match parse(fn_name, ctx) {
             Ok(name) => Ok(parse_quote!(self.#name(#(#args),*))),
@@ -188,6 +371,82 @@ where
     }
 }
 
+/// Finds the name and the parameters of an internal function of the current contract
+/// if any of the parameters is a storage mapping.
+fn storage_mapping_params<T: ContractInfo>(
+    fn_name: &Expression,
+    ctx: &T,
+) -> Option<(String, Vec<Param>)> {
+    let name = match fn_name {
+        Expression::Variable(name) => name,
+        _ => return None,
+    };
+    let snake_name = utils::to_snake_case(name);
+    ctx.current_contract()
+        .fn_implementations()
+        .into_iter()
+        .find(|f| f.name == snake_name)
+        .and_then(|f| f.implementations.first().map(|(_, f)| f.params().to_vec()))
+        .filter(|params| {
+            params
+                .iter()
+                .any(|p| primitives::is_storage_mapping(p, ctx))
+        })
+        .map(|params| (name.to_owned(), params))
+}
+
+/// Parses an initialization of a struct, the values are converted to the types of the fields.
+fn parse_struct_init<T>(s: &Struct, args: &[Expression], ctx: &mut T) -> ParserResult<syn::Expr>
+where
+    T: StorageInfo
+        + TypeInfo
+        + EventsRegister
+        + ExternalCallsRegister
+        + ContractInfo
+        + FnContext
+        + ErrorInfo,
+{
+    if s.fields.len() != args.len() {
+        return formatted_invalid_expr!("{} expects {} values", s.name, s.fields.len());
+    }
+    let ty = ty::parse_type_from_ty(&Type::Custom(s.name.clone()), ctx)?;
+    let values = args
+        .iter()
+        .map(|value| primitives::get_var_or_parse(value, ctx))
+        .collect::<ParserResult<Vec<_>>>()?;
+    let fields = s
+        .fields
+        .iter()
+        .map(|(name, _)| utils::to_snake_case_ident(name));
+    Ok(parse_quote!(#ty { #(#fields: #values),* }))
+}
+
+/// Parses a conversion of an integer to an enum, reverting with `Panic::EnumConversion`
+/// if the value does not match any variant.
+fn parse_enum_conversion<T>(name: &str, value: &Expression, ctx: &mut T) -> ParserResult<syn::Expr>
+where
+    T: StorageInfo
+        + TypeInfo
+        + EventsRegister
+        + ExternalCallsRegister
+        + ContractInfo
+        + FnContext
+        + ErrorInfo,
+{
+    let enum_def = ctx.find_enum(name).ok_or(ParserError::InvalidType)?;
+    let ty = utils::to_ident(name);
+    let value = primitives::get_var_or_parse(value, ctx)?;
+    let arms = enum_def.variants.iter().enumerate().map(|(idx, variant)| {
+        let variant = utils::to_ident(variant);
+        quote!(Some(#idx) => #ty::#variant,)
+    });
+    let panic = syn_utils::panic(quote!(EnumConversion), ctx);
+    Ok(parse_quote!(match #value.checked_as_usize() {
+        #(#arms)*
+        _ => #panic
+    }))
+}
+
 //TODO: change naming
 fn parse_ext_call<T>(
     variable: &str,
@@ -204,6 +463,15 @@ where
         + FnContext
         + ErrorInfo,
 {
+    // eg. `value.max(other)`, where a library is attached to `uint256`
+    if let Some(ItemType::Storage(_) | ItemType::Local(_)) = ctx.type_from_string(variable) {
+        let receiver = Expression::Variable(variable.to_owned());
+        if find_attached_fn(&receiver, fn_name, ctx).is_some() {
+            let fn_name = Expression::MemberAccess(fn_name.to_owned(), Box::new(receiver));
+            return parse_func(&fn_name, args, ctx);
+        }
+    }
+
     let fn_ident = utils::to_snake_case_ident(fn_name);
     let var_ident = utils::to_snake_case_ident(variable);
     // If in solidity code a reference is a contract may be a field,
@@ -246,6 +514,7 @@ where
             let parsed_args = parse_fn_args(&lib.name(), fn_name, args, ctx)?;
             lib_call(variable, fn_ident, parsed_args)
         }
+        Some(ItemType::ValueType(v)) => value_type_call(&v, fn_name, args, ctx),
         Some(ItemType::Storage(Var {
             ty: Type::Array(ty),
             ..
@@ -253,7 +522,7 @@ where
         | Some(ItemType::Local(Var {
             ty: Type::Array(ty),
             ..
-        })) => array::fn_call(variable, fn_ident, args, ctx),
+        })) => array::fn_call(&Expression::from(variable), fn_ident, args, ctx),
         _ => formatted_invalid_expr!("ext_call {} {}", variable, fn_name),
     }
 }
@@ -282,6 +551,41 @@ where
     ))
 }
 
+/// Parses the `wrap` and `unwrap` functions of a user-defined value type.
+///
+/// sol: Price.wrap(value)
+/// rs: Price(value)
+fn value_type_call<T>(
+    value_type: &ValueType,
+    fn_name: &str,
+    args: &[Expression],
+    ctx: &mut T,
+) -> ParserResult<syn::Expr>
+where
+    T: StorageInfo
+        + TypeInfo
+        + EventsRegister
+        + ExternalCallsRegister
+        + ContractInfo
+        + FnContext
+        + ErrorInfo,
+{
+    match (fn_name, args.first()) {
+        ("wrap", Some(arg)) => {
+            let ty = ty::value_type(value_type);
+            let arg = in_context(&Expression::Type(value_type.ty.clone()), ctx, |ctx| {
+                primitives::get_var_or_parse(arg, ctx)
+            })?;
+            Ok(parse_quote!(#ty(#arg)))
+        }
+        ("unwrap", Some(arg)) => {
+            let arg = primitives::get_var_or_parse(arg, ctx)?;
+            Ok(parse_quote!(#arg.0))
+        }
+        _ => formatted_invalid_expr!("value type call {}.{}", value_type.name, fn_name),
+    }
+}
+
 fn lib_call(lib_name: &str, fn_ident: Ident, args: Vec<syn::Expr>) -> ParserResult<syn::Expr> {
     let ident = utils::to_ident(lib_name);
     let mod_name = utils::to_snake_case_ident(lib_name);
@@ -300,7 +604,26 @@ where
 {
     let fn_name = utils::to_prefixed_snake_case_ident("super_", fn_name);
     let args = parse_many(&args, ctx)?;
-    Ok(parse_quote!(self.#fn_name(#(#args),*)))
+
+    // In solidity `super` refers to the next contract in the linearization of the most derived contract,
+    // so the path is independent of the call stack - the classes following the current one are pushed.
+    // `Self::PATH` holds the classes from the most base to the most derived.
+    let path = ctx.current_contract().c3_path();
+    let position = ctx
+        .current_class()
+        .and_then(|class| path.iter().position(|c| c == class));
+    match position {
+        Some(position) => {
+            let len = path.len() - position - 1;
+            Ok(parse_quote!({
+                self.__stack.push_path_on_stack(&Self::PATH[..#len]);
+                let __result = self.#fn_name(#(#args),*);
+                self.__stack.drop_one_from_stack();
+                __result
+            }))
+        }
+        None => Ok(parse_quote!(self.#fn_name(#(#args),*))),
+    }
 }
 
 fn parse_member_access<T>(
@@ -317,7 +640,8 @@ where
         + FnContext
         + ErrorInfo,
 {
-    match ctx.type_from_expression(expr) {
+    let item_type = ctx.type_from_expression(expr);
+    match &item_type {
         Some(ItemType::Enum(name) | ItemType::Contract(name)) => {
             let ty = utils::to_ident(name);
             let member: syn::Member = utils::to_ident(member_name).into();
@@ -329,10 +653,34 @@ where
             Ok(parse_quote!(#ty::#member))
         }
         Some(ItemType::Storage(Var {
-            ty: Type::Array(_), ..
+            ty: Type::Array(_) | Type::FixedArray(_, _),
+            ..
+        }))
+        | Some(ItemType::Local(Var {
+            ty: Type::Array(_) | Type::FixedArray(_, _),
+            ..
         })) => array::read_property(member_name, expr, ctx),
+        // eg. balances[owner].length, bytes(name).length
+        _ if matches!(
+            eval_expression_type(expr, ctx),
+            Some(Type::Array(_) | Type::FixedArray(_, _) | Type::DynamicBytes)
+        ) =>
+        {
+            array::read_property(member_name, expr, ctx)
+        }
+        // the length of `bytesN` is a `uint8` constant
+        _ if member_name == "length"
+            && matches!(eval_expression_type(expr, ctx), Some(Type::Bytes(_))) =>
+        {
+            let bytes = primitives::get_var_or_parse(expr, ctx)?;
+            Ok(parse_quote!(#bytes.length()))
+        }
         _ => {
-            let base_expr: syn::Expr = parse(expr, ctx)?;
+            // A state variable must be read before accessing its member.
+            let base_expr: syn::Expr = match &item_type {
+                Some(ItemType::Storage(_)) => primitives::get_var_or_parse(expr, ctx)?,
+                _ => parse(expr, ctx)?,
+            };
 
             let member: syn::Member = utils::to_snake_case_ident(member_name).into();
             Ok(parse_quote!(#base_expr.#member))
@@ -340,8 +688,27 @@ where
     }
 }
 
-fn parse_variable<T: TypeInfo>(name: &str, ctx: &mut T) -> ParserResult<syn::Expr> {
+fn parse_variable<T>(name: &str, ctx: &mut T) -> ParserResult<syn::Expr>
+where
+    T: StorageInfo
+        + TypeInfo
+        + EventsRegister
+        + ExternalCallsRegister
+        + ContractInfo
+        + FnContext
+        + ErrorInfo,
+{
+    // A local storage pointer is replaced with the storage item it refers to.
+    if let Some(target) = ctx.get_storage_ref(name).cloned() {
+        return primitives::get_var_or_parse(&target, ctx);
+    }
     let ident = utils::to_snake_case_ident(name);
+    if let Some(fn_ref) = primitives::internal_fn_ref(name, ctx) {
+        return Ok(fn_ref);
+    }
+    if let Some(const_ref) = primitives::const_ref(name, ctx) {
+        return Ok(const_ref);
+    }
     let self_ty = ctx
         .type_from_string(name)
         .filter(|i| matches!(i, ItemType::Storage(_)))
@@ -359,15 +726,33 @@ where
         + FnContext
         + ErrorInfo,
 {
+    // The elements are typed to the declared item type, eg. `uint8[3] memory a = [1, 2, 3]`.
+    let ctx_ty = ctx
+        .contextual_expr()
+        .cloned()
+        .and_then(|e| eval_expression_type(&e, ctx));
+    let (item_ty, is_fixed) = match ctx_ty {
+        Some(Type::FixedArray(ty, _)) => (Some(*ty), true),
+        Some(Type::Array(ty)) => (Some(*ty), false),
+        ty => (ty, false),
+    };
+
     let arr = values
         .iter()
-        .map(|e| parse(e, ctx))
+        .map(|e| match &item_ty {
+            Some(ty) => in_context(&Expression::Type(ty.clone()), ctx, |ctx| parse(e, ctx)),
+            None => parse(e, ctx),
+        })
         .map(|e| match e {
             Ok(r) => Ok(quote!(#r)),
             Err(e) => Err(e),
         })
         .collect::<Result<Punctuated<TokenStream, Token![,]>, ParserError>>()?;
-    Ok(parse_quote!(odra::prelude::vec![#arr]))
+    if is_fixed {
+        Ok(parse_quote!(nysa_types::FixedArray([#arr])))
+    } else {
+        Ok(parse_quote!(odra::prelude::vec![#arr]))
+    }
 }
 
 /// Parses a bytes slice into a syn::Expr that creates a new [nysa_types::FixedBytes].
@@ -396,6 +781,111 @@ where
     })?
 }
 
+/// Parses keccak256 call, the EIP-712 struct hashing idiom is mapped onto `nysa_types::eip712`.
+///
+/// `keccak256(abi.encode(TYPEHASH, ...))` of value types hashes a struct - the same words are hashed.
+fn parse_keccak<T>(args: &[Expression], ctx: &mut T) -> ParserResult<syn::Expr>
+where
+    T: StorageInfo
+        + TypeInfo
+        + EventsRegister
+        + ExternalCallsRegister
+        + ContractInfo
+        + FnContext
+        + ErrorInfo,
+{
+    if let [Expression::AbiEncode(values)] = args {
+        if let [type_hash, members @ ..] = values.as_slice() {
+            let is_struct = eval_expression_type(type_hash, ctx) == Some(Type::Bytes(32))
+                && members.iter().all(|m| is_word(m, ctx));
+            if is_struct {
+                let type_hash = primitives::get_var_or_parse(type_hash, ctx)?;
+                let members = members
+                    .iter()
+                    .map(|m| primitives::get_var_or_parse(m, ctx))
+                    .collect::<ParserResult<Vec<_>>>()?;
+                return Ok(syn_utils::eip712_hash_struct(type_hash, &members));
+            }
+        }
+    }
+    let args = parse_values(args, ctx)?;
+    Ok(syn_utils::keccak256(&args))
+}
+
+/// Checks if the value is encoded as a single word by `abi.encode`.
+fn is_word<T: TypeInfo + ContractInfo>(value: &Expression, ctx: &T) -> bool {
+    match eval_expression_type(value, ctx) {
+        Some(Type::Address | Type::Bool | Type::Int(_) | Type::Uint(_) | Type::Bytes(_)) => true,
+        Some(Type::Custom(name)) => {
+            !matches!(ctx.type_from_string(&name), Some(ItemType::Struct(_)))
+        }
+        _ => false,
+    }
+}
+
+/// Parses `ecrecover(hash, v, r, s)`, the arguments are typed like the parameters of the builtin.
+fn parse_ecrecover<T>(args: &[Expression], ctx: &mut T) -> ParserResult<syn::Expr>
+where
+    T: StorageInfo
+        + TypeInfo
+        + EventsRegister
+        + ExternalCallsRegister
+        + ContractInfo
+        + FnContext
+        + ErrorInfo,
+{
+    let params = [
+        Type::Bytes(32),
+        Type::Uint(8),
+        Type::Bytes(32),
+        Type::Bytes(32),
+    ];
+    if args.len() != params.len() {
+        return Err(ParserError::InvalidExpression(
+            "ecrecover expects a hash, v, r and s".to_string(),
+        ));
+    }
+    let args = args
+        .iter()
+        .zip(params)
+        .map(|(arg, ty)| math::eval_in_context(arg, &Expression::Type(ty), ctx))
+        .collect::<ParserResult<Vec<_>>>()?;
+    Ok(parse_quote!(nysa_types::ecrecover(#(#args),*)))
+}
+
+/// Decodes the values as a tuple, a single value is taken out of a one-element tuple.
+///
+/// Invalid data reverts, like in Solidity.
+fn parse_abi_decode<T>(
+    data: &Expression,
+    types: &[Expression],
+    ctx: &mut T,
+) -> ParserResult<syn::Expr>
+where
+    T: StorageInfo
+        + TypeInfo
+        + EventsRegister
+        + ExternalCallsRegister
+        + ContractInfo
+        + FnContext
+        + ErrorInfo,
+{
+    let data = parse(data, ctx)?;
+    let types = types
+        .iter()
+        .map(|ty| {
+            let ty = Type::try_from(ty).map_err(|_| ParserError::InvalidType)?;
+            ty::parse_type_from_ty(&ty, ctx)
+        })
+        .collect::<ParserResult<Vec<_>>>()?;
+    let decoded =
+        syn_utils::unwrap_or_revert(quote!(nysa_types::abi::decode::<(#(#types,)*)>(&#data)));
+    match types.len() {
+        1 => Ok(parse_quote!(#decoded.0)),
+        _ => Ok(decoded),
+    }
+}
+
 fn parse_type_info<T>(ty: &Expression, property: &str, ctx: &mut T) -> ParserResult<syn::Expr>
 where
     T: StorageInfo
@@ -406,13 +896,34 @@ where
         + FnContext
         + ErrorInfo,
 {
-    let ty = parse(ty, ctx)?;
-    let property = match property {
-        "max" => utils::to_ident("MAX"),
-        "min" => utils::to_ident("MIN"),
-        _ => return Err(ParserError::UnknownProperty(property.to_string())),
-    };
-    Ok(parse_quote!(#ty::#property))
+    match (ty, property) {
+        (Expression::Type(Type::Int(_) | Type::Uint(_)), "max" | "min") => {
+            let ty = parse(ty, ctx)?;
+            let property = utils::to_ident(property.to_uppercase());
+            Ok(parse_quote!(#ty::#property))
+        }
+        // the first and the last enum variant
+        (Expression::Variable(name), "max" | "min") if ctx.find_enum(name).is_some() => {
+            let e = ctx.find_enum(name).unwrap();
+            let variant = match property {
+                "min" => e.variants.first(),
+                _ => e.variants.last(),
+            };
+            let ty = utils::to_ident(name);
+            let variant = variant.map(utils::to_ident);
+            Ok(parse_quote!(#ty::#variant))
+        }
+        // ERC-165 interface id is known at the compile time
+        (Expression::Variable(name), "interfaceId") => {
+            let interface = ctx
+                .find_interface(name)
+                .ok_or(ParserError::UnknownProperty(property.to_string()))?;
+            let id = abi::interface_id(&interface, ctx)?;
+            parse_bytes_lit(&id)
+        }
+        (Expression::Variable(name), "name") => Ok(syn_utils::string_from(name)),
+        _ => Err(ParserError::UnknownProperty(property.to_string())),
+    }
 }
 
 fn parse_init<T>(expr: &Expression, ctx: &mut T) -> ParserResult<syn::Expr>
@@ -425,9 +936,14 @@ where
         + FnContext
         + ErrorInfo,
 {
+    // eg. new uint256[](len) - a dynamic array of `len` default values
     if let Expression::Func(box Expression::Type(Type::Array(_)), args) = expr {
-        let args = parse_many(&args, ctx)?;
-        return Ok(parse_quote!(odra::prelude::vec::Vec::with_capacity(#(#args),*)));
+        let len_ty = Expression::Type(Type::Uint(256));
+        let len = in_context(&len_ty, ctx, |ctx| {
+            primitives::get_var_or_parse(&args[0], ctx)
+        })?;
+        let len = syn_utils::checked_usize(len, quote!(OutOfMemory), ctx);
+        return Ok(parse_quote!(odra::prelude::vec![Default::default(); #len]));
     }
     todo!()
 }
@@ -480,13 +996,7 @@ where
             let p = &params[i];
             let arg = &args[i];
 
-            let ty = eval_expression_type(arg, ctx);
-            let required_ty = p.ty.clone();
-            let mut parsed_arg = parse(arg, ctx)?;
-            if ty.is_some_and(|t| t != required_ty) {
-                parsed_arg = parse_quote!((#parsed_arg).cast());
-            }
-            parsed_args.push(parsed_arg);
+            parsed_args.push(parse(arg, ctx)?);
         }
     }
     Ok(parsed_args)
@@ -1,16 +1,16 @@
 use crate::{
     error::ParserResult,
     formatted_invalid_expr,
-    model::ir::{eval_expression_type, Expression},
+    model::ir::{eval_expression_type, Expression, Type},
     parser::{
         context::{
             ContractInfo, EventsRegister, ExternalCallsRegister, FnContext, StorageInfo, TypeInfo,
         },
-        odra::{syn_utils::ty::u256, ty},
+        odra::ty,
     },
+    ParserError,
 };
-use proc_macro2::TokenStream;
-use syn::{parse_quote, punctuated::Punctuated, Token};
+use syn::parse_quote;
 
 macro_rules! to_uint {
     ($value:expr, $t:ty) => {
@@ -18,30 +18,310 @@ macro_rules! to_uint {
     };
 }
 
+/// Parses a number literal into a `nysa_types` integer of the contextual type (`U256` by default),
+/// or into a fixed-point number if used as one.
+///
+/// The value is built by a const constructor. Returns an error if the value does not fit the type.
 pub(crate) fn to_typed_int_expr<
     T: StorageInfo + TypeInfo + EventsRegister + ExternalCallsRegister + ContractInfo + FnContext,
 >(
     value: &[u64],
     ctx: &mut T,
 ) -> ParserResult<syn::Expr> {
-    let arr = value
-        .iter()
-        .map(|v| quote::quote!(#v))
-        .collect::<Punctuated<TokenStream, Token![,]>>();
-    let ty = ctx
+    if let Some(ty) = contextual_fixed_type(ctx) {
+        return to_fixed_expr(value, &[1], false, &ty, ctx);
+    }
+    // only zero converts to a fixed-size byte array, eg. `bytes32 x = 0`
+    if let Some(size) = contextual_bytes_size(ctx) {
+        if value.iter().all(|limb| *limb == 0) {
+            return Ok(parse_quote!(nysa_types::FixedBytes::<#size>::ZERO));
+        }
+    }
+    let ty = contextual_int_type(ctx).unwrap_or(Type::Uint(256));
+    to_int_expr(value, false, &ty, ctx)
+}
+
+/// Parses a negated number literal eg. `-1` into a `nysa_types` integer of the contextual type
+/// (`I256` by default).
+pub(crate) fn to_negative_int_expr<
+    T: StorageInfo + TypeInfo + EventsRegister + ExternalCallsRegister + ContractInfo + FnContext,
+>(
+    value: &[u64],
+    ctx: &mut T,
+) -> ParserResult<syn::Expr> {
+    if let Some(ty) = contextual_fixed_type(ctx) {
+        return to_fixed_expr(value, &[1], true, &ty, ctx);
+    }
+    let ty = contextual_int_type(ctx).unwrap_or(Type::Int(256));
+    to_int_expr(value, true, &ty, ctx)
+}
+
+/// Parses a non-integer rational literal, eg. `1.5`, into a fixed-point number of the contextual type.
+///
+/// Solidity does not infer a fixed-point type, so the literal must be used as a typed value.
+pub(crate) fn to_typed_fixed_expr<
+    T: StorageInfo + TypeInfo + EventsRegister + ExternalCallsRegister + ContractInfo + FnContext,
+>(
+    numer: &[u64],
+    denom: &[u64],
+    ctx: &mut T,
+) -> ParserResult<syn::Expr> {
+    match contextual_fixed_type(ctx) {
+        Some(ty) => to_fixed_expr(numer, denom, false, &ty, ctx),
+        None => formatted_invalid_expr!(
+            "Rational literal {} used as a non fixed-point value",
+            to_decimal_fraction(numer, denom)
+        ),
+    }
+}
+
+/// Parses a negated non-integer rational literal, eg. `-1.5`, into a fixed-point number
+/// of the contextual type.
+pub(crate) fn to_negative_fixed_expr<
+    T: StorageInfo + TypeInfo + EventsRegister + ExternalCallsRegister + ContractInfo + FnContext,
+>(
+    numer: &[u64],
+    denom: &[u64],
+    ctx: &mut T,
+) -> ParserResult<syn::Expr> {
+    match contextual_fixed_type(ctx) {
+        Some(ty) => to_fixed_expr(numer, denom, true, &ty, ctx),
+        None => formatted_invalid_expr!(
+            "Rational literal -{} used as a non fixed-point value",
+            to_decimal_fraction(numer, denom)
+        ),
+    }
+}
+
+/// Returns the contextual type if it is an integer, eg. a hex literal `0xff` is a number
+/// only if assigned to an integer.
+pub(crate) fn contextual_int_type<T: TypeInfo + ContractInfo + FnContext>(ctx: &T) -> Option<Type> {
+    ctx.contextual_expr()
+        .and_then(|e| eval_expression_type(e, ctx))
+        .filter(|ty| matches!(ty, Type::Uint(_) | Type::Int(_)))
+}
+
+/// Returns the size of the contextual type if it is a fixed-size byte array.
+fn contextual_bytes_size<T: TypeInfo + ContractInfo + FnContext>(ctx: &T) -> Option<usize> {
+    match ctx
         .contextual_expr()
-        .map(|e| eval_expression_type(e, ctx))
-        .map(|t| t.map(|t| ty::parse_type_from_ty(&t, ctx).ok()))
-        .flatten()
-        .flatten()
-        .unwrap_or(u256());
-
-    if value.is_empty() {
-        Ok(parse_quote!(#ty::ZERO))
-    } else if value.len() == 1 && value[0] == 1 {
-        Ok(parse_quote!(#ty::ONE))
-    } else {
-        Ok(parse_quote!(#ty::from_limbs_slice(&[#arr])))
+        .and_then(|e| eval_expression_type(e, ctx))
+    {
+        Some(Type::Bytes(size)) => Some(size as usize),
+        _ => None,
+    }
+}
+
+/// Returns the contextual type if it is a fixed-point number.
+fn contextual_fixed_type<T: TypeInfo + ContractInfo + FnContext>(ctx: &T) -> Option<Type> {
+    ctx.contextual_expr()
+        .and_then(|e| eval_expression_type(e, ctx))
+        .filter(|ty| matches!(ty, Type::Fixed(_, _) | Type::Ufixed(_, _)))
+}
+
+/// Converts big-endian bytes, eg. of a hex literal, into little-endian limbs.
+pub(crate) fn to_limbs(bytes: &[u8]) -> Vec<u64> {
+    let mut limbs = bytes
+        .rchunks(8)
+        .map(|chunk| chunk.iter().fold(0u64, |acc, b| acc << 8 | *b as u64))
+        .collect::<Vec<_>>();
+    trim(&mut limbs);
+    limbs
+}
+
+/// Builds an integer of the given type from a literal value, checking if the value fits the type.
+pub(crate) fn to_int_expr<T: TypeInfo>(
+    value: &[u64],
+    is_negative: bool,
+    ty: &Type,
+    ctx: &T,
+) -> ParserResult<syn::Expr> {
+    let mut value = value.to_vec();
+    trim(&mut value);
+    let (bits, is_signed) = match ty {
+        Type::Uint(bits) => (*bits as usize, false),
+        Type::Int(bits) => (*bits as usize, true),
+        _ => return Err(ParserError::InvalidType),
+    };
+    if !fits(&value, is_negative, bits, is_signed) {
+        let sign = if is_negative { "-" } else { "" };
+        return Err(ParserError::LiteralOutOfRange(
+            format!("{}{}", sign, to_decimal(&value)),
+            ty.clone(),
+        ));
+    }
+
+    let rust_ty = ty::parse_type_from_ty(ty, ctx)?;
+    match (value.as_slice(), is_negative) {
+        ([], _) => Ok(parse_quote!(#rust_ty::ZERO)),
+        ([1], false) => Ok(parse_quote!(#rust_ty::ONE)),
+        ([1], true) => Ok(parse_quote!(#rust_ty::MINUS_ONE)),
+        _ => {
+            let mut limbs = value.clone();
+            limbs.resize(bits.div_ceil(64), 0);
+            if is_negative {
+                negate(&mut limbs, bits);
+            }
+            Ok(parse_quote!(#rust_ty::from_limbs([#(#limbs),*])))
+        }
+    }
+}
+
+/// Builds a fixed-point number of the given type from a rational literal value.
+///
+/// The number is created from its integer representation, eg. `1.5` as `fixed8x1` is `15`.
+/// Returns an error if the value has more decimals than the type or does not fit the type.
+pub(crate) fn to_fixed_expr<T: TypeInfo>(
+    numer: &[u64],
+    denom: &[u64],
+    is_negative: bool,
+    ty: &Type,
+    ctx: &T,
+) -> ParserResult<syn::Expr> {
+    let (bits, decimals, int_ty) = match ty {
+        Type::Fixed(bits, decimals) => (*bits as usize, *decimals, Type::Int(*bits)),
+        Type::Ufixed(bits, decimals) => (*bits as usize, *decimals, Type::Uint(*bits)),
+        _ => return Err(ParserError::InvalidType),
+    };
+    let out_of_range = || {
+        let sign = if is_negative { "-" } else { "" };
+        ParserError::LiteralOutOfRange(
+            format!("{}{}", sign, to_decimal_fraction(numer, denom)),
+            ty.clone(),
+        )
+    };
+    let raw = scale(numer, denom, decimals as usize).ok_or_else(out_of_range)?;
+    if !fits(&raw, is_negative, bits, matches!(ty, Type::Fixed(_, _))) {
+        return Err(out_of_range());
+    }
+
+    let rust_ty = ty::parse_type_from_ty(ty, ctx)?;
+    if raw.is_empty() {
+        return Ok(parse_quote!(#rust_ty::ZERO));
+    }
+    let raw = to_int_expr(&raw, is_negative, &int_ty, ctx)?;
+    Ok(parse_quote!(#rust_ty::from_raw(#raw)))
+}
+
+/// Computes `numer * 10^decimals / denom`, returns `None` if the result is not an integer.
+///
+/// The denominator of a decimal literal is a product of powers of 2 and 5.
+fn scale(numer: &[u64], denom: &[u64], decimals: usize) -> Option<Vec<u64>> {
+    let mut value = numer.to_vec();
+    trim(&mut value);
+    for _ in 0..decimals {
+        mul_small(&mut value, 10);
+    }
+    let mut denom = denom.to_vec();
+    trim(&mut denom);
+    for factor in [2, 5] {
+        while denom != [1] {
+            let mut quotient = denom.clone();
+            if div_small(&mut quotient, factor) != 0 {
+                break;
+            }
+            denom = quotient;
+            if div_small(&mut value, factor) != 0 {
+                return None;
+            }
+        }
+    }
+    (denom == [1]).then_some(value)
+}
+
+/// Formats a rational literal value, eg. `0.125`.
+fn to_decimal_fraction(numer: &[u64], denom: &[u64]) -> String {
+    // each factor of the denominator adds at least one bit
+    (0..=bit_len(denom))
+        .find_map(|decimals| scale(numer, denom, decimals).map(|value| (value, decimals)))
+        .map(|(value, decimals)| {
+            let digits = format!("{:0>width$}", to_decimal(&value), width = decimals + 1);
+            let (int, fract) = digits.split_at(digits.len() - decimals);
+            match fract.is_empty() {
+                true => int.to_string(),
+                false => format!("{}.{}", int, fract),
+            }
+        })
+        .unwrap_or_else(|| format!("{}/{}", to_decimal(numer), to_decimal(denom)))
+}
+
+/// Checks if the value fits in an integer of the given size.
+fn fits(value: &[u64], is_negative: bool, bits: usize, is_signed: bool) -> bool {
+    let len = bit_len(value);
+    match (is_signed, is_negative) {
+        (false, false) => len <= bits,
+        (false, true) => value.is_empty(),
+        (true, false) => len < bits,
+        // the minimal value is -2^(bits-1)
+        (true, true) => {
+            len < bits || (len == bits && value.iter().map(|l| l.count_ones()).sum::<u32>() == 1)
+        }
+    }
+}
+
+fn bit_len(value: &[u64]) -> usize {
+    value
+        .iter()
+        .rposition(|l| *l != 0)
+        .map(|i| i * 64 + 64 - value[i].leading_zeros() as usize)
+        .unwrap_or_default()
+}
+
+/// Computes the two's complement of the value, the bits above the size are cleared.
+fn negate(limbs: &mut [u64], bits: usize) {
+    let mut carry = true;
+    for limb in limbs.iter_mut() {
+        let (value, overflow) = (!*limb).overflowing_add(carry as u64);
+        *limb = value;
+        carry = overflow;
+    }
+    if bits % 64 != 0 {
+        if let Some(last) = limbs.last_mut() {
+            *last &= (1u64 << (bits % 64)) - 1;
+        }
+    }
+}
+
+fn trim(limbs: &mut Vec<u64>) {
+    while limbs.last() == Some(&0) {
+        limbs.pop();
+    }
+}
+
+fn mul_small(value: &mut Vec<u64>, factor: u64) {
+    let mut carry = 0u128;
+    for limb in value.iter_mut() {
+        let acc = *limb as u128 * factor as u128 + carry;
+        *limb = acc as u64;
+        carry = acc >> 64;
+    }
+    if carry != 0 {
+        value.push(carry as u64);
+    }
+}
+
+/// Divides the value in place, starting from the most significant limb, returns the remainder.
+fn div_small(value: &mut Vec<u64>, divisor: u64) -> u64 {
+    let mut rem = 0u128;
+    for limb in value.iter_mut().rev() {
+        let acc = rem << 64 | *limb as u128;
+        *limb = (acc / divisor as u128) as u64;
+        rem = acc % divisor as u128;
+    }
+    trim(value);
+    rem as u64
+}
+
+fn to_decimal(value: &[u64]) -> String {
+    let mut value = value.to_vec();
+    let mut digits = vec![];
+    while !value.is_empty() {
+        let rem = div_small(&mut value, 10);
+        digits.push(char::from(b'0' + rem as u8));
+    }
+    match digits.is_empty() {
+        true => "0".to_string(),
+        false => digits.iter().rev().collect(),
     }
 }
 
@@ -2,13 +2,13 @@ use syn::{parse_quote, BinOp};
 
 use crate::{
     error::ParserResult,
-    model::ir::{BitwiseOp, Expression, LogicalOp, MathOp, Op, UnaryOp},
+    model::ir::{BitwiseOp, Expression, LogicalOp, MathOp, Op, Type, UnaryOp},
     parser::{
         context::{
             ContractInfo, ErrorInfo, EventsRegister, ExternalCallsRegister, FnContext, StorageInfo,
             TypeInfo,
         },
-        odra::expr::math,
+        odra::expr::{math, num},
     },
 };
 
@@ -27,11 +27,17 @@ pub(crate) fn bin_op<
     op: O,
     ctx: &mut T,
 ) -> ParserResult<syn::Expr> {
+    let op: BinOp = op.into();
+    // a shift amount does not have to be of the same type as the shifted value,
+    // a literal amount is `uint256`, eg. `int8(v) >> 200`
+    if let BinOp::Shl(_) | BinOp::Shr(_) = op {
+        let left_expr = math::eval(left, ctx)?;
+        let right_expr = math::eval_in_context(right, &Expression::Type(Type::Uint(256)), ctx)?;
+        return Ok(parse_quote!(#left_expr #op #right_expr));
+    }
     let left_expr = math::eval_in_context(left, right, ctx)?;
     let right_expr = math::eval_in_context(right, left, ctx)?;
 
-    let op: BinOp = op.into();
-
     Ok(parse_quote!(#left_expr #op #right_expr))
 }
 
@@ -48,7 +54,22 @@ pub(crate) fn unary_op<
     op: &UnaryOp,
     ctx: &mut T,
 ) -> ParserResult<syn::Expr> {
+    // a negative constant, eg. `-1`
+    match (expr, op) {
+        (Expression::NumberLiteral(value), UnaryOp::Minus) => {
+            return num::to_negative_int_expr(value, ctx)
+        }
+        (Expression::RationalLiteral(numer, denom), UnaryOp::Minus) => {
+            return num::to_negative_fixed_expr(numer, denom, ctx)
+        }
+        _ => {}
+    }
     let expr = math::eval(expr, ctx)?;
+    // an operand must be parenthesized, eg. `~(a << b)` is not `!a << b`
+    let expr: syn::Expr = match expr {
+        syn::Expr::Binary(_) => parse_quote!((#expr)),
+        _ => expr,
+    };
 
     Ok(match op {
         UnaryOp::Not => parse_quote!(!#expr),
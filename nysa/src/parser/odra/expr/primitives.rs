@@ -2,21 +2,21 @@ use proc_macro2::{Ident, TokenStream};
 use syn::{parse_quote, punctuated::Punctuated, BinOp, Token};
 
 use super::{
-    num, parse,
+    math, num, parse,
     syn_utils::{self, ArrayReader, DefaultValue, ReadValue, UnwrapOrNone, UnwrapOrRevert},
 };
 use crate::{
     error::ParserResult,
     formatted_invalid_expr,
-    model::ir::{Expression, TupleItem, Type, Var},
+    model::ir::{eval_expression_type, Expression, Param, TupleItem, Type, Var},
     parser::{
         context::{
             self, ContractInfo, ErrorInfo, EventsRegister, ExternalCallsRegister, FnContext,
             ItemType, StorageInfo, TypeInfo,
         },
         odra::{
-            expr::array,
             syn_utils::{in_context, AsExpression, AsSelfField},
+            ty,
         },
     },
     utils::{self, to_snake_case_ident},
@@ -37,11 +37,165 @@ pub fn get_var_or_parse<
     ctx: &mut T,
 ) -> ParserResult<syn::Expr> {
     match expr {
-        Expression::Variable(name) => get_var(name, ctx),
+        Expression::Variable(name) => match ctx.get_storage_ref(name).cloned() {
+            Some(target) => get_var_or_parse(&target, ctx),
+            None => match internal_fn_ref(name, ctx) {
+                Some(fn_ref) => Ok(fn_ref),
+                None => get_var(name, ctx),
+            },
+        },
         _ => parse(expr, ctx),
     }
 }
 
+/// Parses an internal function used as a value (eg. `op = double;`) into a function pointer.
+///
+/// Each Rust function has its own type, so if the function type is known from the context,
+/// the function is cast to a function pointer.
+///
+/// Returns `None` if the name does not refer to a function of the current contract.
+pub fn internal_fn_ref<T: TypeInfo + ContractInfo + FnContext>(
+    name: &str,
+    ctx: &T,
+) -> Option<syn::Expr> {
+    let ident = to_snake_case_ident(name);
+    if ctx.type_from_string(name).is_some()
+        || !ctx.current_contract().has_function(&ident.to_string())
+    {
+        return None;
+    }
+    let fn_ty = ctx
+        .contextual_expr()
+        .and_then(|e| eval_expression_type(e, ctx))
+        .filter(|ty| matches!(ty, Type::Function(_)))
+        .and_then(|ty| ty::parse_type_from_ty(&ty, ctx).ok());
+    match fn_ty {
+        Some(fn_ty) => Some(parse_quote!(Self::#ident as #fn_ty)),
+        None => Some(parse_quote!(Self::#ident)),
+    }
+}
+
+/// Parses a constant of the current contract into a reference to the associated const of the module.
+///
+/// Returns `None` if the name does not refer to a constant or is shadowed by a local variable.
+pub fn const_ref<T: TypeInfo + ContractInfo>(name: &str, ctx: &T) -> Option<syn::Expr> {
+    if ctx.type_from_string(name).is_some() {
+        return None;
+    }
+    ctx.current_contract()
+        .vars()
+        .iter()
+        .find(|v| v.is_immutable && v.name == name)
+        .map(|v| {
+            let ident = utils::to_ident(&v.name);
+            parse_quote!(Self::#ident)
+        })
+}
+
+/// Replaces a local storage pointer with the storage expression it refers to.
+///
+/// Eg. if `Position storage p = positions[id];` was defined, `p.size` is resolved
+/// to `positions[id].size`.
+pub fn resolve_storage_ref<T: FnContext>(expr: &Expression, ctx: &T) -> Expression {
+    match expr {
+        Expression::Variable(name) => ctx
+            .get_storage_ref(name)
+            .cloned()
+            .unwrap_or_else(|| expr.clone()),
+        Expression::Collection(name, keys) => match ctx.get_storage_ref(name) {
+            Some(Expression::Variable(target)) => {
+                Expression::Collection(target.clone(), keys.clone())
+            }
+            Some(Expression::Collection(target, target_keys)) => Expression::Collection(
+                target.clone(),
+                target_keys.iter().chain(keys).cloned().collect(),
+            ),
+            _ => expr.clone(),
+        },
+        Expression::MemberAccess(field, base) => {
+            Expression::MemberAccess(field.clone(), Box::new(resolve_storage_ref(base, ctx)))
+        }
+        _ => expr.clone(),
+    }
+}
+
+/// Builds the name of a hidden local variable holding a key of a storage pointer.
+pub fn storage_key_name(name: &str, idx: usize) -> String {
+    format!("__{}_key{}", name, idx)
+}
+
+/// Checks if a parameter is a mapping passed by its leading keys.
+///
+/// Library functions have no storage, so a library takes a mapping by reference.
+pub fn is_storage_mapping<T: ContractInfo>(param: &Param, ctx: &T) -> bool {
+    param.is_storage
+        && matches!(param.ty, Type::Mapping(_, _))
+        && !ctx.current_contract().is_library()
+}
+
+/// Finds the state mapping a storage mapping parameter of an internal function points to.
+///
+/// A storage item cannot be borrowed, so a mapping is passed by its leading keys - the parameter type
+/// must match exactly one state mapping, either the whole mapping or a mapping nested in it.
+///
+/// Returns the state variable name and the types of the leading keys.
+pub fn storage_mapping_target<T: StorageInfo>(
+    ty: &Type,
+    ctx: &T,
+) -> ParserResult<(String, Vec<Type>)> {
+    let mut targets = ctx.storage().into_iter().filter_map(|var| {
+        let mut key_types = vec![];
+        let mut item = var.ty;
+        while &item != ty {
+            match item {
+                Type::Mapping(key, value) => {
+                    key_types.push(Type::try_from(key.as_ref()).ok()?);
+                    item = Type::try_from(value.as_ref()).ok()?;
+                }
+                _ => return None,
+            }
+        }
+        Some((var.name, key_types))
+    });
+    match (targets.next(), targets.next()) {
+        (Some(target), None) => Ok(target),
+        (None, _) => formatted_invalid_expr!("no state mapping of type {:?}", ty),
+        _ => formatted_invalid_expr!("ambiguous storage mapping of type {:?}", ty),
+    }
+}
+
+/// Parses a storage mapping passed to an internal function into the leading keys of the state mapping.
+///
+/// sol: _reduce(allowances[owner], spender);
+/// rs: self._reduce(owner, spender);
+pub fn storage_mapping_arg<
+    T: StorageInfo
+        + TypeInfo
+        + EventsRegister
+        + ExternalCallsRegister
+        + ContractInfo
+        + FnContext
+        + ErrorInfo,
+>(
+    arg: &Expression,
+    ty: &Type,
+    ctx: &mut T,
+) -> ParserResult<Vec<syn::Expr>> {
+    let (target, key_types) = storage_mapping_target(ty, ctx)?;
+    let keys = match resolve_storage_ref(arg, ctx) {
+        Expression::Variable(name) if name == target => vec![],
+        Expression::Collection(name, keys) if name == target => keys,
+        arg => return formatted_invalid_expr!("{:?} does not point to {}", arg, target),
+    };
+    if keys.len() != key_types.len() {
+        return formatted_invalid_expr!("{:?} does not point to a mapping of type {:?}", arg, ty);
+    }
+    keys.iter()
+        .zip(key_types)
+        .map(|(key, key_ty)| parse_storage_key(key, &Expression::Type(key_ty), ctx))
+        .collect()
+}
+
 /// Parses an assign expression (=, +=, -=, *=, /=).
 ///
 /// In solidity there is left-hand and right-hand statement
@@ -83,12 +237,29 @@ pub fn assign<
             Expression::Collection(name, keys) => {
                 update_collection(name, keys, right, operator, ctx)
             }
+            Expression::Index(base, keys) => {
+                let value = get_var_or_parse(right, ctx)?;
+                let value = match operator {
+                    Some(op) => {
+                        let current_value = parse_index(base, keys, None, ctx)?;
+                        combine(left, current_value, &op.into(), value, ctx)
+                    }
+                    None => value,
+                };
+                parse_index(base, keys, Some(value), ctx)
+            }
+            // A storage pointer is re-pointed by an assignment statement.
+            Expression::Variable(name) if ctx.get_storage_ref(name).is_some() => {
+                Err(ParserError::InvalidStatement(
+                    "A storage pointer must be re-pointed by a statement",
+                ))
+            }
             Expression::Variable(name) => update_variable(name, right, operator, ctx),
             Expression::Tuple(left_items) => update_tuple(left_items, right, operator, ctx),
-            Expression::MemberAccess(field, var) => {
-                let l = parse(left, ctx)?;
-                let r = parse(right, ctx)?;
-                Ok(parse_quote!(#l = #r))
+            Expression::MemberAccess(field, base) => {
+                let value = get_var_or_parse(right, ctx)?;
+                let op = operator.map(Into::<BinOp>::into);
+                update_member(field, base, value, op, ctx)
             }
             _ => todo!(),
         },
@@ -110,11 +281,22 @@ pub fn assign<
 /// # Returns
 ///
 /// A parsed syn expression.
-pub fn set_var<T: StorageInfo + TypeInfo + FnContext>(
+pub fn set_var<
+    T: StorageInfo
+        + TypeInfo
+        + EventsRegister
+        + ExternalCallsRegister
+        + ContractInfo
+        + FnContext
+        + ErrorInfo,
+>(
     id: &str,
     value_expr: syn::Expr,
     ctx: &mut T,
 ) -> ParserResult<syn::Expr> {
+    if let Some(target) = ctx.get_storage_ref(id).cloned() {
+        return write(&target, value_expr, ctx);
+    }
     let item_type = ctx.type_from_string(id);
     let var = var(&item_type, id)?;
 
@@ -138,11 +320,14 @@ pub fn set_var<T: StorageInfo + TypeInfo + FnContext>(
 /// # Returns
 ///
 /// A parsed syn expression.
-pub fn get_var<T: StorageInfo + TypeInfo + FnContext>(
+pub fn get_var<T: StorageInfo + TypeInfo + ContractInfo + FnContext>(
     id: &str,
     ctx: &mut T,
 ) -> ParserResult<syn::Expr> {
     let item_type = ctx.type_from_string(id);
+    if let Some(const_ref) = const_ref(id, ctx) {
+        return Ok(const_ref);
+    }
     let var = var(&item_type, id)?;
 
     match item_type {
@@ -166,6 +351,16 @@ where
         + FnContext
         + ErrorInfo,
 {
+    let resolved = resolve_storage_ref(
+        &Expression::Collection(name.to_owned(), keys_expr.to_vec()),
+        ctx,
+    );
+    if let Expression::Collection(target, target_keys) = &resolved {
+        if target != name {
+            return parse_collection(target, target_keys, value_expr, ctx);
+        }
+    }
+
     let ident = to_snake_case_ident(name);
 
     let item_type = ctx
@@ -175,7 +370,9 @@ where
         ))?;
     match &item_type {
         ItemType::Storage(v) => parse_storage_collection(ident, keys_expr, value_expr, &v.ty, ctx),
-        ItemType::Local(v) => parse_local_collection(ident, keys_expr, value_expr, &v.ty, ctx),
+        ItemType::Local(v) => {
+            parse_local_collection(quote!(#ident), keys_expr, value_expr, &v.ty, ctx)
+        }
         _ => formatted_invalid_expr!("unknown collection {:?}", item_type),
     }
 }
@@ -197,18 +394,240 @@ fn assign_default<
 
     match left {
         Expression::Variable(name) => set_var(&name, default_expr, ctx),
-        Expression::Collection(name, keys) => match ctx.type_from_string(name) {
-            Some(ItemType::Storage(Var {
-                ty: Type::Array(_), ..
-            })) => array::replace_value(name, &keys[0], default_expr, ctx),
+        Expression::Collection(_, _) => match resolve_storage_ref(left, ctx) {
+            target @ Expression::Collection(_, _) => write(&target, default_expr, ctx),
             _ => Err(err()),
         },
+        Expression::Index(_, _) => write(left, default_expr, ctx),
         _ => Err(err()),
     }
 }
 
+/// Writes a value to a storage item (a state variable, a collection item or a struct field).
+pub fn write<
+    T: StorageInfo
+        + TypeInfo
+        + EventsRegister
+        + ExternalCallsRegister
+        + ContractInfo
+        + FnContext
+        + ErrorInfo,
+>(
+    target: &Expression,
+    value: syn::Expr,
+    ctx: &mut T,
+) -> ParserResult<syn::Expr> {
+    match target {
+        Expression::Variable(name) => set_var(name, value, ctx),
+        Expression::Collection(name, keys) => parse_collection(name, keys, Some(value), ctx),
+        Expression::Index(base, keys) => parse_index(base, keys, Some(value), ctx),
+        Expression::MemberAccess(field, base) => update_member(field, base, value, None, ctx),
+        _ => formatted_invalid_expr!("cannot write to {:?}", target),
+    }
+}
+
+/// Parses an update of a struct field.
+///
+/// A struct read from the storage is a copy, so if the struct is kept in the storage
+/// (a state variable, a collection item or a local storage pointer), the whole struct is read,
+/// updated and written back.
+///
+/// sol: positions[id].size += 1;
+/// rs: {
+///   let mut __value = self.positions.get_or_default(&id);
+///   __value.size = __value.size + nysa_types::U256::ONE;
+///   self.positions.set(&id, __value);
+/// }
+fn update_member<
+    T: StorageInfo
+        + TypeInfo
+        + EventsRegister
+        + ExternalCallsRegister
+        + ContractInfo
+        + FnContext
+        + ErrorInfo,
+>(
+    field: &str,
+    base: &Expression,
+    value: syn::Expr,
+    op: Option<BinOp>,
+    ctx: &mut T,
+) -> ParserResult<syn::Expr> {
+    let target = Expression::MemberAccess(field.to_owned(), Box::new(base.clone()));
+    let base = resolve_storage_ref(base, ctx);
+    let (root, mut fields) = member_path(&base);
+    fields.push(field.to_owned());
+    let members = fields.iter().map(to_snake_case_ident);
+    let members = quote!(#(.#members)*);
+
+    if is_storage_item(root, ctx) {
+        let tmp = format_ident!("__value");
+        let current_value = get_var_or_parse(root, ctx)?;
+        let new_value = match op {
+            Some(op) => combine(&target, parse_quote!(#tmp #members), &op, value, ctx),
+            None => value,
+        };
+        let update = write(root, tmp.clone().as_expression(), ctx)?;
+        Ok(parse_quote!({
+            let mut #tmp = #current_value;
+            #tmp #members = #new_value;
+            #update;
+        }))
+    } else {
+        let current_value = get_var_or_parse(root, ctx)?;
+        let current_value: syn::Expr = parse_quote!(#current_value #members);
+        match op {
+            Some(op) => {
+                let new_value = combine(&target, current_value.clone(), &op, value, ctx);
+                Ok(parse_quote!(#current_value = #new_value))
+            }
+            None => Ok(parse_quote!(#current_value = #value)),
+        }
+    }
+}
+
+/// Parses a call of a library function taking a storage reference as the first argument.
+///
+/// If the receiver is kept in the storage, it is read, passed by a mutable reference and,
+/// if the function is not a view function, written back.
+///
+/// sol: _ids.increment();
+/// rs: {
+///   let mut __value = self._ids.get_or_default();
+///   let __result = super::counters::Counters::increment(&mut __value);
+///   self._ids.set(__value);
+///   __result
+/// }
+pub fn call_with_storage_ref<
+    T: StorageInfo
+        + TypeInfo
+        + EventsRegister
+        + ExternalCallsRegister
+        + ContractInfo
+        + FnContext
+        + ErrorInfo,
+    F: FnOnce(syn::Expr) -> ParserResult<syn::Expr>,
+>(
+    receiver: &Expression,
+    is_mutable: bool,
+    call: F,
+    ctx: &mut T,
+) -> ParserResult<syn::Expr> {
+    let receiver = resolve_storage_ref(receiver, ctx);
+    let (root, fields) = member_path(&receiver);
+    let members = fields.iter().map(to_snake_case_ident);
+    let members = quote!(#(.#members)*);
+
+    if is_storage_item(root, ctx) && !is_mutable {
+        let current_value = get_var_or_parse(root, ctx)?;
+        call(parse_quote!(&mut #current_value #members))
+    } else if is_storage_item(root, ctx) {
+        let tmp = format_ident!("__value");
+        let current_value = get_var_or_parse(root, ctx)?;
+        let call = call(parse_quote!(&mut #tmp #members))?;
+        let update = write(root, tmp.clone().as_expression(), ctx)?;
+        Ok(parse_quote!({
+            let mut #tmp = #current_value;
+            let __result = #call;
+            #update;
+            __result
+        }))
+    } else {
+        // otherwise, the receiver is a storage reference passed to the current function
+        let target = get_var_or_parse(root, ctx)?;
+        match fields.is_empty() {
+            true => call(target),
+            false => call(parse_quote!(&mut #target #members)),
+        }
+    }
+}
+
+/// Splits a (nested) member access expression into the root expression and the accessed fields.
+///
+/// Eg. `a.b.c` is split into `a` and `[b, c]`.
+fn member_path(expr: &Expression) -> (&Expression, Vec<String>) {
+    match expr {
+        Expression::MemberAccess(field, base) => {
+            let (root, mut fields) = member_path(base);
+            fields.push(field.to_owned());
+            (root, fields)
+        }
+        _ => (expr, vec![]),
+    }
+}
+
+fn is_storage_item<T: TypeInfo>(expr: &Expression, ctx: &T) -> bool {
+    match expr {
+        Expression::Variable(name) | Expression::Collection(name, _) => {
+            matches!(ctx.type_from_string(name), Some(ItemType::Storage(_)))
+        }
+        _ => false,
+    }
+}
+
+/// Parses a collection access of a struct field, eg. `set._indexes[value]`.
+///
+/// A struct kept in the storage is read, updated and written back, like in [update_member].
+///
+/// sol: _members._indexes[value] = 1;
+/// rs: {
+///   let mut __value = self._members.get_or_default();
+///   __value._indexes.set(&value, nysa_types::U256::ONE);
+///   self._members.set(__value);
+/// }
+pub fn parse_index<T>(
+    base: &Expression,
+    keys_expr: &[Expression],
+    value_expr: Option<syn::Expr>,
+    ctx: &mut T,
+) -> ParserResult<syn::Expr>
+where
+    T: StorageInfo
+        + TypeInfo
+        + EventsRegister
+        + ExternalCallsRegister
+        + ContractInfo
+        + FnContext
+        + ErrorInfo,
+{
+    let ty = eval_expression_type(base, ctx).ok_or(ParserError::InvalidCollection)?;
+    let value_expr = match value_expr {
+        Some(value) => value,
+        None => {
+            let collection = parse(base, ctx)?;
+            return parse_local_collection(quote!(#collection), keys_expr, None, &ty, ctx);
+        }
+    };
+
+    let resolved = resolve_storage_ref(base, ctx);
+    let (root, fields) = member_path(&resolved);
+    let members = fields.iter().map(to_snake_case_ident);
+    let members = quote!(#(.#members)*);
+    if is_storage_item(root, ctx) {
+        let tmp = format_ident!("__value");
+        let current_value = get_var_or_parse(root, ctx)?;
+        let update =
+            parse_local_collection(quote!(#tmp #members), keys_expr, Some(value_expr), &ty, ctx)?;
+        let write_back = write(root, tmp.clone().as_expression(), ctx)?;
+        Ok(parse_quote!({
+            let mut #tmp = #current_value;
+            #update;
+            #write_back;
+        }))
+    } else {
+        let root = get_var_or_parse(root, ctx)?;
+        parse_local_collection(
+            quote!(#root #members),
+            keys_expr,
+            Some(value_expr),
+            &ty,
+            ctx,
+        )
+    }
+}
+
 fn parse_local_collection<T>(
-    var_ident: Ident,
+    var_ident: TokenStream,
     keys_expr: &[Expression],
     value_expr: Option<syn::Expr>,
     ty: &Type,
@@ -231,15 +650,17 @@ where
     // A local mapping should not exists but eg. can be passed by a reference to a function.
     if let Type::Mapping(_, _) = ty {
         // iterate over nested mappings
+        let key_types = mapping_key_types(ty);
         let key = match keys_expr.len() {
             0 => return Err(ParserError::InvalidCollection),
-            1 => parse_storage_key(&keys_expr[0], ctx)?,
+            1 => parse_storage_key(&keys_expr[0], &key_types[0], ctx)?,
             _ => {
                 let keys = keys_expr
                     .iter()
-                    .map(|k| parse_storage_key(&k, ctx))
+                    .zip(key_types.iter())
+                    .map(|(k, ty)| parse_storage_key(k, ty, ctx))
                     .collect::<ParserResult<Punctuated<syn::Expr, Token![,]>>>()?;
-                keys.as_expression()
+                parse_quote!((#keys))
             }
         };
         if let Some(value) = value_expr {
@@ -249,12 +670,46 @@ where
         }
     }
 
+    // A byte of `bytes` is read/written as `bytes1`, a byte of `bytesN` is read-only.
+    if let (Type::DynamicBytes | Type::Bytes(_), [index]) = (ty, keys_expr) {
+        let index = match num::try_to_generic_int_expr(index) {
+            Ok(e) => e,
+            Err(_) => {
+                let index = get_var_or_parse(index, ctx)?;
+                syn_utils::checked_usize(index, quote!(IndexOutOfBounds), ctx)
+            }
+        };
+        return match value_expr {
+            Some(_) if ty != &Type::DynamicBytes => Err(ParserError::InvalidCollection),
+            Some(value) => Ok(syn_utils::unwrap_or_panic(
+                quote!(#var_ident.set(#index, #value)),
+                quote!(IndexOutOfBounds),
+                ctx,
+            )),
+            None => Ok(syn_utils::unwrap_or_panic(
+                quote!(#var_ident.at(#index)),
+                quote!(IndexOutOfBounds),
+                ctx,
+            )),
+        };
+    }
+
+    let is_mut = value_expr.is_some();
     let mut collection = quote!(#var_ident);
-    for i in 0..keys_len {
-        let key = parse_local_key(&keys_expr[i], ctx)?;
-        collection.extend(quote!([#key]));
+    for key in keys_expr {
+        let index = match num::try_to_generic_int_expr(key) {
+            Ok(key) => key.to_token_stream(),
+            Err(_) => {
+                let key = get_var_or_parse(key, ctx)?;
+                syn_utils::checked_usize(key, quote!(IndexOutOfBounds), ctx).to_token_stream()
+            }
+        };
+        collection = index_expr(collection, index, is_mut, ctx);
     }
 
+    if !is_mut {
+        collection = read_item(collection, array_item_ty(ty, keys_len), ctx);
+    }
     let assign = value_expr.map(|e| quote!(= #e));
     Ok(parse_quote!(#collection #assign))
 }
@@ -275,42 +730,159 @@ where
         + FnContext
         + ErrorInfo,
 {
+    if keys_expr.is_empty() {
+        return Err(ParserError::InvalidCollection);
+    }
+    // Nested mappings are flattened, so keys of all the nested mappings form a single key,
+    // the remaining keys are indices of an array.
+    let depth = mapping_depth(ty);
+    if keys_expr.len() < depth {
+        return formatted_invalid_expr!(
+            "partial key access to {}, a nested mapping can be accessed only by a storage reference",
+            var_ident
+        );
+    }
+    let (keys_expr, indices) = keys_expr.split_at(depth);
+
+    let field = var_ident.as_self_field();
+    let key_types = mapping_key_types(ty);
     let key = match keys_expr.len() {
-        0 => return Err(ParserError::InvalidCollection),
-        1 => parse_storage_key(&keys_expr[0], ctx)?,
+        0 => None,
+        1 => Some(parse_storage_key(&keys_expr[0], &key_types[0], ctx)?),
         _ => {
             let keys = keys_expr
                 .iter()
-                .map(|k| parse_storage_key(&k, ctx))
+                .zip(key_types.iter())
+                .map(|(k, ty)| parse_storage_key(k, ty, ctx))
                 .collect::<ParserResult<Punctuated<syn::Expr, Token![,]>>>()?;
-            keys.as_expression()
+            Some(parse_quote!((#keys)))
         }
     };
 
-    let field = var_ident.as_self_field();
-    match value_expr {
-        Some(value) => Ok(parse_quote!(#field.set(&#key, #value))),
-        None => Ok(to_read_expr(field, Some(key), ty, ctx)),
+    if indices.is_empty() {
+        return match value_expr {
+            Some(value) => Ok(parse_quote!(#field.set(&#key, #value))),
+            None => Ok(to_read_expr(field, key, ty, ctx)),
+        };
+    }
+
+    // An array kept in the storage - the whole array is read, updated and written back.
+    let array = to_read_expr(&field, key.clone(), ty, ctx);
+    let index_ty = Expression::Type(Type::Uint(256));
+    let update = |key: Option<syn::Expr>| {
+        let key = key.map(|k| quote!(&#k,));
+        quote!(#field.set(#key result))
+    };
+    match mapping_value_ty(ty, ctx) {
+        array_ty @ (Type::Array(_) | Type::FixedArray(_, _)) => {
+            let item_ty = array_item_ty(&array_ty, indices.len());
+            let indices = indices
+                .iter()
+                .map(|i| {
+                    let i = parse_storage_key(i, &index_ty, ctx)?;
+                    Ok(syn_utils::checked_usize(i, quote!(IndexOutOfBounds), ctx))
+                })
+                .collect::<ParserResult<Vec<_>>>()?;
+            let indexed = |array: TokenStream, is_mut: bool| {
+                indices.iter().fold(array, |array, i| {
+                    index_expr(array, i.to_token_stream(), is_mut, ctx)
+                })
+            };
+
+            match value_expr {
+                Some(value) => {
+                    let update = update(key);
+                    let item = indexed(quote!(result), true);
+                    Ok(parse_quote!({
+                        let mut result = #array;
+                        #item = #value;
+                        #update;
+                    }))
+                }
+                None => {
+                    let item = indexed(array.to_token_stream(), false);
+                    Ok(read_item(item, item_ty, ctx).as_expression())
+                }
+            }
+        }
+        // A byte of `bytes` is read/written as `bytes1`.
+        Type::DynamicBytes if indices.len() == 1 => {
+            let index = parse_storage_key(&indices[0], &index_ty, ctx)?;
+            let index = syn_utils::checked_usize(index, quote!(IndexOutOfBounds), ctx);
+            match value_expr {
+                Some(value) => {
+                    let update = update(key);
+                    let set = syn_utils::unwrap_or_panic(
+                        quote!(result.set(#index, #value)),
+                        quote!(IndexOutOfBounds),
+                        ctx,
+                    );
+                    Ok(parse_quote!({
+                        let mut result = #array;
+                        #set;
+                        #update;
+                    }))
+                }
+                None => Ok(syn_utils::unwrap_or_panic(
+                    quote!(#array.at(#index)),
+                    quote!(IndexOutOfBounds),
+                    ctx,
+                )),
+            }
+        }
+        Type::Bytes(_) if indices.len() == 1 && value_expr.is_none() => {
+            let index = parse_storage_key(&indices[0], &index_ty, ctx)?;
+            let index = syn_utils::checked_usize(index, quote!(IndexOutOfBounds), ctx);
+            Ok(syn_utils::unwrap_or_panic(
+                quote!(#array.at(#index)),
+                quote!(IndexOutOfBounds),
+                ctx,
+            ))
+        }
+        _ => Err(ParserError::InvalidCollection),
     }
 }
 
-fn parse_storage_key<T>(key: &Expression, ctx: &mut T) -> ParserResult<syn::Expr>
-where
-    T: StorageInfo
-        + TypeInfo
-        + EventsRegister
-        + ExternalCallsRegister
-        + ContractInfo
-        + FnContext
-        + ErrorInfo,
-{
-    match key {
-        Expression::NumberLiteral(v) => num::to_typed_int_expr(v, ctx),
-        _ => get_var_or_parse(key, ctx),
+/// Counts the levels of a (nested) mapping.
+///
+/// Eg. `mapping(address => mapping(address => uint256))` has two levels.
+fn mapping_depth(ty: &Type) -> usize {
+    match ty {
+        Type::Mapping(_, box Expression::Type(value)) => 1 + mapping_depth(value),
+        Type::Mapping(_, _) => 1,
+        _ => 0,
     }
 }
 
-fn parse_local_key<T>(key: &Expression, ctx: &mut T) -> ParserResult<syn::Expr>
+/// Collects key types of a (nested) mapping.
+fn mapping_key_types(ty: &Type) -> Vec<Expression> {
+    match ty {
+        Type::Mapping(key, box Expression::Type(value)) => {
+            [vec![*key.clone()], mapping_key_types(value)].concat()
+        }
+        Type::Mapping(key, _) => vec![*key.clone()],
+        _ => vec![],
+    }
+}
+
+/// Finds the type of a value stored in a (nested) mapping.
+fn mapping_value_ty<T: TypeInfo>(ty: &Type, ctx: &T) -> Type {
+    match ty {
+        Type::Mapping(_, box Expression::Type(value)) => mapping_value_ty(value, ctx),
+        Type::Mapping(_, value) => match ctx.type_from_expression(value) {
+            Some(ItemType::Struct(s)) => Type::Custom(s.name),
+            Some(ItemType::ValueType(v)) => Type::Custom(v.name),
+            _ => Type::try_from(&**value).unwrap_or(Type::Unknown),
+        },
+        _ => ty.clone(),
+    }
+}
+
+pub(super) fn parse_storage_key<T>(
+    key: &Expression,
+    key_ty: &Expression,
+    ctx: &mut T,
+) -> ParserResult<syn::Expr>
 where
     T: StorageInfo
         + TypeInfo
@@ -320,13 +892,15 @@ where
         + FnContext
         + ErrorInfo,
 {
-    match num::try_to_generic_int_expr(key) {
-        Ok(e) => Ok(e),
-        Err(_) => get_var_or_parse(key, ctx),
+    match key {
+        Expression::NumberLiteral(v) => {
+            in_context(key_ty, ctx, |ctx| num::to_typed_int_expr(v, ctx))
+        }
+        _ => get_var_or_parse(key, ctx),
     }
 }
 
-fn to_read_expr<F: ToTokens, T: StorageInfo + TypeInfo>(
+fn to_read_expr<F: ToTokens, T: StorageInfo + TypeInfo + ContractInfo>(
     field: F,
     key_expr: Option<syn::Expr>,
     ty: &Type,
@@ -341,35 +915,133 @@ fn to_read_expr<F: ToTokens, T: StorageInfo + TypeInfo>(
                 context::ItemType::Contract(_)
                 | context::ItemType::Library(_)
                 | context::ItemType::Interface(_) => <UnwrapOrNone as ReadValue>::expr(field, key),
-                context::ItemType::Enum(_) => <DefaultValue as ReadValue>::expr(field, key),
+                // an unset struct reads as a struct with zero-valued fields
+                context::ItemType::Enum(_)
+                | context::ItemType::ValueType(_)
+                | context::ItemType::Struct(_) => <DefaultValue as ReadValue>::expr(field, key),
                 _ => <UnwrapOrRevert as ReadValue>::expr(field, key),
             })
             .unwrap(),
-        Type::String | Type::Bool | Type::Uint(_) | Type::Int(_) => {
-            <DefaultValue as ReadValue>::expr(field, key)
-        }
-        Type::Mapping(_, v) => {
-            let ty = match ctx.type_from_expression(v) {
-                Some(ItemType::Struct(s)) => Type::Custom(s.name),
-                _ => Type::try_from(&**v).unwrap(),
-            };
-
-            to_read_expr(field, key_expr, &ty, ctx)
-        }
-        Type::Array(ty) => {
-            let key = key_expr.and_then(|key| match &**ty {
-                Type::Uint(size) => match size {
-                    256..=512 => Some(quote!([#key.as_usize()])),
-                    _ => Some(quote!([#key as usize])),
-                },
-                _ => Some(quote!([#key])),
+        Type::String
+        | Type::Bool
+        | Type::Uint(_)
+        | Type::Int(_)
+        | Type::Fixed(_, _)
+        | Type::Ufixed(_, _)
+        | Type::Bytes(_)
+        | Type::DynamicBytes => <DefaultValue as ReadValue>::expr(field, key),
+        Type::Mapping(_, v) => match mapping_value_ty(ty, ctx) {
+            // A missing array is an empty array.
+            Type::Array(_) | Type::FixedArray(_, _) => {
+                <DefaultValue as ReadValue>::expr(field, key)
+            }
+            ty => to_read_expr(field, key_expr, &ty, ctx),
+        },
+        // A stored function is dispatched to a function pointer, calling a zero function reverts.
+        Type::Function(f) => {
+            let value = <DefaultValue as ReadValue>::expr(field, key);
+            let value = syn_utils::unwrap_or_panic(value, quote!(ZeroFunction), ctx);
+            let fn_ty = ty::parse_type_from_ty(ty, ctx).expect("Should be a valid type");
+            let fns = ctx.current_contract().internal_fns_of_type(f);
+            let arms = fns.iter().map(|name| {
+                let variant = utils::to_pascal_case_ident(name);
+                let ident = to_snake_case_ident(name);
+                quote!(InternalFunction::#variant => Self::#ident as #fn_ty)
             });
-            <ArrayReader as ReadValue>::expr(field, key)
+            // the enum may keep functions of other types, that cannot be assigned to this variable
+            let other = match fns.len() < ctx.current_contract().stored_internal_fns().len() {
+                true => {
+                    let panic = syn_utils::panic(quote!(ZeroFunction), ctx);
+                    Some(quote!(_ => #panic,))
+                }
+                false => None,
+            };
+            parse_quote!(match #value { #(#arms,)* #other })
         }
+        Type::Array(_) | Type::FixedArray(_, _) => match key_expr {
+            Some(key) => {
+                let array = <ArrayReader as ReadValue>::expr(field, quote!());
+                let index = syn_utils::checked_usize(key, quote!(IndexOutOfBounds), ctx);
+                let item = index_expr(array.to_token_stream(), index.to_token_stream(), false, ctx);
+                read_item(item, array_item_ty(ty, 1), ctx).as_expression()
+            }
+            None => <ArrayReader as ReadValue>::expr(field, quote!()),
+        },
         _ => <UnwrapOrRevert as ReadValue>::expr(field, key),
     }
 }
 
+/// Combines the current value of the target with a new value in a compound assignment (eg. `x += 1`).
+fn combine<T: TypeInfo + ContractInfo + FnContext>(
+    target: &Expression,
+    current_value: syn::Expr,
+    op: &BinOp,
+    value: syn::Expr,
+    ctx: &T,
+) -> syn::Expr {
+    let ty = eval_expression_type(target, ctx);
+    math::checked_op(&current_value, op, &value, ty, ctx)
+        .unwrap_or_else(|| parse_quote!(#current_value #op #value))
+}
+
+/// Builds a bounds-checked array indexing expression, reverting with `Panic::IndexOutOfBounds`.
+///
+/// The result is a place expression, so may be read or assigned.
+fn index_expr<T: ContractInfo>(
+    array: TokenStream,
+    index: TokenStream,
+    is_mut: bool,
+    ctx: &T,
+) -> TokenStream {
+    let item = match is_mut {
+        true => quote!(#array.get_mut(#index)),
+        false => quote!(#array.get(#index)),
+    };
+    let item = syn_utils::unwrap_or_panic(item, quote!(IndexOutOfBounds), ctx);
+    quote!((*#item))
+}
+
+/// Finds the type of an item of a (nested) array indexed `depth` times.
+fn array_item_ty(ty: &Type, depth: usize) -> Option<&Type> {
+    match (depth, ty) {
+        (0, ty) => Some(ty),
+        (_, Type::Array(item) | Type::FixedArray(item, _)) => array_item_ty(item, depth - 1),
+        _ => None,
+    }
+}
+
+/// Reads an array item by value - an item which is not `Copy` is cloned out of the array.
+fn read_item<T: TypeInfo>(item: TokenStream, ty: Option<&Type>, ctx: &T) -> TokenStream {
+    match ty {
+        Some(ty) if !is_copy(ty, ctx) => quote!(#item.clone()),
+        _ => item,
+    }
+}
+
+/// Checks if a value of the type is `Copy` in the generated code.
+fn is_copy<T: TypeInfo>(ty: &Type, ctx: &T) -> bool {
+    match ty {
+        Type::Address
+        | Type::Bool
+        | Type::Int(_)
+        | Type::Uint(_)
+        | Type::Fixed(_, _)
+        | Type::Ufixed(_, _)
+        | Type::Bytes(_) => true,
+        // contracts are referenced by an address, value types derive `Copy`
+        Type::Custom(name) => matches!(
+            ctx.type_from_string(name),
+            Some(
+                ItemType::Contract(_)
+                    | ItemType::Interface(_)
+                    | ItemType::Library(_)
+                    | ItemType::ValueType(_)
+            )
+        ),
+        _ => false,
+    }
+}
+
 fn update_collection<T, O>(
     name: &str,
     keys: &[Expression],
@@ -387,15 +1059,16 @@ where
         + ErrorInfo,
     O: Into<BinOp>,
 {
-    if operator.is_none() {
-        let value = get_var_or_parse(right, ctx)?;
-        parse_collection(name, &keys, Some(value), ctx)
-    } else {
-        let op = operator.map(Into::<BinOp>::into);
+    if let Some(op) = operator {
+        let op: BinOp = op.into();
         let value_expr = get_var_or_parse(right, ctx)?;
         let current_value_expr = parse_collection(name, &keys, None, ctx)?;
-        let new_value: syn::Expr = parse_quote!(#current_value_expr #op #value_expr);
+        let target = Expression::Collection(name.to_owned(), keys.to_vec());
+        let new_value = combine(&target, current_value_expr, &op, value_expr, ctx);
         parse_collection(name, &keys, Some(new_value), ctx)
+    } else {
+        let value = get_var_or_parse(right, ctx)?;
+        parse_collection(name, &keys, Some(value), ctx)
     }
 }
 
@@ -415,15 +1088,57 @@ where
         + ErrorInfo,
     O: Into<BinOp>,
 {
-    if operator.is_none() {
-        let right = get_var_or_parse(right, ctx)?;
-        set_var(&name, right, ctx)
-    } else {
-        let op = operator.map(Into::<BinOp>::into);
+    if let Some(op) = operator {
+        let op: BinOp = op.into();
         let current_value_expr = get_var(&name, ctx)?;
         let value_expr = get_var_or_parse(right, ctx)?;
-        let new_value: syn::Expr = parse_quote!(#current_value_expr #op #value_expr);
+        let target = Expression::Variable(name.to_owned());
+        let new_value = combine(&target, current_value_expr, &op, value_expr, ctx);
         set_var(&name, new_value, ctx)
+    } else if let Some(ItemType::Storage(Var {
+        ty: Type::Function(_),
+        ..
+    })) = ctx.type_from_string(name)
+    {
+        let right = stored_fn(right, ctx)?;
+        set_var(name, right, ctx)
+    } else {
+        let right = get_var_or_parse(right, ctx)?;
+        set_var(&name, right, ctx)
+    }
+}
+
+/// Parses an internal function assigned to a function-typed state variable into a variant
+/// of the `InternalFunction` enum.
+///
+/// sol: op = double;
+/// rs: self.op.set(Some(InternalFunction::Double));
+fn stored_fn<T: StorageInfo + TypeInfo + ContractInfo>(
+    expr: &Expression,
+    ctx: &T,
+) -> ParserResult<syn::Expr> {
+    let err = || ParserError::UnexpectedExpression("an internal function", expr.clone());
+    let name = match expr {
+        Expression::Variable(name) => name,
+        _ => return Err(err()),
+    };
+    match ctx.type_from_string(name) {
+        // a copy of another stored function
+        item_type @ Some(ItemType::Storage(Var {
+            ty: Type::Function(_),
+            ..
+        })) => {
+            let var = var(&item_type, name)?;
+            Ok(parse_quote!(#var.get_or_default()))
+        }
+        None if ctx
+            .current_contract()
+            .has_function(&utils::to_snake_case(name)) =>
+        {
+            let variant = utils::to_pascal_case_ident(utils::to_snake_case(name));
+            Ok(parse_quote!(Some(InternalFunction::#variant)))
+        }
+        _ => Err(err()),
     }
 }
 
@@ -1,5 +1,9 @@
-use crate::parser::odra::{expr, syn_utils::ty};
-use quote::ToTokens;
+use crate::parser::{
+    context::ContractInfo,
+    odra::{expr, syn_utils::ty},
+};
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
 use syn::parse_quote;
 
 pub fn unwrap_or_revert<T: ToTokens>(expr: T) -> syn::Expr {
@@ -15,34 +19,101 @@ pub fn none() -> syn::Expr {
     parse_quote!(None)
 }
 
-pub fn try_fixed_bytes<T: ToTokens>(args: &[T]) -> syn::Expr {
-    parse_quote!(nysa_types::FixedBytes::try_from(&self.env().hash(#(#args),*)).unwrap_or_default())
+pub fn keccak256<T: ToTokens>(args: &[T]) -> syn::Expr {
+    hash(quote!(nysa_types::keccak256), args)
+}
+
+pub fn sha256<T: ToTokens>(args: &[T]) -> syn::Expr {
+    hash(quote!(nysa_types::sha256), args)
+}
+
+pub fn ripemd160<T: ToTokens>(args: &[T]) -> syn::Expr {
+    hash(quote!(nysa_types::ripemd160), args)
+}
+
+/// Hashes a single bytes argument, multiple arguments (allowed before Solidity 0.5) are packed first.
+fn hash<T: ToTokens>(hash_fn: TokenStream, args: &[T]) -> syn::Expr {
+    match args {
+        [arg] => parse_quote!(#hash_fn(&#arg)),
+        _ => {
+            let packed = encode_packed(args);
+            parse_quote!(#hash_fn(&#packed))
+        }
+    }
 }
 
 pub fn unsigned_one() -> syn::Expr {
     parse_quote!(nysa_types::Unsigned::ONE)
 }
 
-pub fn revert_user_error(num: u16) -> syn::Expr {
-    if cfg!(test) {
-        parse_quote!(self.env().revert(odra::ExecutionError::User(1u16)))
+pub fn revert_user_error<C: ContractInfo>(num: u16, ctx: &C) -> syn::Expr {
+    let num = if cfg!(test) { 1u16 } else { num };
+    let error = quote!(odra::ExecutionError::User(#num));
+    if ctx.current_contract().is_library() {
+        parse_quote!(nysa_types::panic(#error))
     } else {
-        parse_quote!(self.env().revert(odra::ExecutionError::User(#num)))
+        parse_quote!(self.env().revert(#error))
     }
 }
 
-pub fn revert<T: ToTokens>(error: T) -> syn::Expr {
-    parse_quote!(self.env().revert(Error::#error))
+pub fn revert<T: ToTokens, C: ContractInfo>(error: T, ctx: &C) -> syn::Expr {
+    if ctx.current_contract().is_library() {
+        parse_quote!(nysa_types::panic(Error::#error))
+    } else {
+        parse_quote!(self.env().revert(Error::#error))
+    }
+}
+
+/// Reverts with a panic code, a library function has no env so reverts through the host API instead.
+pub fn panic<T: ToTokens, C: ContractInfo>(panic: T, ctx: &C) -> syn::Expr {
+    if ctx.current_contract().is_library() {
+        parse_quote!(nysa_types::panic(Panic::#panic))
+    } else {
+        parse_quote!(self.env().revert(Panic::#panic))
+    }
+}
+
+/// Unwraps an option or reverts with a panic code, a library function has no env so reverts through
+/// the host API instead.
+pub fn unwrap_or_panic<T: ToTokens, P: ToTokens, C: ContractInfo>(
+    expr: T,
+    panic: P,
+    ctx: &C,
+) -> syn::Expr {
+    if ctx.current_contract().is_library() {
+        parse_quote!(nysa_types::unwrap_or_panic(#expr, Panic::#panic))
+    } else {
+        parse_quote!(odra::UnwrapOrRevert::unwrap_or_revert_with(#expr, &self.env(), Panic::#panic))
+    }
+}
+
+/// Converts an integer into `usize`, reverts with a panic code if the value does not fit.
+pub fn checked_usize<T: ToTokens, P: ToTokens, C: ContractInfo>(
+    expr: T,
+    panic: P,
+    ctx: &C,
+) -> syn::Expr {
+    unwrap_or_panic(quote!(#expr.checked_as_usize()), panic, ctx)
+}
+
+pub fn encode_packed<T: ToTokens>(args: &[T]) -> syn::Expr {
+    parse_quote!(nysa_types::abi::encode_packed(&[#(&#args),*]))
+}
+
+pub fn abi_encode<T: ToTokens>(args: &[T]) -> syn::Expr {
+    parse_quote!(nysa_types::abi::encode(&[#(&#args),*]))
+}
+
+pub fn abi_encode_with_selector<S: ToTokens, T: ToTokens>(selector: S, args: &[T]) -> syn::Expr {
+    parse_quote!(nysa_types::abi::encode_with_selector(#selector, &[#(&#args),*]))
+}
+
+pub fn abi_encode_with_signature<S: ToTokens, T: ToTokens>(signature: S, args: &[T]) -> syn::Expr {
+    parse_quote!(nysa_types::abi::encode_with_signature(#signature, &[#(&#args),*]))
 }
 
-pub fn serialize<T: ToTokens>(args: &[T]) -> syn::Expr {
-    let to_bytes: syn::Type = parse_quote!(odra::casper_types::bytesrepr::ToBytes);
-    let unwrap_or_revert: syn::Type = parse_quote!(odra::UnwrapOrRevert);
-    parse_quote!({
-        let mut result = Vec::new();
-        #(result.extend(#unwrap_or_revert::unwrap_or_revert(#to_bytes::to_bytes(&#args), &self.env()));)*
-        result
-    })
+pub fn eip712_hash_struct<S: ToTokens, T: ToTokens>(type_hash: S, members: &[T]) -> syn::Expr {
+    parse_quote!(nysa_types::eip712::hash_struct(#type_hash, &[#(&#members),*]))
 }
 
 pub fn default() -> syn::Expr {
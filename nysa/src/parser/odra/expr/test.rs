@@ -1,10 +1,11 @@
 use quote::{quote, ToTokens};
 use solidity_parser::pt;
 
-use crate::model::ir::{eval_expression_type, Expression, Type, Var};
+use crate::model::ir::{eval_expression_type, Expression, Struct, Type, Var};
 use crate::model::ContractData;
 use crate::parser::context::*;
 use crate::parser::odra::test::assert_tokens_eq;
+use crate::ParserError;
 
 #[test]
 fn assign_and_compare() {
@@ -16,9 +17,13 @@ fn assign_and_compare() {
         let solidity_expr = "(x = a + b) <= 256";
         let expected_rust_code = quote!(
             {
-                x = (a + b);
+                x = odra::UnwrapOrRevert::unwrap_or_revert_with(
+                    a.checked_add(b),
+                    &self.env(),
+                    Panic::ArithmeticOverflow
+                );
                 x
-            } <= nysa_types::U32::from_limbs_slice(&[256u64])
+            } <= nysa_types::U32::from_limbs([256u64])
         );
 
         assert_expression(solidity_expr, expected_rust_code, ctx);
@@ -33,11 +38,19 @@ fn complex_stmt() {
         let solidity_expr = "!(y == 0 || (z = x * y) / y == x)";
         let expected_rust_code = quote!(
             !(y == nysa_types::U32::ZERO
-                || ({
-                    z = (x * y);
-                    z
-                } / y)
-                    == x)
+                || odra::UnwrapOrRevert::unwrap_or_revert_with(
+                    ({
+                        z = odra::UnwrapOrRevert::unwrap_or_revert_with(
+                            x.checked_mul(y),
+                            &self.env(),
+                            Panic::ArithmeticOverflow
+                        );
+                        z
+                    })
+                    .checked_div(y),
+                    &self.env(),
+                    Panic::DivisionByZero
+                ) == x)
         );
 
         assert_expression(solidity_expr, expected_rust_code, ctx);
@@ -97,6 +110,152 @@ fn eval_math() {
     })
 }
 
+#[test]
+fn eval_ops() {
+    with_context(|ctx| {
+        ctx.register_local_var(&"y".to_string(), &Type::Uint(32));
+        ctx.register_local_var(&"x".to_string(), &Type::Uint(256));
+        ctx.register_local_var(&"b".to_string(), &Type::Bool);
+        ctx.register_local_var(&"owner".to_string(), &Type::Address);
+
+        assert_expression_type("y << x", Some(Type::Uint(32)), ctx);
+        assert_expression_type("y & x", Some(Type::Uint(256)), ctx);
+        assert_expression_type("y ** x", Some(Type::Uint(32)), ctx);
+        assert_expression_type("b ? y : x", Some(Type::Uint(256)), ctx);
+        assert_expression_type("owner.balance", Some(Type::Uint(256)), ctx);
+    })
+}
+
+#[test]
+fn storage_ref_member_update() {
+    let position = Struct {
+        namespace: None,
+        name: "Position".to_string(),
+        fields: vec![("size".to_string(), Expression::Type(Type::Uint(256)))],
+    };
+    let mut global_ctx = GlobalContext::new(
+        vec![],
+        vec![],
+        vec![],
+        vec![],
+        vec![],
+        vec![],
+        vec![position],
+        vec![],
+    );
+    let storage = vec![Var {
+        name: "positions".to_string(),
+        ty: Type::Mapping(
+            Box::new(Expression::Type(Type::Uint(256))),
+            Box::new(Expression::Variable("Position".to_string())),
+        ),
+        initializer: None,
+        is_immutable: false,
+    }];
+    let data = ContractData::with_storage("test", storage);
+    let contract_ctx = ContractContext::new(&mut global_ctx, data);
+    let mut ctx = LocalContext::new(contract_ctx);
+    ctx.register_local_var(&"id".to_string(), &Type::Uint(256));
+    ctx.register_local_var(&"p".to_string(), &Type::Custom("Position".to_string()));
+    ctx.register_storage_ref(
+        "p",
+        &Expression::Collection("positions".to_string(), vec![Expression::from("id")]),
+    );
+    ctx.register_local_var(&"q".to_string(), &Type::Custom("Position".to_string()));
+
+    let expected_rust_code = quote!({
+        let mut __value = self.positions.get_or_default(&id);
+        __value.size = odra::UnwrapOrRevert::unwrap_or_revert_with(
+            __value.size.checked_add(nysa_types::U256::ONE),
+            &self.env(),
+            Panic::ArithmeticOverflow
+        );
+        self.positions.set(&id, __value);
+    });
+    assert_expression("p.size += 1", expected_rust_code, &mut ctx);
+
+    let expected_rust_code = quote!(
+        q.size = odra::UnwrapOrRevert::unwrap_or_revert_with(
+            q.size.checked_add(nysa_types::U256::ONE),
+            &self.env(),
+            Panic::ArithmeticOverflow
+        )
+    );
+    assert_expression("q.size += 1", expected_rust_code, &mut ctx);
+}
+
+#[test]
+fn illegal_conversions() {
+    with_context(|ctx| {
+        ctx.register_local_var(&"a".to_string(), &Type::Uint(8));
+        ctx.register_local_var(&"b".to_string(), &Type::Int(16));
+        ctx.register_local_var(&"c".to_string(), &Type::Uint(256));
+
+        let cases = [
+            ("int16(a)", Type::Uint(8), Type::Int(16)),
+            ("uint256(b)", Type::Int(16), Type::Uint(256)),
+            ("bytes4(a)", Type::Uint(8), Type::Bytes(4)),
+            ("address(c)", Type::Uint(256), Type::Address),
+        ];
+        for (solidity_expr, from, to) in cases {
+            let e = parse_expression(solidity_expr);
+            let result = super::parse(&e, ctx);
+            assert!(
+                matches!(result, Err(ParserError::InvalidConversion(f, t)) if f == from && t == to)
+            );
+        }
+    })
+}
+
+#[test]
+fn address_conversions() {
+    with_context(|ctx| {
+        ctx.register_local_var(&"owner".to_string(), &Type::Address);
+        ctx.register_local_var(&"id".to_string(), &Type::Uint(160));
+        ctx.register_local_var(&"hash".to_string(), &Type::Bytes(20));
+
+        let cases = [
+            ("uint160(owner)", Type::Address, Type::Uint(160)),
+            ("bytes20(owner)", Type::Address, Type::Bytes(20)),
+            ("address(id)", Type::Uint(160), Type::Address),
+            ("address(hash)", Type::Bytes(20), Type::Address),
+        ];
+        for (solidity_expr, from, to) in cases {
+            let e = parse_expression(solidity_expr);
+            let result = super::parse(&e, ctx);
+            assert!(
+                matches!(result, Err(ParserError::AddressConversion(f, t)) if f == from && t == to)
+            );
+        }
+    })
+}
+
+#[test]
+fn literals_out_of_range() {
+    with_context(|ctx| {
+        ctx.register_local_var(&"a".to_string(), &Type::Uint(8));
+        ctx.register_local_var(&"b".to_string(), &Type::Int(8));
+        ctx.register_local_var(&"f".to_string(), &Type::Fixed(8, 1));
+
+        let cases = [
+            ("a = 256", "256", Type::Uint(8)),
+            ("a = -1", "-1", Type::Uint(8)),
+            ("b = -129", "-129", Type::Int(8)),
+            ("b = 128", "128", Type::Int(8)),
+            ("f = 0.25", "0.25", Type::Fixed(8, 1)),
+            ("f = -12.9", "-12.9", Type::Fixed(8, 1)),
+            ("f = 13", "13", Type::Fixed(8, 1)),
+        ];
+        for (solidity_expr, literal, ty) in cases {
+            let e = parse_expression(solidity_expr);
+            let result = super::parse(&e, ctx);
+            assert!(
+                matches!(result, Err(ParserError::LiteralOutOfRange(l, t)) if l == literal && t == ty)
+            );
+        }
+    })
+}
+
 fn assert_expression<T: AsRef<str>, R: ToTokens>(
     solidity_expr: T,
     expected: R,
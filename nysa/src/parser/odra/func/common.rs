@@ -10,7 +10,7 @@ use crate::{
         context::{
             ContractInfo, EventsRegister, ExternalCallsRegister, FnContext, StorageInfo, TypeInfo,
         },
-        odra::{stmt, ty},
+        odra::{expr::primitives, stmt, ty},
     },
     utils,
 };
@@ -25,20 +25,28 @@ pub(super) fn parse_visibility(vis: &Visibility) -> syn::Visibility {
 
 pub(super) fn parse_parameter<T: TypeInfo>(param: &Param, info: &T) -> ParserResult<syn::FnArg> {
     let ty = ty::parse_type_from_ty(&param.ty, info)?;
+    // A storage reference is modified in place and written back by the caller.
+    let ty = match param.is_storage {
+        true => parse_quote!(&mut #ty),
+        false => ty,
+    };
     let name = utils::to_snake_case_ident(&param.name);
     Ok(syn_utils::fn_arg(name, ty))
 }
 
-pub(super) fn context_args<T: TypeInfo + FnContext>(
+pub(super) fn context_args<T: TypeInfo + StorageInfo + ContractInfo + FnContext>(
     params: &[Param],
     is_mutable: bool,
     ctx: &mut T,
 ) -> ParserResult<Vec<FnArg>> {
-    let mut args = params
-        .iter()
-        .map(|p| parse_parameter(p, ctx))
-        .collect::<ParserResult<Vec<_>>>()?;
-    args.insert(0, syn_utils::self_arg(is_mutable));
+    let mut args = vec![syn_utils::self_arg(is_mutable)];
+    for param in params {
+        if primitives::is_storage_mapping(param, ctx) {
+            args.extend(storage_mapping_args(param, ctx)?);
+        } else {
+            args.push(parse_parameter(param, ctx)?);
+        }
+    }
 
     params
         .iter()
@@ -47,6 +55,33 @@ pub(super) fn context_args<T: TypeInfo + FnContext>(
     Ok(args)
 }
 
+/// Parses a storage mapping parameter into the leading keys of the state mapping it points to,
+/// the parameter is registered as a storage pointer.
+///
+/// sol: function _reduce(mapping(address => uint256) storage allowed, ..)
+/// rs: fn _reduce(&mut self, _allowed_key_0: Option<odra::Address>, ..)
+fn storage_mapping_args<T: TypeInfo + StorageInfo + FnContext>(
+    param: &Param,
+    ctx: &mut T,
+) -> ParserResult<Vec<FnArg>> {
+    let (target, key_types) = primitives::storage_mapping_target(&param.ty, ctx)?;
+    let mut args = vec![];
+    let mut keys = vec![];
+    for (idx, key_ty) in key_types.iter().enumerate() {
+        let key_name = primitives::storage_key_name(&param.name, idx);
+        let ty = ty::parse_type_from_ty(key_ty, ctx)?;
+        args.push(syn_utils::fn_arg(utils::to_snake_case_ident(&key_name), ty));
+        ctx.register_local_var(&key_name, key_ty);
+        keys.push(Expression::Variable(key_name));
+    }
+    let target = match keys.is_empty() {
+        true => Expression::Variable(target),
+        false => Expression::Collection(target, keys),
+    };
+    ctx.register_storage_ref(&param.name, &target);
+    Ok(args)
+}
+
 pub(super) fn args<T: TypeInfo>(
     params: &[Param],
     is_mutable: bool,
@@ -46,11 +46,15 @@ where
 
     let implementations = definitions
         .iter()
-        .map(|(class, def)| ClassFnImpl {
-            class: Some(class.to_owned().clone()),
-            fun: def.name.clone().into(),
-            implementation: parse_body(def, ctx),
-            visibility: common::parse_visibility(&def.vis),
+        .map(|(class, def)| {
+            // `super` is resolved relatively to the class the implementation is defined in
+            ctx.set_current_class(class);
+            ClassFnImpl {
+                class: Some(class.to_owned().clone()),
+                fun: def.name.clone().into(),
+                implementation: parse_body(def, ctx),
+                visibility: common::parse_visibility(&def.vis),
+            }
         })
         .collect();
 
@@ -1,7 +1,7 @@
 use syn::parse_quote;
 
 use crate::{
-    parser::context::{ExternalCallsRegister, TypeInfo},
+    parser::context::{ContractInfo, ExternalCallsRegister, TypeInfo},
     utils,
 };
 
@@ -51,6 +51,24 @@ pub(super) fn imports_code<T: ExternalCallsRegister + TypeInfo>(ctx: &T) -> Vec<
         .collect()
 }
 
+/// Generates an enum of internal functions kept in function-typed state variables.
+///
+/// A function pointer cannot be serialized, so a function is stored as an enum variant
+/// and dispatched when read.
+pub(super) fn internal_functions_code<T: ContractInfo>(ctx: &T) -> Vec<syn::Item> {
+    let fns = ctx.current_contract().stored_internal_fns();
+    if fns.is_empty() {
+        return vec![];
+    }
+    let variants = fns.iter().map(utils::to_pascal_case_ident);
+    vec![parse_quote! {
+        #[derive(odra::OdraType, Copy, Clone, PartialEq, Eq, Debug)]
+        enum InternalFunction {
+            #(#variants),*
+        }
+    }]
+}
+
 /// Generates Odra-specific implementations for PathStack.
 pub(super) fn path_stack_default_impl() -> Vec<syn::Item> {
     vec![
@@ -28,13 +28,36 @@ where
         + FnContext
         + ErrorInfo,
 {
+    ctx.push_scope();
     let stmts = stmts
         .iter()
         .map(|stmt| super::parse_statement(stmt, true, ctx))
-        .collect::<ParserResult<Vec<syn::Stmt>>>()?;
+        .collect::<ParserResult<Vec<syn::Stmt>>>();
+    ctx.drop_scope();
+    let stmts = stmts?;
     Ok(parse_quote!({ #(#stmts)* }))
 }
 
+/// Parses an `unchecked` block of statements and returns a `syn::Stmt`.
+///
+/// The arithmetic operations inside the block wrap on overflow instead of reverting.
+pub(super) fn unchecked_block<T>(stmts: &[Stmt], ctx: &mut T) -> ParserResult<syn::Stmt>
+where
+    T: StorageInfo
+        + TypeInfo
+        + EventsRegister
+        + ExternalCallsRegister
+        + ContractInfo
+        + FnContext
+        + ErrorInfo,
+{
+    let is_unchecked = ctx.is_unchecked();
+    ctx.set_unchecked(true);
+    let block = block(stmts, ctx);
+    ctx.set_unchecked(is_unchecked);
+    block
+}
+
 /// Parses a block of statements and returns a `syn::Stmt` returning a value that
 /// the last statement returns.
 ///
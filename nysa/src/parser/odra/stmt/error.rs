@@ -7,17 +7,36 @@ use crate::parser::context::{
 use crate::parser::odra::expr;
 use crate::parser::odra::syn_utils::AsStatement;
 
-/// Generates a `revert` statement with an error message.
+/// Generates a `revert` statement with a custom error.
 ///
 /// # Arguments
 ///
-/// * `error_msg` - The error message to include in the `revert` statement.
+/// * `error_name` - The name of the custom error.
+/// * `args` - The error arguments.
+/// * `ctx` - A mutable reference to the context object that provides information about the contract, storage, types, etc.
 ///
 /// # Returns
 ///
 /// Returns a `ParserResult` containing the generated `revert` statement as a `syn::Stmt`.
-pub(crate) fn revert_with_msg(error_msg: &str) -> ParserResult<syn::Stmt> {
-    Ok(expr::error::revert_with_err(error_msg).as_statement())
+pub(crate) fn revert_with_error<T>(
+    error_name: &str,
+    args: &[Expression],
+    ctx: &mut T,
+) -> ParserResult<syn::Stmt>
+where
+    T: StorageInfo
+        + TypeInfo
+        + EventsRegister
+        + ExternalCallsRegister
+        + ContractInfo
+        + FnContext
+        + ErrorInfo,
+{
+    let revert = expr::error::revert_with_custom_err(None, error_name, args, ctx)?;
+    match revert {
+        syn::Expr::Block(_) => Ok(syn::Stmt::Expr(revert)),
+        _ => Ok(revert.as_statement()),
+    }
 }
 
 /// Generates a `revert` statement with an optional error message.
@@ -83,7 +102,7 @@ mod tests {
 
     #[test]
     fn revert_with_error() {
-        let stmt = Stmt::RevertWithError("MyError".to_string());
+        let stmt = Stmt::RevertWithError("MyError".to_string(), vec![]);
 
         assert_tokens_eq(
             unsafe_parse_with_empty_context(stmt),
@@ -1,6 +1,6 @@
 use crate::{
     error::ParserResult,
-    model::ir::Stmt,
+    model::ir::{Expression, Stmt},
     parser::context::{
         ContractInfo, ErrorInfo, EventsRegister, ExternalCallsRegister, FnContext, StorageInfo,
         TypeInfo,
@@ -35,9 +35,15 @@ where
         + ErrorInfo,
 {
     match stmt {
+        Stmt::Expression(Expression::Assign(box Expression::Variable(name), Some(box target)))
+            if ctx.get_storage_ref(name).is_some() =>
+        {
+            variables::repoint_storage_ref(name, target, ctx)
+        }
         Stmt::Expression(expr) => expr::parse_expr(expr, is_semi, ctx),
         Stmt::VarDefinition(name, ty, init) => variables::definition(name, ty, init, ctx),
         Stmt::VarDeclaration(name, ty) => variables::declaration(name, ty, ctx),
+        Stmt::StorageRef(name, ty, target) => variables::storage_ref(name, ty, target, ctx),
         Stmt::Return(expr) => ret::ret(expr, ctx),
         Stmt::ReturnVoid => ret::ret_unit(),
         Stmt::If(assertion, if_body) => control_flow::if_stmt(assertion, if_body, ctx),
@@ -45,10 +51,11 @@ where
             control_flow::if_else_stmt(assertion, if_body, else_body, ctx)
         }
         Stmt::Block(stmts) => block::block(stmts, ctx),
+        Stmt::UncheckedBlock(stmts) => block::unchecked_block(stmts, ctx),
         Stmt::ReturningBlock(stmts) => block::ret_block(stmts, ctx),
         Stmt::Emit(expr) => event::emit(expr, ctx),
         Stmt::Revert(msg) => error::revert(msg, ctx),
-        Stmt::RevertWithError(msg) => error::revert_with_msg(msg),
+        Stmt::RevertWithError(name, args) => error::revert_with_error(name, args, ctx),
         Stmt::While(assertion, block) => control_flow::while_loop(assertion, block, ctx),
         #[cfg(test)]
         Stmt::Fail => Err(crate::ParserError::InvalidStatement("Fail")),
@@ -1,10 +1,13 @@
+use syn::parse_quote;
+
 use crate::error::ParserResult;
-use crate::model::ir::Expression;
+use crate::formatted_invalid_expr;
+use crate::model::ir::{Expression, TupleItem};
 use crate::parser::context::{
     ContractInfo, ErrorInfo, EventsRegister, ExternalCallsRegister, FnContext, StorageInfo,
     TypeInfo,
 };
-use crate::parser::odra::expr;
+use crate::parser::odra::{expr, syn_utils::in_context};
 
 use super::syn_utils;
 
@@ -26,12 +29,28 @@ where
         + FnContext
         + ErrorInfo,
 {
-    let ret_ty = ctx.current_fn().ret_ty();
+    let ret_types = ctx.current_fn().ret_types();
     // to find out the type of returned value, parsing `expr` need more context
     // in it needs to know the type from the function signature.
-    ctx.push_contextual_expr(ret_ty);
-    let ret = expr::primitives::get_var_or_parse(expr, ctx)?;
-    ctx.drop_contextual_expr();
+    let ret = match expr {
+        // each item of a tuple is parsed in the context of its own type
+        Expression::Tuple(items) if items.len() == ret_types.len() => {
+            let items = items
+                .iter()
+                .zip(ret_types.iter())
+                .map(|(item, ty)| match item {
+                    TupleItem::Expr(e) => {
+                        in_context(ty, ctx, |ctx| expr::primitives::get_var_or_parse(e, ctx))
+                    }
+                    _ => formatted_invalid_expr!("tuple parsing failed"),
+                })
+                .collect::<ParserResult<Vec<syn::Expr>>>()?;
+            parse_quote!(( #(#items),* ))
+        }
+        _ => in_context(&ctx.current_fn().ret_ty(), ctx, |ctx| {
+            expr::primitives::get_var_or_parse(expr, ctx)
+        })?,
+    };
     Ok(syn_utils::ret(Some(ret)))
 }
 
@@ -8,6 +8,10 @@ pub fn definition(ident: Ident, expr: syn::Expr) -> syn::Stmt {
     parse_quote!(let mut #ident = #expr;)
 }
 
+pub fn empty() -> syn::Stmt {
+    syn::Stmt::Item(syn::Item::Verbatim(Default::default()))
+}
+
 pub fn ret(expr: Option<syn::Expr>) -> syn::Stmt {
     parse_quote!(return #expr;)
 }
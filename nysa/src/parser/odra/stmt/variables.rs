@@ -5,7 +5,10 @@ use crate::parser::context::{
     TypeInfo,
 };
 use crate::parser::odra::expr::{self, primitives};
+use crate::parser::odra::syn_utils::in_context;
 use crate::utils;
+use crate::ParserError;
+use syn::parse_quote;
 
 use super::syn_utils;
 
@@ -59,11 +62,138 @@ where
         + ErrorInfo,
 {
     let name = utils::to_snake_case_ident(name);
-    let expr = primitives::get_var_or_parse(init, ctx)?;
+    let expr = in_context(&Expression::Type(ty.clone()), ctx, |ctx| {
+        primitives::get_var_or_parse(init, ctx)
+    })?;
     register_var(&name, ty, init, ctx);
     Ok(syn_utils::definition(name, expr))
 }
 
+/// A storage pointer definition. In Odra a storage item cannot be borrowed, so no variable is
+/// created - the context keeps track of the referenced storage expression and any further
+/// access to the pointer is translated into a direct storage read/write.
+///
+/// The collection keys are evaluated into hidden local variables, so the pointer keeps
+/// referring to the same item if a key variable changes later. The pointer is valid
+/// until the end of the block it is defined in.
+///
+/// Updates the context - registers a local variable, the key variables and a storage reference.
+///
+/// ## Solidity example
+/// `Position storage p = positions[id];`
+///
+/// ## Arguments
+/// * name - variable name
+/// * ty - variable type
+/// * target - the storage expression the variable points to
+/// * ctx - parser context
+pub(super) fn storage_ref<T>(
+    name: &str,
+    ty: &Type,
+    target: &Expression,
+    ctx: &mut T,
+) -> ParserResult<syn::Stmt>
+where
+    T: StorageInfo
+        + TypeInfo
+        + EventsRegister
+        + ExternalCallsRegister
+        + ContractInfo
+        + FnContext
+        + ErrorInfo,
+{
+    let target = primitives::resolve_storage_ref(target, ctx);
+    let (target, keys) = hoist_keys(name, &target, ctx)?;
+    register_var(name, ty, &target, ctx);
+    ctx.register_storage_ref(name, &target);
+    Ok(keys)
+}
+
+/// Re-points a storage pointer to another storage item, eg. `p = positions[otherId];`.
+///
+/// The pointer is resolved statically, so it may be re-pointed only in the block it is defined in,
+/// a pointer re-pointed in a nested (eg. conditional) block is not supported.
+pub(super) fn repoint_storage_ref<T>(
+    name: &str,
+    target: &Expression,
+    ctx: &mut T,
+) -> ParserResult<syn::Stmt>
+where
+    T: StorageInfo
+        + TypeInfo
+        + EventsRegister
+        + ExternalCallsRegister
+        + ContractInfo
+        + FnContext
+        + ErrorInfo,
+{
+    if !ctx.is_storage_ref_in_scope(name) {
+        return Err(ParserError::InvalidStatement(
+            "A storage pointer must be re-pointed in the block it is defined in",
+        ));
+    }
+    let target = primitives::resolve_storage_ref(target, ctx);
+    let (target, keys) = hoist_keys(name, &target, ctx)?;
+    ctx.register_storage_ref(name, &target);
+    Ok(keys)
+}
+
+/// Replaces non-literal keys of a storage collection item with hidden local variables.
+///
+/// Returns the updated target and a statement defining the variables.
+fn hoist_keys<T>(
+    name: &str,
+    target: &Expression,
+    ctx: &mut T,
+) -> ParserResult<(Expression, syn::Stmt)>
+where
+    T: StorageInfo
+        + TypeInfo
+        + EventsRegister
+        + ExternalCallsRegister
+        + ContractInfo
+        + FnContext
+        + ErrorInfo,
+{
+    match target {
+        Expression::Collection(collection, keys) => {
+            let mut idents = vec![];
+            let mut values = vec![];
+            let mut hoisted_keys = vec![];
+            for (idx, key) in keys.iter().enumerate() {
+                if matches!(
+                    key,
+                    Expression::NumberLiteral(_) | Expression::BoolLiteral(_)
+                ) {
+                    hoisted_keys.push(key.clone());
+                    continue;
+                }
+                let key_name = primitives::storage_key_name(name, idx);
+                values.push(primitives::get_var_or_parse(key, ctx)?);
+                idents.push(utils::to_snake_case_ident(&key_name));
+                let key_ty = eval_expression_type(key, ctx).unwrap_or(Type::Unknown);
+                ctx.register_local_var(&key_name, &key_ty);
+                hoisted_keys.push(Expression::Variable(key_name));
+            }
+            let target = Expression::Collection(collection.clone(), hoisted_keys);
+            let stmt = match (idents.as_slice(), values.as_slice()) {
+                ([], _) => syn_utils::empty(),
+                ([ident], [value]) => parse_quote!(let #ident = #value;),
+                _ => parse_quote!(let (#(#idents),*) = (#(#values),*);),
+            };
+            Ok((target, stmt))
+        }
+        Expression::MemberAccess(field, base) => {
+            let (base, stmt) = hoist_keys(name, base, ctx)?;
+            Ok((
+                Expression::MemberAccess(field.clone(), Box::new(base)),
+                stmt,
+            ))
+        }
+        _ => Ok((target.clone(), syn_utils::empty())),
+    }
+}
+
 fn register_var<T, S>(name: S, ty: &Type, init: &Expression, ctx: &mut T)
 where
     T: TypeInfo + ContractInfo + FnContext,
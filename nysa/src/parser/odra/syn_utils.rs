@@ -78,6 +78,10 @@ pub mod ty {
         parse_quote!(odra::Mapping<#key, #value>)
     }
 
+    pub fn struct_map<T: ToTokens, U: ToTokens>(key: T, value: U) -> syn::Type {
+        parse_quote!(nysa_types::Mapping<#key, #value>)
+    }
+
     pub fn option<T: ToTokens>(ty: T) -> syn::Type {
         parse_quote!(Option<#ty>)
     }
@@ -98,13 +102,25 @@ pub mod ty {
         parse_quote!(odra::prelude::vec::Vec<#ty>)
     }
 
+    pub fn fixed_array<T: ToTokens>(ty: T, len: usize) -> syn::Type {
+        parse_quote!(nysa_types::FixedArray<#ty, #len>)
+    }
+
     pub fn fixed_bytes(size: usize) -> syn::Type {
         parse_quote!(nysa_types::FixedBytes<#size>)
     }
 
+    pub fn bytes() -> syn::Type {
+        parse_quote!(nysa_types::Bytes)
+    }
+
     pub fn u256() -> syn::Type {
         parse_quote!(nysa_types::U256)
     }
+
+    pub fn internal_function() -> syn::Type {
+        parse_quote!(InternalFunction)
+    }
 }
 
 pub mod attr {
@@ -119,7 +135,7 @@ pub mod attr {
     }
 
     pub fn derive_odra_ty() -> syn::Attribute {
-        syn::parse_quote!(#[derive(odra::OdraType, PartialEq, Eq, Debug, Default)])
+        syn::parse_quote!(#[derive(odra::OdraType, Clone, PartialEq, Eq, Debug, Default)])
     }
 
     pub fn derive_odra_err() -> syn::Attribute {
@@ -34,6 +34,26 @@ fn test_array() {
     test_single("types", "array");
 }
 
+#[test]
+fn test_mapping() {
+    test_single("types", "mapping");
+}
+
+#[test]
+fn test_fixed_array() {
+    test_single("types", "fixed_array");
+}
+
+#[test]
+fn test_function_type() {
+    test_single("types", "function_type");
+}
+
+#[test]
+fn test_value_type() {
+    test_single("types", "value_type");
+}
+
 #[test]
 fn test_enum() {
     test_single("types", "enum");
@@ -44,6 +64,16 @@ fn test_bytes() {
     test_single("types", "bytes");
 }
 
+#[test]
+fn test_dynamic_bytes() {
+    test_single("types", "dynamic_bytes");
+}
+
+#[test]
+fn test_fixed_bytes() {
+    test_single("types", "fixed_bytes");
+}
+
 #[test]
 fn test_cast() {
     test_single("types", "cast");
@@ -65,6 +95,96 @@ fn test_if_else() {
     test_single("conditionals", "ifelse");
 }
 
+#[test]
+fn test_diamond_inheritance() {
+    test_single("inheritance", "diamond");
+}
+
+#[test]
+fn test_inherited_hooks() {
+    test_single("inheritance", "hooks");
+}
+
+#[test]
+fn test_invalid_linearization() {
+    let src = read_file("../resources/inheritance/invalid.sol");
+    let ast = crate::utils::ast::parse(src).unwrap();
+    assert!(matches!(
+        crate::parser::preprocess(&ast),
+        Err(crate::ParserError::InvalidInheritance(_))
+    ));
+}
+
+#[test]
+fn test_type_info() {
+    test_single("types", "type_info");
+}
+
+#[test]
+fn test_custom_errors() {
+    test_single("errors", "custom");
+}
+
+#[test]
+fn test_colliding_errors() {
+    test_single("errors", "colliding");
+}
+
+#[test]
+fn test_require() {
+    test_single("errors", "require");
+}
+
+#[test]
+fn test_panic() {
+    test_single("errors", "panic");
+}
+
+#[test]
+fn test_abi_encode_packed() {
+    test_single("abi", "packed");
+}
+
+#[test]
+fn test_abi_codec() {
+    test_single("abi", "codec");
+}
+
+#[test]
+fn test_conversions() {
+    test_single("types", "conversions");
+}
+
+#[test]
+fn test_widening() {
+    test_single("types", "widening");
+}
+
+#[test]
+fn test_widening_args() {
+    test_single("types", "widening_args");
+}
+
+#[test]
+fn test_literals() {
+    test_single("types", "literals");
+}
+
+#[test]
+fn test_fixed() {
+    test_single("types", "fixed");
+}
+
+#[test]
+fn test_crypto_builtins() {
+    test_single("crypto", "builtins");
+}
+
+#[test]
+fn test_eip712() {
+    test_single("crypto", "eip712");
+}
+
 #[test]
 fn test_lib_math() {
     test_single("library", "math");
@@ -75,6 +195,16 @@ fn test_lib_safe_math() {
     test_single("library", "safe_math");
 }
 
+#[test]
+fn test_lib_using_for() {
+    test_single("library", "using_for");
+}
+
+#[test]
+fn test_lib_enumerable_set() {
+    test_single("library", "enumerable_set");
+}
+
 #[test]
 #[ignore]
 fn test_lib_mapping() {
@@ -112,6 +242,7 @@ fn assert_impl<P: AsRef<Path>>(result: TokenStream, file_path: P) {
     let content = read_file(file_path);
     let content = content.replace("{{STACK_DEF}}", STACK_DEF);
     let content = content.replace("{{DEFAULT_MODULES}}", DEFAULT_MODULES);
+    let content = content.replace("{{PANIC_DEF}}", PANIC_DEF);
     let content = content.replace("{{DEFAULT_IMPORTS}}", DEFAULT_IMPORTS);
 
     pretty_assertions::assert_eq!(parse(result.to_string().as_str()), parse(content.as_str()));
@@ -136,7 +267,9 @@ fn read_file<P: AsRef<Path>>(file_path: P) -> String {
 }
 
 const DEFAULT_MODULES: &str = r#"
-pub mod errors {}
+pub mod errors {
+    {{PANIC_DEF}}
+}
 pub mod events {
     use odra::prelude::*;
 }
@@ -144,6 +277,22 @@ pub mod enums {}
 pub mod structs {}
 "#;
 
+const PANIC_DEF: &str = r#"
+#[derive(odra::OdraError, PartialEq, Eq, Debug)]
+pub enum Panic {
+    Generic = 0x4e00,
+    Assert = 0x4e01,
+    ArithmeticOverflow = 0x4e11,
+    DivisionByZero = 0x4e12,
+    EnumConversion = 0x4e21,
+    StorageEncoding = 0x4e22,
+    EmptyArrayPop = 0x4e31,
+    IndexOutOfBounds = 0x4e32,
+    OutOfMemory = 0x4e41,
+    ZeroFunction = 0x4e51,
+}
+"#;
+
 const DEFAULT_IMPORTS: &str = r#"
 use super::errors::*;
 use super::events::*;
@@ -1,10 +1,10 @@
-use proc_macro2::TokenStream;
+use proc_macro2::{Literal, TokenStream};
 use quote::{format_ident, quote};
 use syn::parse_quote;
 
 use crate::{
     error::ParserResult,
-    model::ir::{Expression, Type},
+    model::ir::{Expression, Type, ValueType},
     parser::context::{ItemType, TypeInfo},
     utils, ParserError,
 };
@@ -31,11 +31,17 @@ pub fn parse_state_ty<T: TypeInfo>(ty: &Type, ctx: &T) -> ParserResult<syn::Type
         Type::Bool => Ok(var(bool())),
         Type::Int(size) => Ok(var(build_int(*size))),
         Type::Uint(size) => Ok(var(build_uint(*size))),
+        Type::Fixed(m, n) => Ok(var(build_fixed(*m, *n, true))),
+        Type::Ufixed(m, n) => Ok(var(build_fixed(*m, *n, false))),
         Type::Custom(name) => ctx
             .type_from_string(name)
-            .map(|ty| match ty {
+            .map(|item| match item {
                 ItemType::Contract(_) | ItemType::Interface(_) => var(option(address())),
-                ItemType::Enum(_) | ItemType::Struct(_) => var(utils::to_ident(name)),
+                // a struct may be defined in a library, eg. `counters::Counter`
+                ItemType::Enum(_) | ItemType::Struct(_) => {
+                    var(parse_type_from_ty(ty, ctx).expect("Should be a valid type"))
+                }
+                ItemType::ValueType(v) => var(value_type(&v)),
                 ItemType::Event => todo!(),
                 ItemType::Storage(_) => todo!(),
                 ItemType::Local(_) => todo!(),
@@ -43,10 +49,19 @@ pub fn parse_state_ty<T: TypeInfo>(ty: &Type, ctx: &T) -> ParserResult<syn::Type
             })
             .ok_or(ParserError::InvalidType),
         Type::Bytes(i) => Ok(var(fixed_bytes(*i as usize))),
+        Type::DynamicBytes => Ok(var(bytes())),
         Type::Array(ty) => {
             let ty = parse_type_from_ty(ty, ctx)?;
             Ok(var(vec(ty)))
         }
+        Type::FixedArray(ty, len) => {
+            let ty = parse_type_from_ty(ty, ctx)?;
+            Ok(var(fixed_array(ty, *len)))
+        }
+        Type::Function(f) if f.is_external => Err(ParserError::ExternalFunctionType(ty.clone())),
+        // A function pointer cannot be serialized, the storage keeps a variant of the `InternalFunction`
+        // enum instead, an unset variable is a zero function.
+        Type::Function(_) => Ok(var(option(internal_function()))),
         Type::Unknown => Err(ParserError::InvalidType),
     }
 }
@@ -65,8 +80,13 @@ pub fn parse_type_from_expr<T: TypeInfo>(
         }
         Expression::Variable(name) => match ctx.type_from_string(name) {
             Some(ItemType::Enum(_) | ItemType::Struct(_)) => Ok(utils::to_ident(name).as_type()),
+            Some(ItemType::ValueType(v)) => Ok(value_type(&v)),
             _ => Err(err()),
         },
+        Expression::Collection(_, _) => {
+            let ty = Type::try_from(expr).map_err(|_| err())?;
+            parse_type_from_ty(&ty, ctx)
+        }
         _ => Err(err()),
     }
 }
@@ -78,12 +98,21 @@ pub fn parse_type_from_ty<T: TypeInfo>(ty: &Type, t: &T) -> Result<syn::Type, Pa
         Type::Bool => Ok(bool()),
         Type::Int(size) => Ok(build_int(*size).as_type()),
         Type::Uint(size) => Ok(build_uint(*size).as_type()),
+        Type::Fixed(m, n) => Ok(build_fixed(*m, *n, true).as_type()),
+        Type::Ufixed(m, n) => Ok(build_fixed(*m, *n, false).as_type()),
+        // A mapping in a struct is a part of the struct value, nested mappings are flattened
+        // like in a mapping state variable.
         Type::Mapping(key, value) => {
             let key = parse_type_from_expr(key, t)?;
-            let value = parse_type_from_expr(value, t)?;
-            Ok(map(key, value))
+            let (key, value) = compose_key(vec![key], value, t)?;
+            let key = match key.len() {
+                1 => key[0].clone(),
+                _ => parse_quote!((#(#key,)*)),
+            };
+            Ok(struct_map(key, value))
         }
         Type::Bytes(len) => Ok(fixed_bytes(*len as usize)),
+        Type::DynamicBytes => Ok(bytes()),
         Type::Custom(name) => t
             .type_from_string(name)
             .map(|ty| match ty {
@@ -97,6 +126,7 @@ pub fn parse_type_from_ty<T: TypeInfo>(ty: &Type, t: &T) -> Result<syn::Type, Pa
                     let ident = utils::to_ident(name).as_type();
                     parse_quote!(#namespace #ident)
                 }
+                ItemType::ValueType(v) => value_type(&v),
                 ItemType::Event => todo!(),
                 ItemType::Storage(_) => todo!(),
                 ItemType::Local(_) => todo!(),
@@ -104,10 +134,46 @@ pub fn parse_type_from_ty<T: TypeInfo>(ty: &Type, t: &T) -> Result<syn::Type, Pa
             })
             .ok_or(ParserError::InvalidType),
         Type::Array(ty) => Ok(vec(parse_type_from_ty(ty, t)?)),
+        Type::FixedArray(ty, len) => Ok(fixed_array(parse_type_from_ty(ty, t)?, *len)),
+        Type::Function(f) if f.is_external => Err(ParserError::ExternalFunctionType(ty.clone())),
+        // An internal function is a module method, eg. `fn(&Self, nysa_types::U256) -> bool`
+        Type::Function(f) => {
+            let receiver = match f.is_mutable {
+                true => quote!(&mut Self),
+                false => quote!(&Self),
+            };
+            let params = f
+                .params
+                .iter()
+                .map(|p| parse_type_from_ty(p, t))
+                .collect::<Result<Vec<_>, _>>()?;
+            let returns = f
+                .returns
+                .iter()
+                .map(|r| parse_type_from_ty(r, t))
+                .collect::<Result<Vec<_>, _>>()?;
+            let ret = match returns.len() {
+                0 => None,
+                1 => Some(quote!(-> #(#returns)*)),
+                _ => Some(quote!(-> (#(#returns),*))),
+            };
+            Ok(parse_quote!(fn(#receiver, #(#params),*) #ret))
+        }
         Type::Unknown => Err(ParserError::InvalidType),
     }
 }
 
+/// A user-defined value type is a newtype defined in the structs module, eg. `my_contract::Price`.
+pub fn value_type(ty: &ValueType) -> syn::Type {
+    let namespace = ty
+        .namespace
+        .as_ref()
+        .map(utils::to_snake_case_ident)
+        .map(|i| quote!(#i::));
+    let ident = utils::to_ident(&ty.name);
+    parse_quote!(#namespace #ident)
+}
+
 fn build_int(size: u16) -> TokenStream {
     let s = format_ident!("I{}", size);
     quote::quote!(nysa_types::#s)
@@ -118,6 +184,22 @@ fn build_uint(size: u16) -> TokenStream {
     quote::quote!(nysa_types::#s)
 }
 
+/// Builds a fixed-point type, eg. `nysa_types::Fixed::<128, 2, 18>` - the size, the number of limbs
+/// and the number of decimals.
+///
+/// The turbofish form is valid both as a type and in an expression, eg. `nysa_types::Fixed::<128, 2, 18>::ZERO`.
+fn build_fixed(size: u16, decimals: u8, is_signed: bool) -> TokenStream {
+    let ty = if is_signed {
+        format_ident!("Fixed")
+    } else {
+        format_ident!("Ufixed")
+    };
+    let limbs = Literal::usize_unsuffixed((size as usize).div_ceil(64));
+    let size = Literal::u16_unsuffixed(size);
+    let decimals = Literal::u8_unsuffixed(decimals);
+    quote::quote!(nysa_types::#ty::<#size, #limbs, #decimals>)
+}
+
 fn compose_key<T: TypeInfo>(
     parts: Vec<syn::Type>,
     value: &Expression,
@@ -1,12 +1,11 @@
 use c3_lang_parser::c3_ast::VarDef;
-use proc_macro2::TokenStream;
-use syn::{parse_quote, punctuated::Punctuated, Token};
+use syn::parse_quote;
 
 use crate::{
-    model::ir::{Expression, Type, Var},
+    model::ir::{Expression, Type, UnaryOp, Var},
     parser::{
         context::{ContractInfo, TypeInfo},
-        odra::expr,
+        odra::expr::{self, abi, num},
     },
     utils, ParserError,
 };
@@ -43,20 +42,29 @@ pub fn const_def<T: TypeInfo + ContractInfo>(ctx: &mut T) -> Result<Vec<syn::Ite
                     Ok(parse_quote!(pub const #const_ident: &str = #s;))
                 }
                 Expression::NumberLiteral(n) => {
-                    if let Type::Uint(size) | Type::Int(size) = v.ty {
-                        let words = to_sized_u64_words(n, size.div_ceil(64) as usize);
-                        let num = words_to_number(words, &ty);
-                        Ok(parse_quote!(pub const #const_ident: #ty = #num;))
-                    } else {
-                        Err(ParserError::InvalidType)
-                    }
+                    let value = number_const(n, false, &v.ty, ctx)?;
+                    Ok(parse_quote!(pub const #const_ident: #ty = #value;))
+                }
+                // a negative constant, eg. `int256 constant MIN = -1`
+                Expression::UnaryOp(box Expression::NumberLiteral(n), UnaryOp::Minus) => {
+                    let value = number_const(n, true, &v.ty, ctx)?;
+                    Ok(parse_quote!(pub const #const_ident: #ty = #value;))
+                }
+                Expression::RationalLiteral(numer, denom) => {
+                    let value = num::to_fixed_expr(numer, denom, false, &v.ty, ctx)?;
+                    Ok(parse_quote!(pub const #const_ident: #ty = #value;))
+                }
+                Expression::UnaryOp(
+                    box Expression::RationalLiteral(numer, denom),
+                    UnaryOp::Minus,
+                ) => {
+                    let value = num::to_fixed_expr(numer, denom, true, &v.ty, ctx)?;
+                    Ok(parse_quote!(pub const #const_ident: #ty = #value;))
                 }
                 Expression::BytesLiteral(bytes) => {
-                    if let Type::Uint(size) | Type::Int(size) = v.ty {
-                        let bytes = bytes.iter().rev().map(|u| *u).collect::<Vec<_>>();
-                        let words = to_bytes_u64_words(&bytes, size.div_ceil(64) as usize);
-                        let num = words_to_number(words, &ty);
-                        Ok(parse_quote!(pub const #const_ident: #ty = #num;))
+                    if let Type::Uint(_) | Type::Int(_) = v.ty {
+                        let value = num::to_int_expr(&num::to_limbs(bytes), false, &v.ty, ctx)?;
+                        Ok(parse_quote!(pub const #const_ident: #ty = #value;))
                     } else if let Type::Bytes(b) = v.ty {
                         let value = expr::parse_bytes_lit(bytes)?;
                         Ok(parse_quote!(pub const #const_ident: #ty = #value;))
@@ -64,6 +72,21 @@ pub fn const_def<T: TypeInfo + ContractInfo>(ctx: &mut T) -> Result<Vec<syn::Ite
                         Err(ParserError::InvalidType)
                     }
                 }
+                Expression::Keccak256(args) => match args.as_slice() {
+                    [Expression::StringLiteral(s)] => {
+                        let value = expr::parse_bytes_lit(&abi::keccak256(s.as_bytes()))?;
+                        Ok(parse_quote!(pub const #const_ident: #ty = #value;))
+                    }
+                    [Expression::BytesLiteral(bytes)] => {
+                        let value = expr::parse_bytes_lit(&abi::keccak256(bytes))?;
+                        Ok(parse_quote!(pub const #const_ident: #ty = #value;))
+                    }
+                    // a constant is computed at transpile time, so must hash a literal
+                    _ => Err(ParserError::UnexpectedExpression(
+                        "keccak256 of a string or bytes literal",
+                        expr.clone(),
+                    )),
+                },
                 Expression::ArrayLiteral(_) => todo!(),
                 _ => todo!(),
             }
@@ -71,45 +94,24 @@ pub fn const_def<T: TypeInfo + ContractInfo>(ctx: &mut T) -> Result<Vec<syn::Ite
         .collect()
 }
 
+/// An integer literal may initialize both an integer and a fixed-point constant.
+fn number_const<T: TypeInfo>(
+    value: &[u64],
+    is_negative: bool,
+    ty: &Type,
+    ctx: &T,
+) -> Result<syn::Expr, ParserError> {
+    match ty {
+        Type::Fixed(_, _) | Type::Ufixed(_, _) => {
+            num::to_fixed_expr(value, &[1], is_negative, ty, ctx)
+        }
+        _ => num::to_int_expr(value, is_negative, ty, ctx),
+    }
+}
+
 /// Transforms [Var] into a c3 ast [VarDef].
 fn variable_def<T: TypeInfo>(v: &Var, t: &T) -> Result<VarDef, ParserError> {
     let ident = utils::to_snake_case_ident(&v.name);
     let ty = ty::parse_state_ty(&v.ty, t)?.as_type();
     Ok(VarDef { ident, ty })
 }
-
-fn to_bytes_u64_words(input: &[u8], size: usize) -> Vec<u64> {
-    let mut output = vec![0; size];
-    let mut idx = 0;
-    let mut start = 0;
-
-    while start < input.len() {
-        let end = std::cmp::min(start + 8, input.len());
-
-        let mut bytes = [0u8; 8];
-        for i in start..end {
-            bytes[i % 8] = input[i];
-        }
-        output[idx] = u64::from_le_bytes(bytes);
-
-        start = end;
-        idx += 1;
-    }
-    output
-}
-
-fn to_sized_u64_words(input: &[u64], size: usize) -> Vec<u64> {
-    let mut output = vec![0; size];
-    for i in 0..input.len() {
-        output[i] = input[i];
-    }
-    output
-}
-
-fn words_to_number(words: Vec<u64>, ty: &syn::Type) -> TokenStream {
-    let arr = words
-        .iter()
-        .map(|v| quote::quote!(#v))
-        .collect::<Punctuated<TokenStream, Token![,]>>();
-    quote::quote!(#ty::from_limbs([#arr]))
-}
@@ -0,0 +1,737 @@
+//! Static type checking of the IR.
+//!
+//! Walks each function body, tracks the types of local variables and checks if every value
+//! is used where Solidity allows it - assignments, returns, function call arguments and conditions.
+//! A type error is reported before any code is generated.
+use crate::{
+    error::ParserResult,
+    model::ir::{
+        eval_expression_type, find_attached_fn, Expression, Function, LogicalOp, MathOp, Package,
+        Stmt, TupleItem, Type, UnaryOp, Var,
+    },
+    utils::{self, ast::FREE_FUNCTIONS_LIB},
+    ParserError,
+};
+
+use super::context::{
+    ContractContext, ContractInfo, FnContext, GlobalContext, ItemType, LocalContext, TypeInfo,
+};
+
+/// Checks the types in all the contracts of the package.
+pub fn check_package(package: &Package, ctx: &mut GlobalContext) -> ParserResult<()> {
+    package.contracts().iter().try_for_each(|data| {
+        let mut ctx = LocalContext::new(ContractContext::new(ctx, data.clone()));
+        check_contract(&mut ctx)
+    })
+}
+
+/// Checks the types in all the functions of the current contract.
+pub fn check_contract<T: TypeInfo + ContractInfo + FnContext>(ctx: &mut T) -> ParserResult<()> {
+    for implementations in ctx.current_contract().fn_implementations() {
+        // each implementation has its own local variables
+        for (_, f) in implementations.implementations.iter() {
+            ctx.set_current_fn(&implementations);
+            let result = check_fn(f, ctx);
+            ctx.clear_current_fn();
+            result?;
+        }
+    }
+    Ok(())
+}
+
+fn check_fn<T: TypeInfo + ContractInfo + FnContext>(f: &Function, ctx: &mut T) -> ParserResult<()> {
+    f.params()
+        .iter()
+        .for_each(|p| ctx.register_local_var(&p.name, &p.ty));
+    match f {
+        Function::Function(f) => {
+            f.ret
+                .iter()
+                .filter_map(|(name, ty)| Some((name.as_ref()?, Type::try_from(ty).ok()?)))
+                .for_each(|(name, ty)| ctx.register_local_var(name, &ty));
+            check_stmts(&f.stmts, ctx)
+        }
+        Function::Constructor(c) => check_stmts(&c.stmts, ctx),
+        Function::Modifier(m) => {
+            check_stmts(&m.before_stmts, ctx)?;
+            check_stmts(&m.after_stmts, ctx)
+        }
+    }
+}
+
+fn check_stmts<T: TypeInfo + ContractInfo + FnContext>(
+    stmts: &[Stmt],
+    ctx: &mut T,
+) -> ParserResult<()> {
+    stmts.iter().try_for_each(|stmt| check_stmt(stmt, ctx))
+}
+
+fn check_stmt<T: TypeInfo + ContractInfo + FnContext>(
+    stmt: &Stmt,
+    ctx: &mut T,
+) -> ParserResult<()> {
+    match stmt {
+        Stmt::Return(expr) => {
+            check_expr(expr, ctx)?;
+            check_return(expr, ctx)
+        }
+        Stmt::Expression(expr) | Stmt::Emit(expr) | Stmt::Revert(Some(expr)) => {
+            check_expr(expr, ctx)
+        }
+        Stmt::VarDefinition(name, ty, init) => {
+            check_expr(init, ctx)?;
+            if ty == &Type::Unknown {
+                let ty = eval_expression_type(init, ctx).unwrap_or(Type::Unknown);
+                ctx.register_local_var(name, &ty);
+                Ok(())
+            } else {
+                ctx.register_local_var(name, ty);
+                expect_type(init, ty, ctx)
+            }
+        }
+        Stmt::VarDeclaration(name, ty) | Stmt::StorageRef(name, ty, _) => {
+            ctx.register_local_var(name, ty);
+            Ok(())
+        }
+        Stmt::If(condition, body) | Stmt::While(condition, body) => {
+            check_condition(condition, ctx)?;
+            check_stmt(body, ctx)
+        }
+        Stmt::IfElse(condition, if_body, else_body) => {
+            check_condition(condition, ctx)?;
+            check_stmt(if_body, ctx)?;
+            check_stmt(else_body, ctx)
+        }
+        // a local variable is visible until the end of the block it is defined in
+        Stmt::Block(stmts) | Stmt::UncheckedBlock(stmts) | Stmt::ReturningBlock(stmts) => {
+            ctx.push_scope();
+            let result = check_stmts(stmts, ctx);
+            ctx.drop_scope();
+            result
+        }
+        Stmt::RevertWithError(_, args) => args.iter().try_for_each(|arg| check_expr(arg, ctx)),
+        _ => Ok(()),
+    }
+}
+
+/// Checks if the returned value (or each item of a returned tuple) matches the declared type.
+fn check_return<T: TypeInfo + ContractInfo + FnContext>(
+    expr: &Expression,
+    ctx: &T,
+) -> ParserResult<()> {
+    let ret_types = ctx
+        .current_fn()
+        .ret_types()
+        .iter()
+        .map(|ty| Type::try_from(ty).ok())
+        .collect::<Vec<_>>();
+    match (expr, ret_types.as_slice()) {
+        (Expression::Tuple(items), _) if items.len() == ret_types.len() => items
+            .iter()
+            .zip(ret_types.iter())
+            .try_for_each(|(item, ty)| match (item, ty) {
+                (TupleItem::Expr(e), Some(ty)) => expect_type(e, ty, ctx),
+                _ => Ok(()),
+            }),
+        (_, [Some(ty)]) => expect_type(expr, ty, ctx),
+        _ => Ok(()),
+    }
+}
+
+/// Checks the expression and all its subexpressions.
+pub fn check_expr<T: TypeInfo + ContractInfo + FnContext>(
+    expr: &Expression,
+    ctx: &T,
+) -> ParserResult<()> {
+    match expr {
+        Expression::Require(condition, _) | Expression::Assert(condition) => {
+            check_condition(condition, ctx)
+        }
+        Expression::Assign(left, Some(right)) => {
+            check_expr(left, ctx)?;
+            check_expr(right, ctx)?;
+            match value_type(left, ctx) {
+                Some(ty) => expect_type(right, &ty, ctx),
+                None => Ok(()),
+            }
+        }
+        Expression::LogicalOp(left, right, LogicalOp::And | LogicalOp::Or) => {
+            check_condition(left, ctx)?;
+            check_condition(right, ctx)
+        }
+        Expression::MathOp(left, right, MathOp::Pow) => {
+            check_expr(left, ctx)?;
+            check_expr(right, ctx)
+        }
+        // a comparison or a mathematical operation requires operands of a common type
+        Expression::LogicalOp(left, right, _) | Expression::MathOp(left, right, _) => {
+            check_expr(left, ctx)?;
+            check_expr(right, ctx)?;
+            check_common_type(left, right, ctx)
+        }
+        Expression::Not(e) => check_condition(e, ctx),
+        Expression::UnaryOp(e, _) | Expression::Increment(e) | Expression::Decrement(e) => {
+            check_expr(e, ctx)
+        }
+        Expression::BitwiseOp(left, right, _) | Expression::AssignAnd(left, right, _) => {
+            check_expr(left, ctx)?;
+            check_expr(right, ctx)
+        }
+        Expression::Collection(_, keys) => keys.iter().try_for_each(|k| check_expr(k, ctx)),
+        Expression::Index(base, keys) => {
+            check_expr(base, ctx)?;
+            keys.iter().try_for_each(|k| check_expr(k, ctx))
+        }
+        Expression::MemberAccess(_, e) => check_expr(e, ctx),
+        Expression::Tuple(items) => items.iter().try_for_each(|item| match item {
+            TupleItem::Expr(e) => check_expr(e, ctx),
+            _ => Ok(()),
+        }),
+        Expression::Func(_, args)
+        | Expression::ExternalCall(_, _, args)
+        | Expression::SuperCall(_, args) => {
+            args.iter().try_for_each(|arg| check_expr(arg, ctx))?;
+            check_call_args(expr, args, ctx)
+        }
+        Expression::Statement(stmt) => match &**stmt {
+            // a conditional expression `c ? a : b`
+            Stmt::IfElse(condition, if_body, else_body) => {
+                check_condition(condition, ctx)?;
+                check_branch(if_body, ctx)?;
+                check_branch(else_body, ctx)
+            }
+            _ => Ok(()),
+        },
+        _ => Ok(()),
+    }
+}
+
+fn check_branch<T: TypeInfo + ContractInfo + FnContext>(stmt: &Stmt, ctx: &T) -> ParserResult<()> {
+    match stmt {
+        Stmt::ReturningBlock(stmts) => stmts.iter().try_for_each(|stmt| match stmt {
+            Stmt::Expression(e) => check_expr(e, ctx),
+            _ => Ok(()),
+        }),
+        _ => Ok(()),
+    }
+}
+
+/// Checks the arguments of a call against the parameters of the function it resolves to.
+///
+/// An overloaded function is called if the arguments match any of the candidates.
+fn check_call_args<T: TypeInfo + ContractInfo>(
+    call: &Expression,
+    args: &[Expression],
+    ctx: &T,
+) -> ParserResult<()> {
+    let mut first_error = None;
+    for params in call_signatures(call, ctx)
+        .iter()
+        .filter(|params| params.len() == args.len())
+    {
+        match check_args(args, params, ctx) {
+            Ok(()) => return Ok(()),
+            Err(err) => {
+                first_error.get_or_insert(err);
+            }
+        }
+    }
+    first_error.map_or(Ok(()), Err)
+}
+
+fn check_args<T: TypeInfo + ContractInfo>(
+    args: &[Expression],
+    params: &[Type],
+    ctx: &T,
+) -> ParserResult<()> {
+    args.iter()
+        .zip(params.iter())
+        .try_for_each(|(arg, ty)| expect_type(arg, ty, ctx))
+}
+
+/// Returns the parameter types of the function the call resolves to - the first candidate
+/// the arguments match, otherwise the first one with the same number of parameters.
+pub(super) fn resolve_call<T: TypeInfo + ContractInfo>(
+    call: &Expression,
+    args: &[Expression],
+    ctx: &T,
+) -> Option<Vec<Type>> {
+    let candidates = call_signatures(call, ctx)
+        .into_iter()
+        .filter(|params| params.len() == args.len())
+        .collect::<Vec<_>>();
+    candidates
+        .iter()
+        .find(|params| check_args(args, params, ctx).is_ok())
+        .or(candidates.first())
+        .cloned()
+}
+
+/// Returns the parameter types of each function the call may resolve to.
+///
+/// A call resolves to a function of the current contract (or of a base contract, if called
+/// with `super`), a free function, a library or an external contract function, a function pointer,
+/// a struct initialization or an array `push`.
+fn call_signatures<T: TypeInfo + ContractInfo>(call: &Expression, ctx: &T) -> Vec<Vec<Type>> {
+    match call {
+        Expression::Func(f, _) => fn_signatures(f, ctx),
+        Expression::SuperCall(name, _) => internal_signatures(name, ctx),
+        Expression::ExternalCall(variable, fn_name, _) => {
+            ext_call_signatures(variable, fn_name, ctx)
+        }
+        _ => vec![],
+    }
+}
+
+fn fn_signatures<T: TypeInfo + ContractInfo>(f: &Expression, ctx: &T) -> Vec<Vec<Type>> {
+    match ctx.type_from_expression(f) {
+        // eg. op(a, b), where `op` is an internal function pointer
+        Some(
+            ItemType::Local(Var {
+                ty: Type::Function(f),
+                ..
+            })
+            | ItemType::Storage(Var {
+                ty: Type::Function(f),
+                ..
+            }),
+        ) => return vec![f.params],
+        // eg. Position(size, owner), a struct is initialized with the values of its fields
+        Some(ItemType::Struct(s)) => {
+            return s
+                .fields
+                .iter()
+                .map(|(_, ty)| Type::try_from(ty))
+                .collect::<Result<Vec<_>, _>>()
+                .into_iter()
+                .collect();
+        }
+        _ => {}
+    }
+    match f {
+        // eg. balances[owner].push(value) or a library function attached to the receiver
+        Expression::MemberAccess(fn_name, receiver) => {
+            match find_attached_fn(receiver, fn_name, ctx) {
+                Some((_, f)) => vec![attached_params(&f)],
+                None if fn_name == "push" => match value_type(receiver, ctx) {
+                    Some(Type::Array(item)) => vec![vec![*item]],
+                    _ => vec![],
+                },
+                None => vec![],
+            }
+        }
+        Expression::Variable(name) => internal_signatures(name, ctx),
+        _ => vec![],
+    }
+}
+
+/// Returns the signatures of a function of the current contract or a free function.
+fn internal_signatures<T: TypeInfo + ContractInfo>(name: &str, ctx: &T) -> Vec<Vec<Type>> {
+    let name = utils::to_snake_case(name);
+    let functions = match ctx
+        .current_contract()
+        .fn_implementations()
+        .into_iter()
+        .find(|f| f.name == name)
+    {
+        Some(f) => f.implementations.into_iter().map(|(_, f)| f).collect(),
+        None => ctx.find_fns(FREE_FUNCTIONS_LIB, &name),
+    };
+    signatures(&functions)
+}
+
+/// Returns the signatures of a function called on a library, a contract or a variable, eg.
+/// `Math.max(a, b)`, `token.transfer(to, amount)`, `values.push(value)` or `value.max(other)`,
+/// where a library is attached to the type of `value`.
+fn ext_call_signatures<T: TypeInfo + ContractInfo>(
+    variable: &str,
+    fn_name: &str,
+    ctx: &T,
+) -> Vec<Vec<Type>> {
+    let name = utils::to_snake_case(fn_name);
+    match ctx.type_from_string(variable) {
+        Some(ItemType::Library(_)) => signatures(&ctx.find_fns(variable, &name)),
+        Some(ItemType::Contract(class) | ItemType::Interface(class)) => {
+            signatures(&ctx.find_fns(&class, &name))
+        }
+        Some(ItemType::Storage(v) | ItemType::Local(v)) => {
+            let receiver = Expression::Variable(variable.to_owned());
+            if let Some((_, f)) = find_attached_fn(&receiver, fn_name, ctx) {
+                return vec![attached_params(&f)];
+            }
+            match v.ty {
+                Type::Custom(class) => signatures(&ctx.find_fns(&class, &name)),
+                Type::Array(item) if fn_name == "push" => vec![vec![*item]],
+                _ => vec![],
+            }
+        }
+        _ => vec![],
+    }
+}
+
+fn signatures(functions: &[Function]) -> Vec<Vec<Type>> {
+    functions
+        .iter()
+        .filter(|f| matches!(f, Function::Function(_)))
+        .map(|f| f.params().iter().map(|p| p.ty.clone()).collect())
+        .collect()
+}
+
+/// The receiver of an attached function is passed as the first argument.
+fn attached_params(f: &Function) -> Vec<Type> {
+    f.params().iter().skip(1).map(|p| p.ty.clone()).collect()
+}
+
+fn check_condition<T: TypeInfo + ContractInfo + FnContext>(
+    expr: &Expression,
+    ctx: &T,
+) -> ParserResult<()> {
+    check_expr(expr, ctx)?;
+    expect_type(expr, &Type::Bool, ctx)
+}
+
+/// Checks if the value is implicitly convertible to the expected type.
+///
+/// A literal takes the type from the context, an array literal is checked item by item.
+/// A value of a contract, a mapping, a function or an unknown type is not checked.
+fn expect_type<T: TypeInfo + ContractInfo>(
+    expr: &Expression,
+    expected: &Type,
+    ctx: &T,
+) -> ParserResult<()> {
+    if let (Expression::ArrayLiteral(items), Type::Array(item) | Type::FixedArray(item, _)) =
+        (expr, expected)
+    {
+        return items.iter().try_for_each(|i| expect_type(i, item, ctx));
+    }
+    if is_literal(expr) {
+        return Ok(());
+    }
+    let actual = value_type(expr, ctx).and_then(|ty| checked_type(&ty, ctx));
+    match (checked_type(expected, ctx), actual) {
+        (Some(expected), Some(actual)) if !is_convertible(&actual, &expected) => {
+            Err(ParserError::TypeMismatch(expected, actual))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Returns the type of the value, the type of an array variable is taken from the declaration
+/// as the variable evaluates to the item type.
+pub(super) fn value_type<T: TypeInfo + ContractInfo>(expr: &Expression, ctx: &T) -> Option<Type> {
+    match expr {
+        Expression::Variable(name) => match ctx.type_from_string(name) {
+            Some(ItemType::Storage(var) | ItemType::Local(var)) => Some(var.ty),
+            _ => eval_expression_type(expr, ctx),
+        },
+        _ => eval_expression_type(expr, ctx),
+    }
+}
+
+/// Returns the type a value is checked against - an elementary type, a struct, an enum
+/// or an array of them, a struct or an enum is referred to by its declared name.
+fn checked_type<T: TypeInfo>(ty: &Type, ctx: &T) -> Option<Type> {
+    match ty {
+        Type::Custom(name) => match ctx.type_from_string(name)? {
+            ItemType::Struct(s) => Some(Type::Custom(s.name)),
+            ItemType::Enum(e) => Some(Type::Custom(e)),
+            _ => None,
+        },
+        Type::Array(item) => checked_type(item, ctx).map(|item| Type::Array(Box::new(item))),
+        Type::FixedArray(item, len) => {
+            checked_type(item, ctx).map(|item| Type::FixedArray(Box::new(item), *len))
+        }
+        ty if is_elementary(ty) => Some(ty.clone()),
+        _ => None,
+    }
+}
+
+fn check_common_type<T: TypeInfo + ContractInfo>(
+    left: &Expression,
+    right: &Expression,
+    ctx: &T,
+) -> ParserResult<()> {
+    if is_literal(left) || is_literal(right) {
+        return Ok(());
+    }
+    match (
+        eval_expression_type(left, ctx),
+        eval_expression_type(right, ctx),
+    ) {
+        (Some(l), Some(r)) if is_elementary(&l) && is_elementary(&r) && l.common(&r).is_none() => {
+            Err(ParserError::TypeMismatch(l, r))
+        }
+        _ => Ok(()),
+    }
+}
+
+fn is_convertible(from: &Type, to: &Type) -> bool {
+    from == to || from.widens_to(to)
+}
+
+fn is_elementary(ty: &Type) -> bool {
+    matches!(
+        ty,
+        Type::Address
+            | Type::Bool
+            | Type::String
+            | Type::DynamicBytes
+            | Type::Uint(_)
+            | Type::Int(_)
+            | Type::Fixed(_, _)
+            | Type::Ufixed(_, _)
+            | Type::Bytes(_)
+    )
+}
+
+fn is_literal(expr: &Expression) -> bool {
+    match expr {
+        Expression::NumberLiteral(_)
+        | Expression::RationalLiteral(_, _)
+        | Expression::BytesLiteral(_)
+        | Expression::StringLiteral(_) => true,
+        Expression::UnaryOp(expr, UnaryOp::Minus) => is_literal(expr),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod t {
+    use super::*;
+    use crate::utils::ast;
+
+    fn check(src: &str) -> ParserResult<()> {
+        let solidity_ast = ast::parse(src).unwrap();
+        let package = super::super::preprocess(&solidity_ast).unwrap();
+        let mut ctx = GlobalContext::new(
+            package.events().to_vec(),
+            package.interfaces().to_vec(),
+            package.libraries().to_vec(),
+            package.enums().to_vec(),
+            package.errors().to_vec(),
+            package.contracts().to_vec(),
+            package.structs().to_vec(),
+            package.value_types().to_vec(),
+        );
+        check_package(&package, &mut ctx)
+    }
+
+    fn contract(body: &str) -> String {
+        format!("contract Test {{ uint256 total; {} }}", body)
+    }
+
+    #[test]
+    fn valid_types() {
+        let src = contract(
+            r#"
+            function double(uint64 a) internal pure returns (uint64) {
+                return a * 2;
+            }
+
+            function f(uint8 a, bool flag) public returns (uint256, bytes32) {
+                uint256 b = flag ? a : total;
+                total += double(a);
+                require(b > a, "too small");
+                bytes4 selector = 0x12345678;
+                return (b, selector);
+            }
+            "#,
+        );
+        assert_eq!(check(&src), Ok(()));
+    }
+
+    #[test]
+    fn block_scoped_locals_and_overloads() {
+        let src = contract(
+            r#"
+            function g(uint8 a) internal {}
+
+            function g(bool a) internal {}
+
+            function f(bool flag) public {
+                uint8 x = 1;
+                {
+                    bool x = flag;
+                    g(x);
+                }
+                uint16 y = x;
+                g(x);
+            }
+            "#,
+        );
+        assert_eq!(check(&src), Ok(()));
+    }
+
+    #[test]
+    fn type_mismatch() {
+        let cases = [
+            (
+                "function f(uint256 a) public { uint8 b = a; }",
+                Type::Uint(8),
+                Type::Uint(256),
+            ),
+            (
+                "function f(int8 a) public { total = a; }",
+                Type::Uint(256),
+                Type::Int(8),
+            ),
+            (
+                "function f(uint256 a) public returns (bool) { return a; }",
+                Type::Bool,
+                Type::Uint(256),
+            ),
+            (
+                "function f(uint256 a) public { require(a); }",
+                Type::Bool,
+                Type::Uint(256),
+            ),
+            (
+                "function f(uint256 a, int256 b) public returns (bool) { return a < b; }",
+                Type::Uint(256),
+                Type::Int(256),
+            ),
+            (
+                "function g(uint8 a) internal {} function f(uint16 a) public { g(a); }",
+                Type::Uint(8),
+                Type::Uint(16),
+            ),
+        ];
+        for (body, expected, actual) in cases {
+            assert_eq!(
+                check(&contract(body)),
+                Err(ParserError::TypeMismatch(expected, actual))
+            );
+        }
+    }
+
+    const DECLARATIONS: &str = r#"
+        interface IToken {
+            function mint(uint8 amount) external;
+            function mint(address to, uint8 amount) external;
+        }
+
+        library Math {
+            function half(uint8 a) internal pure returns (uint8) {
+                return a / 2;
+            }
+        }
+
+        enum Color { Red, Green }
+
+        enum Size { Small, Large }
+
+        struct Point { uint8 x; Color color; }
+
+        struct Line { Point start; Point end; }
+
+        contract Base {
+            function g(uint8 a) internal virtual {}
+        }
+    "#;
+
+    fn with_declarations(body: &str) -> String {
+        format!(
+            "{} contract Test is Base {{ IToken token; uint8[] values; {} }}",
+            DECLARATIONS, body
+        )
+    }
+
+    #[test]
+    fn valid_call_args() {
+        let src = with_declarations(
+            r#"
+            function g(uint8 a) internal override {
+                super.g(a);
+            }
+
+            function paint(Point memory p, Color c, uint8[2] memory pair) internal {}
+
+            function f(uint8 a, address to, uint8[] memory items) public {
+                token.mint(a);
+                token.mint(to, a);
+                Math.half(a);
+                values.push(a);
+                Point memory p = Point(a, Color.Red);
+                paint(p, Color.Green, [a, 1]);
+                Line memory l = Line(p, p);
+                uint8[] memory copy = items;
+            }
+            "#,
+        );
+        assert_eq!(check(&src), Ok(()));
+    }
+
+    #[test]
+    fn call_args_mismatch() {
+        let point = || Type::Custom("Point".to_string());
+        let cases = [
+            // an external call
+            (
+                "function f(uint16 a) public { token.mint(a); }",
+                Type::Uint(8),
+                Type::Uint(16),
+            ),
+            // a library call
+            (
+                "function f(uint16 a) public { Math.half(a); }",
+                Type::Uint(8),
+                Type::Uint(16),
+            ),
+            // a super call
+            (
+                "function g(uint8 a) internal override { uint16 b = a; super.g(b); }",
+                Type::Uint(8),
+                Type::Uint(16),
+            ),
+            // an array push
+            (
+                "function f(uint16 a) public { values.push(a); }",
+                Type::Uint(8),
+                Type::Uint(16),
+            ),
+            // a struct field
+            (
+                "function f(uint8 a, Size s) public { Point memory p = Point(a, s); }",
+                Type::Custom("Color".to_string()),
+                Type::Custom("Size".to_string()),
+            ),
+            // a struct
+            (
+                "function take(Point memory p) internal {} function f(Line memory l) public { take(l); }",
+                point(),
+                Type::Custom("Line".to_string()),
+            ),
+            // an enum
+            (
+                "function take(uint8 c) internal {} function f(Color c) public { take(c); }",
+                Type::Uint(8),
+                Type::Custom("Color".to_string()),
+            ),
+            // an array
+            (
+                "function take(uint256[] memory a) internal {} function f(uint8[] memory a) public { take(a); }",
+                Type::Array(Box::new(Type::Uint(256))),
+                Type::Array(Box::new(Type::Uint(8))),
+            ),
+            (
+                "function take(Point[2] memory a) internal {} function f(Point[] memory a) public { take(a); }",
+                Type::FixedArray(Box::new(point()), 2),
+                Type::Array(Box::new(point())),
+            ),
+            // an array literal
+            (
+                "function take(uint8[2] memory a) internal {} function f(uint16 a) public { take([a, a]); }",
+                Type::Uint(8),
+                Type::Uint(16),
+            ),
+        ];
+        for (body, expected, actual) in cases {
+            assert_eq!(
+                check(&with_declarations(body)),
+                Err(ParserError::TypeMismatch(expected, actual)),
+                "{}",
+                body
+            );
+        }
+    }
+}
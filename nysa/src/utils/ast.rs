@@ -1,16 +1,224 @@
+use std::collections::HashMap;
+
 use solidity_parser::{
     pt::{
-        ContractDefinition, ContractPart, ContractTy, EnumDefinition, ErrorDefinition,
-        EventDefinition, FunctionDefinition, SourceUnitPart, StructDefinition, Using,
-        VariableDefinition,
+        CatchClause, CodeLocation, ContractDefinition, ContractPart, ContractTy, EnumDefinition,
+        ErrorDefinition, EventDefinition, Expression, FunctionDefinition, Identifier, Loc,
+        SourceUnitPart, Statement, StructDefinition, Type, TypeDefinition, Using, UsingList,
+        VariableAttribute, VariableDefinition,
     },
     Diagnostic,
 };
 
+/// The name of the library gathering all the free (top level) functions.
+pub(crate) const FREE_FUNCTIONS_LIB: &str = "FreeFunctions";
+
 /// Parses
 pub(crate) fn parse<I: AsRef<str>>(input: I) -> Result<Vec<SourceUnitPart>, Vec<Diagnostic>> {
     let solidity_ast = solidity_parser::parse(input.as_ref(), 0);
-    solidity_ast.map(|ast| ast.0 .0)
+    solidity_ast.and_then(|ast| {
+        let mut parts = move_free_functions(ast.0 .0);
+        resolve_array_sizes(&mut parts)?;
+        Ok(parts)
+    })
+}
+
+/// Moves free (top level) functions to a library named [FREE_FUNCTIONS_LIB],
+/// so they can be processed like any other library function.
+fn move_free_functions(parts: Vec<SourceUnitPart>) -> Vec<SourceUnitPart> {
+    let (functions, mut parts): (Vec<_>, Vec<_>) = parts
+        .into_iter()
+        .partition(|part| matches!(part, SourceUnitPart::FunctionDefinition(_)));
+    if functions.is_empty() {
+        return parts;
+    }
+
+    let functions = functions
+        .into_iter()
+        .filter_map(|part| match part {
+            SourceUnitPart::FunctionDefinition(f) => Some(ContractPart::FunctionDefinition(f)),
+            _ => None,
+        })
+        .collect();
+    parts.push(SourceUnitPart::ContractDefinition(Box::new(
+        ContractDefinition {
+            doc: vec![],
+            loc: Loc::Implicit,
+            ty: ContractTy::Library(Loc::Implicit),
+            name: Identifier {
+                loc: Loc::Implicit,
+                name: FREE_FUNCTIONS_LIB.to_string(),
+            },
+            base: vec![],
+            parts: functions,
+        },
+    )));
+    parts
+}
+
+/// Replaces a constant length of a fixed-size array type with the constant value, eg. `uint256[SIZE]`.
+///
+/// The length must be known to build the array type, so a length which is neither a number literal
+/// nor a number constant is reported as an error.
+fn resolve_array_sizes(parts: &mut [SourceUnitPart]) -> Result<(), Vec<Diagnostic>> {
+    let constants = extract_constants(parts);
+    let mut errors = vec![];
+    let mut resolve = |ty: &mut Expression| resolve_array_size(ty, &constants, &mut errors);
+
+    for part in parts.iter_mut() {
+        match part {
+            SourceUnitPart::ContractDefinition(contract) => {
+                for part in contract.parts.iter_mut() {
+                    match part {
+                        ContractPart::VariableDefinition(var) => resolve(&mut var.ty),
+                        ContractPart::StructDefinition(def) => {
+                            def.fields.iter_mut().for_each(|f| resolve(&mut f.ty))
+                        }
+                        ContractPart::FunctionDefinition(func) => {
+                            visit_fn_types(func, &mut resolve)
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            SourceUnitPart::StructDefinition(def) => {
+                def.fields.iter_mut().for_each(|f| resolve(&mut f.ty))
+            }
+            SourceUnitPart::VariableDefinition(var) => resolve(&mut var.ty),
+            _ => {}
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Collects top level and contract level constants initialized with a number literal.
+fn extract_constants(parts: &[SourceUnitPart]) -> HashMap<String, Expression> {
+    let is_number_constant = |var: &VariableDefinition| {
+        var.attrs
+            .iter()
+            .any(|attr| matches!(attr, VariableAttribute::Constant(_)))
+            && matches!(
+                var.initializer,
+                Some(Expression::NumberLiteral(..) | Expression::HexNumberLiteral(..))
+            )
+    };
+    filter_source_unit_part(parts, |unit| match unit {
+        SourceUnitPart::ContractDefinition(contract) => {
+            let constants = filter_source_part(contract, |part| match part {
+                ContractPart::VariableDefinition(var) if is_number_constant(var) => {
+                    Some(var.as_ref())
+                }
+                _ => None,
+            });
+            Some(constants)
+        }
+        SourceUnitPart::VariableDefinition(var) if is_number_constant(var) => {
+            Some(vec![var.as_ref()])
+        }
+        _ => None,
+    })
+    .into_iter()
+    .filter_map(|var| {
+        var.initializer
+            .clone()
+            .map(|value| (var.name.name.to_owned(), value))
+    })
+    .collect()
+}
+
+fn resolve_array_size(
+    ty: &mut Expression,
+    constants: &HashMap<String, Expression>,
+    errors: &mut Vec<Diagnostic>,
+) {
+    match ty {
+        Expression::ArraySubscript(_, arr, size) => {
+            if let Some(size) = size {
+                let value = match size.as_ref() {
+                    Expression::NumberLiteral(..) | Expression::HexNumberLiteral(..) => None,
+                    Expression::Variable(id) if constants.contains_key(&id.name) => {
+                        constants.get(&id.name).cloned()
+                    }
+                    expr => {
+                        errors.push(Diagnostic::type_error(
+                            expr.loc(),
+                            "Array length must be a number literal or a constant".to_string(),
+                        ));
+                        None
+                    }
+                };
+                if let Some(value) = value {
+                    **size = value;
+                }
+            }
+            resolve_array_size(arr, constants, errors)
+        }
+        Expression::Type(_, Type::Mapping(_, _, value)) => {
+            resolve_array_size(value, constants, errors)
+        }
+        _ => {}
+    }
+}
+
+/// Calls `f` on the type of each parameter, return value and local variable of a function.
+fn visit_fn_types<F: FnMut(&mut Expression)>(func: &mut FunctionDefinition, f: &mut F) {
+    func.params
+        .iter_mut()
+        .chain(func.returns.iter_mut())
+        .filter_map(|(_, param)| param.as_mut())
+        .for_each(|param| f(&mut param.ty));
+    if let Some(body) = func.body.as_mut() {
+        visit_stmt_types(body, f);
+    }
+}
+
+fn visit_stmt_types<F: FnMut(&mut Expression)>(stmt: &mut Statement, f: &mut F) {
+    match stmt {
+        Statement::Block { statements, .. } => {
+            statements.iter_mut().for_each(|s| visit_stmt_types(s, f))
+        }
+        Statement::VariableDefinition(_, decl, _) => f(&mut decl.ty),
+        Statement::If(_, _, if_true, if_false) => {
+            visit_stmt_types(if_true, f);
+            if let Some(if_false) = if_false {
+                visit_stmt_types(if_false, f);
+            }
+        }
+        Statement::While(_, _, body) | Statement::DoWhile(_, body, _) => visit_stmt_types(body, f),
+        Statement::For(_, init, _, next, body) => [init, next, body]
+            .into_iter()
+            .flatten()
+            .for_each(|s| visit_stmt_types(s, f)),
+        Statement::Try(_, _, returns, clauses) => {
+            if let Some((params, body)) = returns {
+                params
+                    .iter_mut()
+                    .filter_map(|(_, param)| param.as_mut())
+                    .for_each(|param| f(&mut param.ty));
+                visit_stmt_types(body, f);
+            }
+            for clause in clauses {
+                match clause {
+                    CatchClause::Simple(_, param, body) => {
+                        if let Some(param) = param {
+                            f(&mut param.ty);
+                        }
+                        visit_stmt_types(body, f);
+                    }
+                    CatchClause::Named(_, _, param, body) => {
+                        f(&mut param.ty);
+                        visit_stmt_types(body, f);
+                    }
+                }
+            }
+        }
+        _ => {}
+    }
 }
 
 /// Filters [ContractDefinition] from solidity ast.
@@ -50,7 +258,7 @@ pub(crate) fn extract_vars(contract: &ContractDefinition) -> Vec<&VariableDefini
     })
 }
 
-/// Filters [Using] from a contract.
+/// Filters [Using] directives attaching a library or a list of functions from a contract.
 pub(crate) fn extract_using(contract: &ContractDefinition) -> Vec<&Using> {
     filter_source_part(contract, |part| match part {
         ContractPart::Using(u) => Some(u.as_ref()),
@@ -58,6 +266,16 @@ pub(crate) fn extract_using(contract: &ContractDefinition) -> Vec<&Using> {
     })
 }
 
+/// Filters top level [Using] directives, eg. `using L for T global;`.
+///
+/// A file is a single compilation unit, so a top level directive applies to all the contracts.
+pub(crate) fn extract_file_using(ast: &[SourceUnitPart]) -> Vec<&Using> {
+    filter_source_unit_part(ast, |unit| match unit {
+        SourceUnitPart::Using(u) => Some(vec![u.as_ref()]),
+        _ => None,
+    })
+}
+
 /// Iterates over [SourceUnitPart]s and collects all [EventDefinition]s.
 ///
 /// An [EventDefinition] may be at the top level or inside a [ContractDefinition].
@@ -112,6 +330,44 @@ pub(crate) fn extract_structs(ast: &[SourceUnitPart]) -> Vec<(Option<String>, &S
     })
 }
 
+/// Iterates over [SourceUnitPart]s and collects all [TypeDefinition]s along with it's namespace.
+///
+/// A [TypeDefinition] may be at the top level or inside a [ContractDefinition].
+/// A top level type does not have the namespace (is None).
+pub(crate) fn extract_type_definitions(
+    ast: &[SourceUnitPart],
+) -> Vec<(Option<String>, &TypeDefinition)> {
+    filter_source_unit_part(ast, |unit| match unit {
+        SourceUnitPart::ContractDefinition(contract) => {
+            let types = filter_source_part(contract, |part| match part {
+                ContractPart::TypeDefinition(ty) => {
+                    Some((Some(contract.name.name.to_owned()), ty.as_ref()))
+                }
+                _ => None,
+            });
+            Some(types)
+        }
+        SourceUnitPart::TypeDefinition(ty) => Some(vec![(None, ty.as_ref())]),
+        _ => None,
+    })
+}
+
+/// Iterates over [SourceUnitPart]s and collects all [Using] directives binding a list of functions, eg. `using {add as +} for Price global;`.
+pub(crate) fn extract_using_functions(ast: &[SourceUnitPart]) -> Vec<&Using> {
+    let is_fn_list = |u: &Using| matches!(u.list, UsingList::Functions(_));
+    filter_source_unit_part(ast, |unit| match unit {
+        SourceUnitPart::ContractDefinition(contract) => {
+            let using = filter_source_part(contract, |part| match part {
+                ContractPart::Using(u) if is_fn_list(u) => Some(u.as_ref()),
+                _ => None,
+            });
+            Some(using)
+        }
+        SourceUnitPart::Using(u) if is_fn_list(u) => Some(vec![u.as_ref()]),
+        _ => None,
+    })
+}
+
 /// Iterates over [SourceUnitPart]s and collects all [ErrorDefinition]s.
 ///
 /// An [ErrorDefinition] may be at the top level or inside a [ContractDefinition].
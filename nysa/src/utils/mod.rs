@@ -29,6 +29,11 @@ pub(crate) fn to_snake_case(input: &str) -> String {
     }
 }
 
+/// Converts a &str into pascal-cased Ident, eg. a function name into an enum variant.
+pub(crate) fn to_pascal_case_ident<T: AsRef<str>>(name: T) -> proc_macro2::Ident {
+    format_ident!("{}", name.as_ref().to_case(Case::Pascal))
+}
+
 /// Converts an `input` into [Ident](proc_macro2::Ident).
 pub(crate) fn to_ident<T: AsRef<str>>(input: T) -> proc_macro2::Ident {
     format_ident!("{}", input.as_ref())
@@ -53,11 +58,6 @@ where
     collection.iter().map(R::from).collect()
 }
 
-/// A type that can be represented as a vector of strings
-pub trait AsStringVec {
-    fn as_string_vec(&self) -> Vec<String>;
-}
-
 #[cfg(test)]
 mod t {
     use crate::utils::to_snake_case_ident;
@@ -0,0 +1,188 @@
+{{DEFAULT_MODULES}}
+pub mod payloads {
+    #![allow(unused_braces, unused_mut, unused_parens, non_snake_case, unused_imports)]
+    {{DEFAULT_IMPORTS}}
+    {{STACK_DEF}}
+
+    #[derive(Clone)]
+    enum ClassName {
+        Payloads,
+    }
+    #[odra::module]
+    pub struct Payloads {
+        __stack: PathStack,
+    }
+    #[odra::module]
+    impl Payloads {
+        const PATH: &'static [ClassName; 1usize] = &[ClassName::Payloads];
+        pub const TRANSFER_SELECTOR: nysa_types::FixedBytes<4usize> = nysa_types::FixedBytes([
+            169u8,
+            5u8,
+            156u8,
+            187u8,
+        ]);
+        pub fn approve_payload(
+            &self,
+            spender: Option<odra::Address>,
+            amount: nysa_types::U256,
+        ) -> nysa_types::Bytes {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_approve_payload(spender, amount);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_approve_payload(
+            &self,
+            spender: Option<odra::Address>,
+            amount: nysa_types::U256,
+        ) -> nysa_types::Bytes {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::Payloads => {
+                    return nysa_types::abi::encode_with_signature(
+                        odra::prelude::string::String::from("approve(address,uint256)"),
+                        &[&spender, &amount],
+                    );
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_approve_payload(spender, amount),
+            }
+        }
+        pub fn decode_amount(&self, data: nysa_types::Bytes) -> nysa_types::U256 {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_decode_amount(data);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_decode_amount(&self, data: nysa_types::Bytes) -> nysa_types::U256 {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::Payloads => {
+                    return odra::UnwrapOrRevert::unwrap_or_revert(
+                            nysa_types::abi::decode::<(nysa_types::U256,)>(&data),
+                            &self.env(),
+                        )
+                        .0;
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_decode_amount(data),
+            }
+        }
+        pub fn decode_list(
+            &self,
+            data: nysa_types::Bytes,
+        ) -> odra::prelude::vec::Vec<nysa_types::U256> {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_decode_list(data);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_decode_list(
+            &self,
+            data: nysa_types::Bytes,
+        ) -> odra::prelude::vec::Vec<nysa_types::U256> {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::Payloads => {
+                    let mut values = odra::UnwrapOrRevert::unwrap_or_revert(
+                            nysa_types::abi::decode::<
+                                (odra::prelude::vec::Vec<nysa_types::U256>,),
+                            >(&data),
+                            &self.env(),
+                        )
+                        .0;
+                    return values;
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_decode_list(data),
+            }
+        }
+        pub fn decode_order(
+            &self,
+            data: nysa_types::Bytes,
+        ) -> (nysa_types::U256, Option<odra::Address>) {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_decode_order(data);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_decode_order(
+            &self,
+            data: nysa_types::Bytes,
+        ) -> (nysa_types::U256, Option<odra::Address>) {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::Payloads => {
+                    let (mut id, mut owner, mut note) = odra::UnwrapOrRevert::unwrap_or_revert(
+                        nysa_types::abi::decode::<
+                            (
+                                nysa_types::U256,
+                                Option<odra::Address>,
+                                odra::prelude::string::String,
+                            ),
+                        >(&data),
+                        &self.env(),
+                    );
+                    return (id, owner);
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_decode_order(data),
+            }
+        }
+        pub fn encode_order(
+            &self,
+            id: nysa_types::U256,
+            owner: Option<odra::Address>,
+            note: odra::prelude::string::String,
+        ) -> nysa_types::Bytes {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_encode_order(id, owner, note);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_encode_order(
+            &self,
+            id: nysa_types::U256,
+            owner: Option<odra::Address>,
+            note: odra::prelude::string::String,
+        ) -> nysa_types::Bytes {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::Payloads => {
+                    return nysa_types::abi::encode(&[&id, &owner, &note]);
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_encode_order(id, owner, note),
+            }
+        }
+        #[odra(init)]
+        pub fn init(&mut self) {}
+        pub fn transfer_payload(
+            &self,
+            to: Option<odra::Address>,
+            amount: nysa_types::U256,
+        ) -> nysa_types::Bytes {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_transfer_payload(to, amount);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_transfer_payload(
+            &self,
+            to: Option<odra::Address>,
+            amount: nysa_types::U256,
+        ) -> nysa_types::Bytes {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::Payloads => {
+                    return nysa_types::abi::encode_with_selector(
+                        Self::TRANSFER_SELECTOR,
+                        &[&to, &amount],
+                    );
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_transfer_payload(to, amount),
+            }
+        }
+    }
+}
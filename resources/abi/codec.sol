@@ -0,0 +1,32 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.19;
+
+contract Payloads {
+    bytes4 constant TRANSFER_SELECTOR = 0xa9059cbb;
+
+    function encodeOrder(uint256 id, address owner, string memory note) public pure returns (bytes memory) {
+        return abi.encode(id, owner, note);
+    }
+
+    function decodeOrder(bytes memory data) public pure returns (uint256, address) {
+        (uint256 id, address owner, string memory note) = abi.decode(data, (uint256, address, string));
+        return (id, owner);
+    }
+
+    function decodeAmount(bytes memory data) public pure returns (uint256) {
+        return abi.decode(data, (uint256));
+    }
+
+    function decodeList(bytes memory data) public pure returns (uint256[] memory) {
+        uint256[] memory values = abi.decode(data, (uint256[]));
+        return values;
+    }
+
+    function transferPayload(address to, uint256 amount) public pure returns (bytes memory) {
+        return abi.encodeWithSelector(TRANSFER_SELECTOR, to, amount);
+    }
+
+    function approvePayload(address spender, uint256 amount) public pure returns (bytes memory) {
+        return abi.encodeWithSignature("approve(address,uint256)", spender, amount);
+    }
+}
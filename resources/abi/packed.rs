@@ -0,0 +1,162 @@
+{{DEFAULT_MODULES}}
+pub mod commitments {
+    #![allow(unused_braces, unused_mut, unused_parens, non_snake_case, unused_imports)]
+    {{DEFAULT_IMPORTS}}
+    {{STACK_DEF}}
+
+    #[derive(Clone)]
+    enum ClassName {
+        Commitments,
+    }
+    #[odra::module]
+    pub struct Commitments {
+        __stack: PathStack,
+        revealed: odra::Mapping<nysa_types::FixedBytes<32usize>, bool>,
+        commitments: odra::Mapping<
+            Option<odra::Address>,
+            nysa_types::FixedBytes<32usize>,
+        >,
+    }
+    #[odra::module]
+    impl Commitments {
+        const PATH: &'static [ClassName; 1usize] = &[ClassName::Commitments];
+        pub const MINTER_ROLE: nysa_types::FixedBytes<32usize> = nysa_types::FixedBytes([
+            159u8,
+            45u8,
+            240u8,
+            254u8,
+            210u8,
+            199u8,
+            118u8,
+            72u8,
+            222u8,
+            88u8,
+            96u8,
+            164u8,
+            204u8,
+            80u8,
+            140u8,
+            208u8,
+            129u8,
+            140u8,
+            133u8,
+            184u8,
+            184u8,
+            161u8,
+            171u8,
+            76u8,
+            238u8,
+            239u8,
+            141u8,
+            152u8,
+            28u8,
+            137u8,
+            86u8,
+            166u8,
+        ]);
+        pub fn commit(&mut self, commitment: nysa_types::FixedBytes<32usize>) {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_commit(commitment);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_commit(&mut self, commitment: nysa_types::FixedBytes<32usize>) {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::Commitments => {
+                    self.commitments.set(&Some(self.env().caller()), commitment);
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_commit(commitment),
+            }
+        }
+        #[odra(init)]
+        pub fn init(&mut self) {}
+        pub fn pack(
+            &self,
+            a: nysa_types::U16,
+            b: bool,
+            c: odra::prelude::string::String,
+        ) -> nysa_types::Bytes {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_pack(a, b, c);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_pack(
+            &self,
+            a: nysa_types::U16,
+            b: bool,
+            c: odra::prelude::string::String,
+        ) -> nysa_types::Bytes {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::Commitments => {
+                    return nysa_types::abi::encode_packed(&[&a, &b, &c]);
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_pack(a, b, c),
+            }
+        }
+        pub fn position_key(
+            &self,
+            owner: Option<odra::Address>,
+            tick_lower: nysa_types::I24,
+            tick_upper: nysa_types::I24,
+        ) -> nysa_types::FixedBytes<32usize> {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_position_key(owner, tick_lower, tick_upper);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_position_key(
+            &self,
+            owner: Option<odra::Address>,
+            tick_lower: nysa_types::I24,
+            tick_upper: nysa_types::I24,
+        ) -> nysa_types::FixedBytes<32usize> {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::Commitments => {
+                    return nysa_types::keccak256(
+                        &nysa_types::abi::encode_packed(&[&owner, &tick_lower, &tick_upper]),
+                    );
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_position_key(owner, tick_lower, tick_upper),
+            }
+        }
+        pub fn reveal(
+            &mut self,
+            value: nysa_types::U256,
+            salt: nysa_types::FixedBytes<32usize>,
+        ) {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_reveal(value, salt);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_reveal(
+            &mut self,
+            value: nysa_types::U256,
+            salt: nysa_types::FixedBytes<32usize>,
+        ) {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::Commitments => {
+                    let mut commitment = nysa_types::keccak256(
+                        &nysa_types::abi::encode_packed(&[&value, &salt]),
+                    );
+                    if !(self.commitments.get_or_default(&Some(self.env().caller()))
+                        == commitment)
+                    {
+                        self.env().revert(odra::ExecutionError::User(1u16))
+                    }
+                    self.revealed.set(&commitment, true);
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_reveal(value, salt),
+            }
+        }
+    }
+}
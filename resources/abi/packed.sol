@@ -0,0 +1,27 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.19;
+
+contract Commitments {
+    bytes32 public constant MINTER_ROLE = keccak256("MINTER_ROLE");
+
+    mapping(bytes32 => bool) revealed;
+    mapping(address => bytes32) commitments;
+
+    function commit(bytes32 commitment) public {
+        commitments[msg.sender] = commitment;
+    }
+
+    function reveal(uint256 value, bytes32 salt) public {
+        bytes32 commitment = keccak256(abi.encodePacked(value, salt));
+        require(commitments[msg.sender] == commitment, "Invalid reveal");
+        revealed[commitment] = true;
+    }
+
+    function positionKey(address owner, int24 tickLower, int24 tickUpper) public pure returns (bytes32) {
+        return keccak256(abi.encodePacked(owner, tickLower, tickUpper));
+    }
+
+    function pack(uint16 a, bool b, string memory c) public pure returns (bytes memory) {
+        return abi.encodePacked(a, b, c);
+    }
+}
@@ -27,12 +27,12 @@ pub mod if_else {
             let __class = self.__stack.pop_from_top_path();
             match __class {
                 ClassName::IfElse => {
-                    if x < nysa_types::U256::from_limbs_slice(&[10u64]) {
+                    if x < nysa_types::U256::from_limbs([10u64, 0u64, 0u64, 0u64]) {
                         return nysa_types::U256::ZERO;
-                    } else if x < nysa_types::U256::from_limbs_slice(&[20u64]) {
+                    } else if x < nysa_types::U256::from_limbs([20u64, 0u64, 0u64, 0u64]) {
                         return nysa_types::U256::ONE;
                     } else {
-                        return nysa_types::U256::from_limbs_slice(&[2u64]);
+                        return nysa_types::U256::from_limbs([2u64, 0u64, 0u64, 0u64]);
                     }
                 }
                 #[allow(unreachable_patterns)]
@@ -51,10 +51,10 @@ pub mod if_else {
             let __class = self.__stack.pop_from_top_path();
             match __class {
                 ClassName::IfElse => {
-                    return if _x < nysa_types::U24::from_limbs_slice(&[10u64]) { 
+                    return if _x < nysa_types::U24::from_limbs([10u64]) { 
                         nysa_types::U32::ONE
                     } else { 
-                        nysa_types::U32::from_limbs_slice(&[2u64]) 
+                        nysa_types::U32::from_limbs([2u64]) 
                     };
                 }
                 #[allow(unreachable_patterns)]
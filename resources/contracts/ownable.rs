@@ -1,4 +1,6 @@
-pub mod errors {}
+pub mod errors {
+    {{PANIC_DEF}}
+}
 pub mod events {
     use odra::prelude::*;
     #[derive(odra::Event, PartialEq, Eq, Debug)]
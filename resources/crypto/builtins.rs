@@ -0,0 +1,267 @@
+{{DEFAULT_MODULES}}
+pub mod signatures {
+    #![allow(unused_braces, unused_mut, unused_parens, non_snake_case, unused_imports)]
+    {{DEFAULT_IMPORTS}}
+    {{STACK_DEF}}
+
+    #[derive(Clone)]
+    enum ClassName {
+        Signatures,
+    }
+    #[odra::module]
+    pub struct Signatures {
+        __stack: PathStack,
+        nonces: odra::Mapping<Option<odra::Address>, nysa_types::U256>,
+    }
+    #[odra::module]
+    impl Signatures {
+        const PATH: &'static [ClassName; 1usize] = &[ClassName::Signatures];
+        pub const PERMIT_TYPEHASH: nysa_types::FixedBytes<32usize> = nysa_types::FixedBytes([
+            25u8,
+            3u8,
+            176u8,
+            87u8,
+            61u8,
+            220u8,
+            11u8,
+            101u8,
+            198u8,
+            21u8,
+            4u8,
+            203u8,
+            232u8,
+            58u8,
+            37u8,
+            67u8,
+            198u8,
+            232u8,
+            161u8,
+            251u8,
+            138u8,
+            245u8,
+            128u8,
+            167u8,
+            119u8,
+            165u8,
+            178u8,
+            248u8,
+            7u8,
+            62u8,
+            49u8,
+            157u8,
+        ]);
+        pub const TRANSFER_TYPEHASH: nysa_types::FixedBytes<32usize> = nysa_types::FixedBytes([
+            90u8,
+            50u8,
+            232u8,
+            145u8,
+            225u8,
+            55u8,
+            189u8,
+            13u8,
+            243u8,
+            133u8,
+            121u8,
+            128u8,
+            223u8,
+            73u8,
+            201u8,
+            253u8,
+            178u8,
+            10u8,
+            218u8,
+            102u8,
+            128u8,
+            4u8,
+            105u8,
+            162u8,
+            239u8,
+            105u8,
+            188u8,
+            148u8,
+            45u8,
+            69u8,
+            210u8,
+            169u8,
+        ]);
+        pub const MESSAGE_PREFIX_HASH: nysa_types::FixedBytes<32usize> = nysa_types::FixedBytes([
+            23u8,
+            138u8,
+            36u8,
+            17u8,
+            171u8,
+            111u8,
+            188u8,
+            27u8,
+            161u8,
+            16u8,
+            100u8,
+            64u8,
+            137u8,
+            114u8,
+            37u8,
+            156u8,
+            85u8,
+            141u8,
+            14u8,
+            130u8,
+            253u8,
+            72u8,
+            176u8,
+            171u8,
+            163u8,
+            173u8,
+            129u8,
+            209u8,
+            79u8,
+            6u8,
+            94u8,
+            115u8,
+        ]);
+        pub fn fingerprint(
+            &self,
+            data: nysa_types::Bytes,
+        ) -> (nysa_types::FixedBytes<32usize>, nysa_types::FixedBytes<20usize>) {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_fingerprint(data);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_fingerprint(
+            &self,
+            data: nysa_types::Bytes,
+        ) -> (nysa_types::FixedBytes<32usize>, nysa_types::FixedBytes<20usize>) {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::Signatures => {
+                    return (nysa_types::sha256(&data), nysa_types::ripemd160(&data));
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_fingerprint(data),
+            }
+        }
+        #[odra(init)]
+        pub fn init(&mut self) {}
+        pub fn mix(
+            &self,
+            a: nysa_types::U256,
+            b: nysa_types::U256,
+            m: nysa_types::U256,
+        ) -> (nysa_types::U256, nysa_types::U256) {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_mix(a, b, m);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_mix(
+            &self,
+            a: nysa_types::U256,
+            b: nysa_types::U256,
+            m: nysa_types::U256,
+        ) -> (nysa_types::U256, nysa_types::U256) {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::Signatures => {
+                    return (
+                        odra::UnwrapOrRevert::unwrap_or_revert_with(
+                            a.checked_add_mod(b, m),
+                            &self.env(),
+                            Panic::DivisionByZero,
+                        ),
+                        odra::UnwrapOrRevert::unwrap_or_revert_with(
+                            odra::UnwrapOrRevert::unwrap_or_revert_with(
+                                    a.checked_add(b),
+                                    &self.env(),
+                                    Panic::ArithmeticOverflow,
+                                )
+                                .checked_mul_mod(
+                                    b,
+                                    nysa_types::U256::from_limbs([7u64, 0u64, 0u64, 0u64]),
+                                ),
+                            &self.env(),
+                            Panic::DivisionByZero,
+                        ),
+                    );
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_mix(a, b, m),
+            }
+        }
+        pub fn permit(
+            &mut self,
+            owner: Option<odra::Address>,
+            spender: Option<odra::Address>,
+            value: nysa_types::U256,
+            v: nysa_types::U8,
+            r: nysa_types::FixedBytes<32usize>,
+            s: nysa_types::FixedBytes<32usize>,
+        ) {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_permit(owner, spender, value, v, r, s);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_permit(
+            &mut self,
+            owner: Option<odra::Address>,
+            spender: Option<odra::Address>,
+            value: nysa_types::U256,
+            v: nysa_types::U8,
+            r: nysa_types::FixedBytes<32usize>,
+            s: nysa_types::FixedBytes<32usize>,
+        ) {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::Signatures => {
+                    let mut digest = nysa_types::eip712::hash_struct(
+                        Self::PERMIT_TYPEHASH,
+                        &[&owner, &spender, &value, &self.nonces.get_or_default(&owner)],
+                    );
+                    let mut signer = nysa_types::ecrecover(digest, v, r, s);
+                    if !(signer != None && signer == owner) {
+                        self.env().revert(odra::ExecutionError::User(1u16))
+                    }
+                    self.nonces
+                        .set(
+                            &owner,
+                            odra::UnwrapOrRevert::unwrap_or_revert_with(
+                                self
+                                    .nonces
+                                    .get_or_default(&owner)
+                                    .checked_add(nysa_types::U256::ONE),
+                                &self.env(),
+                                Panic::ArithmeticOverflow,
+                            ),
+                        );
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_permit(owner, spender, value, v, r, s),
+            }
+        }
+        pub fn to_eth_signed_message_hash(
+            &self,
+            hash: nysa_types::FixedBytes<32usize>,
+        ) -> nysa_types::FixedBytes<32usize> {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_to_eth_signed_message_hash(hash);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_to_eth_signed_message_hash(
+            &self,
+            hash: nysa_types::FixedBytes<32usize>,
+        ) -> nysa_types::FixedBytes<32usize> {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::Signatures => {
+                    return nysa_types::keccak256(&nysa_types::abi::encode_packed(&[
+                        &odra::prelude::string::String::from("\u{19}Ethereum Signed Message:\n32"),
+                        &hash,
+                    ]));
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_to_eth_signed_message_hash(hash),
+            }
+        }
+    }
+}
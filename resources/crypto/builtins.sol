@@ -0,0 +1,29 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.19;
+
+contract Signatures {
+    bytes32 constant PERMIT_TYPEHASH = keccak256("Permit(address owner,address spender,uint256 value,uint256 nonce)");
+    bytes32 constant TRANSFER_TYPEHASH = keccak256("Transfer(address to," "uint256 value)");
+    bytes32 constant MESSAGE_PREFIX_HASH = keccak256("\x19Ethereum Signed Message:\n32");
+
+    mapping(address => uint256) nonces;
+
+    function permit(address owner, address spender, uint256 value, uint8 v, bytes32 r, bytes32 s) public {
+        bytes32 digest = keccak256(abi.encode(PERMIT_TYPEHASH, owner, spender, value, nonces[owner]));
+        address signer = ecrecover(digest, v, r, s);
+        require(signer != address(0) && signer == owner, "Invalid signature");
+        nonces[owner] += 1;
+    }
+
+    function toEthSignedMessageHash(bytes32 hash) public pure returns (bytes32) {
+        return keccak256(abi.encodePacked("\x19Ethereum Signed Message:\n32", hash));
+    }
+
+    function fingerprint(bytes memory data) public pure returns (bytes32, bytes20) {
+        return (sha256(data), ripemd160(data));
+    }
+
+    function mix(uint256 a, uint256 b, uint256 m) public pure returns (uint256, uint256) {
+        return (addmod(a, b, m), mulmod(a + b, b, 7));
+    }
+}
@@ -0,0 +1,234 @@
+{{DEFAULT_MODULES}}
+pub mod permit {
+    #![allow(unused_braces, unused_mut, unused_parens, non_snake_case, unused_imports)]
+    {{DEFAULT_IMPORTS}}
+    {{STACK_DEF}}
+
+    #[derive(Clone)]
+    enum ClassName {
+        Permit,
+    }
+    #[odra::module]
+    pub struct Permit {
+        __stack: PathStack,
+        domain_separator: odra::Var<nysa_types::FixedBytes<32usize>>,
+        nonces: odra::Mapping<Option<odra::Address>, nysa_types::U256>,
+        allowance: odra::Mapping<
+            (Option<odra::Address>, Option<odra::Address>),
+            nysa_types::U256,
+        >,
+    }
+    #[odra::module]
+    impl Permit {
+        const PATH: &'static [ClassName; 1usize] = &[ClassName::Permit];
+        pub const DOMAIN_TYPEHASH: nysa_types::FixedBytes<32usize> = nysa_types::FixedBytes([
+            139u8,
+            115u8,
+            195u8,
+            198u8,
+            155u8,
+            184u8,
+            254u8,
+            61u8,
+            81u8,
+            46u8,
+            204u8,
+            76u8,
+            247u8,
+            89u8,
+            204u8,
+            121u8,
+            35u8,
+            159u8,
+            123u8,
+            23u8,
+            155u8,
+            15u8,
+            250u8,
+            202u8,
+            169u8,
+            167u8,
+            93u8,
+            82u8,
+            43u8,
+            57u8,
+            64u8,
+            15u8,
+        ]);
+        pub const PERMIT_TYPEHASH: nysa_types::FixedBytes<32usize> = nysa_types::FixedBytes([
+            110u8,
+            113u8,
+            237u8,
+            174u8,
+            18u8,
+            177u8,
+            185u8,
+            127u8,
+            77u8,
+            31u8,
+            96u8,
+            55u8,
+            15u8,
+            239u8,
+            16u8,
+            16u8,
+            95u8,
+            162u8,
+            250u8,
+            174u8,
+            1u8,
+            38u8,
+            17u8,
+            74u8,
+            22u8,
+            156u8,
+            100u8,
+            132u8,
+            93u8,
+            97u8,
+            38u8,
+            201u8,
+        ]);
+        pub fn commitment(
+            &self,
+            salt: nysa_types::FixedBytes<32usize>,
+            secret: odra::prelude::string::String,
+        ) -> nysa_types::FixedBytes<32usize> {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_commitment(salt, secret);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_commitment(
+            &self,
+            salt: nysa_types::FixedBytes<32usize>,
+            secret: odra::prelude::string::String,
+        ) -> nysa_types::FixedBytes<32usize> {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::Permit => {
+                    return nysa_types::keccak256(
+                        &nysa_types::abi::encode(&[&salt, &secret]),
+                    );
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_commitment(salt, secret),
+            }
+        }
+        pub fn digest(
+            &self,
+            struct_hash: nysa_types::FixedBytes<32usize>,
+        ) -> nysa_types::FixedBytes<32usize> {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_digest(struct_hash);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_digest(
+            &self,
+            struct_hash: nysa_types::FixedBytes<32usize>,
+        ) -> nysa_types::FixedBytes<32usize> {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::Permit => {
+                    return nysa_types::keccak256(&nysa_types::abi::encode_packed(&[
+                        &nysa_types::FixedBytes([25u8, 1u8]),
+                        &self.domain_separator.get_or_default(),
+                        &struct_hash,
+                    ]));
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_digest(struct_hash),
+            }
+        }
+        #[odra(init)]
+        pub fn init(
+            &mut self,
+            name: odra::prelude::string::String,
+            chain_id: nysa_types::U256,
+            verifying_contract: Option<odra::Address>,
+        ) {
+            self.domain_separator
+                .set(
+                    nysa_types::eip712::hash_struct(
+                        Self::DOMAIN_TYPEHASH,
+                        &[
+                            &nysa_types::keccak256(
+                                &nysa_types::Bytes::from(name.as_bytes()),
+                            ),
+                            &nysa_types::keccak256(
+                                &odra::prelude::string::String::from("1"),
+                            ),
+                            &chain_id,
+                            &verifying_contract,
+                        ],
+                    ),
+                );
+        }
+        pub fn permit(
+            &mut self,
+            owner: Option<odra::Address>,
+            spender: Option<odra::Address>,
+            value: nysa_types::U256,
+            deadline: nysa_types::U256,
+            v: nysa_types::U8,
+            r: nysa_types::FixedBytes<32usize>,
+            s: nysa_types::FixedBytes<32usize>,
+        ) {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_permit(owner, spender, value, deadline, v, r, s);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_permit(
+            &mut self,
+            owner: Option<odra::Address>,
+            spender: Option<odra::Address>,
+            value: nysa_types::U256,
+            deadline: nysa_types::U256,
+            v: nysa_types::U8,
+            r: nysa_types::FixedBytes<32usize>,
+            s: nysa_types::FixedBytes<32usize>,
+        ) {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::Permit => {
+                    let mut struct_hash = nysa_types::eip712::hash_struct(
+                        Self::PERMIT_TYPEHASH,
+                        &[
+                            &owner,
+                            &spender,
+                            &value,
+                            &self.nonces.get_or_default(&owner),
+                            &deadline,
+                        ],
+                    );
+                    let mut digest = nysa_types::keccak256(&nysa_types::abi::encode_packed(&[
+                        &odra::prelude::string::String::from("\u{19}\u{1}"),
+                        &self.domain_separator.get_or_default(),
+                        &struct_hash,
+                    ]));
+                    let mut signer = nysa_types::ecrecover(digest, v, r, s);
+                    if !(signer != None && signer == owner) {
+                        self.env().revert(odra::ExecutionError::User(1u16))
+                    }
+                    self.nonces
+                        .set(
+                            &owner,
+                            odra::UnwrapOrRevert::unwrap_or_revert_with(
+                                self
+                                    .nonces
+                                    .get_or_default(&owner)
+                                    .checked_add(nysa_types::U256::ONE),
+                                &self.env(),
+                                Panic::ArithmeticOverflow,
+                            ),
+                        );
+                    self.allowance.set(&(owner, spender), value);
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_permit(owner, spender, value, deadline, v, r, s),
+            }
+        }
+    }
+}
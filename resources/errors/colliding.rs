@@ -0,0 +1,65 @@
+pub mod errors {
+    {{PANIC_DEF}}
+    #[derive(odra::OdraError, PartialEq, Eq, Debug)]
+    pub enum Error {
+        Error61 = 33673isize,
+        Error239 = 33743isize,
+    }
+}
+pub mod events {
+    use odra::prelude::*;
+}
+pub mod enums {}
+pub mod structs {}
+pub mod colliding {
+    #![allow(unused_braces, unused_mut, unused_parens, non_snake_case, unused_imports)]
+    {{DEFAULT_IMPORTS}}
+    {{STACK_DEF}}
+
+    #[derive(Clone)]
+    enum ClassName {
+        Colliding,
+    }
+    #[odra::module]
+    pub struct Colliding {
+        __stack: PathStack,
+    }
+    #[odra::module]
+    impl Colliding {
+        const PATH: &'static [ClassName; 1usize] = &[ClassName::Colliding];
+        pub fn first(&self) {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_first();
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_first(&self) {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::Colliding => {
+                    self.env().revert(Error::Error61);
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_first(),
+            }
+        }
+        #[odra(init)]
+        pub fn init(&mut self) {}
+        pub fn second(&self) {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_second();
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_second(&self) {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::Colliding => {
+                    self.env().revert(Error::Error239);
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_second(),
+            }
+        }
+    }
+}
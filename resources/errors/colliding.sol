@@ -0,0 +1,16 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.19;
+
+// the selectors of `Error61()` and `Error239()` share the first two bytes
+error Error61();
+error Error239();
+
+contract Colliding {
+    function first() public pure {
+        revert Error61();
+    }
+
+    function second() public pure {
+        revert Error239();
+    }
+}
@@ -0,0 +1,102 @@
+pub mod errors {
+    {{PANIC_DEF}}
+    #[derive(odra::OdraError, PartialEq, Eq, Debug)]
+    pub enum Error {
+        Unauthorized = 33460isize,
+        InsufficientBalance = 53063isize,
+    }
+}
+pub mod events {
+    use odra::prelude::*;
+}
+pub mod enums {}
+pub mod structs {}
+pub mod vault {
+    #![allow(unused_braces, unused_mut, unused_parens, non_snake_case, unused_imports)]
+    {{DEFAULT_IMPORTS}}
+    {{STACK_DEF}}
+
+    #[derive(Clone)]
+    enum ClassName {
+        Vault,
+    }
+    #[odra::module]
+    pub struct Vault {
+        __stack: PathStack,
+        owner: odra::Var<Option<odra::Address>>,
+        balances: odra::Mapping<Option<odra::Address>, nysa_types::U256>,
+    }
+    #[odra::module]
+    impl Vault {
+        const PATH: &'static [ClassName; 1usize] = &[ClassName::Vault];
+        pub fn deposit(
+            &mut self,
+            account: Option<odra::Address>,
+            amount: nysa_types::U256,
+        ) {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_deposit(account, amount);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_deposit(
+            &mut self,
+            account: Option<odra::Address>,
+            amount: nysa_types::U256,
+        ) {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::Vault => {
+                    if Some(self.env().caller()) != self.owner.get().unwrap_or(None) {
+                        self.env().revert(Error::Unauthorized);
+                    }
+                    self.balances
+                        .set(
+                            &account,
+                            odra::UnwrapOrRevert::unwrap_or_revert_with(
+                                self.balances.get_or_default(&account).checked_add(amount),
+                                &self.env(),
+                                Panic::ArithmeticOverflow,
+                            ),
+                        );
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_deposit(account, amount),
+            }
+        }
+        #[odra(init)]
+        pub fn init(&mut self) {
+            self.owner.set(Some(self.env().caller()));
+        }
+        pub fn withdraw(&mut self, amount: nysa_types::U256) {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_withdraw(amount);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_withdraw(&mut self, amount: nysa_types::U256) {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::Vault => {
+                    let mut balance = self
+                        .balances
+                        .get_or_default(&Some(self.env().caller()));
+                    if balance < amount {
+                        self.env().revert(Error::InsufficientBalance);
+                    }
+                    self.balances
+                        .set(
+                            &Some(self.env().caller()),
+                            odra::UnwrapOrRevert::unwrap_or_revert_with(
+                                balance.checked_sub(amount),
+                                &self.env(),
+                                Panic::ArithmeticOverflow,
+                            ),
+                        );
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_withdraw(amount),
+            }
+        }
+    }
+}
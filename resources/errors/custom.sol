@@ -0,0 +1,29 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.19;
+
+error Unauthorized();
+error InsufficientBalance(uint256 available, uint256 required);
+
+contract Vault {
+    address owner;
+    mapping(address => uint256) balances;
+
+    constructor() {
+        owner = msg.sender;
+    }
+
+    function deposit(address account, uint256 amount) public {
+        if (msg.sender != owner) {
+            revert Unauthorized();
+        }
+        balances[account] += amount;
+    }
+
+    function withdraw(uint256 amount) public {
+        uint256 balance = balances[msg.sender];
+        if (balance < amount) {
+            revert InsufficientBalance(balance, amount);
+        }
+        balances[msg.sender] = balance - amount;
+    }
+}
@@ -0,0 +1,201 @@
+pub mod errors {
+    {{PANIC_DEF}}
+}
+pub mod events {
+    use odra::prelude::*;
+}
+pub mod enums {
+    #[derive(odra::OdraType, Clone, PartialEq, Eq, Debug, Default)]
+    pub enum Status {
+        #[default]
+        Pending = 0u8,
+        Done = 1u8,
+    }
+}
+pub mod structs {}
+pub mod panics {
+    #![allow(unused_braces, unused_mut, unused_parens, non_snake_case, unused_imports)]
+    use super::enums::*;
+    {{DEFAULT_IMPORTS}}
+    {{STACK_DEF}}
+
+    #[derive(Clone)]
+    enum ClassName {
+        Panics,
+    }
+    #[odra::module]
+    pub struct Panics {
+        __stack: PathStack,
+        values: odra::Var<odra::prelude::vec::Vec<nysa_types::U256>>,
+        total: odra::Var<nysa_types::U256>,
+    }
+    #[odra::module]
+    impl Panics {
+        const PATH: &'static [ClassName; 1usize] = &[ClassName::Panics];
+        pub fn add(&mut self, value: nysa_types::U256) {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_add(value);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_add(&mut self, value: nysa_types::U256) {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::Panics => {
+                    {
+                        let mut result = self.values.get_or_default();
+                        result.push(value);
+                        self.values.set(result);
+                    };
+                    self.total
+                        .set(
+                            odra::UnwrapOrRevert::unwrap_or_revert_with(
+                                self.total.get_or_default().checked_add(value),
+                                &self.env(),
+                                Panic::ArithmeticOverflow,
+                            ),
+                        );
+                    if !(self.total.get_or_default() >= value) {
+                        self.env().revert(Panic::Assert)
+                    }
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_add(value),
+            }
+        }
+        #[odra(init)]
+        pub fn init(&mut self) {}
+        pub fn remove_last(&mut self) {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_remove_last();
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_remove_last(&mut self) {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::Panics => {
+                    {
+                        let mut result = self.values.get_or_default();
+                        odra::UnwrapOrRevert::unwrap_or_revert_with(
+                            result.pop(),
+                            &self.env(),
+                            Panic::EmptyArrayPop,
+                        );
+                        self.values.set(result);
+                    };
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_remove_last(),
+            }
+        }
+        pub fn signed_div(
+            &self,
+            a: nysa_types::I256,
+            b: nysa_types::I256,
+        ) -> nysa_types::I256 {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_signed_div(a, b);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_signed_div(
+            &self,
+            a: nysa_types::I256,
+            b: nysa_types::I256,
+        ) -> nysa_types::I256 {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::Panics => {
+                    return odra::UnwrapOrRevert::unwrap_or_revert_with(
+                        a
+                            .checked_div(
+                                odra::UnwrapOrRevert::unwrap_or_revert_with(
+                                    b.non_zero(),
+                                    &self.env(),
+                                    Panic::DivisionByZero,
+                                ),
+                            ),
+                        &self.env(),
+                        Panic::ArithmeticOverflow,
+                    );
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_signed_div(a, b),
+            }
+        }
+        pub fn to_status(&self, value: nysa_types::U8) -> Status {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_to_status(value);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_to_status(&self, value: nysa_types::U8) -> Status {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::Panics => {
+                    return match value.checked_as_usize() {
+                        Some(0usize) => Status::Pending,
+                        Some(1usize) => Status::Done,
+                        _ => self.env().revert(Panic::EnumConversion),
+                    };
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_to_status(value),
+            }
+        }
+        pub fn wrapping_div(
+            &self,
+            a: nysa_types::I256,
+            b: nysa_types::I256,
+        ) -> nysa_types::I256 {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_wrapping_div(a, b);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_wrapping_div(
+            &self,
+            a: nysa_types::I256,
+            b: nysa_types::I256,
+        ) -> nysa_types::I256 {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::Panics => {
+                    return (a
+                        / odra::UnwrapOrRevert::unwrap_or_revert_with(
+                            b.non_zero(),
+                            &self.env(),
+                            Panic::DivisionByZero,
+                        ));
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_wrapping_div(a, b),
+            }
+        }
+        pub fn wrapping_sum(
+            &self,
+            a: nysa_types::U256,
+            b: nysa_types::U256,
+        ) -> nysa_types::U256 {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_wrapping_sum(a, b);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_wrapping_sum(
+            &self,
+            a: nysa_types::U256,
+            b: nysa_types::U256,
+        ) -> nysa_types::U256 {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::Panics => {
+                    return (a + b);
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_wrapping_sum(a, b),
+            }
+        }
+    }
+}
@@ -0,0 +1,42 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.19;
+
+contract Panics {
+    enum Status {
+        Pending,
+        Done
+    }
+
+    uint256[] values;
+    uint256 total;
+
+    function add(uint256 value) public {
+        values.push(value);
+        total += value;
+        assert(total >= value);
+    }
+
+    function removeLast() public {
+        values.pop();
+    }
+
+    function wrappingSum(uint256 a, uint256 b) public pure returns (uint256) {
+        unchecked {
+            return a + b;
+        }
+    }
+
+    function signedDiv(int256 a, int256 b) public pure returns (int256) {
+        return a / b;
+    }
+
+    function wrappingDiv(int256 a, int256 b) public pure returns (int256) {
+        unchecked {
+            return a / b;
+        }
+    }
+
+    function toStatus(uint8 value) public pure returns (Status) {
+        return Status(value);
+    }
+}
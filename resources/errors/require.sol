@@ -0,0 +1,27 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.26;
+
+error Unauthorized();
+error InsufficientBalance(uint256 available, uint256 required);
+
+contract Vault {
+    address owner;
+    mapping(address => uint256) balances;
+
+    constructor() {
+        owner = msg.sender;
+    }
+
+    function deposit(address account, uint256 amount) public {
+        require(amount > 0);
+        require(msg.sender == owner, Unauthorized());
+        balances[account] += amount;
+    }
+
+    function withdraw(uint256 amount) public {
+        uint256 balance = balances[msg.sender];
+        require(balance >= amount, InsufficientBalance(balance, amount));
+        require(amount < 1000, "Amount too big");
+        balances[msg.sender] = balance - amount;
+    }
+}
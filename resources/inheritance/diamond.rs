@@ -0,0 +1,142 @@
+{{DEFAULT_MODULES}}
+pub mod diamond {
+    #![allow(unused_braces, unused_mut, unused_parens, non_snake_case, unused_imports)]
+    {{DEFAULT_IMPORTS}}
+    {{STACK_DEF}}
+
+    #[derive(Clone)]
+    enum ClassName {
+        Diamond,
+        Right,
+        Left,
+        Base,
+    }
+    #[odra::module]
+    pub struct Diamond {
+        __stack: PathStack,
+        calls: odra::Var<nysa_types::U256>,
+        left: odra::Var<nysa_types::U256>,
+        right: odra::Var<nysa_types::U256>,
+    }
+    #[odra::module]
+    impl Diamond {
+        const PATH: &'static [ClassName; 4usize] = &[
+            ClassName::Base,
+            ClassName::Left,
+            ClassName::Right,
+            ClassName::Diamond,
+        ];
+        pub(crate) fn hook(&mut self) {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_hook();
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_hook(&mut self) {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::Base => {
+                    self.calls
+                        .set(
+                            odra::UnwrapOrRevert::unwrap_or_revert_with(
+                                self
+                                    .calls
+                                    .get_or_default()
+                                    .checked_add(nysa_types::U256::ONE),
+                                &self.env(),
+                                Panic::ArithmeticOverflow,
+                            ),
+                        );
+                }
+                ClassName::Left => {
+                    self.left.set(self.calls.get_or_default());
+                    {
+                        self.__stack.push_path_on_stack(&Self::PATH[..1usize]);
+                        let __result = self.super_hook();
+                        self.__stack.drop_one_from_stack();
+                        __result
+                    };
+                }
+                ClassName::Right => {
+                    self.right.set(self.calls.get_or_default());
+                    {
+                        self.__stack.push_path_on_stack(&Self::PATH[..2usize]);
+                        let __result = self.super_hook();
+                        self.__stack.drop_one_from_stack();
+                        __result
+                    };
+                }
+                ClassName::Diamond => {
+                    {
+                        self.__stack.push_path_on_stack(&Self::PATH[..3usize]);
+                        let __result = self.super_hook();
+                        self.__stack.drop_one_from_stack();
+                        __result
+                    };
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_hook(),
+            }
+        }
+        fn _base_init(&mut self) {}
+        fn _left_init(&mut self) {
+            self._base_init();
+        }
+        fn _right_init(&mut self) {
+            self._base_init();
+        }
+        #[odra(init)]
+        pub fn init(&mut self) {
+            self._left_init();
+            self._right_init();
+        }
+        pub fn name(&self) -> odra::prelude::string::String {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_name();
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_name(&self) -> odra::prelude::string::String {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::Base => {
+                    return odra::prelude::string::String::from("Base");
+                }
+                ClassName::Right => {
+                    return {
+                        self.__stack.push_path_on_stack(&Self::PATH[..2usize]);
+                        let __result = self.super_name();
+                        self.__stack.drop_one_from_stack();
+                        __result
+                    };
+                }
+                ClassName::Diamond => {
+                    return {
+                        self.__stack.push_path_on_stack(&Self::PATH[..3usize]);
+                        let __result = self.super_name();
+                        self.__stack.drop_one_from_stack();
+                        __result
+                    };
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_name(),
+            }
+        }
+        pub fn run(&mut self) {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_run();
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_run(&mut self) {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::Diamond => {
+                    self.hook();
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_run(),
+            }
+        }
+    }
+}
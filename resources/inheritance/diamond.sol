@@ -0,0 +1,50 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.19;
+
+contract Base {
+    uint256 public calls;
+
+    function hook() internal virtual {
+        calls = calls + 1;
+    }
+
+    function name() public pure virtual returns (string memory) {
+        return "Base";
+    }
+}
+
+contract Left is Base {
+    uint256 public left;
+
+    function hook() internal virtual override {
+        left = calls;
+        super.hook();
+    }
+}
+
+contract Right is Base {
+    uint256 public right;
+
+    function hook() internal virtual override {
+        right = calls;
+        super.hook();
+    }
+
+    function name() public pure virtual override returns (string memory) {
+        return super.name();
+    }
+}
+
+contract Diamond is Left, Right {
+    function hook() internal override(Left, Right) {
+        super.hook();
+    }
+
+    function name() public pure override returns (string memory) {
+        return super.name();
+    }
+
+    function run() public {
+        hook();
+    }
+}
@@ -0,0 +1,264 @@
+{{DEFAULT_MODULES}}
+pub mod token {
+    #![allow(unused_braces, unused_mut, unused_parens, non_snake_case, unused_imports)]
+    {{DEFAULT_IMPORTS}}
+    {{STACK_DEF}}
+
+    #[derive(Clone)]
+    enum ClassName {
+        Token,
+        ERC20Capped,
+        ERC20Pausable,
+        ERC20,
+    }
+    #[odra::module]
+    pub struct Token {
+        __stack: PathStack,
+        _balances: odra::Mapping<Option<odra::Address>, nysa_types::U256>,
+        _paused: odra::Var<bool>,
+        _minted: odra::Var<nysa_types::U256>,
+    }
+    #[odra::module]
+    impl Token {
+        const PATH: &'static [ClassName; 4usize] = &[
+            ClassName::ERC20,
+            ClassName::ERC20Pausable,
+            ClassName::ERC20Capped,
+            ClassName::Token,
+        ];
+        pub(crate) fn _before_token_transfer(
+            &mut self,
+            from: Option<odra::Address>,
+            to: Option<odra::Address>,
+            amount: nysa_types::U256,
+        ) {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super__before_token_transfer(from, to, amount);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super__before_token_transfer(
+            &mut self,
+            from: Option<odra::Address>,
+            to: Option<odra::Address>,
+            amount: nysa_types::U256,
+        ) {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::ERC20 => {}
+                ClassName::ERC20Pausable => {
+                    {
+                        self.__stack.push_path_on_stack(&Self::PATH[..1usize]);
+                        let __result = self
+                            .super__before_token_transfer(from, to, amount);
+                        self.__stack.drop_one_from_stack();
+                        __result
+                    };
+                    if !(!(self._paused.get_or_default())) {
+                        self.env().revert(odra::ExecutionError::User(1u16))
+                    }
+                }
+                ClassName::ERC20Capped => {
+                    {
+                        self.__stack.push_path_on_stack(&Self::PATH[..2usize]);
+                        let __result = self
+                            .super__before_token_transfer(from, to, amount);
+                        self.__stack.drop_one_from_stack();
+                        __result
+                    };
+                    if from == None {
+                        if !(odra::UnwrapOrRevert::unwrap_or_revert_with(
+                            self._minted.get_or_default().checked_add(amount),
+                            &self.env(),
+                            Panic::ArithmeticOverflow,
+                        ) <= nysa_types::U256::from_limbs([1000u64, 0u64, 0u64, 0u64]))
+                        {
+                            self.env().revert(odra::ExecutionError::User(1u16))
+                        }
+                        self._minted
+                            .set(
+                                odra::UnwrapOrRevert::unwrap_or_revert_with(
+                                    self._minted.get_or_default().checked_add(amount),
+                                    &self.env(),
+                                    Panic::ArithmeticOverflow,
+                                ),
+                            );
+                    }
+                }
+                ClassName::Token => {
+                    {
+                        self.__stack.push_path_on_stack(&Self::PATH[..3usize]);
+                        let __result = self
+                            .super__before_token_transfer(from, to, amount);
+                        self.__stack.drop_one_from_stack();
+                        __result
+                    };
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super__before_token_transfer(from, to, amount),
+            }
+        }
+        pub(crate) fn _mint(
+            &mut self,
+            account: Option<odra::Address>,
+            amount: nysa_types::U256,
+        ) {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super__mint(account, amount);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super__mint(
+            &mut self,
+            account: Option<odra::Address>,
+            amount: nysa_types::U256,
+        ) {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::ERC20 => {
+                    self._before_token_transfer(None, account, amount);
+                    self._balances
+                        .set(
+                            &account,
+                            odra::UnwrapOrRevert::unwrap_or_revert_with(
+                                self._balances.get_or_default(&account).checked_add(amount),
+                                &self.env(),
+                                Panic::ArithmeticOverflow,
+                            ),
+                        );
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super__mint(account, amount),
+            }
+        }
+        pub(crate) fn _pause(&mut self) {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super__pause();
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super__pause(&mut self) {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::ERC20Pausable => {
+                    self._paused.set(true);
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super__pause(),
+            }
+        }
+        pub(crate) fn _transfer(
+            &mut self,
+            from: Option<odra::Address>,
+            to: Option<odra::Address>,
+            amount: nysa_types::U256,
+        ) {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super__transfer(from, to, amount);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super__transfer(
+            &mut self,
+            from: Option<odra::Address>,
+            to: Option<odra::Address>,
+            amount: nysa_types::U256,
+        ) {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::ERC20 => {
+                    self._before_token_transfer(from, to, amount);
+                    self._balances
+                        .set(
+                            &from,
+                            odra::UnwrapOrRevert::unwrap_or_revert_with(
+                                self._balances.get_or_default(&from).checked_sub(amount),
+                                &self.env(),
+                                Panic::ArithmeticOverflow,
+                            ),
+                        );
+                    self._balances
+                        .set(
+                            &to,
+                            odra::UnwrapOrRevert::unwrap_or_revert_with(
+                                self._balances.get_or_default(&to).checked_add(amount),
+                                &self.env(),
+                                Panic::ArithmeticOverflow,
+                            ),
+                        );
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super__transfer(from, to, amount),
+            }
+        }
+        pub fn balance_of(&self, account: Option<odra::Address>) -> nysa_types::U256 {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_balance_of(account);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_balance_of(&self, account: Option<odra::Address>) -> nysa_types::U256 {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::ERC20 => {
+                    return self._balances.get_or_default(&account);
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_balance_of(account),
+            }
+        }
+        fn _erc_20_init(&mut self) {}
+        fn _erc_20_pausable_init(&mut self) {}
+        fn _erc_20_capped_init(&mut self) {}
+        #[odra(init)]
+        pub fn init(&mut self) {
+            self._erc_20_pausable_init();
+            self._erc_20_capped_init();
+            self._mint(
+                Some(self.env().caller()),
+                nysa_types::U256::from_limbs([100u64, 0u64, 0u64, 0u64]),
+            );
+        }
+        pub fn pause(&mut self) {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_pause();
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_pause(&mut self) {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::Token => {
+                    self._pause();
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_pause(),
+            }
+        }
+        pub fn transfer(
+            &mut self,
+            to: Option<odra::Address>,
+            amount: nysa_types::U256,
+        ) -> bool {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_transfer(to, amount);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_transfer(
+            &mut self,
+            to: Option<odra::Address>,
+            amount: nysa_types::U256,
+        ) -> bool {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::ERC20 => {
+                    self._transfer(Some(self.env().caller()), to, amount);
+                    return true;
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_transfer(to, amount),
+            }
+        }
+    }
+}
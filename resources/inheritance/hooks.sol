@@ -0,0 +1,70 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.19;
+
+abstract contract ERC20 {
+    mapping(address => uint256) private _balances;
+
+    function balanceOf(address account) public view returns (uint256) {
+        return _balances[account];
+    }
+
+    function transfer(address to, uint256 amount) public returns (bool) {
+        _transfer(msg.sender, to, amount);
+        return true;
+    }
+
+    function _transfer(address from, address to, uint256 amount) internal {
+        _beforeTokenTransfer(from, to, amount);
+        _balances[from] = _balances[from] - amount;
+        _balances[to] = _balances[to] + amount;
+    }
+
+    function _mint(address account, uint256 amount) internal {
+        _beforeTokenTransfer(address(0), account, amount);
+        _balances[account] = _balances[account] + amount;
+    }
+
+    function _beforeTokenTransfer(address from, address to, uint256 amount) internal virtual {}
+}
+
+abstract contract ERC20Pausable is ERC20 {
+    bool private _paused;
+
+    function _pause() internal {
+        _paused = true;
+    }
+
+    function _beforeTokenTransfer(address from, address to, uint256 amount) internal virtual override {
+        super._beforeTokenTransfer(from, to, amount);
+        require(!_paused, "ERC20Pausable: token transfer while paused");
+    }
+}
+
+abstract contract ERC20Capped is ERC20 {
+    uint256 private _minted;
+
+    function _beforeTokenTransfer(address from, address to, uint256 amount) internal virtual override {
+        super._beforeTokenTransfer(from, to, amount);
+        if (from == address(0)) {
+            require(_minted + amount <= 1000, "ERC20Capped: cap exceeded");
+            _minted = _minted + amount;
+        }
+    }
+}
+
+contract Token is ERC20Pausable, ERC20Capped {
+    constructor() {
+        _mint(msg.sender, 100);
+    }
+
+    function pause() public {
+        _pause();
+    }
+
+    function _beforeTokenTransfer(address from, address to, uint256 amount)
+        internal
+        override(ERC20Pausable, ERC20Capped)
+    {
+        super._beforeTokenTransfer(from, to, amount);
+    }
+}
@@ -0,0 +1,9 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.19;
+
+contract A {}
+
+contract B is A {}
+
+// the bases must be listed from the "most base-like" to the "most derived"
+contract C is B, A {}
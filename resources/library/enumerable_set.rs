@@ -0,0 +1,260 @@
+pub mod errors {
+    {{PANIC_DEF}}
+}
+pub mod events {
+    use odra::prelude::*;
+}
+pub mod enums {}
+pub mod structs {
+    pub mod enumerable_set {
+        #[derive(odra::OdraType, Clone, PartialEq, Eq, Debug, Default)]
+        pub struct Set {
+            pub _values: odra::prelude::vec::Vec<nysa_types::FixedBytes<32usize>>,
+            pub _indexes: nysa_types::Mapping<
+                nysa_types::FixedBytes<32usize>,
+                nysa_types::U256,
+            >,
+        }
+    }
+}
+pub mod enumerable_set {
+    #![allow(unused_braces, unused_mut, unused_parens, non_snake_case, unused_imports)]
+    {{DEFAULT_IMPORTS}}
+    {{STACK_DEF}}
+
+    #[derive(Clone)]
+    enum ClassName {
+        EnumerableSet,
+    }
+    #[odra::module]
+    pub struct EnumerableSet {
+        __stack: PathStack,
+    }
+    #[odra::module]
+    impl EnumerableSet {
+        const PATH: &'static [ClassName; 1usize] = &[ClassName::EnumerableSet];
+        pub(crate) fn add(
+            set: &mut enumerable_set::Set,
+            value: nysa_types::FixedBytes<32usize>,
+        ) -> bool {
+            if !(Self::contains(set, value)) {
+                {
+                    let mut result = set._values.clone();
+                    result.push(value);
+                    set._values = result;
+                };
+                set._indexes.set(&value, nysa_types::U256::from(set._values.len()));
+                return true;
+            } else {
+                return false;
+            }
+        }
+        pub(crate) fn at(
+            set: &mut enumerable_set::Set,
+            index: nysa_types::U256,
+        ) -> nysa_types::FixedBytes<32usize> {
+            return (*nysa_types::unwrap_or_panic(
+                set
+                    ._values
+                    .get(
+                        nysa_types::unwrap_or_panic(
+                            index.checked_as_usize(),
+                            Panic::IndexOutOfBounds,
+                        ),
+                    ),
+                Panic::IndexOutOfBounds,
+            ));
+        }
+        pub(crate) fn contains(
+            set: &mut enumerable_set::Set,
+            value: nysa_types::FixedBytes<32usize>,
+        ) -> bool {
+            return set._indexes.get_or_default(&value) != nysa_types::U256::ZERO;
+        }
+        pub(crate) fn length(set: &mut enumerable_set::Set) -> nysa_types::U256 {
+            return nysa_types::U256::from(set._values.len());
+        }
+        pub(crate) fn remove(
+            set: &mut enumerable_set::Set,
+            value: nysa_types::FixedBytes<32usize>,
+        ) -> bool {
+            let mut value_index = set._indexes.get_or_default(&value);
+            if value_index != nysa_types::U256::ZERO {
+                let mut to_delete_index = nysa_types::unwrap_or_panic(
+                    value_index.checked_sub(nysa_types::U256::ONE),
+                    Panic::ArithmeticOverflow,
+                );
+                let mut last_index = nysa_types::unwrap_or_panic(
+                    nysa_types::U256::from(set._values.len())
+                        .checked_sub(nysa_types::U256::ONE),
+                    Panic::ArithmeticOverflow,
+                );
+                if last_index != to_delete_index {
+                    let mut last_value = (*nysa_types::unwrap_or_panic(
+                        set
+                            ._values
+                            .get(
+                                nysa_types::unwrap_or_panic(
+                                    last_index.checked_as_usize(),
+                                    Panic::IndexOutOfBounds,
+                                ),
+                            ),
+                        Panic::IndexOutOfBounds,
+                    ));
+                    (*nysa_types::unwrap_or_panic(
+                        set
+                            ._values
+                            .get_mut(
+                                nysa_types::unwrap_or_panic(
+                                    to_delete_index.checked_as_usize(),
+                                    Panic::IndexOutOfBounds,
+                                ),
+                            ),
+                        Panic::IndexOutOfBounds,
+                    )) = last_value;
+                    set._indexes.set(&last_value, value_index);
+                }
+                {
+                    let mut result = set._values.clone();
+                    nysa_types::unwrap_or_panic(result.pop(), Panic::EmptyArrayPop);
+                    set._values = result;
+                };
+                set._indexes.set(&value, Default::default());
+                return true;
+            } else {
+                return false;
+            }
+        }
+    }
+}
+pub mod members {
+    #![allow(unused_braces, unused_mut, unused_parens, non_snake_case, unused_imports)]
+    {{DEFAULT_IMPORTS}}
+    {{STACK_DEF}}
+
+    #[derive(Clone)]
+    enum ClassName {
+        Members,
+    }
+    #[odra::module]
+    pub struct Members {
+        __stack: PathStack,
+        _members: odra::Var<enumerable_set::Set>,
+    }
+    #[odra::module]
+    impl Members {
+        const PATH: &'static [ClassName; 1usize] = &[ClassName::Members];
+        pub fn count(&self) -> nysa_types::U256 {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_count();
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_count(&self) -> nysa_types::U256 {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::Members => {
+                    return super::enumerable_set::EnumerableSet::length(
+                        &mut self._members.get_or_default(),
+                    );
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_count(),
+            }
+        }
+        #[odra(init)]
+        pub fn init(&mut self) {}
+        pub fn is_member(&self, member: nysa_types::FixedBytes<32usize>) -> bool {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_is_member(member);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_is_member(&self, member: nysa_types::FixedBytes<32usize>) -> bool {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::Members => {
+                    return super::enumerable_set::EnumerableSet::contains(
+                        &mut self._members.get_or_default(),
+                        member,
+                    );
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_is_member(member),
+            }
+        }
+        pub fn join(&mut self, member: nysa_types::FixedBytes<32usize>) -> bool {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_join(member);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_join(&mut self, member: nysa_types::FixedBytes<32usize>) -> bool {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::Members => {
+                    return {
+                        let mut __value = self._members.get_or_default();
+                        let __result = super::enumerable_set::EnumerableSet::add(
+                            &mut __value,
+                            member,
+                        );
+                        self._members.set(__value);
+                        __result
+                    };
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_join(member),
+            }
+        }
+        pub fn leave(&mut self, member: nysa_types::FixedBytes<32usize>) -> bool {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_leave(member);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_leave(&mut self, member: nysa_types::FixedBytes<32usize>) -> bool {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::Members => {
+                    return {
+                        let mut __value = self._members.get_or_default();
+                        let __result = super::enumerable_set::EnumerableSet::remove(
+                            &mut __value,
+                            member,
+                        );
+                        self._members.set(__value);
+                        __result
+                    };
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_leave(member),
+            }
+        }
+        pub fn member_at(
+            &self,
+            index: nysa_types::U256,
+        ) -> nysa_types::FixedBytes<32usize> {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_member_at(index);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_member_at(
+            &self,
+            index: nysa_types::U256,
+        ) -> nysa_types::FixedBytes<32usize> {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::Members => {
+                    return super::enumerable_set::EnumerableSet::at(
+                        &mut self._members.get_or_default(),
+                        index,
+                    );
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_member_at(index),
+            }
+        }
+    }
+}
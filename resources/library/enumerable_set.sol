@@ -0,0 +1,75 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.19;
+
+library EnumerableSet {
+    struct Set {
+        bytes32[] _values;
+        mapping(bytes32 => uint256) _indexes;
+    }
+
+    function add(Set storage set, bytes32 value) internal returns (bool) {
+        if (!contains(set, value)) {
+            set._values.push(value);
+            set._indexes[value] = set._values.length;
+            return true;
+        } else {
+            return false;
+        }
+    }
+
+    function remove(Set storage set, bytes32 value) internal returns (bool) {
+        uint256 valueIndex = set._indexes[value];
+        if (valueIndex != 0) {
+            uint256 toDeleteIndex = valueIndex - 1;
+            uint256 lastIndex = set._values.length - 1;
+            if (lastIndex != toDeleteIndex) {
+                bytes32 lastValue = set._values[lastIndex];
+                set._values[toDeleteIndex] = lastValue;
+                set._indexes[lastValue] = valueIndex;
+            }
+            set._values.pop();
+            delete set._indexes[value];
+            return true;
+        } else {
+            return false;
+        }
+    }
+
+    function contains(Set storage set, bytes32 value) internal view returns (bool) {
+        return set._indexes[value] != 0;
+    }
+
+    function length(Set storage set) internal view returns (uint256) {
+        return set._values.length;
+    }
+
+    function at(Set storage set, uint256 index) internal view returns (bytes32) {
+        return set._values[index];
+    }
+}
+
+contract Members {
+    using EnumerableSet for EnumerableSet.Set;
+
+    EnumerableSet.Set private _members;
+
+    function join(bytes32 member) public returns (bool) {
+        return _members.add(member);
+    }
+
+    function leave(bytes32 member) public returns (bool) {
+        return _members.remove(member);
+    }
+
+    function isMember(bytes32 member) public view returns (bool) {
+        return _members.contains(member);
+    }
+
+    function count() public view returns (uint256) {
+        return _members.length();
+    }
+
+    function memberAt(uint256 index) public view returns (bytes32) {
+        return _members.at(index);
+    }
+}
@@ -8,7 +8,7 @@ pub mod events {
 pub mod enums {}
 pub mod structs {
     pub mod position {
-        #[derive(odra::OdraType, PartialEq, Eq, Debug, Default)]
+        #[derive(odra::OdraType, Clone, PartialEq, Eq, Debug, Default)]
         pub struct Info {
             pub liquidity: nysa_types::U128,
             pub fee_growth_inside_0_last_x_128: nysa_types::U256,
@@ -16,7 +16,7 @@ pub mod structs {
         }
     }
     pub mod pool {
-        #[derive(odra::OdraType, PartialEq, Eq, Debug, Default)]
+        #[derive(odra::OdraType, Clone, PartialEq, Eq, Debug, Default)]
         pub struct ModifyPositionParams {
             pub owner: Option<odra::Address>,
             pub tick_lower: nysa_types::I24,
@@ -24,7 +24,7 @@ pub mod structs {
             pub liquidity_delta: nysa_types::I128,
             pub tick_spacing: nysa_types::I24,
         }
-        #[derive(odra::OdraType, PartialEq, Eq, Debug, Default)]
+        #[derive(odra::OdraType, Clone, PartialEq, Eq, Debug, Default)]
         pub struct ModifyPositionState {
             pub flipped_lower: bool,
             pub liquidity_gross_after_lower: nysa_types::U128,
@@ -33,7 +33,7 @@ pub mod structs {
             pub fee_growth_inside_0_x_128: nysa_types::U256,
             pub fee_growth_inside_1_x_128: nysa_types::U256,
         }
-        #[derive(odra::OdraType, PartialEq, Eq, Debug, Default)]
+        #[derive(odra::OdraType, Clone, PartialEq, Eq, Debug, Default)]
         pub struct State {
             pub fee_growth_global_0_x_128: nysa_types::U256,
             pub fee_growth_global_1_x_128: nysa_types::U256,
@@ -118,14 +118,14 @@ pub mod pool {
         const PATH: &'static [ClassName; 1usize] = &[ClassName::Pool];
         pub(crate) fn get_swap_fee(fees_storage: nysa_types::U24) -> nysa_types::U16 {
             return nysa_types::U16::from(
-                *(fees_storage >> nysa_types::U24::from_limbs_slice(&[12u64])),
+                *(fees_storage >> nysa_types::U256::from_limbs([12u64, 0u64, 0u64, 0u64])),
             );
         }
         pub(crate) fn get_withdraw_fee(
             fees_storage: nysa_types::U24,
         ) -> nysa_types::U16 {
             return nysa_types::U16::from(
-                *(fees_storage & nysa_types::U24::from_limbs_slice(&[4095u64])),
+                *(fees_storage & nysa_types::U24::from_limbs([4095u64])),
             );
         }
         pub(crate) fn modify_position(
@@ -207,20 +207,20 @@ pub mod position {
                 liquidity_next = _self.liquidity;
             } else {
                 liquidity_next = if liquidity_delta < nysa_types::I128::ZERO {
-                    (_self.liquidity - nysa_types::U128::from(*(-liquidity_delta)))
+                    (_self.liquidity - <nysa_types::U128 as nysa_types::ConvertFrom<_>>::convert_from((-liquidity_delta)))
                 } else {
-                    (_self.liquidity + nysa_types::U128::from(*liquidity_delta))
+                    (_self.liquidity + <nysa_types::U128 as nysa_types::ConvertFrom<_>>::convert_from(liquidity_delta))
                 };
             }
             {
                 fees_owed_0 = super::full_math::FullMath::mul_div(
                     (fee_growth_inside_0_x_128 - _self.fee_growth_inside_0_last_x_128),
-                    (_self.liquidity).cast(),
+                    <nysa_types::U256 as nysa_types::ConvertFrom<_>>::convert_from(_self.liquidity),
                     super::fixed_point_128::FixedPoint128::Q128,
                 );
                 fees_owed_1 = super::full_math::FullMath::mul_div(
                     (fee_growth_inside_1_x_128 - _self.fee_growth_inside_1_last_x_128),
-                    (_self.liquidity).cast(),
+                    <nysa_types::U256 as nysa_types::ConvertFrom<_>>::convert_from(_self.liquidity),
                     super::fixed_point_128::FixedPoint128::Q128,
                 );
             }
@@ -30,12 +30,30 @@ pub mod math {
 
         pub(crate) fn sqrt(y: nysa_types::U256) -> nysa_types::U256 {
             let mut z = Default::default();
-            if y > nysa_types::U256::from_limbs_slice(&[3u64]) {
+            if y > nysa_types::U256::from_limbs([3u64, 0u64, 0u64, 0u64]) {
                 z = y;
-                let mut x = ((y / nysa_types::U256::from_limbs_slice(&[2u64])) + nysa_types::U256::ONE);
+                let mut x = nysa_types::unwrap_or_panic(
+                    nysa_types::unwrap_or_panic(
+                        y.checked_div(nysa_types::U256::from_limbs([2u64, 0u64, 0u64, 0u64])),
+                        Panic::DivisionByZero,
+                    )
+                    .checked_add(nysa_types::U256::ONE),
+                    Panic::ArithmeticOverflow,
+                );
                 while x < z {
                     z = x;
-                    x = (((y / x) + x) / nysa_types::U256::from_limbs_slice(&[2u64]));
+                    x = nysa_types::unwrap_or_panic(
+                        nysa_types::unwrap_or_panic(
+                            nysa_types::unwrap_or_panic(
+                                y.checked_div(x),
+                                Panic::DivisionByZero,
+                            )
+                            .checked_add(x),
+                            Panic::ArithmeticOverflow,
+                        )
+                        .checked_div(nysa_types::U256::from_limbs([2u64, 0u64, 0u64, 0u64])),
+                        Panic::DivisionByZero,
+                    );
                 }
             } else if y != nysa_types::U256::ZERO {
                 z = nysa_types::U256::ONE;
@@ -21,11 +21,14 @@ pub mod safe_math {
         pub(crate) fn add(x: nysa_types::U256, y: nysa_types::U256) -> nysa_types::U256 {
             let mut z = Default::default();
             if !({
-                z = (x + y);
+                z = nysa_types::unwrap_or_panic(
+                    x.checked_add(y),
+                    Panic::ArithmeticOverflow,
+                );
                 z
             } >= x)
             {
-                self.env().revert(odra::ExecutionError::User(1u16))
+                nysa_types::panic(odra::ExecutionError::User(1u16))
             }
             return (z);
         }
@@ -33,12 +36,19 @@ pub mod safe_math {
         pub(crate) fn mul(x: nysa_types::U256, y: nysa_types::U256) -> nysa_types::U256 {
             let mut z = Default::default();
             if !(y == nysa_types::U256::ZERO
-                || ({
-                    z = (x * y);
-                    z
-                } / y) == x)
+                || nysa_types::unwrap_or_panic(
+                    ({
+                        z = nysa_types::unwrap_or_panic(
+                            x.checked_mul(y),
+                            Panic::ArithmeticOverflow,
+                        );
+                        z
+                    })
+                    .checked_div(y),
+                    Panic::DivisionByZero,
+                ) == x)
             {
-                self.env().revert(odra::ExecutionError::User(1u16))
+                nysa_types::panic(odra::ExecutionError::User(1u16))
             }
             return (z);
         }
@@ -46,11 +56,14 @@ pub mod safe_math {
         pub(crate) fn sub(x: nysa_types::U256, y: nysa_types::U256) -> nysa_types::U256 {
             let mut z = Default::default();
             if !({
-                z = (x - y);
+                z = nysa_types::unwrap_or_panic(
+                    x.checked_sub(y),
+                    Panic::ArithmeticOverflow,
+                );
                 z
             } <= x)
             {
-                self.env().revert(odra::ExecutionError::User(1u16))
+                nysa_types::panic(odra::ExecutionError::User(1u16))
             }
             return (z);
         }
@@ -0,0 +1,314 @@
+pub mod errors {
+    {{PANIC_DEF}}
+}
+pub mod events {
+    use odra::prelude::*;
+}
+pub mod enums {}
+pub mod structs {
+    pub mod counters {
+        #[derive(odra::OdraType, Clone, PartialEq, Eq, Debug, Default)]
+        pub struct Counter {
+            pub value: nysa_types::U256,
+        }
+    }
+    #[derive(odra::OdraType, Clone, PartialEq, Eq, Debug, Default)]
+    pub struct Point {
+        pub x: nysa_types::U256,
+        pub y: nysa_types::U256,
+    }
+}
+pub mod arrays {
+    #![allow(unused_braces, unused_mut, unused_parens, non_snake_case, unused_imports)]
+    {{DEFAULT_IMPORTS}}
+    {{STACK_DEF}}
+
+    #[derive(Clone)]
+    enum ClassName {
+        Arrays,
+    }
+    #[odra::module]
+    pub struct Arrays {
+        __stack: PathStack,
+    }
+    #[odra::module]
+    impl Arrays {
+        const PATH: &'static [ClassName; 1usize] = &[ClassName::Arrays];
+        pub(crate) fn sum(
+            values: odra::prelude::vec::Vec<nysa_types::U256>,
+        ) -> nysa_types::U256 {
+            let mut result = nysa_types::U256::ZERO;
+            let mut i = nysa_types::U256::ZERO;
+            while i < nysa_types::U256::from(values.len()) {
+                result = nysa_types::unwrap_or_panic(
+                    result
+                        .checked_add(
+                            (*nysa_types::unwrap_or_panic(
+                                values.get(nysa_types::unwrap_or_panic(
+                                    i.checked_as_usize(),
+                                    Panic::IndexOutOfBounds,
+                                )),
+                                Panic::IndexOutOfBounds,
+                            )),
+                        ),
+                    Panic::ArithmeticOverflow,
+                );
+                i = nysa_types::unwrap_or_panic(
+                    i.checked_add(nysa_types::U256::ONE),
+                    Panic::ArithmeticOverflow,
+                );
+            }
+            return result;
+        }
+    }
+}
+pub mod counters {
+    #![allow(unused_braces, unused_mut, unused_parens, non_snake_case, unused_imports)]
+    {{DEFAULT_IMPORTS}}
+    {{STACK_DEF}}
+
+    #[derive(Clone)]
+    enum ClassName {
+        Counters,
+    }
+    #[odra::module]
+    pub struct Counters {
+        __stack: PathStack,
+    }
+    #[odra::module]
+    impl Counters {
+        const PATH: &'static [ClassName; 1usize] = &[ClassName::Counters];
+        pub(crate) fn current(counter: &mut counters::Counter) -> nysa_types::U256 {
+            return counter.value;
+        }
+        pub(crate) fn increment(counter: &mut counters::Counter) {
+            counter
+                .value = nysa_types::unwrap_or_panic(
+                counter.value.checked_add(nysa_types::U256::ONE),
+                Panic::ArithmeticOverflow,
+            );
+        }
+        pub(crate) fn next(counter: &mut counters::Counter) -> nysa_types::U256 {
+            Self::increment(counter);
+            return Self::current(counter);
+        }
+    }
+}
+pub mod free_functions {
+    #![allow(unused_braces, unused_mut, unused_parens, non_snake_case, unused_imports)]
+    {{DEFAULT_IMPORTS}}
+    {{STACK_DEF}}
+
+    #[derive(Clone)]
+    enum ClassName {
+        FreeFunctions,
+    }
+    #[odra::module]
+    pub struct FreeFunctions {
+        __stack: PathStack,
+    }
+    #[odra::module]
+    impl FreeFunctions {
+        const PATH: &'static [ClassName; 1usize] = &[ClassName::FreeFunctions];
+        pub(crate) fn twice(value: nysa_types::U256) -> nysa_types::U256 {
+            return nysa_types::unwrap_or_panic(
+                value.checked_mul(nysa_types::U256::from_limbs([2u64, 0u64, 0u64, 0u64])),
+                Panic::ArithmeticOverflow,
+            );
+        }
+    }
+}
+pub mod geometry {
+    #![allow(unused_braces, unused_mut, unused_parens, non_snake_case, unused_imports)]
+    {{DEFAULT_IMPORTS}}
+    {{STACK_DEF}}
+
+    #[derive(Clone)]
+    enum ClassName {
+        Geometry,
+    }
+    #[odra::module]
+    pub struct Geometry {
+        __stack: PathStack,
+    }
+    #[odra::module]
+    impl Geometry {
+        const PATH: &'static [ClassName; 1usize] = &[ClassName::Geometry];
+        pub(crate) fn norm(point: Point) -> nysa_types::U256 {
+            return nysa_types::unwrap_or_panic(
+                point.x.checked_add(point.y),
+                Panic::ArithmeticOverflow,
+            );
+        }
+    }
+}
+pub mod math {
+    #![allow(unused_braces, unused_mut, unused_parens, non_snake_case, unused_imports)]
+    {{DEFAULT_IMPORTS}}
+    {{STACK_DEF}}
+
+    #[derive(Clone)]
+    enum ClassName {
+        Math,
+    }
+    #[odra::module]
+    pub struct Math {
+        __stack: PathStack,
+    }
+    #[odra::module]
+    impl Math {
+        const PATH: &'static [ClassName; 1usize] = &[ClassName::Math];
+        pub(crate) fn is_zero(account: Option<odra::Address>) -> bool {
+            return account == None;
+        }
+        pub(crate) fn max(a: nysa_types::U256, b: nysa_types::U256) -> nysa_types::U256 {
+            return if a >= b { a } else { b };
+        }
+    }
+}
+pub mod registry {
+    #![allow(unused_braces, unused_mut, unused_parens, non_snake_case, unused_imports)]
+    {{DEFAULT_IMPORTS}}
+    {{STACK_DEF}}
+
+    #[derive(Clone)]
+    enum ClassName {
+        Registry,
+    }
+    #[odra::module]
+    pub struct Registry {
+        __stack: PathStack,
+        _ids: odra::Var<counters::Counter>,
+        _nonces: odra::Mapping<Option<odra::Address>, counters::Counter>,
+        _values: odra::Var<odra::prelude::vec::Vec<nysa_types::U256>>,
+        _owner: odra::Var<Option<odra::Address>>,
+    }
+    #[odra::module]
+    impl Registry {
+        const PATH: &'static [ClassName; 1usize] = &[ClassName::Registry];
+        pub fn best(&self, value: nysa_types::U256) -> nysa_types::U256 {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_best(value);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_best(&self, value: nysa_types::U256) -> nysa_types::U256 {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::Registry => {
+                    return super::math::Math::max(
+                        value,
+                        super::counters::Counters::current(
+                            &mut self._ids.get_or_default(),
+                        ),
+                    );
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_best(value),
+            }
+        }
+        pub fn distance(&self, x: nysa_types::U256, y: nysa_types::U256) -> nysa_types::U256 {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_distance(x, y);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_distance(&self, x: nysa_types::U256, y: nysa_types::U256) -> nysa_types::U256 {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::Registry => {
+                    let mut point = Point { x: x, y: y };
+                    return super::geometry::Geometry::norm(point);
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_distance(x, y),
+            }
+        }
+        pub fn doubled(&self, value: nysa_types::U256) -> nysa_types::U256 {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_doubled(value);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_doubled(&self, value: nysa_types::U256) -> nysa_types::U256 {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::Registry => {
+                    return super::free_functions::FreeFunctions::twice(value);
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_doubled(value),
+            }
+        }
+        pub fn has_owner(&self) -> bool {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_has_owner();
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_has_owner(&self) -> bool {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::Registry => {
+                    return !(super::math::Math::is_zero(
+                        self._owner.get().unwrap_or(None),
+                    ));
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_has_owner(),
+            }
+        }
+        #[odra(init)]
+        pub fn init(&mut self) {}
+        pub fn register(&mut self, value: nysa_types::U256) -> nysa_types::U256 {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_register(value);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_register(&mut self, value: nysa_types::U256) -> nysa_types::U256 {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::Registry => {
+                    {
+                        let mut result = self._values.get_or_default();
+                        result.push(value);
+                        self._values.set(result);
+                    };
+                    {
+                        let mut __value = self._nonces.get_or_default(&Some(self.env().caller()));
+                        let __result = super::counters::Counters::increment(
+                            &mut __value,
+                        );
+                        self._nonces.set(&Some(self.env().caller()), __value);
+                        __result
+                    };
+                    return {
+                        let mut __value = self._ids.get_or_default();
+                        let __result = super::counters::Counters::next(&mut __value);
+                        self._ids.set(__value);
+                        __result
+                    };
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_register(value),
+            }
+        }
+        pub fn total(&self) -> nysa_types::U256 {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_total();
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_total(&self) -> nysa_types::U256 {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::Registry => {
+                    return super::arrays::Arrays::sum(self._values.get_or_default());
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_total(),
+            }
+        }
+    }
+}
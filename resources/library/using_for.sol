@@ -0,0 +1,99 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.19;
+
+library Math {
+    function max(uint256 a, uint256 b) internal pure returns (uint256) {
+        return a >= b ? a : b;
+    }
+
+    function isZero(address account) internal pure returns (bool) {
+        return account == address(0);
+    }
+}
+
+library Arrays {
+    function sum(uint256[] memory values) internal pure returns (uint256) {
+        uint256 result = 0;
+        uint256 i = 0;
+        while (i < values.length) {
+            result = result + values[i];
+            i = i + 1;
+        }
+        return result;
+    }
+}
+
+library Counters {
+    struct Counter {
+        uint256 value;
+    }
+
+    function current(Counter storage counter) internal view returns (uint256) {
+        return counter.value;
+    }
+
+    function increment(Counter storage counter) internal {
+        counter.value += 1;
+    }
+
+    function next(Counter storage counter) internal returns (uint256) {
+        increment(counter);
+        return current(counter);
+    }
+}
+
+struct Point {
+    uint256 x;
+    uint256 y;
+}
+
+library Geometry {
+    function norm(Point memory point) internal pure returns (uint256) {
+        return point.x + point.y;
+    }
+}
+
+using Geometry for Point global;
+
+function twice(uint256 value) pure returns (uint256) {
+    return value * 2;
+}
+
+contract Registry {
+    using Math for *;
+    using Arrays for uint256[];
+    using Counters for Counters.Counter;
+    using {twice} for uint256;
+
+    Counters.Counter private _ids;
+    mapping(address => Counters.Counter) private _nonces;
+    uint256[] private _values;
+    address private _owner;
+
+    function register(uint256 value) public returns (uint256) {
+        _values.push(value);
+        _nonces[msg.sender].increment();
+        return _ids.next();
+    }
+
+    function total() public view returns (uint256) {
+        return _values.sum();
+    }
+
+    function best(uint256 value) public view returns (uint256) {
+        return value.max(_ids.current());
+    }
+
+    function hasOwner() public view returns (bool) {
+        return !_owner.isZero();
+    }
+
+    function doubled(uint256 value) public pure returns (uint256) {
+        return value.twice();
+    }
+
+    function distance(uint256 x, uint256 y) public pure returns (uint256) {
+        Point memory point = Point(x, y);
+        return point.norm();
+    }
+}
@@ -48,9 +48,9 @@ pub mod my_contract {
 
         #[odra(init)]
         pub fn init(&mut self, _my_uint: nysa_types::U256) {
-            self.my_number.set(nysa_types::U256::from_limbs_slice(&[42u64]));
+            self.my_number.set(nysa_types::U256::from_limbs([42u64, 0u64, 0u64, 0u64]));
             self.min_int.set(nysa_types::I256::MIN);
-            self.neg.set(-nysa_types::I32::from_limbs_slice(&[9u64]));
+            self.neg.set(nysa_types::I32::from_limbs([4294967287u64]));
             self.boo.set(true);
             self.my_uint_2.set(_my_uint);
         }
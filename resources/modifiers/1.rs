@@ -58,10 +58,18 @@ pub mod function_modifier {
                 ClassName::FunctionModifier => {
                     self.modifier_before_no_reentrancy();
 
-                    self.x.set(self.x.get_or_default() - i);
+                    self.x.set(odra::UnwrapOrRevert::unwrap_or_revert_with(
+                        self.x.get_or_default().checked_sub(i),
+                        &self.env(),
+                        Panic::ArithmeticOverflow,
+                    ));
 
                     if i > nysa_types::U32::ONE {
-                        self.decrement((i - nysa_types::U32::ONE));
+                        self.decrement(odra::UnwrapOrRevert::unwrap_or_revert_with(
+                            i.checked_sub(nysa_types::U32::ONE),
+                            &self.env(),
+                            Panic::ArithmeticOverflow,
+                        ));
                     }
 
                     self.modifier_after_no_reentrancy();
@@ -74,7 +82,7 @@ pub mod function_modifier {
         #[odra(init)]
         pub fn init(&mut self) {
             self.owner.set(Some(self.env().caller()));
-            self.x.set(nysa_types::U32::from_limbs_slice(&[10u64]));
+            self.x.set(nysa_types::U32::from_limbs([10u64]));
         }
 
         fn modifier_before_no_reentrancy(&mut self) {
@@ -59,8 +59,12 @@ pub mod bitwise_ops {
             let __class = self.__stack.pop_from_top_path();
             match __class {
                 ClassName::BitwiseOps => {
-                    let mut mask = (nysa_types::U256::ONE
-                        << n - nysa_types::U256::ONE) << (len - n);
+                    let mut mask = (nysa_types::U256::ONE << n - nysa_types::U256::ONE)
+                        << odra::UnwrapOrRevert::unwrap_or_revert_with(
+                            len.checked_sub(n),
+                            &self.env(),
+                            Panic::ArithmeticOverflow,
+                        );
                     return x & mask;
                 }
                 #[allow(unreachable_patterns)]
@@ -113,7 +117,11 @@ pub mod bitwise_ops {
             let __class = self.__stack.pop_from_top_path();
             match __class {
                 ClassName::BitwiseOps => {
-                    return (x % nysa_types::U256::ONE << n);
+                    return odra::UnwrapOrRevert::unwrap_or_revert_with(
+                        x.checked_rem(nysa_types::U256::ONE << n),
+                        &self.env(),
+                        Panic::DivisionByZero,
+                    );
                 }
                 #[allow(unreachable_patterns)]
                 _ => self.super_get_last_n_bits_using_mod(x, n),
@@ -140,7 +148,11 @@ pub mod bitwise_ops {
                         x
                     } > nysa_types::U256::ZERO
                     {
-                        i += nysa_types::Unsigned::ONE;
+                        i = odra::UnwrapOrRevert::unwrap_or_revert_with(
+                            i.checked_add(nysa_types::Unsigned::ONE),
+                            &self.env(),
+                            Panic::ArithmeticOverflow,
+                        );
                     }
                     return i;
                 }
@@ -1,4 +1,6 @@
-pub mod errors {}
+pub mod errors {
+    {{PANIC_DEF}}
+}
 pub mod events {
     use odra::prelude::*;
 }
@@ -18,7 +20,7 @@ pub mod array {
         __stack: PathStack,
         arr: odra::Var<odra::prelude::vec::Vec<nysa_types::U256>>,
         arr_2: odra::Var<odra::prelude::vec::Vec<nysa_types::U256>>,
-        my_fixed_size_arr: odra::Var<odra::prelude::vec::Vec<nysa_types::U256>>,
+        my_fixed_size_arr: odra::Var<nysa_types::FixedArray<nysa_types::U256, 10usize>>,
     }
 
     #[odra::module]
@@ -36,10 +38,19 @@ pub mod array {
             let __class = self.__stack.pop_from_top_path();
             match __class {
                 ClassName::Array => {
-                    let mut a = odra::prelude::vec::Vec::with_capacity(
-                        nysa_types::U256::from_limbs_slice(&[5u64]),
-                    );
-                    a[1] = nysa_types::U256::from_limbs_slice(&[123u64]);
+                    let mut a = odra::prelude::vec![
+                        Default::default();
+                        odra::UnwrapOrRevert::unwrap_or_revert_with(
+                            nysa_types::U256::from_limbs([5u64, 0u64, 0u64, 0u64]).checked_as_usize(),
+                            &self.env(),
+                            Panic::OutOfMemory
+                        )
+                    ];
+                    (*odra::UnwrapOrRevert::unwrap_or_revert_with(
+                        a.get_mut(1),
+                        &self.env(),
+                        Panic::IndexOutOfBounds,
+                    )) = nysa_types::U256::from_limbs([123u64, 0u64, 0u64, 0u64]);
                 }
                 #[allow(unreachable_patterns)]
                 _ => self.super_examples(),
@@ -57,7 +68,15 @@ pub mod array {
             let __class = self.__stack.pop_from_top_path();
             match __class {
                 ClassName::Array => {
-                    return self.arr.get_or_default()[i.as_usize()];
+                    return (*odra::UnwrapOrRevert::unwrap_or_revert_with(
+                        self.arr.get_or_default().get(odra::UnwrapOrRevert::unwrap_or_revert_with(
+                            i.checked_as_usize(),
+                            &self.env(),
+                            Panic::IndexOutOfBounds,
+                        )),
+                        &self.env(),
+                        Panic::IndexOutOfBounds,
+                    ));
                 }
                 #[allow(unreachable_patterns)]
                 _ => self.super_get(i),
@@ -93,7 +112,7 @@ pub mod array {
             let __class = self.__stack.pop_from_top_path();
             match __class {
                 ClassName::Array => {
-                    return self.arr.get_or_default().len().into();
+                    return nysa_types::U256::from(self.arr.get_or_default().len());
                 }
                 #[allow(unreachable_patterns)]
                 _ => self.super_get_length(),
@@ -104,8 +123,8 @@ pub mod array {
         pub fn init(&mut self) {
             self.arr_2.set(odra::prelude::vec![
                 nysa_types::U256::ONE,
-                nysa_types::U256::from_limbs_slice(&[2u64]),
-                nysa_types::U256::from_limbs_slice(&[3u64])
+                nysa_types::U256::from_limbs([2u64, 0u64, 0u64, 0u64]),
+                nysa_types::U256::from_limbs([3u64, 0u64, 0u64, 0u64])
             ]);
         }
 
@@ -122,7 +141,11 @@ pub mod array {
                 ClassName::Array => {
                     {
                         let mut result = self.arr.get_or_default();
-                        result.pop();
+                        odra::UnwrapOrRevert::unwrap_or_revert_with(
+                            result.pop(),
+                            &self.env(),
+                            Panic::EmptyArrayPop,
+                        );
                         self.arr.set(result);
                     };
                 }
@@ -166,7 +189,15 @@ pub mod array {
                 ClassName::Array => {
                     {
                         let mut result = self.arr.get_or_default();
-                        result[index] = Default::default();
+                        (*odra::UnwrapOrRevert::unwrap_or_revert_with(
+                            result.get_mut(odra::UnwrapOrRevert::unwrap_or_revert_with(
+                                index.checked_as_usize(),
+                                &self.env(),
+                                Panic::IndexOutOfBounds,
+                            )),
+                            &self.env(),
+                            Panic::IndexOutOfBounds,
+                        )) = Default::default();
                         self.arr.set(result);
                     };
                 }
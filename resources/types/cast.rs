@@ -1,4 +1,6 @@
-pub mod errors {}
+pub mod errors {
+    {{PANIC_DEF}}
+}
 pub mod events {
     use odra::prelude::*;
 }
@@ -40,9 +42,20 @@ pub mod erc_20 {
             self.decimals.set(_decimals);
             self.total_supply
                 .set(
-                    (_initial_supply 
-                        * nysa_types::U256::from_limbs_slice(&[10u64])
-                            .pow(nysa_types::U256::from(*self.decimals.get_or_default())))
+                    odra::UnwrapOrRevert::unwrap_or_revert_with(
+                        _initial_supply.checked_mul(
+                            odra::UnwrapOrRevert::unwrap_or_revert_with(
+                                nysa_types::U256::from_limbs([10u64, 0u64, 0u64, 0u64])
+                                    .checked_pow(<nysa_types::U256 as nysa_types::ConvertFrom<_>>::convert_from(
+                                        self.decimals.get_or_default()
+                                    )),
+                                &self.env(),
+                                Panic::ArithmeticOverflow,
+                            )
+                        ),
+                        &self.env(),
+                        Panic::ArithmeticOverflow,
+                    )
                 );
             self.balance_of.set(
                 &Some(self.env().caller()), 
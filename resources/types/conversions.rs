@@ -0,0 +1,160 @@
+pub mod errors {
+    {{PANIC_DEF}}
+}
+pub mod events {
+    use odra::prelude::*;
+}
+pub mod enums {
+    #[derive(odra::OdraType, Clone, PartialEq, Eq, Debug, Default)]
+    pub enum Status {
+        #[default]
+        Active = 0u8,
+        Paused = 1u8,
+    }
+}
+pub mod structs {}
+pub mod conversions {
+    #![allow(unused_braces, unused_mut, unused_parens, non_snake_case, unused_imports)]
+    use super::enums::*;
+    {{DEFAULT_IMPORTS}}
+    {{STACK_DEF}}
+
+    #[derive(Clone)]
+    enum ClassName {
+        Conversions,
+    }
+    #[odra::module]
+    pub struct Conversions {
+        __stack: PathStack,
+    }
+    #[odra::module]
+    impl Conversions {
+        const PATH: &'static [ClassName; 1usize] = &[ClassName::Conversions];
+        pub fn fixed_bytes(
+            &self,
+            h: nysa_types::FixedBytes<32usize>,
+            data: nysa_types::Bytes,
+        ) -> (
+            nysa_types::FixedBytes<4usize>,
+            nysa_types::FixedBytes<32usize>,
+            nysa_types::U256,
+            nysa_types::FixedBytes<8usize>,
+        ) {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_fixed_bytes(h, data);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_fixed_bytes(
+            &self,
+            h: nysa_types::FixedBytes<32usize>,
+            data: nysa_types::Bytes,
+        ) -> (
+            nysa_types::FixedBytes<4usize>,
+            nysa_types::FixedBytes<32usize>,
+            nysa_types::U256,
+            nysa_types::FixedBytes<8usize>,
+        ) {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::Conversions => {
+                    return (
+                        <nysa_types::FixedBytes<
+                            4usize,
+                        > as nysa_types::ConvertFrom<_>>::convert_from(h),
+                        <nysa_types::FixedBytes<
+                            32usize,
+                        > as nysa_types::ConvertFrom<
+                            _,
+                        >>::convert_from(
+                            <nysa_types::FixedBytes<
+                                4usize,
+                            > as nysa_types::ConvertFrom<_>>::convert_from(h),
+                        ),
+                        <nysa_types::U256 as nysa_types::ConvertFrom<
+                            _,
+                        >>::convert_from(h),
+                        <nysa_types::FixedBytes<
+                            8usize,
+                        > as nysa_types::ConvertFrom<_>>::convert_from(data),
+                    );
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_fixed_bytes(h, data),
+            }
+        }
+        #[odra(init)]
+        pub fn init(&mut self) {}
+        pub fn integers(
+            &self,
+            a: nysa_types::U256,
+            b: nysa_types::I16,
+        ) -> (nysa_types::U8, nysa_types::I8, nysa_types::I256, nysa_types::U16) {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_integers(a, b);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_integers(
+            &self,
+            a: nysa_types::U256,
+            b: nysa_types::I16,
+        ) -> (nysa_types::U8, nysa_types::I8, nysa_types::I256, nysa_types::U16) {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::Conversions => {
+                    return (
+                        <nysa_types::U8 as nysa_types::ConvertFrom<_>>::convert_from(a),
+                        <nysa_types::I8 as nysa_types::ConvertFrom<_>>::convert_from(b),
+                        <nysa_types::I256 as nysa_types::ConvertFrom<
+                            _,
+                        >>::convert_from(b),
+                        <nysa_types::U16 as nysa_types::ConvertFrom<_>>::convert_from(b),
+                    );
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_integers(a, b),
+            }
+        }
+        pub fn literals(
+            &self,
+        ) -> (nysa_types::U8, nysa_types::FixedBytes<4usize>, nysa_types::I8) {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_literals();
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_literals(
+            &self,
+        ) -> (nysa_types::U8, nysa_types::FixedBytes<4usize>, nysa_types::I8) {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::Conversions => {
+                    return (
+                        nysa_types::U8::from_limbs([255u64]),
+                        nysa_types::FixedBytes([18u8, 52u8, 86u8, 120u8]),
+                        nysa_types::I8::MINUS_ONE,
+                    );
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_literals(),
+            }
+        }
+        pub fn status(&self, s: Status) -> nysa_types::U8 {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_status(s);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_status(&self, s: Status) -> nysa_types::U8 {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::Conversions => {
+                    return nysa_types::U8::from(s as u8);
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_status(s),
+            }
+        }
+    }
+}
@@ -0,0 +1,25 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.0;
+
+contract Conversions {
+    enum Status {
+        Active,
+        Paused
+    }
+
+    function integers(uint256 a, int16 b) public pure returns (uint8, int8, int256, uint16) {
+        return (uint8(a), int8(b), int256(b), uint16(b));
+    }
+
+    function fixedBytes(bytes32 h, bytes memory data) public pure returns (bytes4, bytes32, uint256, bytes8) {
+        return (bytes4(h), bytes32(bytes4(h)), uint256(h), bytes8(data));
+    }
+
+    function literals() public pure returns (uint8, bytes4, int8) {
+        return (uint8(255), bytes4(0x12345678), int8(-1));
+    }
+
+    function status(Status s) public pure returns (uint8) {
+        return uint8(s);
+    }
+}
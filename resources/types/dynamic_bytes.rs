@@ -0,0 +1,228 @@
+{{DEFAULT_MODULES}}
+pub mod dynamic_bytes {
+    #![allow(
+        unused_braces,
+        unused_mut,
+        unused_parens,
+        non_snake_case,
+        unused_imports
+    )]
+    {{DEFAULT_IMPORTS}}
+    {{STACK_DEF}}
+
+    #[derive(Clone)]
+    enum ClassName {
+        DynamicBytes,
+    }
+    #[odra::module]
+    pub struct DynamicBytes {
+        __stack: PathStack,
+        data: odra::Var<nysa_types::Bytes>,
+        name: odra::Var<odra::prelude::string::String>,
+    }
+    #[odra::module]
+    impl DynamicBytes {
+        const PATH: &'static [ClassName; 1usize] = &[ClassName::DynamicBytes];
+        pub fn append(&mut self, suffix: nysa_types::Bytes) {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_append(suffix);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_append(&mut self, suffix: nysa_types::Bytes) {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::DynamicBytes => {
+                    self.data.set(nysa_types::Bytes::concat(&[
+                        self.data.get_or_default().as_slice(),
+                        suffix.as_slice(),
+                    ]));
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_append(suffix),
+            }
+        }
+        pub fn data_length(&self) -> nysa_types::U256 {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_data_length();
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_data_length(&self) -> nysa_types::U256 {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::DynamicBytes => {
+                    return nysa_types::U256::from(self.data.get_or_default().len());
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_data_length(),
+            }
+        }
+        pub fn first_byte(&self) -> nysa_types::FixedBytes<1usize> {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_first_byte();
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_first_byte(&self) -> nysa_types::FixedBytes<1usize> {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::DynamicBytes => {
+                    return odra::UnwrapOrRevert::unwrap_or_revert_with(
+                        self.data
+                            .get_or_default()
+                            .at(odra::UnwrapOrRevert::unwrap_or_revert_with(
+                                nysa_types::U256::ZERO.checked_as_usize(),
+                                &self.env(),
+                                Panic::IndexOutOfBounds,
+                            )),
+                        &self.env(),
+                        Panic::IndexOutOfBounds,
+                    );
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_first_byte(),
+            }
+        }
+        pub fn full_name(
+            &self,
+            suffix: odra::prelude::string::String,
+        ) -> odra::prelude::string::String {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_full_name(suffix);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_full_name(
+            &self,
+            suffix: odra::prelude::string::String,
+        ) -> odra::prelude::string::String {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::DynamicBytes => {
+                    return [
+                        self.name.get_or_default().as_str(),
+                        odra::prelude::string::String::from(" ").as_str(),
+                        suffix.as_str(),
+                    ]
+                    .concat();
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_full_name(suffix),
+            }
+        }
+        #[odra(init)]
+        pub fn init(&mut self) {}
+        pub fn invalid_string(&self) -> odra::prelude::string::String {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_invalid_string();
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_invalid_string(&self) -> odra::prelude::string::String {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::DynamicBytes => {
+                    let mut raw = nysa_types::Bytes::from([97u8, 255u8].as_slice());
+                    return raw.to_utf8_string();
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_invalid_string(),
+            }
+        }
+        pub fn last_byte(&self, b: nysa_types::Bytes) -> nysa_types::FixedBytes<1usize> {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_last_byte(b);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_last_byte(&self, b: nysa_types::Bytes) -> nysa_types::FixedBytes<1usize> {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::DynamicBytes => {
+                    return odra::UnwrapOrRevert::unwrap_or_revert_with(
+                        b.at(odra::UnwrapOrRevert::unwrap_or_revert_with(
+                            odra::UnwrapOrRevert::unwrap_or_revert_with(
+                                nysa_types::U256::from(b.len()).checked_sub(nysa_types::U256::ONE),
+                                &self.env(),
+                                Panic::ArithmeticOverflow,
+                            )
+                            .checked_as_usize(),
+                            &self.env(),
+                            Panic::IndexOutOfBounds,
+                        )),
+                        &self.env(),
+                        Panic::IndexOutOfBounds,
+                    );
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_last_byte(b),
+            }
+        }
+        pub fn name_length(&self) -> nysa_types::U256 {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_name_length();
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_name_length(&self) -> nysa_types::U256 {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::DynamicBytes => {
+                    return nysa_types::U256::from(
+                        nysa_types::Bytes::from(self.name.get_or_default().as_bytes()).len(),
+                    );
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_name_length(),
+            }
+        }
+        pub fn set_byte(&mut self, i: nysa_types::U256, value: nysa_types::FixedBytes<1usize>) {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_set_byte(i, value);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_set_byte(&mut self, i: nysa_types::U256, value: nysa_types::FixedBytes<1usize>) {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::DynamicBytes => {
+                    {
+                        let mut result = self.data.get_or_default();
+                        odra::UnwrapOrRevert::unwrap_or_revert_with(
+                            result.set(
+                                odra::UnwrapOrRevert::unwrap_or_revert_with(
+                                    i.checked_as_usize(),
+                                    &self.env(),
+                                    Panic::IndexOutOfBounds,
+                                ),
+                                value,
+                            ),
+                            &self.env(),
+                            Panic::IndexOutOfBounds,
+                        );
+                        self.data.set(result);
+                    };
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_set_byte(i, value),
+            }
+        }
+        pub fn to_string(&self) -> odra::prelude::string::String {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_to_string();
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_to_string(&self) -> odra::prelude::string::String {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::DynamicBytes => {
+                    return self.data.get_or_default().to_utf8_string();
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_to_string(),
+            }
+        }
+    }
+}
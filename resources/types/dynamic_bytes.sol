@@ -0,0 +1,44 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.19;
+
+contract DynamicBytes {
+    bytes data;
+    string name;
+
+    function nameLength() public view returns (uint256) {
+        return bytes(name).length;
+    }
+
+    function dataLength() public view returns (uint256) {
+        return data.length;
+    }
+
+    function fullName(string memory suffix) public view returns (string memory) {
+        return string.concat(name, " ", suffix);
+    }
+
+    function append(bytes memory suffix) public {
+        data = bytes.concat(data, suffix);
+    }
+
+    function firstByte() public view returns (bytes1) {
+        return data[0];
+    }
+
+    function setByte(uint256 i, bytes1 value) public {
+        data[i] = value;
+    }
+
+    function lastByte(bytes memory b) public pure returns (bytes1) {
+        return b[b.length - 1];
+    }
+
+    function toString() public view returns (string memory) {
+        return string(data);
+    }
+
+    function invalidString() public pure returns (string memory) {
+        bytes memory raw = hex"61ff";
+        return string(raw);
+    }
+}
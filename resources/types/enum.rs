@@ -1,9 +1,11 @@
-pub mod errors {}
+pub mod errors {
+    {{PANIC_DEF}}
+}
 pub mod events {
     use odra::prelude::*;
 }
 pub mod enums {
-    #[derive(odra::OdraType, PartialEq, Eq, Debug, Default)]
+    #[derive(odra::OdraType, Clone, PartialEq, Eq, Debug, Default)]
     pub enum Status {
         #[default]
         Pending = 0u8,
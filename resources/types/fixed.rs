@@ -0,0 +1,160 @@
+{{DEFAULT_MODULES}}
+pub mod fixed_point {
+    #![allow(unused_braces, unused_mut, unused_parens, non_snake_case, unused_imports)]
+    {{DEFAULT_IMPORTS}}
+    {{STACK_DEF}}
+
+    #[derive(Clone)]
+    enum ClassName {
+        FixedPoint,
+    }
+    #[odra::module]
+    pub struct FixedPoint {
+        __stack: PathStack,
+        price: odra::Var<nysa_types::Ufixed::<128, 2, 18>>,
+    }
+    #[odra::module]
+    impl FixedPoint {
+        const PATH: &'static [ClassName; 1usize] = &[ClassName::FixedPoint];
+        pub const STEP: nysa_types::Fixed::<8, 1, 1> = nysa_types::Fixed::<
+            8,
+            1,
+            1,
+        >::from_raw(nysa_types::I8::from_limbs([251u64]));
+        pub const SUPPLY: nysa_types::U256 = nysa_types::U256::from_limbs([
+            2500000000000000000u64,
+            0u64,
+            0u64,
+            0u64,
+        ]);
+        pub fn cost(
+            &self,
+            amount: nysa_types::Ufixed::<128, 2, 18>,
+        ) -> nysa_types::Ufixed::<128, 2, 18> {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_cost(amount);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_cost(
+            &self,
+            amount: nysa_types::Ufixed::<128, 2, 18>,
+        ) -> nysa_types::Ufixed::<128, 2, 18> {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::FixedPoint => {
+                    return odra::UnwrapOrRevert::unwrap_or_revert_with(
+                        odra::UnwrapOrRevert::unwrap_or_revert_with(
+                                self.price.get_or_default().checked_mul(amount),
+                                &self.env(),
+                                Panic::ArithmeticOverflow,
+                            )
+                            .checked_div(
+                                odra::UnwrapOrRevert::unwrap_or_revert_with(
+                                    nysa_types::Ufixed::<
+                                        128,
+                                        2,
+                                        18,
+                                    >::from_raw(
+                                            nysa_types::U128::from_limbs([2000000000000000000u64, 0u64]),
+                                        )
+                                        .non_zero(),
+                                    &self.env(),
+                                    Panic::DivisionByZero,
+                                ),
+                            ),
+                        &self.env(),
+                        Panic::ArithmeticOverflow,
+                    );
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_cost(amount),
+            }
+        }
+        pub fn discount(&self) -> nysa_types::Ufixed::<128, 2, 18> {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_discount();
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_discount(&self) -> nysa_types::Ufixed::<128, 2, 18> {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::FixedPoint => {
+                    return odra::UnwrapOrRevert::unwrap_or_revert_with(
+                        self
+                            .price
+                            .get_or_default()
+                            .checked_sub(
+                                nysa_types::Ufixed::<
+                                    128,
+                                    2,
+                                    18,
+                                >::from_raw(
+                                    nysa_types::U128::from_limbs([125000000000000000u64, 0u64]),
+                                ),
+                            ),
+                        &self.env(),
+                        Panic::ArithmeticOverflow,
+                    );
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_discount(),
+            }
+        }
+        #[odra(init)]
+        pub fn init(&mut self) {}
+        pub fn set_price(&mut self, new_price: nysa_types::Ufixed::<128, 2, 18>) {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_set_price(new_price);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_set_price(&mut self, new_price: nysa_types::Ufixed::<128, 2, 18>) {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::FixedPoint => {
+                    self.price.set(new_price);
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_set_price(new_price),
+            }
+        }
+        pub fn steps(
+            &self,
+            a: nysa_types::Fixed::<8, 1, 1>,
+        ) -> nysa_types::Fixed::<8, 1, 1> {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_steps(a);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_steps(
+            &self,
+            a: nysa_types::Fixed::<8, 1, 1>,
+        ) -> nysa_types::Fixed::<8, 1, 1> {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::FixedPoint => {
+                    let mut b = nysa_types::Fixed::<
+                        8,
+                        1,
+                        1,
+                    >::from_raw(nysa_types::I8::from_limbs([15u64]));
+                    return odra::UnwrapOrRevert::unwrap_or_revert_with(
+                        odra::UnwrapOrRevert::unwrap_or_revert_with(
+                                a.checked_add(b),
+                                &self.env(),
+                                Panic::ArithmeticOverflow,
+                            )
+                            .checked_sub(Self::STEP),
+                        &self.env(),
+                        Panic::ArithmeticOverflow,
+                    );
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_steps(a),
+            }
+        }
+    }
+}
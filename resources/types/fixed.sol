@@ -0,0 +1,25 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.0;
+
+contract FixedPoint {
+    ufixed price;
+    fixed8x1 constant STEP = -0.5;
+    uint256 constant SUPPLY = 2.5e18;
+
+    function setPrice(ufixed newPrice) public {
+        price = newPrice;
+    }
+
+    function cost(ufixed amount) public view returns (ufixed) {
+        return price * amount / 2;
+    }
+
+    function discount() public view returns (ufixed) {
+        return price - 0.125;
+    }
+
+    function steps(fixed8x1 a) public pure returns (fixed8x1) {
+        fixed8x1 b = 1.5;
+        return a + b - STEP;
+    }
+}
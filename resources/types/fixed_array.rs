@@ -0,0 +1,370 @@
+{{DEFAULT_MODULES}}
+pub mod fixed_array {
+    #![allow(unused_braces, unused_mut, unused_parens, non_snake_case, unused_imports)]
+    {{DEFAULT_IMPORTS}}
+    {{STACK_DEF}}
+
+    #[derive(Clone)]
+    enum ClassName {
+        FixedArray,
+    }
+    #[odra::module]
+    pub struct FixedArray {
+        __stack: PathStack,
+        balances: odra::Var<nysa_types::FixedArray<nysa_types::U256, 3usize>>,
+        grid: odra::Var<
+            nysa_types::FixedArray<
+                nysa_types::FixedArray<nysa_types::U8, 2usize>,
+                3usize,
+            >,
+        >,
+        groups: odra::Var<
+            odra::prelude::vec::Vec<odra::prelude::vec::Vec<Option<odra::Address>>>,
+        >,
+        scores: odra::Var<
+            nysa_types::FixedArray<nysa_types::FixedArray<nysa_types::U256, 4usize>, 2usize>,
+        >,
+    }
+    #[odra::module]
+    impl FixedArray {
+        const PATH: &'static [ClassName; 1usize] = &[ClassName::FixedArray];
+        pub const LIMIT: nysa_types::U256 = nysa_types::U256::from_limbs([2u64, 0u64, 0u64, 0u64]);
+        pub fn add_group(&mut self) {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_add_group();
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_add_group(&mut self) {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::FixedArray => {
+                    {
+                        let mut result = self.groups.get_or_default();
+                        result
+                            .push(
+                                odra::prelude::vec![
+                                    Default::default(); odra::UnwrapOrRevert::unwrap_or_revert_with(
+                                        nysa_types::U256::ZERO.checked_as_usize(),
+                                        &self.env(),
+                                        Panic::OutOfMemory
+                                    )
+                                ],
+                            );
+                        self.groups.set(result);
+                    };
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_add_group(),
+            }
+        }
+        pub fn count(&self) -> nysa_types::U256 {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_count();
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_count(&self) -> nysa_types::U256 {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::FixedArray => {
+                    return nysa_types::U256::from(self.balances.get_or_default().len());
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_count(),
+            }
+        }
+        pub fn get_balance(&self, i: nysa_types::U256) -> nysa_types::U256 {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_get_balance(i);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_get_balance(&self, i: nysa_types::U256) -> nysa_types::U256 {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::FixedArray => {
+                    return (*odra::UnwrapOrRevert::unwrap_or_revert_with(
+                        self.balances.get_or_default().get(odra::UnwrapOrRevert::unwrap_or_revert_with(
+                            i.checked_as_usize(),
+                            &self.env(),
+                            Panic::IndexOutOfBounds,
+                        )),
+                        &self.env(),
+                        Panic::IndexOutOfBounds,
+                    ));
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_get_balance(i),
+            }
+        }
+        pub fn get_cell(
+            &self,
+            x: nysa_types::U256,
+            y: nysa_types::U256,
+        ) -> nysa_types::U8 {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_get_cell(x, y);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_get_cell(
+            &self,
+            x: nysa_types::U256,
+            y: nysa_types::U256,
+        ) -> nysa_types::U8 {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::FixedArray => {
+                    return (*odra::UnwrapOrRevert::unwrap_or_revert_with(
+                        (*odra::UnwrapOrRevert::unwrap_or_revert_with(
+                            self.grid.get_or_default().get(odra::UnwrapOrRevert::unwrap_or_revert_with(
+                                x.checked_as_usize(),
+                                &self.env(),
+                                Panic::IndexOutOfBounds,
+                            )),
+                            &self.env(),
+                            Panic::IndexOutOfBounds,
+                        ))
+                            .get(odra::UnwrapOrRevert::unwrap_or_revert_with(
+                                y.checked_as_usize(),
+                                &self.env(),
+                                Panic::IndexOutOfBounds,
+                            )),
+                        &self.env(),
+                        Panic::IndexOutOfBounds,
+                    ));
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_get_cell(x, y),
+            }
+        }
+        #[odra(init)]
+        pub fn init(&mut self) {}
+        pub fn last_scores(&self) -> nysa_types::FixedArray<nysa_types::U256, 4usize> {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_last_scores();
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_last_scores(&self) -> nysa_types::FixedArray<nysa_types::U256, 4usize> {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::FixedArray => {
+                    let mut result = (*odra::UnwrapOrRevert::unwrap_or_revert_with(
+                        self.scores.get_or_default().get(
+                            odra::UnwrapOrRevert::unwrap_or_revert_with(
+                                odra::UnwrapOrRevert::unwrap_or_revert_with(
+                                    Self::LIMIT.checked_sub(nysa_types::U256::ONE),
+                                    &self.env(),
+                                    Panic::ArithmeticOverflow,
+                                )
+                                .checked_as_usize(),
+                                &self.env(),
+                                Panic::IndexOutOfBounds,
+                            ),
+                        ),
+                        &self.env(),
+                        Panic::IndexOutOfBounds,
+                    ))
+                    .clone();
+                    return result;
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_last_scores(),
+            }
+        }
+        pub fn set_balance(&mut self, i: nysa_types::U256, value: nysa_types::U256) {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_set_balance(i, value);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_set_balance(&mut self, i: nysa_types::U256, value: nysa_types::U256) {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::FixedArray => {
+                    {
+                        let mut result = self.balances.get_or_default();
+                        (*odra::UnwrapOrRevert::unwrap_or_revert_with(
+                            result.get_mut(odra::UnwrapOrRevert::unwrap_or_revert_with(
+                                i.checked_as_usize(),
+                                &self.env(),
+                                Panic::IndexOutOfBounds,
+                            )),
+                            &self.env(),
+                            Panic::IndexOutOfBounds,
+                        )) = value;
+                        self.balances.set(result);
+                    };
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_set_balance(i, value),
+            }
+        }
+        pub fn set_balances(
+            &mut self,
+            values: nysa_types::FixedArray<nysa_types::U256, 3usize>,
+        ) {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_set_balances(values);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_set_balances(
+            &mut self,
+            values: nysa_types::FixedArray<nysa_types::U256, 3usize>,
+        ) {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::FixedArray => {
+                    self.balances.set(values);
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_set_balances(values),
+            }
+        }
+        pub fn set_cell(
+            &mut self,
+            x: nysa_types::U256,
+            y: nysa_types::U256,
+            value: nysa_types::U8,
+        ) {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_set_cell(x, y, value);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_set_cell(
+            &mut self,
+            x: nysa_types::U256,
+            y: nysa_types::U256,
+            value: nysa_types::U8,
+        ) {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::FixedArray => {
+                    {
+                        let mut result = self.grid.get_or_default();
+                        (*odra::UnwrapOrRevert::unwrap_or_revert_with(
+                            (*odra::UnwrapOrRevert::unwrap_or_revert_with(
+                                result.get_mut(odra::UnwrapOrRevert::unwrap_or_revert_with(
+                                    x.checked_as_usize(),
+                                    &self.env(),
+                                    Panic::IndexOutOfBounds,
+                                )),
+                                &self.env(),
+                                Panic::IndexOutOfBounds,
+                            ))
+                                .get_mut(odra::UnwrapOrRevert::unwrap_or_revert_with(
+                                    y.checked_as_usize(),
+                                    &self.env(),
+                                    Panic::IndexOutOfBounds,
+                                )),
+                            &self.env(),
+                            Panic::IndexOutOfBounds,
+                        )) = value;
+                        self.grid.set(result);
+                    };
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_set_cell(x, y, value),
+            }
+        }
+        pub fn set_score(
+            &mut self,
+            x: nysa_types::U256,
+            y: nysa_types::U256,
+            value: nysa_types::U256,
+        ) {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_set_score(x, y, value);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_set_score(
+            &mut self,
+            x: nysa_types::U256,
+            y: nysa_types::U256,
+            value: nysa_types::U256,
+        ) {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::FixedArray => {
+                    {
+                        let mut result = self.scores.get_or_default();
+                        (*odra::UnwrapOrRevert::unwrap_or_revert_with(
+                            (*odra::UnwrapOrRevert::unwrap_or_revert_with(
+                                result.get_mut(odra::UnwrapOrRevert::unwrap_or_revert_with(
+                                    y.checked_as_usize(),
+                                    &self.env(),
+                                    Panic::IndexOutOfBounds,
+                                )),
+                                &self.env(),
+                                Panic::IndexOutOfBounds,
+                            ))
+                            .get_mut(
+                                odra::UnwrapOrRevert::unwrap_or_revert_with(
+                                    x.checked_as_usize(),
+                                    &self.env(),
+                                    Panic::IndexOutOfBounds,
+                                ),
+                            ),
+                            &self.env(),
+                            Panic::IndexOutOfBounds,
+                        )) = value;
+                        self.scores.set(result);
+                    };
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_set_score(x, y, value),
+            }
+        }
+        pub fn sum(&self) -> nysa_types::U8 {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_sum();
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_sum(&self) -> nysa_types::U8 {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::FixedArray => {
+                    let mut values = nysa_types::FixedArray([
+                        nysa_types::U8::ONE,
+                        nysa_types::U8::from_limbs([2u64]),
+                        nysa_types::U8::from_limbs([3u64]),
+                    ]);
+                    let mut result = Default::default();
+                    let mut i = nysa_types::U256::ZERO;
+                    while i < nysa_types::U256::from(values.len()) {
+                        result = odra::UnwrapOrRevert::unwrap_or_revert_with(
+                            result
+                                .checked_add(
+                                    (*odra::UnwrapOrRevert::unwrap_or_revert_with(
+                                        values.get(odra::UnwrapOrRevert::unwrap_or_revert_with(
+                                            i.checked_as_usize(),
+                                            &self.env(),
+                                            Panic::IndexOutOfBounds,
+                                        )),
+                                        &self.env(),
+                                        Panic::IndexOutOfBounds,
+                                    )),
+                                ),
+                            &self.env(),
+                            Panic::ArithmeticOverflow,
+                        );
+                        i = odra::UnwrapOrRevert::unwrap_or_revert_with(
+                            i.checked_add(nysa_types::Unsigned::ONE),
+                            &self.env(),
+                            Panic::ArithmeticOverflow,
+                        );
+                    }
+                    return result;
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_sum(),
+            }
+        }
+    }
+}
@@ -0,0 +1,61 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.19;
+
+uint256 constant SIZE = 4;
+
+contract FixedArray {
+    uint256 constant LIMIT = 2;
+
+    uint256[3] balances;
+    uint8[2][3] grid;
+    address[][] groups;
+    uint256[SIZE][LIMIT] scores;
+
+    function setBalance(uint256 i, uint256 value) public {
+        balances[i] = value;
+    }
+
+    function getBalance(uint256 i) public view returns (uint256) {
+        return balances[i];
+    }
+
+    function setBalances(uint256[3] memory values) public {
+        balances = values;
+    }
+
+    function count() public view returns (uint256) {
+        return balances.length;
+    }
+
+    function setCell(uint256 x, uint256 y, uint8 value) public {
+        grid[x][y] = value;
+    }
+
+    function getCell(uint256 x, uint256 y) public view returns (uint8) {
+        return grid[x][y];
+    }
+
+    function addGroup() public {
+        groups.push(new address[](0));
+    }
+
+    function sum() public pure returns (uint8) {
+        uint8[3] memory values = [1, 2, 3];
+        uint8 result;
+        uint256 i = 0;
+        while (i < values.length) {
+            result += values[i];
+            i++;
+        }
+        return result;
+    }
+
+    function setScore(uint256 x, uint256 y, uint256 value) public {
+        scores[y][x] = value;
+    }
+
+    function lastScores() public view returns (uint256[SIZE] memory) {
+        uint256[SIZE] memory result = scores[LIMIT - 1];
+        return result;
+    }
+}
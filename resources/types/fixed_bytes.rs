@@ -0,0 +1,194 @@
+{{DEFAULT_MODULES}}
+pub mod fixed_bytes {
+    #![allow(unused_braces, unused_mut, unused_parens, non_snake_case, unused_imports)]
+    {{DEFAULT_IMPORTS}}
+    {{STACK_DEF}}
+
+    #[derive(Clone)]
+    enum ClassName {
+        FixedBytes,
+    }
+    #[odra::module]
+    pub struct FixedBytes {
+        __stack: PathStack,
+        bitmap: odra::Var<nysa_types::FixedBytes<32usize>>,
+    }
+    #[odra::module]
+    impl FixedBytes {
+        const PATH: &'static [ClassName; 1usize] = &[ClassName::FixedBytes];
+        pub fn clear(&mut self, bit: nysa_types::U8) {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_clear(bit);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_clear(&mut self, bit: nysa_types::U8) {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::FixedBytes => {
+                    self.bitmap
+                        .set(
+                            self.bitmap.get_or_default()
+                                & !(<nysa_types::FixedBytes<
+                                    32usize,
+                                > as nysa_types::ConvertFrom<
+                                    _,
+                                >>::convert_from(nysa_types::U256::ONE) << bit),
+                        );
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_clear(bit),
+            }
+        }
+        pub fn first_byte(&self) -> nysa_types::FixedBytes<1usize> {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_first_byte();
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_first_byte(&self) -> nysa_types::FixedBytes<1usize> {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::FixedBytes => {
+                    return odra::UnwrapOrRevert::unwrap_or_revert_with(
+                        self.bitmap.get_or_default().at(odra::UnwrapOrRevert::unwrap_or_revert_with(
+                            nysa_types::U256::ZERO.checked_as_usize(),
+                            &self.env(),
+                            Panic::IndexOutOfBounds,
+                        )),
+                        &self.env(),
+                        Panic::IndexOutOfBounds,
+                    );
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_first_byte(),
+            }
+        }
+        #[odra(init)]
+        pub fn init(&mut self) {}
+        pub fn length(&self, data: nysa_types::FixedBytes<8usize>) -> nysa_types::U8 {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_length(data);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_length(&self, data: nysa_types::FixedBytes<8usize>) -> nysa_types::U8 {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::FixedBytes => {
+                    return data.length();
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_length(data),
+            }
+        }
+        pub fn max(
+            &self,
+            a: nysa_types::FixedBytes<32usize>,
+            b: nysa_types::FixedBytes<32usize>,
+        ) -> nysa_types::FixedBytes<32usize> {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_max(a, b);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_max(
+            &self,
+            a: nysa_types::FixedBytes<32usize>,
+            b: nysa_types::FixedBytes<32usize>,
+        ) -> nysa_types::FixedBytes<32usize> {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::FixedBytes => {
+                    return if a > b { a } else { b };
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_max(a, b),
+            }
+        }
+        pub fn nibbles(
+            &self,
+            data: nysa_types::FixedBytes<4usize>,
+            i: nysa_types::U256,
+        ) -> (nysa_types::FixedBytes<1usize>, nysa_types::FixedBytes<1usize>) {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_nibbles(data, i);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_nibbles(
+            &self,
+            data: nysa_types::FixedBytes<4usize>,
+            i: nysa_types::U256,
+        ) -> (nysa_types::FixedBytes<1usize>, nysa_types::FixedBytes<1usize>) {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::FixedBytes => {
+                    let mut b = odra::UnwrapOrRevert::unwrap_or_revert_with(
+                        data.at(odra::UnwrapOrRevert::unwrap_or_revert_with(
+                            i.checked_as_usize(),
+                            &self.env(),
+                            Panic::IndexOutOfBounds,
+                        )),
+                        &self.env(),
+                        Panic::IndexOutOfBounds,
+                    );
+                    return (
+                        b >> nysa_types::U256::from_limbs([4u64, 0u64, 0u64, 0u64]),
+                        b & nysa_types::FixedBytes([15u8]),
+                    );
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_nibbles(data, i),
+            }
+        }
+        pub fn set(&mut self, bit: nysa_types::U8) {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_set(bit);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_set(&mut self, bit: nysa_types::U8) {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::FixedBytes => {
+                    self.bitmap
+                        .set(
+                            self.bitmap.get_or_default()
+                                | <nysa_types::FixedBytes<
+                                    32usize,
+                                > as nysa_types::ConvertFrom<
+                                    _,
+                                >>::convert_from(nysa_types::U256::ONE) << bit,
+                        );
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_set(bit),
+            }
+        }
+        pub fn shift(
+            &self,
+            a: nysa_types::FixedBytes<32usize>,
+        ) -> nysa_types::FixedBytes<32usize> {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_shift(a);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_shift(
+            &self,
+            a: nysa_types::FixedBytes<32usize>,
+        ) -> nysa_types::FixedBytes<32usize> {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::FixedBytes => {
+                    let mut b = a;
+                    b = b << nysa_types::U256::from_limbs([8u64, 0u64, 0u64, 0u64]);
+                    return b >> nysa_types::U256::from_limbs([4u64, 0u64, 0u64, 0u64]);
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_shift(a),
+            }
+        }
+    }
+}
@@ -0,0 +1,37 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.0;
+
+contract FixedBytes {
+    bytes32 bitmap;
+
+    function set(uint8 bit) public {
+        bitmap |= bytes32(uint256(1)) << bit;
+    }
+
+    function clear(uint8 bit) public {
+        bitmap &= ~(bytes32(uint256(1)) << bit);
+    }
+
+    function firstByte() public view returns (bytes1) {
+        return bitmap[0];
+    }
+
+    function nibbles(bytes4 data, uint256 i) public pure returns (bytes1, bytes1) {
+        bytes1 b = data[i];
+        return (b >> 4, b & 0x0f);
+    }
+
+    function length(bytes8 data) public pure returns (uint8) {
+        return data.length;
+    }
+
+    function max(bytes32 a, bytes32 b) public pure returns (bytes32) {
+        return a > b ? a : b;
+    }
+
+    function shift(bytes32 a) public pure returns (bytes32) {
+        bytes32 b = a;
+        b <<= 8;
+        return b >> 4;
+    }
+}
@@ -0,0 +1,236 @@
+{{DEFAULT_MODULES}}
+pub mod function_type {
+    #![allow(unused_braces, unused_mut, unused_parens, non_snake_case, unused_imports)]
+    #[derive(odra::OdraType, Copy, Clone, PartialEq, Eq, Debug)]
+    enum InternalFunction {
+        Double,
+        Scale,
+    }
+    {{DEFAULT_IMPORTS}}
+    {{STACK_DEF}}
+
+    #[derive(Clone)]
+    enum ClassName {
+        FunctionType,
+    }
+    #[odra::module]
+    pub struct FunctionType {
+        __stack: PathStack,
+        factor: odra::Var<nysa_types::U256>,
+        strategy: odra::Var<Option<InternalFunction>>,
+        fallback_strategy: odra::Var<Option<InternalFunction>>,
+    }
+    #[odra::module]
+    impl FunctionType {
+        const PATH: &'static [ClassName; 1usize] = &[ClassName::FunctionType];
+        pub(crate) fn apply(
+            &self,
+            op: fn(&Self, nysa_types::U256) -> nysa_types::U256,
+            value: nysa_types::U256,
+        ) -> nysa_types::U256 {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_apply(op, value);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_apply(
+            &self,
+            op: fn(&Self, nysa_types::U256) -> nysa_types::U256,
+            value: nysa_types::U256,
+        ) -> nysa_types::U256 {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::FunctionType => {
+                    return op(self, value);
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_apply(op, value),
+            }
+        }
+        pub fn compute(
+            &self,
+            value: nysa_types::U256,
+            scaled: bool,
+        ) -> nysa_types::U256 {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_compute(value, scaled);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_compute(
+            &self,
+            value: nysa_types::U256,
+            scaled: bool,
+        ) -> nysa_types::U256 {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::FunctionType => {
+                    let mut op = Self::double
+                        as fn(&Self, nysa_types::U256) -> nysa_types::U256;
+                    if scaled {
+                        op = Self::scale
+                            as fn(&Self, nysa_types::U256) -> nysa_types::U256;
+                    }
+                    return odra::UnwrapOrRevert::unwrap_or_revert_with(
+                        odra::UnwrapOrRevert::unwrap_or_revert_with(
+                                self
+                                    .apply(op, value)
+                                    .checked_add(
+                                        self.apply(Self::double, nysa_types::U256::ONE),
+                                    ),
+                                &self.env(),
+                                Panic::ArithmeticOverflow,
+                            )
+                            .checked_add(op(self, nysa_types::U256::ONE)),
+                        &self.env(),
+                        Panic::ArithmeticOverflow,
+                    );
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_compute(value, scaled),
+            }
+        }
+        pub fn compute_with_strategy(
+            &self,
+            value: nysa_types::U256,
+        ) -> nysa_types::U256 {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_compute_with_strategy(value);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_compute_with_strategy(
+            &self,
+            value: nysa_types::U256,
+        ) -> nysa_types::U256 {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::FunctionType => {
+                    return odra::UnwrapOrRevert::unwrap_or_revert_with(
+                        (match odra::UnwrapOrRevert::unwrap_or_revert_with(
+                            self.strategy.get_or_default(),
+                            &self.env(),
+                            Panic::ZeroFunction,
+                        ) {
+                            InternalFunction::Double => {
+                                Self::double
+                                    as fn(&Self, nysa_types::U256) -> nysa_types::U256
+                            }
+                            InternalFunction::Scale => {
+                                Self::scale
+                                    as fn(&Self, nysa_types::U256) -> nysa_types::U256
+                            }
+                        })(self, value)
+                            .checked_add(
+                                self
+                                    .apply(
+                                        match odra::UnwrapOrRevert::unwrap_or_revert_with(
+                                            self.strategy.get_or_default(),
+                                            &self.env(),
+                                            Panic::ZeroFunction,
+                                        ) {
+                                            InternalFunction::Double => {
+                                                Self::double
+                                                    as fn(&Self, nysa_types::U256) -> nysa_types::U256
+                                            }
+                                            InternalFunction::Scale => {
+                                                Self::scale
+                                                    as fn(&Self, nysa_types::U256) -> nysa_types::U256
+                                            }
+                                        },
+                                        nysa_types::U256::ONE,
+                                    ),
+                            ),
+                        &self.env(),
+                        Panic::ArithmeticOverflow,
+                    );
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_compute_with_strategy(value),
+            }
+        }
+        pub(crate) fn double(&self, value: nysa_types::U256) -> nysa_types::U256 {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_double(value);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_double(&self, value: nysa_types::U256) -> nysa_types::U256 {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::FunctionType => {
+                    return odra::UnwrapOrRevert::unwrap_or_revert_with(
+                        value
+                            .checked_mul(
+                                nysa_types::U256::from_limbs([2u64, 0u64, 0u64, 0u64]),
+                            ),
+                        &self.env(),
+                        Panic::ArithmeticOverflow,
+                    );
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_double(value),
+            }
+        }
+        #[odra(init)]
+        pub fn init(&mut self) {
+            self.fallback_strategy.set(Some(InternalFunction::Double));
+        }
+        pub fn reset_strategy(&mut self) {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_reset_strategy();
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_reset_strategy(&mut self) {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::FunctionType => {
+                    self.strategy.set(Default::default());
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_reset_strategy(),
+            }
+        }
+        pub(crate) fn scale(&self, value: nysa_types::U256) -> nysa_types::U256 {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_scale(value);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_scale(&self, value: nysa_types::U256) -> nysa_types::U256 {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::FunctionType => {
+                    return odra::UnwrapOrRevert::unwrap_or_revert_with(
+                        value.checked_mul(self.factor.get_or_default()),
+                        &self.env(),
+                        Panic::ArithmeticOverflow,
+                    );
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_scale(value),
+            }
+        }
+        pub fn set_strategy(&mut self, scaled: bool) {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_set_strategy(scaled);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_set_strategy(&mut self, scaled: bool) {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::FunctionType => {
+                    if scaled {
+                        self.strategy.set(Some(InternalFunction::Scale));
+                    } else {
+                        self.strategy.set(self.fallback_strategy.get_or_default());
+                    }
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_set_strategy(scaled),
+            }
+        }
+    }
+}
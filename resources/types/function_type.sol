@@ -0,0 +1,47 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.19;
+
+contract FunctionType {
+    uint256 factor;
+    function(uint256) internal view returns (uint256) strategy;
+    function(uint256) internal view returns (uint256) fallbackStrategy = double;
+
+    function double(uint256 value) internal pure returns (uint256) {
+        return value * 2;
+    }
+
+    function scale(uint256 value) internal view returns (uint256) {
+        return value * factor;
+    }
+
+    function apply(
+        function(uint256) internal view returns (uint256) op,
+        uint256 value
+    ) internal view returns (uint256) {
+        return op(value);
+    }
+
+    function compute(uint256 value, bool scaled) public view returns (uint256) {
+        function(uint256) internal view returns (uint256) op = double;
+        if (scaled) {
+            op = scale;
+        }
+        return apply(op, value) + apply(double, 1) + op(1);
+    }
+
+    function setStrategy(bool scaled) public {
+        if (scaled) {
+            strategy = scale;
+        } else {
+            strategy = fallbackStrategy;
+        }
+    }
+
+    function resetStrategy() public {
+        delete strategy;
+    }
+
+    function computeWithStrategy(uint256 value) public view returns (uint256) {
+        return strategy(value) + apply(strategy, 1);
+    }
+}
@@ -0,0 +1,141 @@
+{{DEFAULT_MODULES}}
+pub mod literals {
+    #![allow(unused_braces, unused_mut, unused_parens, non_snake_case, unused_imports)]
+    {{DEFAULT_IMPORTS}}
+    {{STACK_DEF}}
+
+    #[derive(Clone)]
+    enum ClassName {
+        Literals,
+    }
+    #[odra::module]
+    pub struct Literals {
+        __stack: PathStack,
+    }
+    #[odra::module]
+    impl Literals {
+        const PATH: &'static [ClassName; 1usize] = &[ClassName::Literals];
+        pub const MIN: nysa_types::I256 = nysa_types::I256::from_limbs([
+            0u64,
+            0u64,
+            0u64,
+            9223372036854775808u64,
+        ]);
+        pub const MAX: nysa_types::U256 = nysa_types::U256::from_limbs([
+            18446744073709551615u64,
+            18446744073709551615u64,
+            18446744073709551615u64,
+            18446744073709551615u64,
+        ]);
+        pub const OFFSET: nysa_types::I64 = nysa_types::I64::from_limbs([
+            18446744073709550616u64,
+        ]);
+        pub fn bounds(&self) -> (nysa_types::I256, nysa_types::U256) {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_bounds();
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_bounds(&self) -> (nysa_types::I256, nysa_types::U256) {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::Literals => {
+                    return (Self::MIN, Self::MAX);
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_bounds(),
+            }
+        }
+        #[odra(init)]
+        pub fn init(&mut self) {}
+        pub fn mask(&self) -> nysa_types::U256 {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_mask();
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_mask(&self) -> nysa_types::U256 {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::Literals => {
+                    let mut m = nysa_types::U256::from_limbs([
+                        18446744073709551615u64,
+                        4294967295u64,
+                        0u64,
+                        0u64,
+                    ]);
+                    return m;
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_mask(),
+            }
+        }
+        pub fn positive(&self, a: nysa_types::I256) -> bool {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_positive(a);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_positive(&self, a: nysa_types::I256) -> bool {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::Literals => {
+                    return a > nysa_types::I256::MINUS_ONE;
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_positive(a),
+            }
+        }
+        pub fn shifts(
+            &self,
+            v: nysa_types::I8,
+            x: nysa_types::U8,
+            n: nysa_types::U8,
+        ) -> (nysa_types::I8, nysa_types::U8, nysa_types::FixedBytes<32usize>) {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_shifts(v, x, n);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_shifts(
+            &self,
+            v: nysa_types::I8,
+            x: nysa_types::U8,
+            n: nysa_types::U8,
+        ) -> (nysa_types::I8, nysa_types::U8, nysa_types::FixedBytes<32usize>) {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::Literals => {
+                    let mut empty = nysa_types::FixedBytes::<32usize>::ZERO;
+                    let mut w = v;
+                    w = w >> nysa_types::U256::from_limbs([200u64, 0u64, 0u64, 0u64]);
+                    return (
+                        w >> nysa_types::U256::from_limbs([200u64, 0u64, 0u64, 0u64]),
+                        x << n,
+                        empty,
+                    );
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_shifts(v, x, n),
+            }
+        }
+        pub fn small(&self) -> (nysa_types::U8, nysa_types::I8, nysa_types::I16) {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_small();
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_small(&self) -> (nysa_types::U8, nysa_types::I8, nysa_types::I16) {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::Literals => {
+                    let mut y = nysa_types::U8::from_limbs([255u64]);
+                    let mut z = nysa_types::I8::from_limbs([128u64]);
+                    return (y, z, nysa_types::I16::from_limbs([65236u64]));
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_small(),
+            }
+        }
+    }
+}
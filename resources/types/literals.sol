@@ -0,0 +1,34 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.0;
+
+contract Literals {
+    int256 constant MIN = -57896044618658097711785492504343953926634992332820282019728792003956564819968;
+    uint256 constant MAX = 115792089237316195423570985008687907853269984665640564039457584007913129639935;
+    int64 constant OFFSET = -1000;
+
+    function small() public pure returns (uint8, int8, int16) {
+        uint8 y = 255;
+        int8 z = -128;
+        return (y, z, -300);
+    }
+
+    function mask() public pure returns (uint256) {
+        uint256 m = 0xffffffffffffffffffffffff;
+        return m;
+    }
+
+    function positive(int256 a) public pure returns (bool) {
+        return a > -1;
+    }
+
+    function bounds() public pure returns (int256, uint256) {
+        return (MIN, MAX);
+    }
+
+    function shifts(int8 v, uint8 x, uint8 n) public pure returns (int8, uint8, bytes32) {
+        bytes32 empty = 0;
+        int8 w = v;
+        w >>= 200;
+        return (w >> 200, x << n, empty);
+    }
+}
@@ -0,0 +1,367 @@
+pub mod errors {
+    {{PANIC_DEF}}
+}
+pub mod events {
+    use odra::prelude::*;
+}
+pub mod enums {}
+pub mod structs {
+    pub mod mapping {
+        #[derive(odra::OdraType, Clone, PartialEq, Eq, Debug, Default)]
+        pub struct Position {
+            pub size: nysa_types::U256,
+            pub owner: Option<odra::Address>,
+        }
+    }
+}
+pub mod mapping {
+    #![allow(unused_braces, unused_mut, unused_parens, non_snake_case, unused_imports)]
+    {{DEFAULT_IMPORTS}}
+    {{STACK_DEF}}
+
+    #[derive(Clone)]
+    enum ClassName {
+        Mapping,
+    }
+    #[odra::module]
+    pub struct Mapping {
+        __stack: PathStack,
+        allowances: odra::Mapping<
+            (Option<odra::Address>, Option<odra::Address>),
+            nysa_types::U256,
+        >,
+        deposits: odra::Mapping<
+            Option<odra::Address>,
+            odra::prelude::vec::Vec<nysa_types::U256>,
+        >,
+        positions: odra::Var<odra::prelude::vec::Vec<mapping::Position>>,
+    }
+    #[odra::module]
+    impl Mapping {
+        const PATH: &'static [ClassName; 1usize] = &[ClassName::Mapping];
+        pub(crate) fn _reduce(
+            &mut self,
+            _allowed_key_0: Option<odra::Address>,
+            spender: Option<odra::Address>,
+            amount: nysa_types::U256,
+        ) {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super__reduce(_allowed_key_0, spender, amount);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super__reduce(
+            &mut self,
+            _allowed_key_0: Option<odra::Address>,
+            spender: Option<odra::Address>,
+            amount: nysa_types::U256,
+        ) {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::Mapping => {
+                    self.allowances
+                        .set(
+                            &(_allowed_key_0, spender),
+                            odra::UnwrapOrRevert::unwrap_or_revert_with(
+                                self
+                                    .allowances
+                                    .get_or_default(&(_allowed_key_0, spender))
+                                    .checked_sub(amount),
+                                &self.env(),
+                                Panic::ArithmeticOverflow,
+                            ),
+                        );
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super__reduce(_allowed_key_0, spender, amount),
+            }
+        }
+        pub fn approve(
+            &mut self,
+            spender: Option<odra::Address>,
+            amount: nysa_types::U256,
+        ) {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_approve(spender, amount);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_approve(
+            &mut self,
+            spender: Option<odra::Address>,
+            amount: nysa_types::U256,
+        ) {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::Mapping => {
+                    self.allowances.set(&(Some(self.env().caller()), spender), amount);
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_approve(spender, amount),
+            }
+        }
+        pub fn deposit(&mut self, amount: nysa_types::U256) {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_deposit(amount);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_deposit(&mut self, amount: nysa_types::U256) {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::Mapping => {
+                    {
+                        let mut result = self
+                            .deposits
+                            .get_or_default(&Some(self.env().caller()));
+                        result.push(amount);
+                        self.deposits.set(&Some(self.env().caller()), result);
+                    };
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_deposit(amount),
+            }
+        }
+        pub fn deposit_count(&self, owner: Option<odra::Address>) -> nysa_types::U256 {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_deposit_count(owner);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_deposit_count(&self, owner: Option<odra::Address>) -> nysa_types::U256 {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::Mapping => {
+                    return nysa_types::U256::from(self.deposits.get_or_default(&owner).len());
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_deposit_count(owner),
+            }
+        }
+        pub fn get_deposit(
+            &self,
+            owner: Option<odra::Address>,
+            i: nysa_types::U256,
+        ) -> nysa_types::U256 {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_get_deposit(owner, i);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_get_deposit(
+            &self,
+            owner: Option<odra::Address>,
+            i: nysa_types::U256,
+        ) -> nysa_types::U256 {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::Mapping => {
+                    return (*odra::UnwrapOrRevert::unwrap_or_revert_with(
+                        self.deposits.get_or_default(&owner).get(odra::UnwrapOrRevert::unwrap_or_revert_with(
+                            i.checked_as_usize(),
+                            &self.env(),
+                            Panic::IndexOutOfBounds,
+                        )),
+                        &self.env(),
+                        Panic::IndexOutOfBounds,
+                    ));
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_get_deposit(owner, i),
+            }
+        }
+        pub fn grow(&mut self, i: nysa_types::U256, size: nysa_types::U256) {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_grow(i, size);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_grow(&mut self, i: nysa_types::U256, size: nysa_types::U256) {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::Mapping => {
+                    {
+                        let mut __value = (*odra::UnwrapOrRevert::unwrap_or_revert_with(
+                            self.positions.get_or_default().get(odra::UnwrapOrRevert::unwrap_or_revert_with(
+                                i.checked_as_usize(),
+                                &self.env(),
+                                Panic::IndexOutOfBounds,
+                            )),
+                            &self.env(),
+                            Panic::IndexOutOfBounds,
+                        ))
+                        .clone();
+                        __value
+                            .size = odra::UnwrapOrRevert::unwrap_or_revert_with(
+                            __value.size.checked_add(size),
+                            &self.env(),
+                            Panic::ArithmeticOverflow,
+                        );
+                        {
+                            let mut result = self.positions.get_or_default();
+                            (*odra::UnwrapOrRevert::unwrap_or_revert_with(
+                                result.get_mut(odra::UnwrapOrRevert::unwrap_or_revert_with(
+                                    i.checked_as_usize(),
+                                    &self.env(),
+                                    Panic::IndexOutOfBounds,
+                                )),
+                                &self.env(),
+                                Panic::IndexOutOfBounds,
+                            )) = __value;
+                            self.positions.set(result);
+                        };
+                    };
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_grow(i, size),
+            }
+        }
+        #[odra(init)]
+        pub fn init(&mut self) {}
+        pub fn open(&mut self, size: nysa_types::U256) {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_open(size);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_open(&mut self, size: nysa_types::U256) {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::Mapping => {
+                    {
+                        let mut result = self.positions.get_or_default();
+                        result.push(mapping::Position {
+                            size: size,
+                            owner: Some(self.env().caller()),
+                        });
+                        self.positions.set(result);
+                    };
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_open(size),
+            }
+        }
+        pub fn reset_deposit(
+            &mut self,
+            owner: Option<odra::Address>,
+            i: nysa_types::U256,
+        ) {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_reset_deposit(owner, i);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_reset_deposit(
+            &mut self,
+            owner: Option<odra::Address>,
+            i: nysa_types::U256,
+        ) {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::Mapping => {
+                    {
+                        let mut result = self.deposits.get_or_default(&owner);
+                        (*odra::UnwrapOrRevert::unwrap_or_revert_with(
+                            result.get_mut(odra::UnwrapOrRevert::unwrap_or_revert_with(
+                                i.checked_as_usize(),
+                                &self.env(),
+                                Panic::IndexOutOfBounds,
+                            )),
+                            &self.env(),
+                            Panic::IndexOutOfBounds,
+                        )) = nysa_types::U256::ZERO;
+                        self.deposits.set(&owner, result);
+                    };
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_reset_deposit(owner, i),
+            }
+        }
+        pub fn revoke(
+            &mut self,
+            owner: Option<odra::Address>,
+            spender: Option<odra::Address>,
+        ) {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_revoke(owner, spender);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_revoke(
+            &mut self,
+            owner: Option<odra::Address>,
+            spender: Option<odra::Address>,
+        ) {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::Mapping => {
+                    self.allowances.set(&(owner, spender), Default::default());
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_revoke(owner, spender),
+            }
+        }
+        pub fn spend(
+            &mut self,
+            owner: Option<odra::Address>,
+            spender: Option<odra::Address>,
+            amount: nysa_types::U256,
+        ) {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_spend(owner, spender, amount);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_spend(
+            &mut self,
+            owner: Option<odra::Address>,
+            spender: Option<odra::Address>,
+            amount: nysa_types::U256,
+        ) {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::Mapping => {
+                    let _allowed_key_0 = owner;
+                    self.allowances
+                        .set(
+                            &(_allowed_key_0, spender),
+                            odra::UnwrapOrRevert::unwrap_or_revert_with(
+                                self
+                                    .allowances
+                                    .get_or_default(&(_allowed_key_0, spender))
+                                    .checked_sub(amount),
+                                &self.env(),
+                                Panic::ArithmeticOverflow,
+                            ),
+                        );
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_spend(owner, spender, amount),
+            }
+        }
+        pub fn spend_own(
+            &mut self,
+            spender: Option<odra::Address>,
+            amount: nysa_types::U256,
+        ) {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_spend_own(spender, amount);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_spend_own(
+            &mut self,
+            spender: Option<odra::Address>,
+            amount: nysa_types::U256,
+        ) {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::Mapping => {
+                    self._reduce(Some(self.env().caller()), spender, amount);
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_spend_own(spender, amount),
+            }
+        }
+    }
+}
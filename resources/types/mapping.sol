@@ -0,0 +1,58 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.19;
+
+contract Mapping {
+    struct Position {
+        uint256 size;
+        address owner;
+    }
+
+    mapping(address => mapping(address => uint256)) allowances;
+    mapping(address => uint256[]) deposits;
+    Position[] positions;
+
+    function approve(address spender, uint256 amount) public {
+        allowances[msg.sender][spender] = amount;
+    }
+
+    function spend(address owner, address spender, uint256 amount) public {
+        mapping(address => uint256) storage allowed = allowances[owner];
+        allowed[spender] -= amount;
+    }
+
+    function spendOwn(address spender, uint256 amount) public {
+        _reduce(allowances[msg.sender], spender, amount);
+    }
+
+    function _reduce(mapping(address => uint256) storage allowed, address spender, uint256 amount) internal {
+        allowed[spender] -= amount;
+    }
+
+    function revoke(address owner, address spender) public {
+        delete allowances[owner][spender];
+    }
+
+    function deposit(uint256 amount) public {
+        deposits[msg.sender].push(amount);
+    }
+
+    function depositCount(address owner) public view returns (uint256) {
+        return deposits[owner].length;
+    }
+
+    function getDeposit(address owner, uint256 i) public view returns (uint256) {
+        return deposits[owner][i];
+    }
+
+    function resetDeposit(address owner, uint256 i) public {
+        deposits[owner][i] = 0;
+    }
+
+    function open(uint256 size) public {
+        positions.push(Position(size, msg.sender));
+    }
+
+    function grow(uint256 i, uint256 size) public {
+        positions[i].size += size;
+    }
+}
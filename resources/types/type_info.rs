@@ -0,0 +1,153 @@
+pub mod errors {
+    {{PANIC_DEF}}
+}
+pub mod events {
+    use odra::prelude::*;
+}
+pub mod enums {
+    #[derive(odra::OdraType, Clone, PartialEq, Eq, Debug, Default)]
+    pub enum Level {
+        #[default]
+        Low = 0u8,
+        Medium = 1u8,
+        High = 2u8,
+    }
+}
+pub mod structs {}
+pub mod ierc_165 {
+    #![allow(unused_imports)]
+    use odra::prelude::*;
+    #[odra::external_contract]
+    pub trait IERC165 {
+        fn supports_interface(
+            &self,
+            interface_id: nysa_types::FixedBytes<4usize>,
+        ) -> bool;
+    }
+}
+pub mod ierc_721_receiver {
+    #![allow(unused_imports)]
+    use odra::prelude::*;
+    #[odra::external_contract]
+    pub trait IERC721Receiver {
+        fn on_erc_721_received(
+            &mut self,
+            operator: Option<odra::Address>,
+            from: Option<odra::Address>,
+            token_id: nysa_types::U256,
+            data: nysa_types::Bytes,
+        ) -> nysa_types::FixedBytes<4usize>;
+    }
+}
+pub mod type_info {
+    #![allow(unused_braces, unused_mut, unused_parens, non_snake_case, unused_imports)]
+    use super::enums::*;
+    {{DEFAULT_IMPORTS}}
+    {{STACK_DEF}}
+
+    #[derive(Clone)]
+    enum ClassName {
+        TypeInfo,
+    }
+    #[odra::module]
+    pub struct TypeInfo {
+        __stack: PathStack,
+    }
+    #[odra::module]
+    impl TypeInfo {
+        const PATH: &'static [ClassName; 1usize] = &[ClassName::TypeInfo];
+        pub fn highest(&self) -> Level {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_highest();
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_highest(&self) -> Level {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::TypeInfo => {
+                    return Level::High;
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_highest(),
+            }
+        }
+        #[odra(init)]
+        pub fn init(&mut self) {}
+        pub fn limits(&self) -> nysa_types::U256 {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_limits();
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_limits(&self) -> nysa_types::U256 {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::TypeInfo => {
+                    let mut small = nysa_types::U8::MAX;
+                    let mut low = nysa_types::I64::MIN;
+                    return nysa_types::U256::MAX;
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_limits(),
+            }
+        }
+        pub fn lowest(&self) -> Level {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_lowest();
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_lowest(&self) -> Level {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::TypeInfo => {
+                    return Level::Low;
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_lowest(),
+            }
+        }
+        pub fn name(&self) -> odra::prelude::string::String {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_name();
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_name(&self) -> odra::prelude::string::String {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::TypeInfo => {
+                    return odra::prelude::string::String::from("TypeInfo");
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_name(),
+            }
+        }
+        pub fn supports_interface(
+            &self,
+            interface_id: nysa_types::FixedBytes<4usize>,
+        ) -> bool {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_supports_interface(interface_id);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_supports_interface(
+            &self,
+            interface_id: nysa_types::FixedBytes<4usize>,
+        ) -> bool {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::TypeInfo => {
+                    return interface_id
+                        == nysa_types::FixedBytes([1u8, 255u8, 201u8, 167u8])
+                        || interface_id
+                            == nysa_types::FixedBytes([21u8, 11u8, 122u8, 2u8]);
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_supports_interface(interface_id),
+            }
+        }
+    }
+}
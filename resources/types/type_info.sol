@@ -0,0 +1,40 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.19;
+
+interface IERC165 {
+    function supportsInterface(bytes4 interfaceId) external view returns (bool);
+}
+
+interface IERC721Receiver {
+    function onERC721Received(address operator, address from, uint256 tokenId, bytes calldata data) external returns (bytes4);
+}
+
+contract TypeInfo is IERC165 {
+    enum Level {
+        Low,
+        Medium,
+        High
+    }
+
+    function supportsInterface(bytes4 interfaceId) public view returns (bool) {
+        return interfaceId == type(IERC165).interfaceId || interfaceId == type(IERC721Receiver).interfaceId;
+    }
+
+    function limits() public pure returns (uint256) {
+        uint8 small = type(uint8).max;
+        int64 low = type(int64).min;
+        return type(uint256).max;
+    }
+
+    function highest() public pure returns (Level) {
+        return type(Level).max;
+    }
+
+    function lowest() public pure returns (Level) {
+        return type(Level).min;
+    }
+
+    function name() public pure returns (string memory) {
+        return type(TypeInfo).name;
+    }
+}
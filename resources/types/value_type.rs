@@ -0,0 +1,179 @@
+pub mod errors {
+    {{PANIC_DEF}}
+}
+pub mod events {
+    use odra::prelude::*;
+}
+pub mod enums {}
+pub mod structs {
+    #[derive(Clone, Copy, Debug, Default)]
+    pub struct Price(pub nysa_types::U128);
+    nysa_types::impl_value_type!(Price, nysa_types::U128);
+    impl core::ops::Add for Price {
+        type Output = Self;
+        fn add(self, rhs: Self) -> Self::Output {
+            super::free_functions::FreeFunctions::add(self, rhs)
+        }
+    }
+    impl core::ops::Sub for Price {
+        type Output = Self;
+        fn sub(self, rhs: Self) -> Self::Output {
+            super::free_functions::FreeFunctions::sub(self, rhs)
+        }
+    }
+    impl PartialEq for Price {
+        fn eq(&self, other: &Self) -> bool {
+            super::free_functions::FreeFunctions::eq(*self, *other)
+        }
+    }
+    impl PartialOrd for Price {
+        fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+            self.0.partial_cmp(&other.0)
+        }
+        fn lt(&self, other: &Self) -> bool {
+            super::free_functions::FreeFunctions::lt(*self, *other)
+        }
+    }
+    pub mod shop {
+        #[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+        pub struct Discount(pub nysa_types::U8);
+        nysa_types::impl_value_type!(Discount, nysa_types::U8);
+    }
+}
+pub mod free_functions {
+    #![allow(unused_braces, unused_mut, unused_parens, non_snake_case, unused_imports)]
+    {{DEFAULT_IMPORTS}}
+    {{STACK_DEF}}
+
+    #[derive(Clone)]
+    enum ClassName {
+        FreeFunctions,
+    }
+    #[odra::module]
+    pub struct FreeFunctions {
+        __stack: PathStack,
+    }
+    #[odra::module]
+    impl FreeFunctions {
+        const PATH: &'static [ClassName; 1usize] = &[ClassName::FreeFunctions];
+        pub(crate) fn add(a: Price, b: Price) -> Price {
+            return Price(
+                nysa_types::unwrap_or_panic(
+                    a.0.checked_add(b.0),
+                    Panic::ArithmeticOverflow,
+                ),
+            );
+        }
+        pub(crate) fn eq(a: Price, b: Price) -> bool {
+            return a.0 == b.0;
+        }
+        pub(crate) fn lt(a: Price, b: Price) -> bool {
+            return a.0 < b.0;
+        }
+        pub(crate) fn sub(a: Price, b: Price) -> Price {
+            return Price(
+                nysa_types::unwrap_or_panic(
+                    a.0.checked_sub(b.0),
+                    Panic::ArithmeticOverflow,
+                ),
+            );
+        }
+    }
+}
+pub mod shop {
+    #![allow(unused_braces, unused_mut, unused_parens, non_snake_case, unused_imports)]
+    {{DEFAULT_IMPORTS}}
+    {{STACK_DEF}}
+
+    #[derive(Clone)]
+    enum ClassName {
+        Shop,
+    }
+    #[odra::module]
+    pub struct Shop {
+        __stack: PathStack,
+        base_price: odra::Var<Price>,
+        discount: odra::Var<shop::Discount>,
+    }
+    #[odra::module]
+    impl Shop {
+        const PATH: &'static [ClassName; 1usize] = &[ClassName::Shop];
+        pub fn final_price(&self) -> nysa_types::U128 {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_final_price();
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_final_price(&self) -> nysa_types::U128 {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::Shop => {
+                    let mut reduced = (self.base_price.get_or_default()
+                        - Price(
+                            <nysa_types::U128 as nysa_types::ConvertFrom<
+                                _,
+                            >>::convert_from(self.discount.get_or_default().0),
+                        ));
+                    if reduced < Price(nysa_types::U128::from_limbs([10u64, 0u64])) {
+                        return nysa_types::U128::from_limbs([10u64, 0u64]);
+                    }
+                    return reduced.0;
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_final_price(),
+            }
+        }
+        #[odra(init)]
+        pub fn init(&mut self) {
+            self.base_price.set(Price(nysa_types::U128::from_limbs([100u64, 0u64])));
+        }
+        pub fn is_base(&self, value: Price) -> bool {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_is_base(value);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_is_base(&self, value: Price) -> bool {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::Shop => {
+                    return value == self.base_price.get_or_default();
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_is_base(value),
+            }
+        }
+        pub fn raise(&mut self, value: Price) {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_raise(value);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_raise(&mut self, value: Price) {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::Shop => {
+                    self.base_price.set((self.base_price.get_or_default() + value));
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_raise(value),
+            }
+        }
+        pub fn set_discount(&mut self, value: nysa_types::U8) {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_set_discount(value);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_set_discount(&mut self, value: nysa_types::U8) {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::Shop => {
+                    self.discount.set(shop::Discount(value));
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_set_discount(value),
+            }
+        }
+    }
+}
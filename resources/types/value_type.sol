@@ -0,0 +1,53 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.19;
+
+type Price is uint128;
+
+using {add as +, sub as -, eq as ==, lt as <} for Price global;
+
+function add(Price a, Price b) pure returns (Price) {
+    return Price.wrap(Price.unwrap(a) + Price.unwrap(b));
+}
+
+function sub(Price a, Price b) pure returns (Price) {
+    return Price.wrap(Price.unwrap(a) - Price.unwrap(b));
+}
+
+function eq(Price a, Price b) pure returns (bool) {
+    return Price.unwrap(a) == Price.unwrap(b);
+}
+
+function lt(Price a, Price b) pure returns (bool) {
+    return Price.unwrap(a) < Price.unwrap(b);
+}
+
+contract Shop {
+    type Discount is uint8;
+
+    Price public basePrice;
+    Discount public discount;
+
+    constructor() {
+        basePrice = Price.wrap(100);
+    }
+
+    function raise(Price value) public {
+        basePrice = basePrice + value;
+    }
+
+    function setDiscount(uint8 value) public {
+        discount = Discount.wrap(value);
+    }
+
+    function finalPrice() public view returns (uint128) {
+        Price reduced = basePrice - Price.wrap(uint128(Discount.unwrap(discount)));
+        if (reduced < Price.wrap(10)) {
+            return 10;
+        }
+        return Price.unwrap(reduced);
+    }
+
+    function isBase(Price value) public view returns (bool) {
+        return value == basePrice;
+    }
+}
@@ -0,0 +1,228 @@
+{{DEFAULT_MODULES}}
+pub mod widening {
+    #![allow(unused_braces, unused_mut, unused_parens, non_snake_case, unused_imports)]
+    {{DEFAULT_IMPORTS}}
+    {{STACK_DEF}}
+
+    #[derive(Clone)]
+    enum ClassName {
+        Widening,
+    }
+    #[odra::module]
+    pub struct Widening {
+        __stack: PathStack,
+        total: odra::Var<nysa_types::U256>,
+        scores: odra::Mapping<nysa_types::U256, nysa_types::U16>,
+    }
+    #[odra::module]
+    impl Widening {
+        const PATH: &'static [ClassName; 1usize] = &[ClassName::Widening];
+        pub fn add(&self, a: nysa_types::U8, b: nysa_types::U64) -> nysa_types::U64 {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_add(a, b);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_add(&self, a: nysa_types::U8, b: nysa_types::U64) -> nysa_types::U64 {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::Widening => {
+                    return odra::UnwrapOrRevert::unwrap_or_revert_with(
+                        <nysa_types::U64 as nysa_types::ConvertFrom<_>>::convert_from(a)
+                            .checked_add(b),
+                        &self.env(),
+                        Panic::ArithmeticOverflow,
+                    );
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_add(a, b),
+            }
+        }
+        pub fn assign(
+            &mut self,
+            a: nysa_types::U8,
+            b: nysa_types::U128,
+        ) -> nysa_types::U256 {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_assign(a, b);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_assign(
+            &mut self,
+            a: nysa_types::U8,
+            b: nysa_types::U128,
+        ) -> nysa_types::U256 {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::Widening => {
+                    let mut sum = <nysa_types::U256 as nysa_types::ConvertFrom<
+                        _,
+                    >>::convert_from(a);
+                    sum = odra::UnwrapOrRevert::unwrap_or_revert_with(
+                        sum
+                            .checked_add(
+                                <nysa_types::U256 as nysa_types::ConvertFrom<
+                                    _,
+                                >>::convert_from(b),
+                            ),
+                        &self.env(),
+                        Panic::ArithmeticOverflow,
+                    );
+                    self.total
+                        .set(
+                            <nysa_types::U256 as nysa_types::ConvertFrom<
+                                _,
+                            >>::convert_from(a),
+                        );
+                    self.scores
+                        .set(
+                            &<nysa_types::U256 as nysa_types::ConvertFrom<
+                                _,
+                            >>::convert_from(a),
+                            <nysa_types::U16 as nysa_types::ConvertFrom<
+                                _,
+                            >>::convert_from(a),
+                        );
+                    return sum;
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_assign(a, b),
+            }
+        }
+        pub fn compare(&self, a: nysa_types::U8, b: nysa_types::U256) -> bool {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_compare(a, b);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_compare(&self, a: nysa_types::U8, b: nysa_types::U256) -> bool {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::Widening => {
+                    return <nysa_types::U256 as nysa_types::ConvertFrom<
+                        _,
+                    >>::convert_from(a) < b;
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_compare(a, b),
+            }
+        }
+        pub fn extend(
+            &self,
+            selector: nysa_types::FixedBytes<4usize>,
+        ) -> nysa_types::FixedBytes<32usize> {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_extend(selector);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_extend(
+            &self,
+            selector: nysa_types::FixedBytes<4usize>,
+        ) -> nysa_types::FixedBytes<32usize> {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::Widening => {
+                    let mut word = <nysa_types::FixedBytes<
+                        32usize,
+                    > as nysa_types::ConvertFrom<_>>::convert_from(selector);
+                    return word;
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_extend(selector),
+            }
+        }
+        #[odra(init)]
+        pub fn init(&mut self) {}
+        pub fn pick(
+            &self,
+            flag: bool,
+            a: nysa_types::U8,
+            b: nysa_types::U32,
+        ) -> nysa_types::U32 {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_pick(flag, a, b);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_pick(
+            &self,
+            flag: bool,
+            a: nysa_types::U8,
+            b: nysa_types::U32,
+        ) -> nysa_types::U32 {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::Widening => {
+                    return if flag {
+                        <nysa_types::U32 as nysa_types::ConvertFrom<_>>::convert_from(a)
+                    } else {
+                        b
+                    };
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_pick(flag, a, b),
+            }
+        }
+        pub fn score(&self, a: nysa_types::U8) -> nysa_types::U256 {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_score(a);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_score(&self, a: nysa_types::U8) -> nysa_types::U256 {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::Widening => {
+                    return <nysa_types::U256 as nysa_types::ConvertFrom<
+                        _,
+                    >>::convert_from(
+                        self
+                            .scores
+                            .get_or_default(
+                                &<nysa_types::U256 as nysa_types::ConvertFrom<
+                                    _,
+                                >>::convert_from(a),
+                            ),
+                    );
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_score(a),
+            }
+        }
+        pub fn signed(
+            &self,
+            a: nysa_types::U8,
+            b: nysa_types::I8,
+        ) -> (nysa_types::I16, nysa_types::I16) {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_signed(a, b);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_signed(
+            &self,
+            a: nysa_types::U8,
+            b: nysa_types::I8,
+        ) -> (nysa_types::I16, nysa_types::I16) {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::Widening => {
+                    return (
+                        <nysa_types::I16 as nysa_types::ConvertFrom<
+                            _,
+                        >>::convert_from(
+                            <nysa_types::U16 as nysa_types::ConvertFrom<
+                                _,
+                            >>::convert_from(a),
+                        ),
+                        <nysa_types::I16 as nysa_types::ConvertFrom<_>>::convert_from(b),
+                    );
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_signed(a, b),
+            }
+        }
+    }
+}
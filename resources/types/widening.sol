@@ -0,0 +1,40 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.0;
+
+contract Widening {
+    uint256 total;
+    mapping(uint256 => uint16) scores;
+
+    function assign(uint8 a, uint128 b) public returns (uint256) {
+        uint256 sum = a;
+        sum += b;
+        total = a;
+        scores[a] = a;
+        return sum;
+    }
+
+    function compare(uint8 a, uint256 b) public pure returns (bool) {
+        return a < b;
+    }
+
+    function add(uint8 a, uint64 b) public pure returns (uint64) {
+        return a + b;
+    }
+
+    function pick(bool flag, uint8 a, uint32 b) public pure returns (uint32) {
+        return flag ? a : b;
+    }
+
+    function signed(uint8 a, int8 b) public pure returns (int16, int16) {
+        return (a, b);
+    }
+
+    function extend(bytes4 selector) public pure returns (bytes32) {
+        bytes32 word = selector;
+        return word;
+    }
+
+    function score(uint8 a) public view returns (uint256) {
+        return scores[a];
+    }
+}
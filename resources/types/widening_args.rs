@@ -0,0 +1,132 @@
+pub mod errors {
+    {{PANIC_DEF}}
+    #[derive(odra::OdraError, PartialEq, Eq, Debug)]
+    pub enum Error {
+        TooLow = 65099isize,
+    }
+}
+pub mod events {
+    use odra::prelude::*;
+    #[derive(odra::Event, PartialEq, Eq, Debug)]
+    pub struct Scored {
+        owner: Option<odra::Address>,
+        score: nysa_types::U256,
+    }
+    impl Scored {
+        pub fn new(owner: Option<odra::Address>, score: nysa_types::U256) -> Self {
+            Self { owner, score }
+        }
+    }
+}
+pub mod enums {}
+pub mod structs {
+    pub mod widening_args {
+        #[derive(odra::OdraType, Clone, PartialEq, Eq, Debug, Default)]
+        pub struct Entry {
+            pub score: nysa_types::U256,
+            pub owner: Option<odra::Address>,
+        }
+    }
+}
+pub mod i_scores {
+    #![allow(unused_imports)]
+    use odra::prelude::*;
+    #[odra::external_contract]
+    pub trait IScores {
+        fn record(&mut self, score: nysa_types::U256);
+    }
+}
+pub mod widening_args {
+    #![allow(unused_braces, unused_mut, unused_parens, non_snake_case, unused_imports)]
+    use super::i_scores::*;
+    {{DEFAULT_IMPORTS}}
+    {{STACK_DEF}}
+
+    #[derive(Clone)]
+    enum ClassName {
+        WideningArgs,
+    }
+    #[odra::module(events = [Scored])]
+    pub struct WideningArgs {
+        __stack: PathStack,
+        total: odra::Var<nysa_types::U256>,
+        history: odra::Var<odra::prelude::vec::Vec<nysa_types::U256>>,
+        entries: odra::Var<odra::prelude::vec::Vec<widening_args::Entry>>,
+        registry: odra::Var<Option<odra::Address>>,
+    }
+    #[odra::module]
+    impl WideningArgs {
+        const PATH: &'static [ClassName; 1usize] = &[ClassName::WideningArgs];
+        pub(crate) fn _store(&mut self, value: nysa_types::U256) {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super__store(value);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super__store(&mut self, value: nysa_types::U256) {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::WideningArgs => {
+                    self.total.set(value);
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super__store(value),
+            }
+        }
+        pub fn collect(&mut self, a: nysa_types::U8, b: nysa_types::U16) {
+            self.__stack.push_path_on_stack(Self::PATH);
+            let result = self.super_collect(a, b);
+            self.__stack.drop_one_from_stack();
+            result
+        }
+        fn super_collect(&mut self, a: nysa_types::U8, b: nysa_types::U16) {
+            let __class = self.__stack.pop_from_top_path();
+            match __class {
+                ClassName::WideningArgs => {
+                    self.env().emit_event(Scored::new(
+                        Some(self.env().caller()),
+                        <nysa_types::U256 as nysa_types::ConvertFrom<_>>::convert_from(a),
+                    ));
+                    {
+                        let mut result = self.history.get_or_default();
+                        result.push(
+                            <nysa_types::U256 as nysa_types::ConvertFrom<_>>::convert_from(a),
+                        );
+                        self.history.set(result);
+                    };
+                    let mut window = nysa_types::FixedArray([
+                        <nysa_types::U256 as nysa_types::ConvertFrom<_>>::convert_from(b),
+                        <nysa_types::U256 as nysa_types::ConvertFrom<_>>::convert_from(a),
+                        <nysa_types::U256 as nysa_types::ConvertFrom<_>>::convert_from(b),
+                    ]);
+                    {
+                        let mut result = self.entries.get_or_default();
+                        result.push(widening_args::Entry {
+                            score: <nysa_types::U256 as nysa_types::ConvertFrom<_>>::convert_from(
+                                a,
+                            ),
+                            owner: Some(self.env().caller()),
+                        });
+                        self.entries.set(result);
+                    };
+                    if <nysa_types::U16 as nysa_types::ConvertFrom<_>>::convert_from(a) < b {
+                        self.env().revert(Error::TooLow);
+                    }
+                    IScoresContractRef::new(
+                        self.env(),
+                        odra::UnwrapOrRevert::unwrap_or_revert(
+                            self.registry.get().unwrap_or(None),
+                            &self.env(),
+                        ),
+                    )
+                    .record(<nysa_types::U256 as nysa_types::ConvertFrom<_>>::convert_from(b));
+                    self._store(<nysa_types::U256 as nysa_types::ConvertFrom<_>>::convert_from(a));
+                }
+                #[allow(unreachable_patterns)]
+                _ => self.super_collect(a, b),
+            }
+        }
+        #[odra(init)]
+        pub fn init(&mut self) {}
+    }
+}
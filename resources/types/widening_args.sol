@@ -0,0 +1,35 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.0;
+
+interface IScores {
+    function record(uint256 score) external;
+}
+
+contract WideningArgs {
+    struct Entry {
+        uint256 score;
+        address owner;
+    }
+
+    event Scored(address indexed owner, uint256 score);
+    error TooLow(uint256 score, uint256 min);
+
+    uint256 total;
+    uint256[] history;
+    Entry[] entries;
+    IScores registry;
+
+    function collect(uint8 a, uint16 b) public {
+        emit Scored(msg.sender, a);
+        history.push(a);
+        uint256[3] memory window = [uint256(b), a, b];
+        entries.push(Entry(a, msg.sender));
+        if (a < b) revert TooLow(a, b);
+        registry.record(b);
+        _store(a);
+    }
+
+    function _store(uint256 value) internal {
+        total = value;
+    }
+}
@@ -72,6 +72,8 @@ pub enum Token<'input> {
 
     Uint(u16),
     Int(u16),
+    Fixed(u16, u8),
+    Ufixed(u16, u8),
     Bytes(u8),
     DynamicBytes,
     Bool,
@@ -172,6 +174,8 @@ pub enum Token<'input> {
     Virtual,
     Override,
     Using,
+    Type,
+    Global,
     Modifier,
     Immutable,
     Unchecked,
@@ -205,6 +209,8 @@ impl<'input> fmt::Display for Token<'input> {
             Token::HexNumber(n) => write!(f, "{}", n),
             Token::Uint(w) => write!(f, "uint{}", w),
             Token::Int(w) => write!(f, "int{}", w),
+            Token::Fixed(m, n) => write!(f, "fixed{}x{}", m, n),
+            Token::Ufixed(m, n) => write!(f, "ufixed{}x{}", m, n),
             Token::Bytes(w) => write!(f, "bytes{}", w),
             Token::DynamicBytes => write!(f, "bytes"),
             Token::Semicolon => write!(f, ";"),
@@ -318,6 +324,8 @@ impl<'input> fmt::Display for Token<'input> {
             Token::Virtual => write!(f, "virtual"),
             Token::Override => write!(f, "override"),
             Token::Using => write!(f, "using"),
+            Token::Type => write!(f, "type"),
+            Token::Global => write!(f, "global"),
             Token::Modifier => write!(f, "modifier"),
             Token::Immutable => write!(f, "immutable"),
             Token::Unchecked => write!(f, "unchecked"),
@@ -565,6 +573,8 @@ static KEYWORDS: phf::Map<&'static str, Token> = phf_map! {
     "virtual" => Token::Virtual,
     "override" => Token::Override,
     "using" => Token::Using,
+    "type" => Token::Type,
+    "global" => Token::Global,
     "modifier" => Token::Modifier,
     "immutable" => Token::Immutable,
     "unchecked" => Token::Unchecked,
@@ -572,6 +582,35 @@ static KEYWORDS: phf::Map<&'static str, Token> = phf_map! {
     "let" => Token::Let,
 };
 
+/// Matches `fixedMxN` and `ufixedMxN`, where `M` is a multiple of 8 up to 256 and `N` is at most 80.
+/// `fixed` and `ufixed` are aliases for `fixed128x18` and `ufixed128x18`.
+fn fixed_point_type<'input>(id: &str) -> Option<Token<'input>> {
+    let (is_signed, size) = if let Some(size) = id.strip_prefix("ufixed") {
+        (false, size)
+    } else if let Some(size) = id.strip_prefix("fixed") {
+        (true, size)
+    } else {
+        return None;
+    };
+    let (m, n) = if size.is_empty() {
+        (128, 18)
+    } else {
+        let (m, n) = size.split_once('x')?;
+        if m.starts_with('0') || (n.starts_with('0') && n.len() > 1) {
+            return None;
+        }
+        (m.parse::<u16>().ok()?, n.parse::<u8>().ok()?)
+    };
+    if m == 0 || m > 256 || m % 8 != 0 || n > 80 {
+        return None;
+    }
+    Some(if is_signed {
+        Token::Fixed(m, n)
+    } else {
+        Token::Ufixed(m, n)
+    })
+}
+
 impl<'input> Lexer<'input> {
     pub fn new(input: &'input str, file_no: usize, comments: &'input mut Vec<Comment>) -> Self {
         Lexer {
@@ -876,6 +915,8 @@ impl<'input> Lexer<'input> {
 
                     return if let Some(w) = KEYWORDS.get(id) {
                         Some(Ok((start, *w, end)))
+                    } else if let Some(w) = fixed_point_type(id) {
+                        Some(Ok((start, w, end)))
                     } else {
                         Some(Ok((start, Token::Identifier(id), end)))
                     };
@@ -1285,6 +1326,21 @@ fn lexertest() {
 
     assert_eq!(tokens, vec!(Ok((0, Token::Identifier("hex"), 3))));
 
+    let tokens = Lexer::new("fixed ufixed64x10 fixed8x0 fixed7x1 ufixed8x81 fixedx", 0, &mut comments)
+        .collect::<Vec<Result<(usize, Token, usize), LexicalError>>>();
+
+    assert_eq!(
+        tokens,
+        vec!(
+            Ok((0, Token::Fixed(128, 18), 5)),
+            Ok((6, Token::Ufixed(64, 10), 17)),
+            Ok((18, Token::Fixed(8, 0), 26)),
+            Ok((27, Token::Identifier("fixed7x1"), 35)),
+            Ok((36, Token::Identifier("ufixed8x81"), 46)),
+            Ok((47, Token::Identifier("fixedx"), 53)),
+        )
+    );
+
     let tokens = Lexer::new("hex\"cafe_dead\" /* adad*** */", 0, &mut comments)
         .collect::<Vec<Result<(usize, Token, usize), LexicalError>>>();
 
@@ -130,6 +130,8 @@ pub enum SourceUnitPart {
     ErrorDefinition(Box<ErrorDefinition>),
     FunctionDefinition(Box<FunctionDefinition>),
     VariableDefinition(Box<VariableDefinition>),
+    TypeDefinition(Box<TypeDefinition>),
+    Using(Box<Using>),
     StraySemicolon(Loc),
 }
 
@@ -149,6 +151,8 @@ pub enum Type {
     String,
     Int(u16),
     Uint(u16),
+    Fixed(u16, u8),
+    Ufixed(u16, u8),
     Bytes(u8),
     Rational,
     DynamicBytes,
@@ -213,15 +217,62 @@ pub enum ContractPart {
     ErrorDefinition(Box<ErrorDefinition>),
     VariableDefinition(Box<VariableDefinition>),
     FunctionDefinition(Box<FunctionDefinition>),
+    TypeDefinition(Box<TypeDefinition>),
     StraySemicolon(Loc),
     Using(Box<Using>),
 }
 
+/// A user-defined value type, eg. `type Price is uint128;`
+#[derive(Debug, PartialEq)]
+pub struct TypeDefinition {
+    pub doc: Vec<DocComment>,
+    pub loc: Loc,
+    pub name: Identifier,
+    pub ty: Expression,
+}
+
 #[derive(Debug, PartialEq)]
 pub struct Using {
     pub loc: Loc,
-    pub library: Identifier,
+    pub list: UsingList,
     pub ty: Option<Expression>,
+    pub global: bool,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum UsingList {
+    /// `using SafeMath for uint256;`
+    Library(Identifier),
+    /// `using {add as +, sub} for Price;`
+    Functions(Vec<UsingFunction>),
+}
+
+#[derive(Debug, PartialEq)]
+pub struct UsingFunction {
+    pub loc: Loc,
+    /// A free function or a library function, eg. `add` or `Math.add`.
+    pub path: Vec<Identifier>,
+    pub oper: Option<UserDefinedOperator>,
+}
+
+/// An operator that can be bound to a function with the `using` directive.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum UserDefinedOperator {
+    BitwiseAnd,
+    BitwiseOr,
+    BitwiseXor,
+    BitwiseNot,
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    Modulo,
+    Equal,
+    NotEqual,
+    Less,
+    LessEqual,
+    More,
+    MoreEqual,
 }
 
 #[derive(Debug, PartialEq, Clone)]
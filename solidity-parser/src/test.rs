@@ -1065,3 +1065,47 @@ fn parse_revert_test() {
 
     assert_eq!(actual_parse_tree, expected_parse_tree);
 }
+
+#[test]
+fn parse_user_defined_value_type_test() {
+    let src = "type Price is uint128;\nusing {add as +, Math.sub as -} for Price global;";
+
+    let mut comments = Vec::new();
+    let lex = lexer::Lexer::new(src, 0, &mut comments);
+    let actual_parse_tree = solidity::SourceUnitParser::new()
+        .parse(src, 0, lex)
+        .unwrap();
+
+    let id = |name: &str, l: usize, r: usize| Identifier {
+        loc: Loc::File(0, l, r),
+        name: name.to_string(),
+    };
+
+    let expected_parse_tree = SourceUnit(vec![
+        SourceUnitPart::TypeDefinition(Box::new(TypeDefinition {
+            doc: vec![],
+            loc: Loc::File(0, 0, 21),
+            name: id("Price", 5, 10),
+            ty: Expression::Type(Loc::File(0, 14, 21), Type::Uint(128)),
+        })),
+        SourceUnitPart::Using(Box::new(Using {
+            loc: Loc::File(0, 23, 71),
+            list: UsingList::Functions(vec![
+                UsingFunction {
+                    loc: Loc::File(0, 30, 38),
+                    path: vec![id("add", 30, 33)],
+                    oper: Some(UserDefinedOperator::Add),
+                },
+                UsingFunction {
+                    loc: Loc::File(0, 40, 53),
+                    path: vec![id("Math", 40, 44), id("sub", 45, 48)],
+                    oper: Some(UserDefinedOperator::Subtract),
+                },
+            ]),
+            ty: Some(Expression::Variable(id("Price", 59, 64))),
+            global: true,
+        })),
+    ]);
+
+    assert_eq!(actual_parse_tree, expected_parse_tree);
+}